@@ -0,0 +1,81 @@
+use criterion::{black_box, criterion_group, criterion_main, Bencher, Criterion};
+use rustis::{
+    client::Client,
+    commands::StringCommands,
+    resp::{BulkBytes, BulkString, RespBuf},
+};
+use std::time::Duration;
+
+const VALUE_SIZE: usize = 1024 * 1024;
+
+pub fn current_thread_runtime() -> tokio::runtime::Runtime {
+    let mut builder = tokio::runtime::Builder::new_current_thread();
+    builder.enable_io();
+    builder.enable_time();
+    builder.build().unwrap()
+}
+
+fn large_resp_buf() -> RespBuf {
+    let mut bytes = format!("${VALUE_SIZE}\r\n").into_bytes();
+    bytes.resize(bytes.len() + VALUE_SIZE, b'x');
+    bytes.extend_from_slice(b"\r\n");
+    RespBuf::from_slice(&bytes)
+}
+
+fn bench_decode_bulk_string(b: &mut Bencher) {
+    let resp_buf = large_resp_buf();
+    b.iter(|| {
+        let value: BulkString = resp_buf.to().unwrap();
+        black_box(value)
+    });
+}
+
+fn bench_decode_bulk_bytes(b: &mut Bencher) {
+    let resp_buf = large_resp_buf();
+    b.iter(|| {
+        let value: BulkBytes = resp_buf.to().unwrap();
+        black_box(value)
+    });
+}
+
+fn bench_get<V>(b: &mut Bencher)
+where
+    V: rustis::resp::PrimitiveResponse + serde::de::DeserializeOwned + Send,
+{
+    let runtime = current_thread_runtime();
+    let client = runtime.block_on(async {
+        let client = Client::connect("127.0.0.1:6379").await.unwrap();
+        client
+            .set("large_key", vec![b'x'; VALUE_SIZE])
+            .await
+            .unwrap();
+        client
+    });
+
+    b.iter(|| {
+        runtime.block_on(async {
+            let value: V = client.get("large_key").await.unwrap();
+            black_box(value)
+        })
+    });
+}
+
+fn bench_decode(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode_1mb_value");
+    group
+        .bench_function("bulk_string", bench_decode_bulk_string)
+        .bench_function("bulk_bytes", bench_decode_bulk_bytes);
+    group.finish();
+}
+
+fn bench_get_large_value(c: &mut Criterion) {
+    let mut group = c.benchmark_group("get_1mb_value");
+    group
+        .measurement_time(Duration::from_secs(10))
+        .bench_function("bulk_string", bench_get::<BulkString>)
+        .bench_function("bulk_bytes", bench_get::<BulkBytes>);
+    group.finish();
+}
+
+criterion_group!(bench, bench_decode, bench_get_large_value);
+criterion_main!(bench);
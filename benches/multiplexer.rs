@@ -1,6 +1,6 @@
 use criterion::{criterion_group, criterion_main, Bencher, Criterion};
 use futures_util::Future;
-use std::time::Duration;
+use std::{str::FromStr, time::Duration};
 
 pub fn current_thread_runtime() -> tokio::runtime::Runtime {
     let mut builder = tokio::runtime::Builder::new_current_thread();
@@ -9,6 +9,13 @@ pub fn current_thread_runtime() -> tokio::runtime::Runtime {
     builder.build().unwrap()
 }
 
+pub fn multi_thread_runtime() -> tokio::runtime::Runtime {
+    let mut builder = tokio::runtime::Builder::new_multi_thread();
+    builder.enable_io();
+    builder.enable_time();
+    builder.build().unwrap()
+}
+
 pub fn block_on_all<F>(f: F) -> F::Output
 where
     F: Future,
@@ -123,13 +130,48 @@ fn bench_rustis_parallel(b: &mut Bencher) {
     });
 }
 
+fn bench_rustis_multi_thread(b: &mut Bencher, write_coalesce: Option<Duration>) {
+    use rustis::{client::Config, commands::StringCommands};
+
+    let runtime = multi_thread_runtime();
+
+    let mut config = Config::from_str("127.0.0.1:6379").unwrap();
+    config.write_coalesce = write_coalesce;
+    let client = runtime.block_on(async { rustis::client::Client::connect(config).await.unwrap() });
+
+    b.iter(|| {
+        runtime.block_on(async {
+            let tasks: Vec<_> = (0..PARALLEL_QUERIES)
+                .map(|i| {
+                    let client = client.clone();
+                    tokio::spawn(async move {
+                        for _ in 0..ITERATIONS {
+                            let key = format!("key{i}");
+                            let value = format!("value{i}");
+                            let _ = client.set(key, value).await;
+                        }
+                    })
+                })
+                .collect();
+
+            futures_util::future::join_all(tasks).await;
+        })
+    });
+}
+
 fn bench_parallel(c: &mut Criterion) {
     let mut group = c.benchmark_group("parallel");
     group
         .measurement_time(Duration::from_secs(15))
         .bench_function("redis_parallel", bench_redis_parallel)
         .bench_function("fred_parallel", bench_fred_parallel)
-        .bench_function("rustis_parallel", bench_rustis_parallel);
+        .bench_function("rustis_parallel", bench_rustis_parallel)
+        .bench_function("rustis_multi_thread", |b| {
+            bench_rustis_multi_thread(b, None)
+        })
+        .bench_function("rustis_multi_thread_write_coalesce", |b| {
+            bench_rustis_multi_thread(b, Some(Duration::from_micros(50)))
+        });
     group.finish();
 }
 
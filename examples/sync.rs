@@ -0,0 +1,16 @@
+use rustis::{
+    client::{SyncClient, SyncPreparedCommand},
+    commands::{GenericCommands, StringCommands},
+    Result,
+};
+
+fn main() -> Result<()> {
+    let client = SyncClient::connect("127.0.0.1:6379")?;
+
+    client.set("key", "value").execute()?;
+    let value: String = client.get("key").execute()?;
+    println!("value: {value}");
+    client.del("key").execute()?;
+
+    client.close()
+}
@@ -0,0 +1,481 @@
+/*!
+Derive macros for [rustis](https://docs.rs/rustis), a Redis client for Rust.
+
+These macros are re-exported by rustis when its `derive` feature is activated:
+see [`ToRedisHash`](macro@ToRedisHash), [`FromRedisHash`](macro@FromRedisHash)
+and [`FromRedisValue`](macro@FromRedisValue).
+*/
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, spanned::Spanned, Attribute, Data, DeriveInput, Error, Fields, Ident,
+    LitStr, Type,
+};
+
+/// Generates the field/value arguments of the struct, to be used with
+/// [`hset`](https://docs.rs/rustis/latest/rustis/commands/trait.HashCommands.html#method.hset).
+///
+/// Implements `ToArgs` and `KeyValueArgsCollection` for the struct and a reference to it.
+/// `Option` fields are omitted when `None`.
+///
+/// # Attributes
+/// * `#[rustis(rename = "name")]` - hash field name, instead of the struct field name
+/// * `#[rustis(skip)]` - the field is not written in the hash
+#[proc_macro_derive(ToRedisHash, attributes(rustis))]
+pub fn derive_to_redis_hash(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    to_redis_hash(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Parses the reply of
+/// [`hgetall`](https://docs.rs/rustis/latest/rustis/commands/trait.HashCommands.html#method.hgetall)
+/// into the struct.
+///
+/// Implements `Deserialize` and `KeyValueCollectionResponse` for the struct.
+/// Missing hash fields are `None` for `Option` fields and an error otherwise.
+/// Unknown hash fields are ignored.
+///
+/// # Attributes
+/// * `#[rustis(rename = "name")]` - hash field name, instead of the struct field name
+/// * `#[rustis(skip)]` - the field is not read from the hash and is set to its default value
+#[proc_macro_derive(FromRedisHash, attributes(rustis))]
+pub fn derive_from_redis_hash(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_redis_hash(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Parses a string reply, e.g. of [`ROLE`](https://redis.io/commands/role/), into a C-like enum.
+///
+/// Implements `Deserialize` and `PrimitiveResponse` for the enum.
+/// A simple or bulk string matches the variant with the same name,
+/// an unknown value is an error unless a fallback variant is declared.
+///
+/// # Attributes
+/// * `#[redis(rename_all = "lowercase")]` on the enum - variant names are lowercase (or `"UPPERCASE"`)
+/// * `#[redis(rename = "name")]` on a variant - string value, instead of the variant name
+/// * `#[redis(other)]` on a variant with a single `String` field, e.g. `Unknown(String)`,
+///   receives the unknown values instead of failing
+///
+/// `#[rustis(...)]` is accepted as well, as for the other derive macros.
+#[proc_macro_derive(FromRedisValue, attributes(redis, rustis))]
+pub fn derive_from_redis_value(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_redis_value(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+struct HashField<'a> {
+    ident: &'a Ident,
+    ty: &'a Type,
+    name: String,
+    skip: bool,
+    is_option: bool,
+}
+
+fn hash_fields(input: &DeriveInput) -> syn::Result<Vec<HashField<'_>>> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "generic structs are not supported",
+        ));
+    }
+
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(input.span(), "only structs are supported"));
+    };
+
+    let Fields::Named(fields) = &data.fields else {
+        return Err(Error::new(
+            data.fields.span(),
+            "only structs with named fields are supported",
+        ));
+    };
+
+    fields
+        .named
+        .iter()
+        .map(|field| {
+            let ident = field.ident.as_ref().expect("named field");
+            let mut name = ident.to_string();
+            let mut skip = false;
+
+            for attr in field.attrs.iter().filter(|a| a.path().is_ident("rustis")) {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("rename") {
+                        name = meta.value()?.parse::<LitStr>()?.value();
+                        Ok(())
+                    } else if meta.path.is_ident("skip") {
+                        skip = true;
+                        Ok(())
+                    } else {
+                        Err(meta.error("expected `rename` or `skip`"))
+                    }
+                })?;
+            }
+
+            Ok(HashField {
+                ident,
+                ty: &field.ty,
+                name,
+                skip,
+                is_option: is_option(&field.ty),
+            })
+        })
+        .collect()
+}
+
+fn is_option(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    type_path
+        .path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "Option")
+}
+
+fn is_string(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    type_path.qself.is_none()
+        && type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "String" && segment.arguments.is_none())
+}
+
+fn to_redis_hash(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let fields = hash_fields(input)?;
+    let fields = fields.iter().filter(|f| !f.skip);
+
+    let mut write_args = Vec::new();
+    let mut num_args = Vec::new();
+
+    for field in fields {
+        let field_ident = field.ident;
+        let name = &field.name;
+        if field.is_option {
+            write_args.push(quote! {
+                if let ::std::option::Option::Some(value) = &self.#field_ident {
+                    args.arg(#name).arg_ref(value);
+                }
+            });
+            num_args.push(quote! { usize::from(self.#field_ident.is_some()) * 2 });
+        } else {
+            write_args.push(quote! {
+                args.arg(#name).arg_ref(&self.#field_ident);
+            });
+            num_args.push(quote! { 2 });
+        }
+    }
+
+    Ok(quote! {
+        impl ::rustis::resp::ToArgs for #ident {
+            fn write_args(&self, args: &mut ::rustis::resp::CommandArgs) {
+                #(#write_args)*
+            }
+
+            fn num_args(&self) -> usize {
+                0 #(+ #num_args)*
+            }
+        }
+
+        impl ::rustis::resp::ToArgs for &#ident {
+            fn write_args(&self, args: &mut ::rustis::resp::CommandArgs) {
+                ::rustis::resp::ToArgs::write_args(*self, args)
+            }
+
+            fn num_args(&self) -> usize {
+                ::rustis::resp::ToArgs::num_args(*self)
+            }
+        }
+
+        impl ::rustis::resp::KeyValueArgsCollection<&'static str, ::std::string::String>
+            for #ident
+        {
+        }
+
+        impl ::rustis::resp::KeyValueArgsCollection<&'static str, ::std::string::String>
+            for &#ident
+        {
+        }
+    })
+}
+
+fn from_redis_hash(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let expecting = format!("struct {ident}");
+    let fields = hash_fields(input)?;
+
+    let mut declarations = Vec::new();
+    let mut matches = Vec::new();
+    let mut initializers = Vec::new();
+
+    for (i, field) in fields.iter().enumerate() {
+        let field_ident = field.ident;
+        let ty = field.ty;
+        let name = &field.name;
+        let var = Ident::new(&format!("__field{i}"), field_ident.span());
+
+        if field.skip {
+            initializers.push(quote! { #field_ident: ::std::default::Default::default() });
+            continue;
+        }
+
+        declarations.push(quote! {
+            let mut #var: ::std::option::Option<#ty> = ::std::option::Option::None;
+        });
+        matches.push(quote! {
+            #name => #var = ::std::option::Option::Some(map.next_value::<#ty>()?),
+        });
+
+        if field.is_option {
+            initializers.push(quote! { #field_ident: #var.unwrap_or_default() });
+        } else {
+            initializers.push(quote! {
+                #field_ident: #var.ok_or_else(|| __serde::de::Error::missing_field(#name))?
+            });
+        }
+    }
+
+    Ok(quote! {
+        const _: () = {
+            use ::rustis::__private::serde as __serde;
+
+            impl<'de> __serde::Deserialize<'de> for #ident {
+                fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+                where
+                    D: __serde::Deserializer<'de>,
+                {
+                    struct Visitor;
+
+                    impl<'de> __serde::de::Visitor<'de> for Visitor {
+                        type Value = #ident;
+
+                        fn expecting(
+                            &self,
+                            formatter: &mut ::std::fmt::Formatter,
+                        ) -> ::std::fmt::Result {
+                            formatter.write_str(#expecting)
+                        }
+
+                        fn visit_map<A>(
+                            self,
+                            mut map: A,
+                        ) -> ::std::result::Result<Self::Value, A::Error>
+                        where
+                            A: __serde::de::MapAccess<'de>,
+                        {
+                            #(#declarations)*
+
+                            while let ::std::option::Option::Some(key) =
+                                map.next_key::<::std::string::String>()?
+                            {
+                                match key.as_str() {
+                                    #(#matches)*
+                                    _ => {
+                                        map.next_value::<__serde::de::IgnoredAny>()?;
+                                    }
+                                }
+                            }
+
+                            ::std::result::Result::Ok(#ident {
+                                #(#initializers,)*
+                            })
+                        }
+                    }
+
+                    deserializer.deserialize_map(Visitor)
+                }
+            }
+
+            impl ::rustis::resp::KeyValueCollectionResponse<
+                ::std::string::String,
+                ::rustis::resp::Value,
+            > for #ident
+            {
+            }
+        };
+    })
+}
+
+fn is_attribute(attr: &Attribute) -> bool {
+    attr.path().is_ident("redis") || attr.path().is_ident("rustis")
+}
+
+fn from_redis_value(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "generic enums are not supported",
+        ));
+    }
+
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new(input.span(), "only enums are supported"));
+    };
+
+    let mut rename_all: Option<String> = None;
+    for attr in input.attrs.iter().filter(|a| is_attribute(a)) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename_all") {
+                let value = meta.value()?.parse::<LitStr>()?;
+                match value.value().as_str() {
+                    "lowercase" | "UPPERCASE" => {
+                        rename_all = Some(value.value());
+                        Ok(())
+                    }
+                    _ => Err(Error::new(
+                        value.span(),
+                        "expected `\"lowercase\"` or `\"UPPERCASE\"`",
+                    )),
+                }
+            } else {
+                Err(meta.error("expected `rename_all`"))
+            }
+        })?;
+    }
+
+    let ident = &input.ident;
+    let mut names = Vec::new();
+    let mut arms = Vec::new();
+    let mut fallback: Option<&Ident> = None;
+
+    for variant in &data.variants {
+        let variant_ident = &variant.ident;
+        let mut name = match rename_all.as_deref() {
+            Some("lowercase") => variant_ident.to_string().to_lowercase(),
+            Some("UPPERCASE") => variant_ident.to_string().to_uppercase(),
+            _ => variant_ident.to_string(),
+        };
+        let mut other = false;
+
+        for attr in variant.attrs.iter().filter(|a| is_attribute(a)) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    name = meta.value()?.parse::<LitStr>()?.value();
+                    Ok(())
+                } else if meta.path.is_ident("other") {
+                    other = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `rename` or `other`"))
+                }
+            })?;
+        }
+
+        if other {
+            let field_type = match &variant.fields {
+                Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+                _ => {
+                    return Err(Error::new(
+                        variant.span(),
+                        "the `other` variant must have a single `String` field",
+                    ))
+                }
+            };
+            if !is_string(field_type) {
+                return Err(Error::new(
+                    field_type.span(),
+                    "the field of the `other` variant must be a `String`",
+                ));
+            }
+            if fallback.is_some() {
+                return Err(Error::new(
+                    variant.span(),
+                    "only one variant can be marked as `other`",
+                ));
+            }
+            fallback = Some(variant_ident);
+            continue;
+        }
+
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new(
+                variant.fields.span(),
+                "only unit variants are supported, except for the `other` variant",
+            ));
+        }
+
+        arms.push(quote! { #name => ::std::result::Result::Ok(#ident::#variant_ident), });
+        names.push(name);
+    }
+
+    let expecting = format!("one of the values of enum {ident}");
+    let unknown_arm = match fallback {
+        Some(variant_ident) => quote! {
+            _ => ::std::result::Result::Ok(#ident::#variant_ident(value.to_owned())),
+        },
+        None => quote! {
+            _ => ::std::result::Result::Err(__serde::de::Error::unknown_variant(value, VARIANTS)),
+        },
+    };
+
+    Ok(quote! {
+        const _: () = {
+            use ::rustis::__private::serde as __serde;
+
+            const VARIANTS: &[&str] = &[#(#names),*];
+
+            impl<'de> __serde::Deserialize<'de> for #ident {
+                fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+                where
+                    D: __serde::Deserializer<'de>,
+                {
+                    struct Visitor;
+
+                    impl<'de> __serde::de::Visitor<'de> for Visitor {
+                        type Value = #ident;
+
+                        fn expecting(
+                            &self,
+                            formatter: &mut ::std::fmt::Formatter,
+                        ) -> ::std::fmt::Result {
+                            formatter.write_str(#expecting)
+                        }
+
+                        fn visit_str<E>(self, value: &str) -> ::std::result::Result<Self::Value, E>
+                        where
+                            E: __serde::de::Error,
+                        {
+                            match value {
+                                #(#arms)*
+                                #unknown_arm
+                            }
+                        }
+
+                        fn visit_bytes<E>(self, value: &[u8]) -> ::std::result::Result<Self::Value, E>
+                        where
+                            E: __serde::de::Error,
+                        {
+                            match ::std::str::from_utf8(value) {
+                                ::std::result::Result::Ok(value) => self.visit_str(value),
+                                ::std::result::Result::Err(_) => ::std::result::Result::Err(
+                                    __serde::de::Error::invalid_value(
+                                        __serde::de::Unexpected::Bytes(value),
+                                        &self,
+                                    ),
+                                ),
+                            }
+                        }
+                    }
+
+                    deserializer.deserialize_str(Visitor)
+                }
+            }
+
+            impl ::rustis::resp::PrimitiveResponse for #ident {}
+        };
+    })
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "debug-commands")]
+use crate::commands::DebugCommands;
 #[cfg(feature = "redis-graph")]
 use crate::commands::GraphCommands;
 #[cfg(feature = "redis-json")]
@@ -10,44 +12,70 @@ use crate::commands::TimeSeriesCommands;
 use crate::commands::{
     BloomCommands, CountMinSketchCommands, CuckooCommands, TDigestCommands, TopKCommands,
 };
+#[cfg(test)]
+use crate::network::Transport;
 use crate::{
     client::{
-        ClientState, ClientTrackingInvalidationStream, IntoConfig, Message, MonitorStream,
-        Pipeline, PreparedCommand, PubSubStream, Transaction,
+        decompress_reply, keyspace_patterns, new_subscriptions, prefix_channel, prefix_command,
+        BackpressurePolicy, BatchPreparedCommand, ClientState, ClientTrackingInvalidationStream,
+        ClusterNodeInfo, ClusterNodes, CompressionConfig, Config, HealthStatus, Interceptor,
+        IntoConfig, KeyMetadata, KeyspaceEventFlags, KeyspaceNotificationStream, Message,
+        MonitorStream, Pipeline, PreparedCommand, PubSubEventStream, PubSubStream, PushMessage,
+        ServerFeature, Transaction, TypedPipeline, Version, XReadGroupStream, XReadStream,
     },
     commands::{
-        BitmapCommands, BlockingCommands, ClusterCommands, ConnectionCommands, GenericCommands,
-        GeoCommands, HashCommands, HyperLogLogCommands, InternalPubSubCommands, ListCommands,
-        PubSubCommands, ScriptingCommands, SentinelCommands, ServerCommands, SetCommands,
-        SortedSetCommands, StreamCommands, StringCommands, TransactionCommands,
+        BitmapCommands, BlockingCommands, ClusterCommands, CommandCommands, CommandInfo,
+        ConnectionCommands, GenericCommands, GeoCommands, HashCommands, HyperLogLogCommands,
+        InternalPubSubCommands, ListCommands, MemoryUsageOptions, ObjectEncoding, PingOptions,
+        PubSubCommands, RedisType, RoleResult, ScriptingCommands, SentinelCommands, ServerCommands,
+        SetCommands, SortedSetCommands, StreamCommands, StringCommands, TransactionCommands,
     },
     network::{
-        timeout, JoinHandle, MsgSender, NetworkHandler, PubSubReceiver, PubSubSender, PushReceiver,
-        PushSender, ReconnectReceiver, ReconnectSender, ResultReceiver, ResultSender,
-        ResultsReceiver, ResultsSender,
+        cache_command_infos, pub_sub_channel, spawn, timeout, CommandInfoCache, JoinHandle,
+        MsgSender, NetworkHandler, PubSubSender, PushReceiver, PushSender, ReconnectReceiver,
+        ReconnectSender, ResultReceiver, ResultSender, ResultsReceiver, ResultsSender,
+        ServerProtocol, ServerVersion, StreamReceiver, StreamSender, WriteReceiver, WriteSender,
+    },
+    resp::{
+        cmd, Command, CommandArgs, KeyValueArgsCollection, PrimitiveResponse, RespBuf, Response,
+        SingleArg, SingleArgCollection,
     },
-    resp::{cmd, Command, CommandArgs, RespBuf, Response, SingleArg, SingleArgCollection},
     Error, Future, Result,
 };
 use futures_channel::{mpsc, oneshot};
-use futures_util::Stream;
+use futures_util::{Stream, StreamExt};
+use log::{trace, warn};
 use serde::de::DeserializeOwned;
 use std::{
     future::IntoFuture,
     sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
-    time::Duration,
+    time::{Duration, Instant},
 };
-use log::trace;
 
 /// Client with a unique connection to a Redis server.
 #[derive(Clone)]
 pub struct Client {
     msg_sender: Arc<Option<MsgSender>>,
+    interceptor_sender: Option<MsgSender>,
     network_task_join_handle: Arc<Option<JoinHandle<()>>>,
     reconnect_sender: ReconnectSender,
+    cluster_nodes: Option<ClusterNodes>,
+    server_version: ServerVersion,
+    server_protocol: ServerProtocol,
     client_state: Arc<RwLock<ClientState>>,
+    command_info_cache: CommandInfoCache,
     command_timeout: Duration,
     retry_on_error: bool,
+    strict_blocking_commands: bool,
+    rtt_ewma_alpha: Option<f64>,
+    avg_rtt: Arc<RwLock<Option<Duration>>>,
+    pub_sub_channel_capacity: Option<usize>,
+    pub_sub_backpressure_policy: BackpressurePolicy,
+    compression: Option<CompressionConfig>,
+    chunk_size: usize,
+    key_prefix: Option<Arc<[u8]>>,
+    #[cfg(feature = "tracing")]
+    peer_name: Arc<str>,
 }
 
 impl Drop for Client {
@@ -81,25 +109,75 @@ impl Client {
     #[inline]
     pub async fn connect(config: impl IntoConfig) -> Result<Self> {
         let config = config.into_config()?;
-        let command_timeout = config.command_timeout;
-        let retry_on_error = config.retry_on_error;
-        let (msg_sender, network_task_join_handle, reconnect_sender) =
-            NetworkHandler::connect(config.into_config()?).await?;
+        let network_handler = NetworkHandler::connect(config.clone()).await?;
+        Ok(Self::from_network_handler(network_handler, &config))
+    }
+
+    /// Starts a client over an in-memory transport, to test the network handler without a server
+    #[cfg(test)]
+    pub(crate) fn connect_with_transport(transport: impl Transport, config: &Config) -> Self {
+        let network_handler = NetworkHandler::start(transport, config);
+        Self::from_network_handler(network_handler, config)
+    }
 
-        Ok(Self {
+    fn from_network_handler(
+        (
+            msg_sender,
+            network_task_join_handle,
+            reconnect_sender,
+            cluster_nodes,
+            server_version,
+            server_protocol,
+            command_info_cache,
+        ): (
+            MsgSender,
+            JoinHandle<()>,
+            ReconnectSender,
+            Option<ClusterNodes>,
+            ServerVersion,
+            ServerProtocol,
+            CommandInfoCache,
+        ),
+        config: &Config,
+    ) -> Self {
+        Self {
             msg_sender: Arc::new(Some(msg_sender)),
+            interceptor_sender: None,
             network_task_join_handle: Arc::new(Some(network_task_join_handle)),
             reconnect_sender,
+            cluster_nodes,
+            server_version,
+            server_protocol,
             client_state: Arc::new(RwLock::new(ClientState::new())),
-            command_timeout,
-            retry_on_error,
-        })
+            command_info_cache,
+            command_timeout: config.command_timeout,
+            retry_on_error: config.retry_on_error,
+            strict_blocking_commands: config.strict_blocking_commands,
+            rtt_ewma_alpha: config.rtt_ewma_alpha,
+            avg_rtt: Arc::new(RwLock::new(None)),
+            pub_sub_channel_capacity: config.pub_sub_channel_capacity,
+            pub_sub_backpressure_policy: config.pub_sub_backpressure_policy,
+            compression: config.compression,
+            chunk_size: config.chunk_size,
+            key_prefix: config
+                .key_prefix
+                .as_ref()
+                .map(|key_prefix| Arc::from(key_prefix.as_bytes())),
+            #[cfg(feature = "tracing")]
+            peer_name: crate::client::peer_name(config),
+        }
     }
 
     /// if this client is the last client on the shared connection, the channel to send messages
     /// to the underlying network handler will be closed explicitely.
     ///
-    /// Then, this function will await for the network handler to be ended
+    /// Then, this function will await for the network handler to be ended:
+    /// the replies of the commands already sent are awaited,
+    /// up to [`close_timeout`](crate::client::Config::close_timeout),
+    /// before closing the connection with [`QUIT`](https://redis.io/commands/quit/).
+    ///
+    /// If other clones of this client exist, the connection stays open
+    /// until the last clone is closed or dropped.
     pub async fn close(mut self) -> Result<()> {
         let mut network_task_join_handle: Arc<Option<JoinHandle<()>>> = Arc::new(None);
         std::mem::swap(
@@ -130,6 +208,444 @@ impl Client {
         self.reconnect_sender.subscribe()
     }
 
+    /// Snapshot of the nodes of the cluster the client is connected to.
+    ///
+    /// The topology is refreshed from the cluster after a `MOVED` redirection,
+    /// or after an `ASK` redirection to an unknown node.
+    ///
+    /// Node connections are owned by the connection of the client and are not shared
+    /// with other clients: clone a client, rather than connecting a new one,
+    /// to reuse its node connections.
+    ///
+    /// An empty vector is returned when the client is not in cluster mode.
+    pub fn cluster_topology(&self) -> Vec<ClusterNodeInfo> {
+        self.cluster_nodes
+            .as_ref()
+            .and_then(|cluster_nodes| cluster_nodes.read().ok().map(|nodes| nodes.clone()))
+            .unwrap_or_default()
+    }
+
+    /// Check that the server is serviceable, e.g. for readiness probes.
+    ///
+    /// `PING` and [`ROLE`](https://redis.io/commands/role/) are sent in a single pipeline
+    /// and the round-trip time of this pipeline is measured.
+    ///
+    /// # Return
+    /// The role of the server, the measured latency and whether the server accepts writes.
+    ///
+    /// # Errors
+    /// Any error returned by the server or the network, which means the server is not serviceable.
+    pub async fn health_check(&self) -> Result<HealthStatus> {
+        let mut pipeline = self.create_pipeline();
+        pipeline.ping::<String>(PingOptions::default()).queue();
+        pipeline.role().queue();
+
+        let start = Instant::now();
+        let (_pong, role): (String, RoleResult) = pipeline.execute().await?;
+        let latency = start.elapsed();
+
+        Ok(HealthStatus::new(role, latency))
+    }
+
+    /// Version of the Redis server, as negotiated with [`HELLO`](https://redis.io/commands/hello/)
+    /// when the connection was established.
+    ///
+    /// The version is refreshed at each reconnection.
+    /// In cluster mode, the lowest version among the nodes is returned.
+    ///
+    /// # Return
+    /// The server version, or `None` if it could not be parsed.
+    pub fn server_version(&self) -> Option<Version> {
+        self.server_version.read().ok().and_then(|version| *version)
+    }
+
+    /// Version of the RESP protocol, as negotiated with [`HELLO`](https://redis.io/commands/hello/)
+    /// when the connection was established.
+    ///
+    /// The protocol is refreshed at each reconnection.
+    ///
+    /// # Return
+    /// The protocol version (`3` for RESP3), or `None` if it is unknown.
+    pub fn protocol(&self) -> Option<usize> {
+        self.server_protocol
+            .read()
+            .ok()
+            .and_then(|protocol| *protocol)
+    }
+
+    /// Check if the Redis server supports a feature, depending on its [version](Client::server_version).
+    ///
+    /// # Return
+    /// `false` if the server version is lower than the [minimum version](ServerFeature::min_version)
+    /// of the feature or if it is unknown.
+    pub fn supports(&self, feature: ServerFeature) -> bool {
+        self.server_version()
+            .is_some_and(|version| version >= feature.min_version())
+    }
+
+    /// Inspect a key, e.g. for admin or debugging tools.
+    ///
+    /// [`TYPE`](https://redis.io/commands/type/), [`PTTL`](https://redis.io/commands/pttl/),
+    /// [`OBJECT ENCODING`](https://redis.io/commands/object-encoding/) and
+    /// [`MEMORY USAGE`](https://redis.io/commands/memory-usage/)
+    /// are sent in a single pipeline.
+    ///
+    /// # Return
+    /// The metadata of the key, or `None` if the key does not exist.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs while sending the pipeline
+    pub async fn key_metadata<K>(&self, key: K) -> Result<Option<KeyMetadata>>
+    where
+        K: SingleArg + Clone,
+    {
+        let mut pipeline = self.create_pipeline();
+        pipeline.key_type(key.clone()).queue();
+        pipeline.pttl(key.clone()).queue();
+        pipeline
+            .object_encoding::<_, Option<ObjectEncoding>>(key.clone())
+            .queue();
+        pipeline
+            .memory_usage(key, MemoryUsageOptions::default())
+            .queue();
+
+        let (key_type, pttl, encoding, memory_bytes): (
+            RedisType,
+            i64,
+            Option<ObjectEncoding>,
+            Option<usize>,
+        ) = pipeline.execute().await?;
+
+        // the key may also have expired or been deleted between two commands of the pipeline
+        match (key_type, pttl, encoding, memory_bytes) {
+            (RedisType::None, ..) | (_, -2, ..) => Ok(None),
+            (key_type, pttl, Some(encoding), Some(memory_bytes)) => Ok(Some(KeyMetadata {
+                key_type,
+                ttl: u64::try_from(pttl).ok().map(Duration::from_millis),
+                encoding,
+                memory_bytes,
+            })),
+            _ => Ok(None),
+        }
+    }
+
+    /// Measure the round-trip time of a [`PING`](https://redis.io/commands/ping/).
+    ///
+    /// When [`Config::rtt_ewma_alpha`](crate::client::Config::rtt_ewma_alpha) is set,
+    /// the measure also updates the moving average returned by [`avg_rtt`](Client::avg_rtt).
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs while sending the `PING`
+    pub async fn ping_rtt(&self) -> Result<Duration> {
+        let start = Instant::now();
+        self.ping::<()>(PingOptions::default()).await?;
+        let rtt = start.elapsed();
+
+        if let Some(alpha) = self.rtt_ewma_alpha {
+            if let Ok(mut avg_rtt) = self.avg_rtt.write() {
+                *avg_rtt = Some(match *avg_rtt {
+                    Some(avg_rtt) => rtt.mul_f64(alpha) + avg_rtt.mul_f64(1. - alpha),
+                    None => rtt,
+                });
+            }
+        }
+
+        Ok(rtt)
+    }
+
+    /// Exponentially weighted moving average of the round-trip times
+    /// measured by [`ping_rtt`](Client::ping_rtt), shared by all the clones of this client.
+    ///
+    /// `None` if [`Config::rtt_ewma_alpha`](crate::client::Config::rtt_ewma_alpha) is not set
+    /// or if no round-trip time has been measured yet.
+    pub fn avg_rtt(&self) -> Option<Duration> {
+        self.avg_rtt.read().ok().and_then(|avg_rtt| *avg_rtt)
+    }
+
+    /// Metadata of a command, as returned by [`COMMAND INFO`](https://redis.io/commands/command-info/):
+    /// arity, flags, key positions, ACL categories and key specifications.
+    ///
+    /// The metadata are cached and shared by all the clones of this client:
+    /// only the first lookup of a command costs a round-trip to the server.
+    /// Commands unknown to the server are not cached: they are looked up again at the next call,
+    /// e.g. after a module is loaded.
+    /// Sub commands are looked up with their container, e.g. `"config|get"`.
+    ///
+    /// In cluster mode, the cache is shared with the connection and filled at connection time.
+    /// With a [`key_prefix`](crate::client::Config::key_prefix),
+    /// commands missing from the built-in key table are prefixed from the cached key positions.
+    ///
+    /// # Return
+    /// The command metadata, or `None` if the command is unknown to the server.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs while fetching the metadata
+    pub async fn cached_command_info(&self, command_name: &str) -> Result<Option<CommandInfo>> {
+        let command_name = command_name.to_uppercase();
+
+        if let Ok(command_info_cache) = self.command_info_cache.read() {
+            if let Some(command_info) = command_info_cache.get(&command_name) {
+                return Ok(Some(command_info.as_ref().clone()));
+            }
+        }
+
+        let command_infos = self.command_info(&command_name).await?;
+
+        let command_info = command_infos.into_iter().next().flatten();
+        if let Some(command_info) = &command_info {
+            cache_command_infos(&self.command_info_cache, [command_info.clone()]);
+        }
+
+        Ok(command_info)
+    }
+
+    /// Removes the specified keys and reports which of them actually existed.
+    ///
+    /// Since [`DEL`](https://redis.io/commands/del/) only replies with a count,
+    /// one `DEL` per key is sent in a pipeline, within a single round-trip:
+    /// each reply tells if its key was removed, without any race between a check and the deletion.
+    ///
+    /// # Return
+    /// The keys that were removed, in the order in which they were passed.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs while sending the pipeline
+    ///
+    /// # See Also
+    /// [`del`](GenericCommands::del)
+    pub async fn del_existing<K, C>(&self, keys: C) -> Result<Vec<String>>
+    where
+        K: SingleArg,
+        C: SingleArgCollection<K>,
+    {
+        let keys = CommandArgs::default().arg(keys).build();
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let commands = keys
+            .iter()
+            .map(|key| cmd("DEL").arg(key.as_slice()))
+            .collect();
+        let results = self.send_batch(commands, None).await?;
+
+        let mut deleted_keys = Vec::new();
+        for (key, result) in keys.iter().zip(results) {
+            if result.to::<usize>()? > 0 {
+                deleted_keys.push(String::from_utf8_lossy(key).into_owned());
+            }
+        }
+
+        Ok(deleted_keys)
+    }
+
+    /// Sets the given keys to their respective values,
+    /// with one [`MSET`](https://redis.io/commands/mset/) per chunk of `chunk_size` keys.
+    ///
+    /// The commands are pipelined within a single round-trip.
+    /// Contrary to a single `MSET`, the keys are not all set atomically.
+    ///
+    /// # Arguments
+    /// * `items` - key/value pairs to set
+    /// * `chunk_size` - maximum number of keys per command,
+    ///   [`Config::chunk_size`](crate::client::Config::chunk_size) if `None`
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs while sending the pipeline
+    ///
+    /// # See Also
+    /// [`mset`](StringCommands::mset)
+    pub async fn mset_chunked<K, V, C>(&self, items: C, chunk_size: Option<usize>) -> Result<()>
+    where
+        K: SingleArg,
+        V: SingleArg,
+        C: KeyValueArgsCollection<K, V>,
+    {
+        let args = CommandArgs::default().arg(items).build();
+        if args.is_empty() {
+            return Ok(());
+        }
+
+        let chunk_size = chunk_size.unwrap_or(self.chunk_size).max(1);
+        let mut commands = Vec::with_capacity(args.len().div_ceil(2 * chunk_size));
+        for pairs in args.chunks(2 * chunk_size) {
+            let mut command = cmd("MSET");
+            for arg in pairs {
+                command = command.arg(arg.as_slice());
+            }
+            if let Some(compression) = self.compression {
+                compression.compress_command(&mut command)?;
+            }
+            commands.push(command);
+        }
+
+        for result in self.send_batch(commands, None).await? {
+            result.to::<()>()?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the values of all specified keys,
+    /// with one [`MGET`](https://redis.io/commands/mget/) per chunk of `chunk_size` keys.
+    ///
+    /// The commands are pipelined within a single round-trip.
+    ///
+    /// # Arguments
+    /// * `keys` - keys to get
+    /// * `chunk_size` - maximum number of keys per command,
+    ///   [`Config::chunk_size`](crate::client::Config::chunk_size) if `None`
+    ///
+    /// # Return
+    /// The values at the specified keys, in the order in which they were passed,
+    /// `None` for each key that does not hold a string value or does not exist.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs while sending the pipeline
+    ///
+    /// # See Also
+    /// [`mget`](StringCommands::mget)
+    pub async fn mget_chunked<K, KK, V>(
+        &self,
+        keys: KK,
+        chunk_size: Option<usize>,
+    ) -> Result<Vec<Option<V>>>
+    where
+        K: SingleArg,
+        KK: SingleArgCollection<K>,
+        V: PrimitiveResponse + DeserializeOwned,
+    {
+        let keys = CommandArgs::default().arg(keys).build();
+        if keys.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let chunk_size = chunk_size.unwrap_or(self.chunk_size).max(1);
+        let commands = keys
+            .chunks(chunk_size)
+            .map(|keys| {
+                keys.iter()
+                    .fold(cmd("MGET"), |command, key| command.arg(key.as_slice()))
+            })
+            .collect();
+
+        let mut values = Vec::with_capacity(keys.len());
+        for mut result in self.send_batch(commands, None).await? {
+            if self.compression.is_some() {
+                result = decompress_reply(result)?;
+            }
+            values.extend(result.to::<Vec<Option<V>>>()?);
+        }
+
+        Ok(values)
+    }
+
+    /// Subscribe to the [keyspace notifications](https://redis.io/docs/manual/keyspace-notifications/)
+    /// of the database `db`.
+    ///
+    /// `events` must contain [`KEYSPACE`](KeyspaceEventFlags::KEYSPACE) and/or
+    /// [`KEYEVENT`](KeyspaceEventFlags::KEYEVENT) to select the channels to subscribe to.
+    ///
+    /// If `events` contains at least one event class, e.g. [`GENERIC`](KeyspaceEventFlags::GENERIC),
+    /// the `notify-keyspace-events` server parameter is first set to `events`.
+    /// Otherwise, notifications must already be enabled on the server.
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::{
+    ///     client::{Client, KeyspaceEventFlags},
+    ///     commands::StringCommands,
+    ///     Result,
+    /// };
+    /// use futures_util::StreamExt;
+    ///
+    /// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+    /// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+    /// async fn main() -> Result<()> {
+    ///     let subscribing_client = Client::connect("127.0.0.1:6379").await?;
+    ///     let regular_client = Client::connect("127.0.0.1:6379").await?;
+    ///
+    ///     let mut stream = subscribing_client
+    ///         .keyspace_notifications(
+    ///             0,
+    ///             KeyspaceEventFlags::KEYSPACE | KeyspaceEventFlags::STRING,
+    ///         )
+    ///         .await?;
+    ///
+    ///     regular_client.set("key", "value").await?;
+    ///
+    ///     let event = stream.next().await.unwrap()?;
+    ///     assert_eq!(b"key".to_vec(), event.key);
+    ///     assert_eq!("set", event.event);
+    ///
+    ///     stream.close().await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn keyspace_notifications(
+        &self,
+        db: usize,
+        events: KeyspaceEventFlags,
+    ) -> Result<KeyspaceNotificationStream> {
+        let patterns = keyspace_patterns(db, events);
+        if patterns.is_empty() {
+            return Err(Error::Client(
+                "KEYSPACE or KEYEVENT flag expected to subscribe to keyspace notifications"
+                    .to_owned(),
+            ));
+        }
+
+        if events.has_event_classes() {
+            self.config_set(("notify-keyspace-events", events.to_string()))
+                .await?;
+        }
+
+        let pub_sub_stream = self.psubscribe(patterns).await?;
+        Ok(KeyspaceNotificationStream::new(pub_sub_stream))
+    }
+
+    /// Get the value of `key`, deserialized from a JSON string.
+    ///
+    /// # Return
+    /// The deserialized value, or `None` when `key` does not exist.
+    ///
+    /// # Errors
+    /// An error is returned if the value is not a valid JSON representation of `T`.
+    ///
+    /// # See Also
+    /// [`get`](StringCommands::get)
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde-json")))]
+    #[cfg(feature = "serde-json")]
+    pub async fn get_serde<K, T>(&self, key: K) -> Result<Option<T>>
+    where
+        K: SingleArg,
+        T: DeserializeOwned,
+    {
+        let value: Option<crate::resp::BulkString> = self.get(key).await?;
+        Ok(value
+            .map(|value| serde_json::from_slice(&value))
+            .transpose()?)
+    }
+
+    /// Set `key` to hold `value`, serialized as a JSON string.
+    ///
+    /// # Errors
+    /// An error is returned if `value` cannot be serialized to JSON.
+    ///
+    /// # See Also
+    /// [`set`](StringCommands::set)
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde-json")))]
+    #[cfg(feature = "serde-json")]
+    pub async fn set_serde<K, T>(&self, key: K, value: &T) -> Result<()>
+    where
+        K: SingleArg,
+        T: serde::Serialize + ?Sized,
+    {
+        let value = serde_json::to_vec(value)?;
+        self.set(key, value).await
+    }
+
     /// Give an immutable generic access to attach any state to a client instance
     pub fn get_client_state(&self) -> RwLockReadGuard<ClientState> {
         self.client_state.read().unwrap()
@@ -196,12 +712,113 @@ impl Client {
 
     #[inline]
     pub async fn send(&self, command: Command, retry_on_error: Option<bool>) -> Result<RespBuf> {
+        self.check_blocking_command(&command)?;
+        self.send_with_max_attempts(command, retry_on_error, None)
+            .await
+    }
+
+    /// Send an [inline command](https://redis.io/docs/reference/protocol-spec/#inline-commands)
+    /// to the server, e.g. `"PING"` or `"SET key value"`.
+    ///
+    /// This is an escape hatch for RESP compatible servers with quirks:
+    /// regular commands should be sent with [`send`](Client::send).
+    /// The line is written as is, followed by `\r\n`, without any escaping of its arguments:
+    /// the caller is responsible for its correctness.
+    ///
+    /// Once an inline command has been sent, replies without RESP type prefix
+    /// are decoded as simple strings on this connection.
+    /// On other connections, such replies remain a protocol error.
+    ///
+    /// # Errors
+    /// * An [`Error::Client`](crate::Error::Client) if the line contains `\r` or `\n`,
+    ///   or if the client is connected to a cluster, since an inline command cannot be routed
+    /// * Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    pub async fn send_inline(&self, line: &str) -> Result<RespBuf> {
+        if line.contains(['\r', '\n']) {
+            return Err(Error::Client(
+                "An inline command cannot contain line breaks".to_owned(),
+            ));
+        }
+
+        if self.cluster_nodes.is_some() {
+            return Err(Error::Client(
+                "Inline commands are not supported in cluster mode".to_owned(),
+            ));
+        }
+
+        self.send_with_max_attempts(Command::inline(line), None, None)
+            .await
+    }
+
+    /// `true` if the connection is shared by clones of this client
+    #[inline]
+    pub(crate) fn is_connection_shared(&self) -> bool {
+        Arc::strong_count(&self.network_task_join_handle) > 1
+    }
+
+    /// Blocking commands monopolize a connection shared by the clones of this client
+    ///
+    /// See [`Config::strict_blocking_commands`](crate::client::Config::strict_blocking_commands)
+    fn check_blocking_command(&self, command: &Command) -> Result<()> {
+        if !self.is_connection_shared() || !is_blocking_command(command) {
+            return Ok(());
+        }
+
+        if self.strict_blocking_commands {
+            Err(Error::Client(format!(
+                "Blocking command {} cannot be sent on a shared connection",
+                command.name
+            )))
+        } else {
+            warn!(
+                "Blocking command {} sent on a shared connection: \
+                the commands of the other clients are stalled until it returns",
+                command.name
+            );
+            Ok(())
+        }
+    }
+
+    #[inline]
+    async fn send_with_max_attempts(
+        &self,
+        command: Command,
+        retry_on_error: Option<bool>,
+        max_attempts: Option<usize>,
+    ) -> Result<RespBuf> {
+        #[cfg(feature = "tracing")]
+        {
+            let span = crate::client::command_span(
+                &crate::client::redacted_statement(&command),
+                &self.peer_name,
+            );
+            crate::client::instrument(
+                span,
+                self.send_single(command, retry_on_error, max_attempts),
+                crate::client::resp_buf_error,
+            )
+            .await
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        self.send_single(command, retry_on_error, max_attempts)
+            .await
+    }
+
+    #[inline]
+    async fn send_single(
+        &self,
+        command: Command,
+        retry_on_error: Option<bool>,
+        max_attempts: Option<usize>,
+    ) -> Result<RespBuf> {
         let (result_sender, result_receiver): (ResultSender, ResultReceiver) = oneshot::channel();
         let message = Message::single(
             command,
             result_sender,
             retry_on_error.unwrap_or(self.retry_on_error),
-        );
+        )
+        .max_attempts(max_attempts);
         self.send_message(message)?;
 
         if self.command_timeout != Duration::ZERO {
@@ -224,12 +841,68 @@ impl Client {
     /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
     #[inline]
     pub fn send_and_forget(&self, command: Command, retry_on_error: Option<bool>) -> Result<()> {
+        self.send_and_forget_with_max_attempts(command, retry_on_error, None)
+    }
+
+    #[inline]
+    fn send_and_forget_with_max_attempts(
+        &self,
+        command: Command,
+        retry_on_error: Option<bool>,
+        max_attempts: Option<usize>,
+    ) -> Result<()> {
         let message =
-            Message::single_forget(command, retry_on_error.unwrap_or(self.retry_on_error));
+            Message::single_forget(command, retry_on_error.unwrap_or(self.retry_on_error))
+                .max_attempts(max_attempts);
         self.send_message(message)?;
         Ok(())
     }
 
+    /// Skips the reply of the next command sent by this client,
+    /// with [`CLIENT REPLY SKIP`](https://redis.io/commands/client-reply/).
+    ///
+    /// This saves bandwidth for high-volume writes when even the acknowledgement is not needed.
+    /// Since no reply will be received, the next command must be sent with
+    /// [`forget`](ClientPreparedCommand::forget) or [`send_and_forget`](Client::send_and_forget).
+    /// The replies of the following commands are received again as usual.
+    ///
+    /// # Errors
+    /// * An [`Error::Client`](crate::Error::Client) if the connection is shared with clones of this client:
+    ///   a command sent by a clone could be the one whose reply is skipped
+    /// * Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::{
+    ///     client::{Client, ClientPreparedCommand},
+    ///     commands::StringCommands,
+    ///     Result,
+    /// };
+    ///
+    /// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+    /// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+    /// async fn main() -> Result<()> {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await?;
+    ///
+    ///     client.next_reply_skip()?;
+    ///     client.set("key", "value").forget()?;
+    ///
+    ///     let value: String = client.get("key").await?;
+    ///     assert_eq!("value", value);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn next_reply_skip(&mut self) -> Result<()> {
+        if self.is_connection_shared() {
+            return Err(Error::Client(
+                "CLIENT REPLY SKIP cannot be sent on a shared connection".to_owned(),
+            ));
+        }
+
+        self.send_and_forget(cmd("CLIENT").arg("REPLY").arg("SKIP"), None)
+    }
+
     /// Send a batch of commands to the Redis server.
     ///
     /// # Arguments
@@ -246,6 +919,30 @@ impl Client {
         &self,
         commands: Vec<Command>,
         retry_on_error: Option<bool>,
+    ) -> Result<Vec<RespBuf>> {
+        #[cfg(feature = "tracing")]
+        {
+            let span = crate::client::command_span(
+                &crate::client::redacted_batch_statement(&commands),
+                &self.peer_name,
+            );
+            crate::client::instrument(
+                span,
+                self.send_batch_internal(commands, retry_on_error),
+                |resp_bufs: &Vec<RespBuf>| resp_bufs.iter().find_map(crate::client::resp_buf_error),
+            )
+            .await
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        self.send_batch_internal(commands, retry_on_error).await
+    }
+
+    #[inline]
+    async fn send_batch_internal(
+        &self,
+        commands: Vec<Command>,
+        retry_on_error: Option<bool>,
     ) -> Result<Vec<RespBuf>> {
         let (results_sender, results_receiver): (ResultsSender, ResultsReceiver) =
             oneshot::channel();
@@ -263,9 +960,252 @@ impl Client {
         }
     }
 
+    /// Send a batch of commands to the Redis server and forget their responses.
+    ///
+    /// Only the write of the batch is awaited: the responses are discarded
+    /// by the network handler as they are received, without being buffered.
+    ///
+    /// # Arguments
+    /// * `commands` - batch of generic [`Command`](crate::resp::Command)s meant to be sent to the Redis server.
+    /// * `retry_on_error` - retry to send the command batch on network error.
+    ///   * `None` - default behaviour defined in [`Config::retry_on_error`](crate::client::Config::retry_on_error)
+    ///   * `Some(true)` - retry sending batch on network error
+    ///   * `Some(false)` - do not retry sending batch on network error
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation,
+    /// including the failure to write the batch to the socket
+    pub async fn send_batch_and_forget(
+        &self,
+        commands: Vec<Command>,
+        retry_on_error: Option<bool>,
+    ) -> Result<()> {
+        let (write_sender, write_receiver): (WriteSender, WriteReceiver) = oneshot::channel();
+        let message = Message::batch_forget(
+            commands,
+            write_sender,
+            retry_on_error.unwrap_or(self.retry_on_error),
+        );
+        self.send_message(message)?;
+
+        if self.command_timeout != Duration::ZERO {
+            timeout(self.command_timeout, write_receiver).await??
+        } else {
+            write_receiver.await?
+        }
+    }
+
+    /// Send a batch of commands to the Redis server
+    /// and stream their results one by one, in order, as they are received.
+    ///
+    /// Contrary to [`send_batch`](Client::send_batch), results are not buffered
+    /// until the whole batch has been received, which keeps memory usage low for very large batches.
+    ///
+    /// # Arguments
+    /// * `commands` - batch of generic [`Command`](crate::resp::Command)s meant to be sent to the Redis server.
+    /// * `buffer_size` - maximum number of results waiting to be consumed.
+    ///   When the buffer is full, the connection stops being read until the stream is consumed:
+    ///   meanwhile, the other commands sent on the same connection are on hold.
+    ///
+    /// # Return
+    /// A stream of the results of the commands.
+    /// Results are not converted: use [`RespBuf::to`](crate::resp::RespBuf::to) to deserialize each one.
+    ///
+    /// The batch is never retried on network error
+    /// and [`command_timeout`](crate::client::Config::command_timeout) does not apply.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::{client::Client, resp::cmd, Result};
+    /// use futures_util::StreamExt;
+    ///
+    /// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+    /// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+    /// async fn main() -> Result<()> {
+    ///     let client = Client::connect("127.0.0.1:6379").await?;
+    ///
+    ///     let commands = (0..1000)
+    ///         .map(|i| cmd("SET").arg(format!("key{i}")).arg(i))
+    ///         .collect();
+    ///     let mut results = client.send_pipeline_iter(commands, 100)?;
+    ///
+    ///     while let Some(result) = results.next().await {
+    ///         result?.to::<()>()?;
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn send_pipeline_iter(
+        &self,
+        commands: Vec<Command>,
+        buffer_size: usize,
+    ) -> Result<impl Stream<Item = Result<RespBuf>>> {
+        let (stream_sender, stream_receiver): (StreamSender, StreamReceiver) =
+            mpsc::channel(buffer_size);
+        let message = Message::stream(commands, stream_sender);
+        self.send_message(message)?;
+        Ok(stream_receiver)
+    }
+
+    /// Add an interceptor which observes every command sent by this client.
+    ///
+    /// The interceptor is only installed on the returned client and its future clones:
+    /// other existing clones sharing the same connection are not affected.
+    ///
+    /// Interceptors are layered: the most recently added interceptor is the outermost one,
+    /// meaning that it sees each command first, before the interceptors added previously.
+    /// Commands go through the interceptors one by one, in order,
+    /// including the commands sent with [`send_and_forget`](Client::send_and_forget).
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::{client::Client, commands::StringCommands, Result};
+    ///
+    /// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+    /// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+    /// async fn main() -> Result<()> {
+    ///     let client = Client::connect("127.0.0.1:6379")
+    ///         .await?
+    ///         .with_interceptor(|command| {
+    ///             if command.name == "AUTH" {
+    ///                 println!("AUTH ***");
+    ///             } else {
+    ///                 println!("{command:?}");
+    ///             }
+    ///         });
+    ///
+    ///     client.set("key", "value").await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub fn with_interceptor<F>(self, interceptor: F) -> Self
+    where
+        F: Fn(&Command) + Send + Sync + 'static,
+    {
+        self.add_interceptor(Interceptor::Observe(Box::new(interceptor)))
+    }
+
+    /// Add an asynchronous interceptor which can rewrite every command sent by this client.
+    ///
+    /// If the interceptor returns an error, the command is not sent and the error is returned to the caller.
+    /// Subscription commands (`SUBSCRIBE`, `PSUBSCRIBE`, `SSUBSCRIBE`) are never rewritten.
+    ///
+    /// See [`with_interceptor`](Client::with_interceptor) for the ordering of interceptors.
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::{
+    ///     client::Client,
+    ///     commands::StringCommands,
+    ///     resp::{cmd, CommandArgs},
+    ///     Result,
+    /// };
+    ///
+    /// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+    /// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+    /// async fn main() -> Result<()> {
+    ///     // multi-tenant isolation: prefix the key of each GET & SET command
+    ///     let client = Client::connect("127.0.0.1:6379")
+    ///         .await?
+    ///         .with_async_interceptor(|mut command| async move {
+    ///             if matches!(command.name, "GET" | "SET") {
+    ///                 let mut args = CommandArgs::default();
+    ///                 for (i, arg) in (&command.args).into_iter().enumerate() {
+    ///                     if i == 0 {
+    ///                         let mut key = b"tenant1:".to_vec();
+    ///                         key.extend_from_slice(arg);
+    ///                         args.arg(key);
+    ///                     } else {
+    ///                         args.arg(arg);
+    ///                     }
+    ///                 }
+    ///                 command.args = args;
+    ///             }
+    ///             Ok(command)
+    ///         });
+    ///
+    ///     client.set("key", "value").await?;
+    ///     let value: String = client.send(cmd("GET").arg("tenant1:key"), None).await?.to()?;
+    ///     assert_eq!("value", value);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub fn with_async_interceptor<F, Fut>(self, interceptor: F) -> Self
+    where
+        F: Fn(Command) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = Result<Command>> + Send + 'static,
+    {
+        self.add_interceptor(Interceptor::Rewrite(Box::new(move |command| {
+            Box::pin(interceptor(command))
+        })))
+    }
+
+    /// Add an interceptor which observes the reply of every command sent by this client,
+    /// except the commands sent with [`send_and_forget`](Client::send_and_forget)
+    /// or [`send_pipeline_iter`](Client::send_pipeline_iter).
+    ///
+    /// The interceptor receives the command as sent to the Redis server,
+    /// i.e. after the rewrites of the interceptors added before this one.
+    ///
+    /// See [`with_interceptor`](Client::with_interceptor) for the ordering of interceptors.
+    #[must_use]
+    pub fn with_reply_interceptor<F>(self, interceptor: F) -> Self
+    where
+        F: Fn(&Command, &Result<RespBuf>) + Send + Sync + 'static,
+    {
+        self.add_interceptor(Interceptor::Reply(Arc::new(interceptor)))
+    }
+
+    fn add_interceptor(mut self, interceptor: Interceptor) -> Self {
+        let downstream = match &self.interceptor_sender {
+            Some(interceptor_sender) => Some(interceptor_sender.clone()),
+            None => (*self.msg_sender).clone(),
+        };
+
+        if let Some(downstream) = downstream {
+            self.interceptor_sender = Some(interceptor.start(downstream));
+        }
+
+        self
+    }
+
+    /// Prefix configured with [`Config::key_prefix`](crate::client::Config::key_prefix)
+    #[inline]
+    pub(crate) fn key_prefix(&self) -> Option<&[u8]> {
+        self.key_prefix.as_deref()
+    }
+
+    /// Prefix the keyspace notification channels or patterns to subscribe to
+    pub(crate) fn prefix_channels(&self, channels: &mut CommandArgs) {
+        if let Some(key_prefix) = &self.key_prefix {
+            for channel in channels.args_mut() {
+                prefix_channel(key_prefix, channel);
+            }
+        }
+    }
+
     #[inline]
-    fn send_message(&self, message: Message) -> Result<()> {
-        if let Some(msg_sender) = &self.msg_sender as &Option<MsgSender> {
+    fn send_message(&self, mut message: Message) -> Result<()> {
+        // subscriptions channels are prefixed by the caller
+        if let (Some(key_prefix), None) = (&self.key_prefix, &message.pub_sub_senders) {
+            for command in &mut message.commands {
+                prefix_command(key_prefix, command, &self.command_info_cache)?;
+            }
+        }
+
+        if let Some(interceptor_sender) = &self.interceptor_sender {
+            trace!("Will enqueue message to interceptors: {message:?}");
+            interceptor_sender.unbounded_send(message)?;
+            Ok(())
+        } else if let Some(msg_sender) = &self.msg_sender as &Option<MsgSender> {
             trace!("Will enqueue message: {message:?}");
             msg_sender.unbounded_send(message)?;
             Ok(())
@@ -288,25 +1228,78 @@ impl Client {
         Pipeline::new(self)
     }
 
+    /// Create a new typed pipeline
+    ///
+    /// See [`TypedPipeline`]
+    #[inline]
+    pub fn create_typed_pipeline(&self) -> TypedPipeline<'_> {
+        TypedPipeline::new(self)
+    }
+
     pub fn create_client_tracking_invalidation_stream(
         &self,
     ) -> Result<impl Stream<Item = Vec<String>>> {
         let (push_sender, push_receiver): (PushSender, PushReceiver) = mpsc::unbounded();
-        let message = Message::client_tracking_invalidation(push_sender);
+        let message = Message::push(push_sender);
         self.send_message(message)?;
         Ok(ClientTrackingInvalidationStream::new(push_receiver))
     }
 
+    /// Registers a handler receiving every [RESP3](https://github.com/antirez/RESP3/blob/master/spec.md#push-type)
+    /// push message which is not matched to a pub/sub subscription
+    /// (e.g. client tracking invalidations or keyspace notifications).
+    ///
+    /// The handler is shared by all the clones of this client and replaces any previously registered handler,
+    /// including the one of a [`client tracking invalidation stream`](Client::create_client_tracking_invalidation_stream).
+    /// Entering [`MONITOR`](Client::monitor) mode also replaces it.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs while registering the handler
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::{client::Client, commands::{ConnectionCommands, HelloOptions}, Result};
+    ///
+    /// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+    /// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+    /// async fn main() -> Result<()> {
+    ///     let mut client = Client::connect("127.0.0.1:6379").await?;
+    ///     client.hello(HelloOptions::new(3)).await?;
+    ///
+    ///     client.on_push(|push_message| {
+    ///         println!("{}: {:?}", push_message.kind, push_message.data);
+    ///     })?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn on_push(&mut self, handler: impl Fn(PushMessage) + Send + 'static) -> Result<()> {
+        let (push_sender, mut push_receiver): (PushSender, PushReceiver) = mpsc::unbounded();
+        self.send_message(Message::push(push_sender))?;
+
+        spawn(async move {
+            while let Some(result) = push_receiver.next().await {
+                match result.and_then(|resp_buf| resp_buf.to::<PushMessage>()) {
+                    Ok(push_message) => handler(push_message),
+                    Err(e) => warn!("Cannot decode push message: {e}"),
+                }
+            }
+        });
+
+        Ok(())
+    }
+
     pub(crate) async fn subscribe_from_pub_sub_sender(
         &self,
         channels: &CommandArgs,
         pub_sub_sender: &PubSubSender,
+        with_events: bool,
     ) -> Result<()> {
         let (result_sender, result_receiver): (ResultSender, ResultReceiver) = oneshot::channel();
 
         let pub_sub_senders = channels
             .into_iter()
-            .map(|c| (c.to_vec(), pub_sub_sender.clone()))
+            .map(|c| (c.to_vec(), pub_sub_sender.clone(), with_events))
             .collect::<Vec<_>>();
 
         let message = Message::pub_sub(
@@ -324,12 +1317,13 @@ impl Client {
         &self,
         patterns: &CommandArgs,
         pub_sub_sender: &PubSubSender,
+        with_events: bool,
     ) -> Result<()> {
         let (result_sender, result_receiver): (ResultSender, ResultReceiver) = oneshot::channel();
 
         let pub_sub_senders = patterns
             .into_iter()
-            .map(|c| (c.to_vec(), pub_sub_sender.clone()))
+            .map(|c| (c.to_vec(), pub_sub_sender.clone(), with_events))
             .collect::<Vec<_>>();
 
         let message = Message::pub_sub(
@@ -347,12 +1341,13 @@ impl Client {
         &self,
         shardchannels: &CommandArgs,
         pub_sub_sender: &PubSubSender,
+        with_events: bool,
     ) -> Result<()> {
         let (result_sender, result_receiver): (ResultSender, ResultReceiver) = oneshot::channel();
 
         let pub_sub_senders = shardchannels
             .into_iter()
-            .map(|c| (c.to_vec(), pub_sub_sender.clone()))
+            .map(|c| (c.to_vec(), pub_sub_sender.clone(), with_events))
             .collect::<Vec<_>>();
 
         let message = Message::pub_sub(
@@ -382,9 +1377,15 @@ impl<'a, R: Response> ClientPreparedCommand<'a, R> for PreparedCommand<'a, &'a C
     ///
     /// # Errors
     /// Any Redis driver [`Error`](crate::Error) that occur during the send operation
-    fn forget(self) -> Result<()> {
-        self.executor
-            .send_and_forget(self.command, self.retry_on_error)
+    fn forget(mut self) -> Result<()> {
+        if let Some(compression) = self.compression() {
+            compression.compress_command(&mut self.command)?;
+        }
+        self.executor.send_and_forget_with_max_attempts(
+            self.command,
+            self.retry_on_error,
+            self.max_attempts,
+        )
     }
 }
 
@@ -395,31 +1396,51 @@ where
     type Output = Result<R>;
     type IntoFuture = Future<'a, R>;
 
-    fn into_future(self) -> Self::IntoFuture {
+    fn into_future(mut self) -> Self::IntoFuture {
         Box::pin(async move {
+            let compression = self.compression();
+            if let Some(compression) = compression {
+                compression.compress_command(&mut self.command)?;
+            }
+
             if let Some(custom_converter) = self.custom_converter {
                 let command_for_result = self.command.clone();
                 let result = self
                     .executor
-                    .send(self.command, self.retry_on_error)
+                    .send_with_max_attempts(self.command, self.retry_on_error, self.max_attempts)
                     .await?;
                 custom_converter(result, command_for_result, self.executor).await
             } else {
-                let result = self
+                let mut result = self
                     .executor
-                    .send(self.command, self.retry_on_error)
+                    .send_with_max_attempts(self.command, self.retry_on_error, self.max_attempts)
                     .await?;
+                if compression.is_some() {
+                    result = decompress_reply(result)?;
+                }
                 result.to()
             }
         })
     }
 }
 
+impl<'a, R: Response> PreparedCommand<'a, &'a Client, R> {
+    /// Compression to apply to the values of this command, if any
+    fn compression(&self) -> Option<CompressionConfig> {
+        if self.compress_values {
+            self.executor.compression
+        } else {
+            None
+        }
+    }
+}
+
 impl<'a> BitmapCommands<'a> for &'a Client {}
 #[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
 #[cfg(feature = "redis-bloom")]
 impl<'a> BloomCommands<'a> for &'a Client {}
 impl<'a> ClusterCommands<'a> for &'a Client {}
+impl<'a> CommandCommands<'a> for &'a Client {}
 #[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
 #[cfg(feature = "redis-bloom")]
 impl<'a> CountMinSketchCommands<'a> for &'a Client {}
@@ -427,6 +1448,9 @@ impl<'a> CountMinSketchCommands<'a> for &'a Client {}
 #[cfg(feature = "redis-bloom")]
 impl<'a> CuckooCommands<'a> for &'a Client {}
 impl<'a> ConnectionCommands<'a> for &'a Client {}
+#[cfg_attr(docsrs, doc(cfg(feature = "debug-commands")))]
+#[cfg(feature = "debug-commands")]
+impl<'a> DebugCommands<'a> for &'a Client {}
 impl<'a> GenericCommands<'a> for &'a Client {}
 impl<'a> GeoCommands<'a> for &'a Client {}
 #[cfg_attr(docsrs, doc(cfg(feature = "redis-graph")))]
@@ -467,13 +1491,17 @@ impl<'a> PubSubCommands<'a> for &'a Client {
         C: SingleArg + Send + 'a,
         CC: SingleArgCollection<C>,
     {
-        let channels = CommandArgs::default().arg(channels).build();
+        let mut channels = CommandArgs::default().arg(channels).build();
+        self.prefix_channels(&mut channels);
+        let channels = new_subscriptions(&CommandArgs::default(), &channels);
 
         Box::pin(async move {
-            let (pub_sub_sender, pub_sub_receiver): (PubSubSender, PubSubReceiver) =
-                mpsc::unbounded();
+            let (pub_sub_sender, pub_sub_receiver) = pub_sub_channel(
+                self.pub_sub_channel_capacity,
+                self.pub_sub_backpressure_policy,
+            );
 
-            self.subscribe_from_pub_sub_sender(&channels, &pub_sub_sender)
+            self.subscribe_from_pub_sub_sender(&channels, &pub_sub_sender, false)
                 .await?;
 
             Ok(PubSubStream::from_channels(
@@ -485,19 +1513,51 @@ impl<'a> PubSubCommands<'a> for &'a Client {
         })
     }
 
+    #[inline]
+    fn subscribe_with_events<C, CC>(self, channels: CC) -> Future<'a, PubSubEventStream>
+    where
+        C: SingleArg + Send + 'a,
+        CC: SingleArgCollection<C>,
+    {
+        let mut channels = CommandArgs::default().arg(channels).build();
+        self.prefix_channels(&mut channels);
+        let channels = new_subscriptions(&CommandArgs::default(), &channels);
+
+        Box::pin(async move {
+            let (pub_sub_sender, pub_sub_receiver) = pub_sub_channel(
+                self.pub_sub_channel_capacity,
+                self.pub_sub_backpressure_policy,
+            );
+
+            self.subscribe_from_pub_sub_sender(&channels, &pub_sub_sender, true)
+                .await?;
+
+            Ok(PubSubEventStream::new(PubSubStream::from_channels(
+                channels,
+                pub_sub_sender,
+                pub_sub_receiver,
+                self.clone(),
+            )))
+        })
+    }
+
     #[inline]
     fn psubscribe<P, PP>(self, patterns: PP) -> Future<'a, PubSubStream>
     where
         P: SingleArg + Send + 'a,
         PP: SingleArgCollection<P>,
     {
-        let patterns = CommandArgs::default().arg(patterns).build();
+        let mut patterns = CommandArgs::default().arg(patterns).build();
+        self.prefix_channels(&mut patterns);
+        let patterns = new_subscriptions(&CommandArgs::default(), &patterns);
 
         Box::pin(async move {
-            let (pub_sub_sender, pub_sub_receiver): (PubSubSender, PubSubReceiver) =
-                mpsc::unbounded();
+            let (pub_sub_sender, pub_sub_receiver) = pub_sub_channel(
+                self.pub_sub_channel_capacity,
+                self.pub_sub_backpressure_policy,
+            );
 
-            self.psubscribe_from_pub_sub_sender(&patterns, &pub_sub_sender)
+            self.psubscribe_from_pub_sub_sender(&patterns, &pub_sub_sender, false)
                 .await?;
 
             Ok(PubSubStream::from_patterns(
@@ -516,12 +1576,15 @@ impl<'a> PubSubCommands<'a> for &'a Client {
         CC: SingleArgCollection<C>,
     {
         let shardchannels = CommandArgs::default().arg(shardchannels).build();
+        let shardchannels = new_subscriptions(&CommandArgs::default(), &shardchannels);
 
         Box::pin(async move {
-            let (pub_sub_sender, pub_sub_receiver): (PubSubSender, PubSubReceiver) =
-                mpsc::unbounded();
+            let (pub_sub_sender, pub_sub_receiver) = pub_sub_channel(
+                self.pub_sub_channel_capacity,
+                self.pub_sub_backpressure_policy,
+            );
 
-            self.ssubscribe_from_pub_sub_sender(&shardchannels, &pub_sub_sender)
+            self.ssubscribe_from_pub_sub_sender(&shardchannels, &pub_sub_sender, false)
                 .await?;
 
             Ok(PubSubStream::from_shardchannels(
@@ -534,6 +1597,35 @@ impl<'a> PubSubCommands<'a> for &'a Client {
     }
 }
 
+/// `true` if the server may block the connection while processing `command`
+fn is_blocking_command(command: &Command) -> bool {
+    const BLOCKING_COMMANDS: [&str; 10] = [
+        "BLMOVE",
+        "BLMPOP",
+        "BLPOP",
+        "BRPOP",
+        "BRPOPLPUSH",
+        "BZMPOP",
+        "BZPOPMAX",
+        "BZPOPMIN",
+        "WAIT",
+        "WAITAOF",
+    ];
+
+    if BLOCKING_COMMANDS
+        .iter()
+        .any(|name| command.name.eq_ignore_ascii_case(name))
+    {
+        return true;
+    }
+
+    (command.name.eq_ignore_ascii_case("XREAD") || command.name.eq_ignore_ascii_case("XREADGROUP"))
+        && command
+            .args
+            .iter()
+            .any(|arg| arg.eq_ignore_ascii_case(b"BLOCK"))
+}
+
 impl<'a> BlockingCommands<'a> for &'a Client {
     fn monitor(self) -> Future<'a, MonitorStream> {
         Box::pin(async move {
@@ -549,4 +1641,54 @@ impl<'a> BlockingCommands<'a> for &'a Client {
             Ok(MonitorStream::new(push_receiver, self.clone()))
         })
     }
+
+    fn xread_stream<V>(
+        self,
+        key: impl Into<String>,
+        last_id: impl Into<String>,
+        count: Option<usize>,
+    ) -> Result<XReadStream<V>>
+    where
+        V: PrimitiveResponse + DeserializeOwned + Send + 'static,
+    {
+        if self.is_connection_shared() {
+            return Err(Error::Client(
+                "XREAD streams cannot be created on a connection shared by multiple clients"
+                    .to_owned(),
+            ));
+        }
+
+        Ok(XReadStream::new(
+            self.clone(),
+            key.into(),
+            last_id.into(),
+            count,
+        ))
+    }
+
+    fn xreadgroup_stream<V>(
+        self,
+        group: impl Into<String>,
+        consumer: impl Into<String>,
+        key: impl Into<String>,
+        count: Option<usize>,
+    ) -> Result<XReadGroupStream<V>>
+    where
+        V: PrimitiveResponse + DeserializeOwned + Send + 'static,
+    {
+        if self.is_connection_shared() {
+            return Err(Error::Client(
+                "XREADGROUP streams cannot be created on a connection shared by multiple clients"
+                    .to_owned(),
+            ));
+        }
+
+        Ok(XReadGroupStream::new(
+            self.clone(),
+            group.into(),
+            consumer.into(),
+            key.into(),
+            count,
+        ))
+    }
 }
@@ -0,0 +1,19 @@
+use std::sync::{Arc, RwLock};
+
+/// Node of the cluster topology, as known by the connection of a [`Client`](crate::client::Client)
+///
+/// See [`Client::cluster_topology`](crate::client::Client::cluster_topology)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClusterNodeInfo {
+    /// Unique id of the node in the cluster
+    pub id: String,
+    /// Host of the node
+    pub host: String,
+    /// Port of the node
+    pub port: u16,
+    /// `true` if the node is a master, `false` if it is a replica
+    pub is_master: bool,
+}
+
+/// Snapshot of the cluster nodes, shared between the cluster connection and its clients
+pub(crate) type ClusterNodes = Arc<RwLock<Vec<ClusterNodeInfo>>>;
@@ -0,0 +1,99 @@
+use crate::{
+    client::{Config, ServerConfig},
+    resp::{Command, RespBuf},
+    Result,
+};
+use std::{future::Future, sync::Arc, time::Instant};
+use tracing::{field::Empty, Instrument, Span};
+
+/// Name of the server, as reported in the `net.peer.name` field of the command spans
+pub(crate) fn peer_name(config: &Config) -> Arc<str> {
+    match &config.server {
+        ServerConfig::Standalone { host, port: _ } => Arc::from(host.as_str()),
+        ServerConfig::Sentinel(sentinel_config) => Arc::from(sentinel_config.service_name.as_str()),
+        ServerConfig::Cluster(cluster_config) => cluster_config
+            .nodes
+            .first()
+            .map(|(host, _)| Arc::from(host.as_str()))
+            .unwrap_or_else(|| Arc::from("")),
+    }
+}
+
+/// Statement of a command, with its argument values redacted, e.g. `SET ? ?`
+pub(crate) fn redacted_statement(command: &Command) -> String {
+    let mut statement = command.name.to_owned();
+    for _ in 0..command.args.len() {
+        statement.push_str(" ?");
+    }
+    statement
+}
+
+/// Statement of a batch of commands, separated by `;`, e.g. `SET ? ?;GET ?`
+pub(crate) fn redacted_batch_statement(commands: &[Command]) -> String {
+    let mut statement = String::new();
+    for (i, command) in commands.iter().enumerate() {
+        if i > 0 {
+            statement.push(';');
+        }
+        statement.push_str(&redacted_statement(command));
+    }
+    statement
+}
+
+/// Span following the OpenTelemetry semantic conventions for database client calls
+///
+/// See <https://opentelemetry.io/docs/specs/semconv/database/redis/>
+pub(crate) fn command_span(statement: &str, peer_name: &str) -> Span {
+    tracing::info_span!(
+        "redis",
+        otel.kind = "client",
+        otel.status_code = Empty,
+        db.system = "redis",
+        db.statement = statement,
+        net.peer.name = peer_name,
+        db.redis.latency_ms = Empty,
+        error.message = Empty,
+    )
+}
+
+/// Run `future` in `span`, then record its latency and its error status
+///
+/// `reply_error` extracts the error message from an error reply of the server
+pub(crate) async fn instrument<T, F>(
+    span: Span,
+    future: F,
+    reply_error: impl Fn(&T) -> Option<String>,
+) -> Result<T>
+where
+    F: Future<Output = Result<T>>,
+{
+    let start = Instant::now();
+    let result = future.instrument(span.clone()).await;
+    span.record(
+        "db.redis.latency_ms",
+        start.elapsed().as_secs_f64() * 1000.0,
+    );
+
+    let error = match &result {
+        Ok(value) => reply_error(value),
+        Err(e) => Some(e.to_string()),
+    };
+
+    if let Some(error) = error {
+        span.record("otel.status_code", "ERROR");
+        span.record("error.message", error.as_str());
+    } else {
+        span.record("otel.status_code", "OK");
+    }
+
+    result
+}
+
+/// Error message of an error reply
+pub(crate) fn resp_buf_error(resp_buf: &RespBuf) -> Option<String> {
+    if resp_buf.is_error() {
+        resp_buf.to::<()>().err().map(|e| e.to_string())
+    } else {
+        None
+    }
+}
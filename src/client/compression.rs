@@ -0,0 +1,245 @@
+use crate::{
+    resp::{Command, RespBuf, ARRAY_TAG, BULK_STRING_TAG, NIL_TAG},
+    Error, Result,
+};
+use bytes::{BufMut, BytesMut};
+
+/// Header prepended to compressed values, followed by the algorithm identifier
+const MAGIC_HEADER: &[u8; 3] = b"\xferz";
+#[cfg(feature = "lz4")]
+const LZ4_ID: u8 = 1;
+#[cfg(feature = "zstd")]
+const ZSTD_ID: u8 = 2;
+#[cfg(feature = "zstd")]
+const ZSTD_LEVEL: i32 = 3;
+/// Maximum size of a decompressed value, which is also the maximum size of a Redis string
+#[cfg(any(feature = "lz4", feature = "zstd"))]
+const MAX_DECOMPRESSED_SIZE: usize = 512 * 1024 * 1024;
+
+/// Compression algorithm of a [`CompressionConfig`](CompressionConfig)
+///
+/// Each algorithm is available behind the cargo feature of the same name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    /// [LZ4](https://docs.rs/lz4_flex) compression (`lz4` feature)
+    #[cfg(feature = "lz4")]
+    Lz4,
+    /// [Zstandard](https://docs.rs/zstd) compression (`zstd` feature)
+    #[cfg(feature = "zstd")]
+    Zstd,
+}
+
+/// Transparent compression of the string values written and read
+/// by the [`StringCommands`](crate::commands::StringCommands) `set`/`get` family.
+///
+/// Values of at least `min_size` bytes are compressed and prefixed by a small header.
+/// When read, only values starting with this header are decompressed:
+/// values written without compression are returned as-is.
+///
+/// Only commands sent directly by a [`Client`](crate::client::Client) participate:
+/// raw commands built with [`cmd`](crate::resp::cmd), pipelines and transactions are left untouched.
+///
+/// Commands working on a part of the stored bytes are not compression aware:
+/// [`getrange`](crate::commands::StringCommands::getrange),
+/// [`append`](crate::commands::StringCommands::append),
+/// [`setrange`](crate::commands::StringCommands::setrange) and
+/// [`strlen`](crate::commands::StringCommands::strlen) are sent and replied as-is,
+/// and thus work on the compressed bytes of a compressed value.
+/// Keys accessed with these commands should hold values below `min_size`.
+///
+/// See [`Config::compression`](crate::client::Config::compression)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompressionConfig {
+    /// Compression algorithm
+    pub algorithm: Compression,
+    /// Minimum size in bytes of a value to be compressed
+    pub min_size: usize,
+}
+
+impl CompressionConfig {
+    /// Compresses in place the values of a `set` family command
+    pub(crate) fn compress_command(&self, command: &mut Command) -> Result<()> {
+        let args = command.args.args_mut();
+        let value_indices = match command.name {
+            "SET" | "SETNX" | "GETSET" => 1..args.len().min(2),
+            "SETEX" | "PSETEX" => 2..args.len().min(3),
+            "MSET" | "MSETNX" => 1..args.len(),
+            _ => return Ok(()),
+        };
+        let step = if matches!(command.name, "MSET" | "MSETNX") {
+            2
+        } else {
+            1
+        };
+
+        for index in value_indices.step_by(step) {
+            let value = &mut args[index];
+            if value.len() >= self.min_size {
+                *value = self.compress(value)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // without compression feature, `Compression` has no variant
+    #[cfg_attr(
+        not(any(feature = "lz4", feature = "zstd")),
+        allow(unreachable_code, unused_variables)
+    )]
+    fn compress(&self, value: &[u8]) -> Result<Vec<u8>> {
+        let (algorithm_id, compressed): (u8, Vec<u8>) = match self.algorithm {
+            #[cfg(feature = "lz4")]
+            Compression::Lz4 => (LZ4_ID, lz4_flex::compress_prepend_size(value)),
+            #[cfg(feature = "zstd")]
+            Compression::Zstd => (
+                ZSTD_ID,
+                zstd::bulk::compress(value, ZSTD_LEVEL)
+                    .map_err(|e| Error::Client(format!("Cannot compress value: {e}")))?,
+            ),
+        };
+
+        let mut output = Vec::with_capacity(MAGIC_HEADER.len() + 1 + compressed.len());
+        output.extend_from_slice(MAGIC_HEADER);
+        output.push(algorithm_id);
+        output.extend_from_slice(&compressed);
+        Ok(output)
+    }
+}
+
+/// Decompresses the bulk strings of a `get` family reply, if any starts with the compression header
+pub(crate) fn decompress_reply(resp_buf: RespBuf) -> Result<RespBuf> {
+    let bytes = resp_buf.as_bytes();
+    let (values, is_array) = match bytes.first() {
+        Some(&BULK_STRING_TAG) => (parse_bulk_strings(bytes, 1), false),
+        Some(&ARRAY_TAG) => {
+            let Some((len, pos)) = parse_integer(bytes, 1) else {
+                return Ok(resp_buf);
+            };
+            (parse_bulk_strings(&bytes[pos..], len), true)
+        }
+        _ => return Ok(resp_buf),
+    };
+
+    // only bulk strings & nils are expected in the reply of the `get` family
+    let Some(values) = values else {
+        return Ok(resp_buf);
+    };
+
+    if !values
+        .iter()
+        .any(|value| value.is_some_and(|v| v.starts_with(MAGIC_HEADER)))
+    {
+        return Ok(resp_buf);
+    }
+
+    let mut output = BytesMut::new();
+    if is_array {
+        output.put_slice(format!("*{}\r\n", values.len()).as_bytes());
+    }
+    for value in values {
+        match value {
+            Some(value) => {
+                let value = decompress(value)?;
+                output.put_slice(format!("${}\r\n", value.len()).as_bytes());
+                output.put_slice(&value);
+                output.put_slice(b"\r\n");
+            }
+            None => output.put_slice(b"_\r\n"),
+        }
+    }
+
+    Ok(RespBuf::new(output.freeze()))
+}
+
+fn decompress(value: &[u8]) -> Result<Vec<u8>> {
+    let Some(compressed) = value.strip_prefix(MAGIC_HEADER) else {
+        return Ok(value.to_vec());
+    };
+
+    match compressed.split_first() {
+        #[cfg(feature = "lz4")]
+        Some((&LZ4_ID, compressed)) => decompress_lz4(compressed),
+        #[cfg(feature = "zstd")]
+        Some((&ZSTD_ID, compressed)) => decompress_zstd(compressed),
+        _ => Err(Error::Client(
+            "Cannot decompress value: unsupported compression algorithm".to_owned(),
+        )),
+    }
+}
+
+#[cfg(feature = "lz4")]
+fn decompress_lz4(compressed: &[u8]) -> Result<Vec<u8>> {
+    let (size, compressed) = lz4_flex::block::uncompressed_size(compressed)
+        .map_err(|e| Error::Client(format!("Cannot decompress value: {e}")))?;
+    if size > MAX_DECOMPRESSED_SIZE {
+        return Err(decompressed_size_error());
+    }
+
+    lz4_flex::decompress(compressed, size)
+        .map_err(|e| Error::Client(format!("Cannot decompress value: {e}")))
+}
+
+/// Streams the decompression to stop at [`MAX_DECOMPRESSED_SIZE`],
+/// whatever the content size announced by the frame
+#[cfg(feature = "zstd")]
+fn decompress_zstd(compressed: &[u8]) -> Result<Vec<u8>> {
+    use std::io::Read;
+
+    let mut value = Vec::new();
+    zstd::stream::read::Decoder::new(compressed)
+        .and_then(|decoder| {
+            decoder
+                .take(MAX_DECOMPRESSED_SIZE as u64 + 1)
+                .read_to_end(&mut value)
+        })
+        .map_err(|e| Error::Client(format!("Cannot decompress value: {e}")))?;
+    if value.len() > MAX_DECOMPRESSED_SIZE {
+        return Err(decompressed_size_error());
+    }
+
+    Ok(value)
+}
+
+#[cfg(any(feature = "lz4", feature = "zstd"))]
+fn decompressed_size_error() -> Error {
+    Error::Client(format!(
+        "Cannot decompress value: decompressed size exceeds {MAX_DECOMPRESSED_SIZE} bytes"
+    ))
+}
+
+/// Parses `count` consecutive bulk strings or nils
+fn parse_bulk_strings(bytes: &[u8], count: usize) -> Option<Vec<Option<&[u8]>>> {
+    let mut values = Vec::with_capacity(count);
+    let mut pos = 0;
+
+    for _ in 0..count {
+        match bytes.get(pos) {
+            Some(&BULK_STRING_TAG) => {
+                let (len, start): (i64, usize) = parse_integer(bytes, pos + 1)?;
+                if len < 0 {
+                    values.push(None);
+                    pos = start;
+                } else {
+                    let end = start + len as usize;
+                    values.push(Some(bytes.get(start..end)?));
+                    pos = end + 2;
+                }
+            }
+            Some(&NIL_TAG) => {
+                values.push(None);
+                pos += 3;
+            }
+            _ => return None,
+        }
+    }
+
+    Some(values)
+}
+
+/// Parses an integer terminated by CRLF, returning the position after the CRLF
+fn parse_integer<T: std::str::FromStr>(bytes: &[u8], pos: usize) -> Option<(T, usize)> {
+    let end = pos + memchr::memchr(b'\r', bytes.get(pos..)?)?;
+    let value = std::str::from_utf8(&bytes[pos..end]).ok()?.parse().ok()?;
+    Some((value, end + 2))
+}
@@ -1,20 +1,41 @@
-use crate::{Error, Result};
+use crate::{client::CompressionConfig, network::lookup_host, Error, Future, Result};
 #[cfg(feature = "tls")]
 use native_tls::{Certificate, Identity, Protocol, TlsConnector, TlsConnectorBuilder};
-use std::{collections::HashMap, str::FromStr, time::Duration};
+use rand::Rng;
+use std::{
+    collections::HashMap,
+    env::{self, VarError},
+    net::SocketAddr,
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
 use url::Url;
 
 const DEFAULT_PORT: u16 = 6379;
 const DEFAULT_DATABASE: usize = 0;
 const DEFAULT_WAIT_BETWEEN_FAILURES: u64 = 250;
+const DEFAULT_SENTINEL_REFRESH_ON_SWITCH: bool = false;
 const DEFAULT_CONNECT_TIMEOUT: u64 = 10_000;
 const DEFAULT_COMMAND_TIMEOUT: u64 =  0;
+const DEFAULT_CLOSE_TIMEOUT: u64 = 5_000;
+const DEFAULT_WRITE_COALESCE: Option<Duration> = None;
 const DEFAULT_AUTO_RESUBSCRTBE: bool =  true;
 const DEFAULT_AUTO_REMONITOR: bool = true;
 const DEFAULT_KEEP_ALIVE: Option<Duration> = None;
 const DEFAULT_NO_DELAY: bool = true;
 const DEFAULT_MAX_COMMAND_ATTEMPTS: usize = 3;
 const DEFAULT_RETRY_ON_ERROR: bool = false;
+const DEFAULT_STRICT_BLOCKING_COMMANDS: bool = false;
+const DEFAULT_RTT_EWMA_ALPHA: Option<f64> = None;
+const DEFAULT_CHUNK_SIZE: usize = 1_000;
+const DEFAULT_PUB_SUB_CHANNEL_CAPACITY: Option<usize> = None;
+const DEFAULT_PUB_SUB_BACKPRESSURE_POLICY: BackpressurePolicy = BackpressurePolicy::Backpressure;
+const DEFAULT_RECONNECT_POLICY: ReconnectPolicy = ReconnectPolicy::ExponentialBackoff {
+    base: Duration::from_millis(100),
+    max: Duration::from_millis(10_000),
+    factor: 2,
+};
 
 type Uri<'a> = (
     &'a str,
@@ -61,6 +82,20 @@ pub struct Config {
     /// 
     /// The default is 0
     pub command_timeout: Duration,
+    /// When the client is [closed](crate::client::Client::close), the time to wait
+    /// for the replies of the commands already sent before closing the connection.
+    ///
+    /// The default is 5 seconds
+    pub close_timeout: Duration,
+    /// Optional delay to wait for more commands before writing a batch to the network (default `None`)
+    ///
+    /// The commands sent concurrently by the clones of a client are written in a single batch
+    /// when they are already queued. Waiting a few dozen microseconds accumulates more commands
+    /// per batch, trading a hair of latency for fewer system calls under a steady load
+    /// of individual commands. The replies received during the delay are processed after it.
+    ///
+    /// In a connection URL, the delay is expressed in microseconds, e.g. `write_coalesce=50`.
+    pub write_coalesce: Option<Duration>,
     /// When the client reconnects, channels subscribed in the previous connection will be
     /// resubscribed automatically if `auto_resubscribe` is `true`.
     /// 
@@ -85,6 +120,9 @@ pub struct Config {
     /// See [`TcpStream::set_nodelay`](https://docs.rs/tokio/latest/tokio/net/struct.TcpStream.html#method.set_nodelay)    
     pub no_delay: bool,
     /// Maximum number of retry attempts to send a command to the Redis server (default `3`).
+    ///
+    /// This limit can be overriden for each command with
+    /// [`PreparedCommand::retry`](crate::client::PreparedCommand::retry).
     pub max_command_attempts: usize,
     /// Defines the default strategy for retries on network error (default `false`):
     /// * `true` - retry sending the command/batch of commands on network error
@@ -93,12 +131,91 @@ pub struct Config {
     /// This strategy can be overriden for each command/batch 
     /// of commands in the following functions:
     /// * [`PreparedCommand::retry_on_error`](crate::client::PreparedCommand::retry_on_error)
+    /// * [`PreparedCommand::retry`](crate::client::PreparedCommand::retry)
     /// * [`Pipeline::retry_on_error`](crate::client::Pipeline::retry_on_error)
     /// * [`Transaction::retry_on_error`](crate::client::Transaction::retry_on_error)
     /// * [`Client::send`](crate::client::Client::send)
     /// * [`Client::send_and_forget`](crate::client::Client::send_and_forget)
     /// * [`Client::send_batch`](crate::client::Client::send_batch)
+    ///
+    /// When enabled, non-idempotent commands should opt out,
+    /// see [`RetryPolicy`](crate::client::RetryPolicy).
     pub retry_on_error: bool,
+    /// Reject blocking commands sent with [`Client::send`](crate::client::Client::send)
+    /// on a connection shared by clones of the client (default `false`).
+    ///
+    /// Blocking commands (`BLPOP`, `BZPOPMIN`, `WAIT`, `XREAD BLOCK`, ...) monopolize the connection:
+    /// the commands of the other clones are stalled until the blocking command returns.
+    /// * `false` - a warning is logged
+    /// * `true` - an [`Error::Client`](crate::Error::Client) is returned and the command is not sent
+    pub strict_blocking_commands: bool,
+    /// Smoothing factor, between `0` (excluded) and `1`, of the exponentially weighted moving average
+    /// of the round-trip times measured by [`Client::ping_rtt`](crate::client::Client::ping_rtt)
+    /// (default `None`: the average is not tracked).
+    ///
+    /// The higher the factor, the more weight is given to the last measures.
+    /// See [`Client::avg_rtt`](crate::client::Client::avg_rtt)
+    pub rtt_ewma_alpha: Option<f64>,
+    /// Default maximum number of keys per command sent by
+    /// [`Client::mset_chunked`](crate::client::Client::mset_chunked) and
+    /// [`Client::mget_chunked`](crate::client::Client::mget_chunked) (default `1000`).
+    ///
+    /// It keeps each command below the `proto-max-bulk-len` & `client-query-buffer-limit` server limits.
+    pub chunk_size: usize,
+    /// Delay between the consecutive failed attempts to reconnect to the server
+    /// (default: exponential backoff from 100 milliseconds up to 10 seconds).
+    ///
+    /// See [`ReconnectPolicy`](ReconnectPolicy)
+    pub reconnect_policy: ReconnectPolicy,
+    /// Maximum number of messages buffered for each [`PubSubStream`](crate::client::PubSubStream)
+    /// which have not been consumed yet (default `None`: unbounded).
+    ///
+    /// Without limit, a slow subscriber facing a fast publisher
+    /// makes the memory grow until exhaustion.
+    /// When the limit is reached, [`pub_sub_backpressure_policy`](Config::pub_sub_backpressure_policy) applies.
+    pub pub_sub_channel_capacity: Option<usize>,
+    /// What to do when the message buffer of a [`PubSubStream`](crate::client::PubSubStream) is full
+    /// (default [`BackpressurePolicy::Backpressure`](BackpressurePolicy::Backpressure)).
+    ///
+    /// Only applies when [`pub_sub_channel_capacity`](Config::pub_sub_channel_capacity) is set.
+    pub pub_sub_backpressure_policy: BackpressurePolicy,
+    /// Optional transparent compression of large string values (default `None`)
+    ///
+    /// Requires the `lz4` or `zstd` feature.
+    /// See [`CompressionConfig`](crate::client::CompressionConfig)
+    pub compression: Option<CompressionConfig>,
+    /// Optional prefix transparently prepended to the keys of each command (default `None`)
+    ///
+    /// Useful to isolate multiple tenants sharing the same Redis database.
+    ///
+    /// Key arguments are located with a static table of key specifications
+    /// covering the built-in generic, string, hash, list, set, sorted set, geo,
+    /// HyperLogLog, stream and scripting commands. Some arguments are not prefixed:
+    /// * key patterns: `KEYS`, `SCAN` with `MATCH`, `SORT` `BY`/`GET`,
+    /// * `GEORADIUS` `STORE`/`STOREDIST` destinations.
+    ///
+    /// Commands missing from the table, e.g. modules commands (`JSON.*`, `FT.*`, `TS.*`, ...),
+    /// are prefixed from their metadata, which must be fetched beforehand with
+    /// [`Client::cached_command_info`](crate::client::Client::cached_command_info).
+    /// Otherwise, or if their keys are movable (e.g. `MIGRATE`),
+    /// they fail with an [`Error::Client`](crate::Error::Client) instead of being sent unprefixed.
+    ///
+    /// Keys returned in replies (`KEYS`, `SCAN`, `RANDOMKEY`, `BLPOP`, `LMPOP`, `XREAD`, ...)
+    /// keep their prefix.
+    ///
+    /// Subscriptions to keyspace notification channels (`__keyspace@<db>__:<key>`)
+    /// are prefixed as well, and the prefix is stripped from the received
+    /// [`PubSubMessage`](crate::client::PubSubMessage)s.
+    /// Keyevent notifications (`__keyevent@<db>__:<event>`) of keys outside the prefix are skipped.
+    pub key_prefix: Option<String>,
+    /// An optional custom resolver of the server host names (default `None`)
+    ///
+    /// When set, the resolver is called at each connection or reconnection.
+    /// The returned addresses are tried in order until a connection succeeds.
+    ///
+    /// See [`Config::with_resolver`](Config::with_resolver)
+    /// and [`Config::with_socket_addrs`](Config::with_socket_addrs)
+    pub resolver: Option<Resolver>,
 }
 
 impl Default for Config {
@@ -112,6 +229,8 @@ impl Default for Config {
             tls_config: Default::default(),
             connect_timeout: Duration::from_millis(DEFAULT_CONNECT_TIMEOUT),
             command_timeout: Duration::from_millis(DEFAULT_COMMAND_TIMEOUT),
+            close_timeout: Duration::from_millis(DEFAULT_CLOSE_TIMEOUT),
+            write_coalesce: DEFAULT_WRITE_COALESCE,
             auto_resubscribe: DEFAULT_AUTO_RESUBSCRTBE,
             auto_remonitor: DEFAULT_AUTO_REMONITOR,
             connection_name: String::from(""),
@@ -119,10 +238,42 @@ impl Default for Config {
             no_delay: DEFAULT_NO_DELAY,
             max_command_attempts: DEFAULT_MAX_COMMAND_ATTEMPTS,
             retry_on_error: DEFAULT_RETRY_ON_ERROR,
+            strict_blocking_commands: DEFAULT_STRICT_BLOCKING_COMMANDS,
+            rtt_ewma_alpha: DEFAULT_RTT_EWMA_ALPHA,
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            reconnect_policy: DEFAULT_RECONNECT_POLICY,
+            pub_sub_channel_capacity: DEFAULT_PUB_SUB_CHANNEL_CAPACITY,
+            pub_sub_backpressure_policy: DEFAULT_PUB_SUB_BACKPRESSURE_POLICY,
+            compression: None,
+            key_prefix: None,
+            resolver: None,
+        }
+    }
+}
+
+fn parse_env_var<T: FromStr>(
+    name: &'static str,
+    value: Option<String>,
+    default: T,
+    malformed: &mut Vec<&'static str>,
+) -> T {
+    match value.map(|value| value.parse::<T>()) {
+        Some(Ok(value)) => value,
+        Some(Err(_)) => {
+            malformed.push(name);
+            default
         }
+        None => default,
     }
 }
 
+fn malformed_env_vars(names: &[&str]) -> Error {
+    Error::Config(format!(
+        "Malformed environment variables: {}",
+        names.join(", ")
+    ))
+}
+
 impl FromStr for Config {
     type Err = Error;
 
@@ -144,6 +295,72 @@ impl Config {
         Self::from_str(uri.as_str())
     }
 
+    /// Build a config from the environment variables of the process
+    ///
+    /// The following variables are read:
+    /// * `REDIS_URL`: URI parsed like any [`IntoConfig`] string, e.g. `redis://:pwd@localhost:6379/1`
+    /// * `REDIS_HOST`: host of the server, `127.0.0.1` by default
+    /// * `REDIS_PORT`: port of the server, `6379` by default
+    /// * `REDIS_PASSWORD`, or `REDISCLI_AUTH` as used by `redis-cli`: password
+    /// * `REDIS_DB`: database index, `0` by default
+    ///
+    /// When `REDIS_URL` is set, it wins and the other variables are ignored.
+    /// When no variable is set, the [`default`](Config::default) config is returned.
+    ///
+    /// # Errors
+    /// An [`Error::Config`](crate::Error::Config) listing the malformed variables
+    pub fn from_env() -> Result<Config> {
+        Self::from_env_vars(|name| env::var(name))
+    }
+
+    /// [`from_env`](Config::from_env) with a custom source of environment variables
+    pub(crate) fn from_env_vars(
+        var: impl Fn(&str) -> std::result::Result<String, VarError>,
+    ) -> Result<Config> {
+        let mut malformed = Vec::new();
+        let mut read = |name: &'static str| match var(name) {
+            Ok(value) => Some(value),
+            Err(VarError::NotPresent) => None,
+            Err(VarError::NotUnicode(_)) => {
+                malformed.push(name);
+                None
+            }
+        };
+
+        let url = read("REDIS_URL");
+        let host = read("REDIS_HOST");
+        let port = read("REDIS_PORT");
+        let password = read("REDIS_PASSWORD").or_else(|| read("REDISCLI_AUTH"));
+        let database = read("REDIS_DB");
+
+        if let Some(url) = url {
+            // the parsing error is not forwarded: it would leak the password of the URL
+            return match Config::from_str(&url) {
+                Ok(config) if malformed.is_empty() => Ok(config),
+                Ok(_) => Err(malformed_env_vars(&malformed)),
+                Err(_) => {
+                    malformed.push("REDIS_URL");
+                    Err(malformed_env_vars(&malformed))
+                }
+            };
+        }
+
+        let port = parse_env_var("REDIS_PORT", port, DEFAULT_PORT, &mut malformed);
+        let database = parse_env_var("REDIS_DB", database, DEFAULT_DATABASE, &mut malformed);
+
+        if !malformed.is_empty() {
+            return Err(malformed_env_vars(&malformed));
+        }
+
+        let mut builder = Config::builder()
+            .host_port(host.unwrap_or_else(|| "127.0.0.1".to_owned()), port)
+            .database(database);
+        if let Some(password) = password {
+            builder = builder.password(password);
+        }
+        builder.build()
+    }
+
     /// Parse address in the standard formart `host`:`port`
     fn parse_addr(str: &str) -> Option<(&str, u16)> {
         let mut iter = str.split(':');
@@ -236,6 +453,12 @@ impl Config {
 
                     sentinel_config.username = query.remove("sentinel_username");
                     sentinel_config.password = query.remove("sentinel_password");
+
+                    if let Some(refresh_on_switch) = query.remove("sentinel_refresh_on_switch") {
+                        if let Ok(refresh_on_switch) = refresh_on_switch.parse::<bool>() {
+                            sentinel_config.refresh_on_switch = refresh_on_switch;
+                        }
+                    }
                 }
 
                 ServerConfig::Sentinel(sentinel_config)
@@ -283,6 +506,18 @@ impl Config {
                 }
             }
 
+            if let Some(millis) = query.remove("close_timeout") {
+                if let Ok(millis) = millis.parse::<u64>() {
+                    config.close_timeout = Duration::from_millis(millis);
+                }
+            }
+
+            if let Some(micros) = query.remove("write_coalesce") {
+                if let Ok(micros) = micros.parse::<u64>() {
+                    config.write_coalesce = Some(Duration::from_micros(micros));
+                }
+            }
+
             if let Some(auto_resubscribe) = query.remove("auto_resubscribe") {
                 if let Ok(auto_resubscribe) = auto_resubscribe.parse::<bool>() {
                     config.auto_resubscribe = auto_resubscribe;
@@ -322,6 +557,40 @@ impl Config {
                     config.retry_on_error = retry_on_error;
                 }
             }
+
+            if let Some(strict_blocking_commands) = query.remove("strict_blocking_commands") {
+                if let Ok(strict_blocking_commands) = strict_blocking_commands.parse::<bool>() {
+                    config.strict_blocking_commands = strict_blocking_commands;
+                }
+            }
+
+            if let Some(rtt_ewma_alpha) = query.remove("rtt_ewma_alpha") {
+                if let Ok(rtt_ewma_alpha) = rtt_ewma_alpha.parse::<f64>() {
+                    config.rtt_ewma_alpha = Some(rtt_ewma_alpha);
+                }
+            }
+
+            if let Some(chunk_size) = query.remove("chunk_size") {
+                if let Ok(chunk_size) = chunk_size.parse::<usize>() {
+                    config.chunk_size = chunk_size;
+                }
+            }
+
+            if let Some(capacity) = query.remove("pub_sub_channel_capacity") {
+                if let Ok(capacity) = capacity.parse::<usize>() {
+                    config.pub_sub_channel_capacity = Some(capacity);
+                }
+            }
+
+            if let Some(policy) = query.remove("pub_sub_backpressure_policy") {
+                if let Ok(policy) = policy.parse::<BackpressurePolicy>() {
+                    config.pub_sub_backpressure_policy = policy;
+                }
+            }
+
+            if let Some(key_prefix) = query.remove("key_prefix") {
+                config.key_prefix = Some(key_prefix);
+            }
         }
 
         Some(config)
@@ -476,6 +745,7 @@ impl ToString for Config {
                 wait_between_failures: _,
                 password: _,
                 username: _,
+                refresh_on_switch: _,
             }) => {
                 s.push_str(
                     &instances
@@ -529,6 +799,27 @@ impl ToString for Config {
             s.push_str(&format!("command_timeout={command_timeout}"));
         }
 
+        let close_timeout = self.close_timeout.as_millis() as u64;
+        if close_timeout != DEFAULT_CLOSE_TIMEOUT {
+            if !query_separator {
+                query_separator = true;
+                s.push('?');
+            } else {
+                s.push('&');
+            }
+            s.push_str(&format!("close_timeout={close_timeout}"));
+        }
+
+        if let Some(write_coalesce) = self.write_coalesce {
+            if !query_separator {
+                query_separator = true;
+                s.push('?');
+            } else {
+                s.push('&');
+            }
+            s.push_str(&format!("write_coalesce={}", write_coalesce.as_micros()));
+        }
+
         if self.auto_resubscribe != DEFAULT_AUTO_RESUBSCRTBE {
             if !query_separator {
                 query_separator = true;
@@ -599,12 +890,79 @@ impl ToString for Config {
             s.push_str(&format!("retry_on_error={}", self.retry_on_error));
         }
 
+        if self.strict_blocking_commands != DEFAULT_STRICT_BLOCKING_COMMANDS {
+            if !query_separator {
+                query_separator = true;
+                s.push('?');
+            } else {
+                s.push('&');
+            }
+            s.push_str(&format!(
+                "strict_blocking_commands={}",
+                self.strict_blocking_commands
+            ));
+        }
+
+        if let Some(rtt_ewma_alpha) = self.rtt_ewma_alpha {
+            if !query_separator {
+                query_separator = true;
+                s.push('?');
+            } else {
+                s.push('&');
+            }
+            s.push_str(&format!("rtt_ewma_alpha={rtt_ewma_alpha}"));
+        }
+
+        if self.chunk_size != DEFAULT_CHUNK_SIZE {
+            if !query_separator {
+                query_separator = true;
+                s.push('?');
+            } else {
+                s.push('&');
+            }
+            s.push_str(&format!("chunk_size={}", self.chunk_size));
+        }
+
+        if let Some(capacity) = self.pub_sub_channel_capacity {
+            if !query_separator {
+                query_separator = true;
+                s.push('?');
+            } else {
+                s.push('&');
+            }
+            s.push_str(&format!("pub_sub_channel_capacity={capacity}"));
+        }
+
+        if self.pub_sub_backpressure_policy != DEFAULT_PUB_SUB_BACKPRESSURE_POLICY {
+            if !query_separator {
+                query_separator = true;
+                s.push('?');
+            } else {
+                s.push('&');
+            }
+            s.push_str(&format!(
+                "pub_sub_backpressure_policy={}",
+                self.pub_sub_backpressure_policy
+            ));
+        }
+
+        if let Some(key_prefix) = &self.key_prefix {
+            if !query_separator {
+                query_separator = true;
+                s.push('?');
+            } else {
+                s.push('&');
+            }
+            s.push_str(&format!("key_prefix={key_prefix}"));
+        }
+
         if let ServerConfig::Sentinel(SentinelConfig {
             instances: _,
             service_name: _,
             wait_between_failures: wait_beetween_failures,
             password,
             username,
+            refresh_on_switch,
         }) = &self.server
         {
             let wait_between_failures = wait_beetween_failures.as_millis() as u64;
@@ -629,6 +987,7 @@ impl ToString for Config {
             }
             if let Some(password) = password {
                 if !query_separator {
+                    query_separator = true;
                     s.push('?');
                 } else {
                     s.push('&');
@@ -636,12 +995,409 @@ impl ToString for Config {
                 s.push_str("sentinel_password=");
                 s.push_str(password);
             }
+            if *refresh_on_switch != DEFAULT_SENTINEL_REFRESH_ON_SWITCH {
+                if !query_separator {
+                    s.push('?');
+                } else {
+                    s.push('&');
+                }
+                s.push_str(&format!("sentinel_refresh_on_switch={refresh_on_switch}"));
+            }
         }
 
         s
     }
 }
 
+impl Config {
+    /// Set a custom resolver of the server host names.
+    ///
+    /// The resolver receives the host and the port of the server to connect to
+    /// and returns the list of addresses to try, in order.
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::client::Config;
+    /// use std::net::SocketAddr;
+    ///
+    /// let config = Config::default().with_resolver(|_host, port| {
+    ///     Box::pin(async move {
+    ///         // query the service discovery layer here
+    ///         Ok(vec![SocketAddr::from(([127, 0, 0, 1], port))])
+    ///     })
+    /// });
+    /// assert!(config.resolver.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_resolver<F>(mut self, resolver: F) -> Self
+    where
+        F: Fn(&str, u16) -> Future<'static, Vec<SocketAddr>> + Send + Sync + 'static,
+    {
+        self.resolver = Some(Resolver::new(resolver));
+        self
+    }
+
+    /// Connect to a list of pre-resolved addresses, tried in order,
+    /// instead of resolving the `host`:`port` pair.
+    ///
+    /// Overrides are keyed by `host`:`port`, so each cluster node or sentinel
+    /// can be given its own addresses by calling this method once per node.
+    /// Other hosts are resolved by the resolver previously set, if any,
+    /// or by the resolver of the async runtime.
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::client::Config;
+    /// use std::net::SocketAddr;
+    ///
+    /// let config = Config::default()
+    ///     .with_socket_addrs("node1", 6379, vec![SocketAddr::from(([10, 0, 0, 1], 6379))])
+    ///     .with_socket_addrs("node2", 6379, vec![SocketAddr::from(([10, 0, 0, 2], 6379))]);
+    /// assert!(config.resolver.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_socket_addrs(
+        self,
+        host: impl Into<String>,
+        port: u16,
+        addrs: Vec<SocketAddr>,
+    ) -> Self {
+        let host = host.into();
+        let previous_resolver = self.resolver.clone();
+        self.with_resolver(move |h, p| {
+            if h == host && p == port {
+                let addrs = addrs.clone();
+                Box::pin(async move { Ok(addrs) })
+            } else if let Some(previous_resolver) = &previous_resolver {
+                previous_resolver.resolve(h, p)
+            } else {
+                let h = h.to_owned();
+                Box::pin(async move { lookup_host(&h, p).await })
+            }
+        })
+    }
+
+    /// Create a [`ConfigBuilder`](ConfigBuilder) initialized with default values
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::{client::Config, Result};
+    /// use std::time::Duration;
+    ///
+    /// fn main() -> Result<()> {
+    ///     let config = Config::builder()
+    ///         .host_port("127.0.0.1", 6379)
+    ///         .password("pwd")
+    ///         .database(1)
+    ///         .connect_timeout(Duration::from_secs(5))
+    ///         .build()?;
+    ///     assert_eq!("redis://:pwd@127.0.0.1/1?connect_timeout=5000", config.to_string());
+    ///     Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::default()
+    }
+}
+
+/// Builder for [`Config`](Config)
+///
+/// Each method sets the [`Config`](Config) field of the same name.
+/// Options are validated all together by [`build`](ConfigBuilder::build).
+#[derive(Debug, Clone, Default)]
+pub struct ConfigBuilder {
+    config: Config,
+}
+
+impl ConfigBuilder {
+    /// Connect to a standalone server
+    #[must_use]
+    pub fn host_port(mut self, host: impl Into<String>, port: u16) -> Self {
+        self.config.server = ServerConfig::Standalone {
+            host: host.into(),
+            port,
+        };
+        self
+    }
+
+    /// See [`Config::server`](Config::server)
+    #[must_use]
+    pub fn server(mut self, server: ServerConfig) -> Self {
+        self.config.server = server;
+        self
+    }
+
+    /// See [`Config::username`](Config::username)
+    #[must_use]
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.config.username = Some(username.into());
+        self
+    }
+
+    /// See [`Config::password`](Config::password)
+    #[must_use]
+    pub fn password(mut self, password: impl Into<String>) -> Self {
+        self.config.password = Some(password.into());
+        self
+    }
+
+    /// See [`Config::database`](Config::database)
+    #[must_use]
+    pub fn database(mut self, database: usize) -> Self {
+        self.config.database = database;
+        self
+    }
+
+    /// See [`Config::tls_config`](Config::tls_config)
+    #[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+    #[cfg(feature = "tls")]
+    #[must_use]
+    pub fn tls(mut self, tls_config: TlsConfig) -> Self {
+        self.config.tls_config = Some(tls_config);
+        self
+    }
+
+    /// See [`Config::connect_timeout`](Config::connect_timeout)
+    #[must_use]
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.config.connect_timeout = connect_timeout;
+        self
+    }
+
+    /// See [`Config::command_timeout`](Config::command_timeout)
+    #[must_use]
+    pub fn command_timeout(mut self, command_timeout: Duration) -> Self {
+        self.config.command_timeout = command_timeout;
+        self
+    }
+
+    /// See [`Config::close_timeout`](Config::close_timeout)
+    #[must_use]
+    pub fn close_timeout(mut self, close_timeout: Duration) -> Self {
+        self.config.close_timeout = close_timeout;
+        self
+    }
+
+    /// See [`Config::write_coalesce`](Config::write_coalesce)
+    #[must_use]
+    pub fn write_coalesce(mut self, write_coalesce: Duration) -> Self {
+        self.config.write_coalesce = Some(write_coalesce);
+        self
+    }
+
+    /// See [`Config::auto_resubscribe`](Config::auto_resubscribe)
+    #[must_use]
+    pub fn auto_resubscribe(mut self, auto_resubscribe: bool) -> Self {
+        self.config.auto_resubscribe = auto_resubscribe;
+        self
+    }
+
+    /// See [`Config::auto_remonitor`](Config::auto_remonitor)
+    #[must_use]
+    pub fn auto_remonitor(mut self, auto_remonitor: bool) -> Self {
+        self.config.auto_remonitor = auto_remonitor;
+        self
+    }
+
+    /// See [`Config::connection_name`](Config::connection_name)
+    #[must_use]
+    pub fn connection_name(mut self, connection_name: impl Into<String>) -> Self {
+        self.config.connection_name = connection_name.into();
+        self
+    }
+
+    /// See [`Config::keep_alive`](Config::keep_alive)
+    #[must_use]
+    pub fn keep_alive(mut self, keep_alive: Duration) -> Self {
+        self.config.keep_alive = Some(keep_alive);
+        self
+    }
+
+    /// See [`Config::no_delay`](Config::no_delay)
+    #[must_use]
+    pub fn no_delay(mut self, no_delay: bool) -> Self {
+        self.config.no_delay = no_delay;
+        self
+    }
+
+    /// See [`Config::max_command_attempts`](Config::max_command_attempts)
+    #[must_use]
+    pub fn max_command_attempts(mut self, max_command_attempts: usize) -> Self {
+        self.config.max_command_attempts = max_command_attempts;
+        self
+    }
+
+    /// See [`Config::retry_on_error`](Config::retry_on_error)
+    #[must_use]
+    pub fn retry_on_error(mut self, retry_on_error: bool) -> Self {
+        self.config.retry_on_error = retry_on_error;
+        self
+    }
+
+    /// See [`Config::strict_blocking_commands`](Config::strict_blocking_commands)
+    #[must_use]
+    pub fn strict_blocking_commands(mut self, strict_blocking_commands: bool) -> Self {
+        self.config.strict_blocking_commands = strict_blocking_commands;
+        self
+    }
+
+    /// See [`Config::rtt_ewma_alpha`](Config::rtt_ewma_alpha)
+    #[must_use]
+    pub fn rtt_ewma_alpha(mut self, rtt_ewma_alpha: f64) -> Self {
+        self.config.rtt_ewma_alpha = Some(rtt_ewma_alpha);
+        self
+    }
+
+    /// See [`Config::chunk_size`](Config::chunk_size)
+    #[must_use]
+    pub fn chunk_size(mut self, chunk_size: usize) -> Self {
+        self.config.chunk_size = chunk_size;
+        self
+    }
+
+    /// See [`Config::reconnect_policy`](Config::reconnect_policy)
+    #[must_use]
+    pub fn reconnect_policy(mut self, reconnect_policy: ReconnectPolicy) -> Self {
+        self.config.reconnect_policy = reconnect_policy;
+        self
+    }
+
+    /// See [`Config::pub_sub_channel_capacity`](Config::pub_sub_channel_capacity)
+    #[must_use]
+    pub fn pub_sub_channel_capacity(mut self, capacity: usize) -> Self {
+        self.config.pub_sub_channel_capacity = Some(capacity);
+        self
+    }
+
+    /// See [`Config::pub_sub_backpressure_policy`](Config::pub_sub_backpressure_policy)
+    #[must_use]
+    pub fn pub_sub_backpressure_policy(mut self, policy: BackpressurePolicy) -> Self {
+        self.config.pub_sub_backpressure_policy = policy;
+        self
+    }
+
+    /// See [`Config::compression`](Config::compression)
+    #[must_use]
+    pub fn compression(mut self, compression: CompressionConfig) -> Self {
+        self.config.compression = Some(compression);
+        self
+    }
+
+    /// See [`Config::key_prefix`](Config::key_prefix)
+    #[must_use]
+    pub fn key_prefix(mut self, key_prefix: impl Into<String>) -> Self {
+        self.config.key_prefix = Some(key_prefix.into());
+        self
+    }
+
+    /// See [`Config::with_resolver`](Config::with_resolver)
+    #[must_use]
+    pub fn resolver<F>(mut self, resolver: F) -> Self
+    where
+        F: Fn(&str, u16) -> Future<'static, Vec<SocketAddr>> + Send + Sync + 'static,
+    {
+        self.config = self.config.with_resolver(resolver);
+        self
+    }
+
+    /// See [`Config::with_socket_addrs`](Config::with_socket_addrs)
+    #[must_use]
+    pub fn socket_addrs(
+        mut self,
+        host: impl Into<String>,
+        port: u16,
+        addrs: Vec<SocketAddr>,
+    ) -> Self {
+        self.config = self.config.with_socket_addrs(host, port, addrs);
+        self
+    }
+
+    /// Validate the options and build the [`Config`](Config)
+    ///
+    /// # Errors
+    /// An [`Error::Config`](crate::Error::Config) is returned when:
+    /// * a database other than `0` is selected with a cluster configuration
+    /// * a cluster configuration has no node
+    /// * a sentinel configuration has no instance or no service name
+    /// * [`rtt_ewma_alpha`](Config::rtt_ewma_alpha) is not in the `]0, 1]` range
+    /// * [`chunk_size`](Config::chunk_size) is `0`
+    pub fn build(self) -> Result<Config> {
+        if let Some(rtt_ewma_alpha) = self.config.rtt_ewma_alpha {
+            if !(rtt_ewma_alpha > 0. && rtt_ewma_alpha <= 1.) {
+                return Err(Error::Config(format!(
+                    "rtt_ewma_alpha must be in the ]0, 1] range: {rtt_ewma_alpha}"
+                )));
+            }
+        }
+
+        if self.config.chunk_size == 0 {
+            return Err(Error::Config(
+                "chunk_size must be greater than 0".to_owned(),
+            ));
+        }
+
+        match &self.config.server {
+            ServerConfig::Standalone { .. } => (),
+            ServerConfig::Sentinel(sentinel_config) => {
+                if sentinel_config.instances.is_empty() {
+                    return Err(Error::Config(
+                        "Sentinel configuration requires at least one instance".to_owned(),
+                    ));
+                }
+                if sentinel_config.service_name.is_empty() {
+                    return Err(Error::Config(
+                        "Sentinel configuration requires a service name".to_owned(),
+                    ));
+                }
+            }
+            ServerConfig::Cluster(cluster_config) => {
+                if cluster_config.nodes.is_empty() {
+                    return Err(Error::Config(
+                        "Cluster configuration requires at least one node".to_owned(),
+                    ));
+                }
+                if self.config.database != 0 {
+                    return Err(Error::Config(
+                        "Cluster configuration does not support database selection".to_owned(),
+                    ));
+                }
+            }
+        }
+
+        Ok(self.config)
+    }
+}
+
+type ResolverFn = dyn Fn(&str, u16) -> Future<'static, Vec<SocketAddr>> + Send + Sync;
+
+/// Custom resolver of the server host names
+///
+/// See [`Config::resolver`](Config::resolver)
+#[derive(Clone)]
+pub struct Resolver(Arc<ResolverFn>);
+
+impl Resolver {
+    /// Create a resolver from a function returning the addresses of a `host`:`port` pair
+    pub fn new<F>(resolver: F) -> Self
+    where
+        F: Fn(&str, u16) -> Future<'static, Vec<SocketAddr>> + Send + Sync + 'static,
+    {
+        Self(Arc::new(resolver))
+    }
+
+    /// Resolve the addresses of a `host`:`port` pair
+    pub fn resolve(&self, host: &str, port: u16) -> Future<'static, Vec<SocketAddr>> {
+        (self.0)(host, port)
+    }
+}
+
+impl std::fmt::Debug for Resolver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Resolver")
+    }
+}
+
 /// Configuration for connecting to a Redis server
 #[derive(Debug, Clone)]
 pub enum ServerConfig {
@@ -684,6 +1440,18 @@ pub struct SentinelConfig {
 
     /// Sentinel password
     pub password: Option<String>,
+
+    /// Reconnect to the new master as soon as Sentinel announces a failover (default `false`).
+    ///
+    /// When enabled, the Sentinel instance which has resolved the master address
+    /// is kept connected and subscribed to the
+    /// [`+switch-master`](https://redis.io/docs/management/sentinel/#pubsub-messages) channel.
+    /// When the master of the service is switched, the connection to the former master is closed
+    /// and the master address is resolved again through the Sentinel instances.
+    /// Any other reconnection also resolves the master address again.
+    ///
+    /// When disabled, a lost connection is reestablished with the same instance.
+    pub refresh_on_switch: bool,
 }
 
 impl Default for SentinelConfig {
@@ -694,6 +1462,7 @@ impl Default for SentinelConfig {
             wait_between_failures: Duration::from_millis(DEFAULT_WAIT_BETWEEN_FAILURES),
             password: None,
             username: None,
+            refresh_on_switch: DEFAULT_SENTINEL_REFRESH_ON_SWITCH,
         }
     }
 }
@@ -705,6 +1474,96 @@ pub struct ClusterConfig {
     pub nodes: Vec<(String, u16)>,
 }
 
+/// Policy applied when the message buffer of a [`PubSubStream`](crate::client::PubSubStream) is full
+///
+/// See [`Config::pub_sub_channel_capacity`](Config::pub_sub_channel_capacity)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackpressurePolicy {
+    /// Stop reading the connection until the subscriber consumes a message.
+    ///
+    /// No message is lost, but the connection is shared by all the clones of the client:
+    /// a slow subscriber stalls the replies of all the other commands and subscriptions.
+    Backpressure,
+    /// Drop the oldest buffered message to make room for the new one
+    DropOldest,
+    /// Drop the new message
+    DropNewest,
+}
+
+impl FromStr for BackpressurePolicy {
+    type Err = Error;
+
+    fn from_str(str: &str) -> Result<Self> {
+        match str {
+            "backpressure" => Ok(BackpressurePolicy::Backpressure),
+            "drop_oldest" => Ok(BackpressurePolicy::DropOldest),
+            "drop_newest" => Ok(BackpressurePolicy::DropNewest),
+            _ => Err(Error::Config(format!(
+                "Cannot parse backpressure policy from {str}"
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for BackpressurePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BackpressurePolicy::Backpressure => f.write_str("backpressure"),
+            BackpressurePolicy::DropOldest => f.write_str("drop_oldest"),
+            BackpressurePolicy::DropNewest => f.write_str("drop_newest"),
+        }
+    }
+}
+
+/// Strategy to compute the delay before the next attempt to reconnect to the server,
+/// after consecutive failed attempts
+///
+/// The count of failed attempts is reset once the client is reconnected.
+///
+/// See [`Config::reconnect_policy`](Config::reconnect_policy)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReconnectPolicy {
+    /// Wait the same delay after each failed attempt
+    Constant(Duration),
+    /// Wait `base * factor^(n - 1)` after the `n`th consecutive failed attempt, up to `max`
+    ExponentialBackoff {
+        base: Duration,
+        max: Duration,
+        factor: u32,
+    },
+    /// Wait a random delay between zero and the delay of
+    /// [`ExponentialBackoff`](ReconnectPolicy::ExponentialBackoff).
+    ///
+    /// Randomization spreads the reconnections of the clients
+    /// after an outage shared by all of them.
+    ExponentialWithJitter {
+        base: Duration,
+        max: Duration,
+        factor: u32,
+    },
+}
+
+impl ReconnectPolicy {
+    /// Delay to wait after `num_failures` consecutive failed attempts
+    pub(crate) fn delay(&self, num_failures: u32) -> Duration {
+        match *self {
+            ReconnectPolicy::Constant(delay) => delay,
+            ReconnectPolicy::ExponentialBackoff { base, max, factor } => {
+                exponential_delay(base, max, factor, num_failures)
+            }
+            ReconnectPolicy::ExponentialWithJitter { base, max, factor } => {
+                let delay = exponential_delay(base, max, factor, num_failures);
+                delay.mul_f64(rand::thread_rng().gen_range(0.0..=1.0))
+            }
+        }
+    }
+}
+
+fn exponential_delay(base: Duration, max: Duration, factor: u32, num_failures: u32) -> Duration {
+    let multiplier = factor.saturating_pow(num_failures.saturating_sub(1));
+    base.saturating_mul(multiplier).min(max)
+}
+
 /// Config for TLS.
 ///
 /// See [TlsConnectorBuilder](https://docs.rs/tokio-native-tls/0.3.0/tokio_native_tls/native_tls/struct.TlsConnectorBuilder.html) documentation
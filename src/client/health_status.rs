@@ -0,0 +1,25 @@
+use crate::commands::RoleResult;
+use std::time::Duration;
+
+/// Result of [`Client::health_check`](crate::client::Client::health_check)
+#[derive(Debug)]
+pub struct HealthStatus {
+    /// Role of the instance in the context of replication
+    pub role: RoleResult,
+    /// Round-trip time of the pipelined `PING` & `ROLE` commands
+    pub latency: Duration,
+    /// `true` if the instance is not a master and cannot accept writes
+    pub read_only: bool,
+}
+
+impl HealthStatus {
+    pub(crate) fn new(role: RoleResult, latency: Duration) -> Self {
+        let read_only = !matches!(role, RoleResult::Master { .. });
+
+        Self {
+            role,
+            latency,
+            read_only,
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{
+    client::{Commands, Message},
+    network::{spawn, MsgReceiver, MsgSender},
+    resp::{Command, RespBuf},
+    Error, Future, Result,
+};
+use futures_channel::{mpsc, oneshot};
+use futures_util::{SinkExt, StreamExt};
+use log::warn;
+use std::{mem, sync::Arc};
+
+type ObserveFn = dyn Fn(&Command) + Send + Sync;
+type RewriteFn = dyn Fn(Command) -> Future<'static, Command> + Send + Sync;
+type ReplyFn = dyn Fn(&Command, &Result<RespBuf>) + Send + Sync;
+
+/// Middleware stage installed in front of the network handler
+/// by [`Client::with_interceptor`](crate::client::Client::with_interceptor) & co.
+pub(crate) enum Interceptor {
+    Observe(Box<ObserveFn>),
+    Rewrite(Box<RewriteFn>),
+    Reply(Arc<ReplyFn>),
+}
+
+impl Interceptor {
+    /// Spawns the interceptor stage and returns the sender to post messages to it.
+    ///
+    /// Messages are processed one at a time and forwarded in order to `downstream`,
+    /// so that interceptors never reorder commands.
+    /// The stage ends when all the returned senders are dropped.
+    pub fn start(self, downstream: MsgSender) -> MsgSender {
+        let (msg_sender, mut msg_receiver): (MsgSender, MsgReceiver) = mpsc::unbounded();
+
+        spawn(async move {
+            while let Some(message) = msg_receiver.next().await {
+                let Some(message) = self.intercept(message).await else {
+                    continue;
+                };
+
+                if let Err(e) = downstream.unbounded_send(message) {
+                    fail_message(
+                        e.into_inner(),
+                        Error::Client("Disconnected from server".to_owned()),
+                    )
+                    .await;
+                }
+            }
+        });
+
+        msg_sender
+    }
+
+    async fn intercept(&self, mut message: Message) -> Option<Message> {
+        match self {
+            Interceptor::Observe(observe) => {
+                for command in &message.commands {
+                    observe(command);
+                }
+            }
+            Interceptor::Rewrite(rewrite) => {
+                // subscriptions are matched against their channel names: they cannot be rewritten
+                if message.pub_sub_senders.is_some() {
+                    return Some(message);
+                }
+
+                for command in &mut message.commands {
+                    let original = mem::replace(command, Command::new(""));
+                    match rewrite(original).await {
+                        Ok(rewritten) => *command = rewritten,
+                        Err(e) => {
+                            fail_message(message, e).await;
+                            return None;
+                        }
+                    }
+                }
+            }
+            Interceptor::Reply(reply) => match &mut message.commands {
+                Commands::Single(command, Some(result_sender)) => {
+                    let (sender, receiver) = oneshot::channel();
+                    let result_sender = mem::replace(result_sender, sender);
+                    let command = command.clone();
+                    let reply = reply.clone();
+
+                    spawn(async move {
+                        // if the reply is never received, dropping `result_sender` notifies the caller
+                        if let Ok(result) = receiver.await {
+                            reply(&command, &result);
+                            let _ = result_sender.send(result);
+                        }
+                    });
+                }
+                Commands::Batch(commands, results_sender) => {
+                    let (sender, receiver) = oneshot::channel();
+                    let results_sender = mem::replace(results_sender, sender);
+                    let commands = commands.clone();
+                    let reply = reply.clone();
+
+                    spawn(async move {
+                        if let Ok(results) = receiver.await {
+                            match &results {
+                                Ok(results) => {
+                                    for (command, result) in commands.iter().zip(results) {
+                                        reply(command, &Ok(result.clone()));
+                                    }
+                                }
+                                Err(e) => {
+                                    for command in &commands {
+                                        reply(command, &Err(e.clone()));
+                                    }
+                                }
+                            }
+                            let _ = results_sender.send(results);
+                        }
+                    });
+                }
+                Commands::None
+                | Commands::Single(_, None)
+                | Commands::BatchForget(_, _)
+                | Commands::Stream(_, _) => (),
+            },
+        }
+
+        Some(message)
+    }
+}
+
+async fn fail_message(message: Message, error: Error) {
+    let result = match message.commands {
+        Commands::Single(_, Some(result_sender)) => result_sender.send(Err(error)).is_ok(),
+        Commands::Batch(_, results_sender) => results_sender.send(Err(error)).is_ok(),
+        Commands::BatchForget(_, Some(write_sender)) => write_sender.send(Err(error)).is_ok(),
+        Commands::Stream(_, mut stream_sender) => stream_sender.send(Err(error)).await.is_ok(),
+        Commands::None | Commands::Single(_, None) | Commands::BatchForget(_, None) => {
+            warn!("Cannot send intercepted command: {error}");
+            true
+        }
+    };
+
+    if !result {
+        warn!("Cannot send value to caller because receiver is not there anymore");
+    }
+}
@@ -0,0 +1,15 @@
+use crate::commands::{ObjectEncoding, RedisType};
+use std::time::Duration;
+
+/// Result of [`Client::key_metadata`](crate::client::Client::key_metadata)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyMetadata {
+    /// Type of the value stored at the key
+    pub key_type: RedisType,
+    /// Remaining time to live of the key, `None` if the key has no associated expire
+    pub ttl: Option<Duration>,
+    /// Internal encoding of the value stored at the key
+    pub encoding: ObjectEncoding,
+    /// Number of bytes that the key and its value require to be stored in RAM
+    pub memory_bytes: usize,
+}
@@ -0,0 +1,426 @@
+use crate::{
+    client::PubSubMessage,
+    commands::CommandInfo,
+    network::{get_cached_command_info, CommandInfoCache},
+    resp::Command,
+    Error, Result,
+};
+
+const KEYSPACE_CHANNEL_PREFIX: &[u8] = b"__keyspace@";
+const KEYEVENT_CHANNEL_PREFIX: &[u8] = b"__keyevent@";
+
+/// Location of key arguments in a command, inspired by
+/// [Redis key specifications](https://redis.io/docs/reference/key-specs/)
+enum KeySpec {
+    /// A single key at the given argument index
+    Index(usize),
+    /// Keys from the argument index `first` to the end of the arguments,
+    /// ignoring the `trailing` last arguments, every `step` arguments
+    Range {
+        first: usize,
+        trailing: usize,
+        step: usize,
+    },
+    /// The number of keys is given at the argument index, followed by the keys
+    KeyNum(usize),
+    /// The first half of the arguments following the `STREAMS` keyword
+    Streams,
+    /// The argument following the last occurrence of the given keyword, e.g. `SORT` `STORE`
+    Keyword(&'static [u8]),
+}
+
+const FIRST: &[KeySpec] = &[KeySpec::Index(0)];
+const SECOND: &[KeySpec] = &[KeySpec::Index(1)];
+const FIRST_TWO: &[KeySpec] = &[KeySpec::Index(0), KeySpec::Index(1)];
+const ALL: &[KeySpec] = &[KeySpec::Range {
+    first: 0,
+    trailing: 0,
+    step: 1,
+}];
+const ALL_BUT_FIRST: &[KeySpec] = &[KeySpec::Range {
+    first: 1,
+    trailing: 0,
+    step: 1,
+}];
+const ALL_BUT_LAST: &[KeySpec] = &[KeySpec::Range {
+    first: 0,
+    trailing: 1,
+    step: 1,
+}];
+const PAIRS: &[KeySpec] = &[KeySpec::Range {
+    first: 0,
+    trailing: 0,
+    step: 2,
+}];
+const NUM_KEYS_FIRST: &[KeySpec] = &[KeySpec::KeyNum(0)];
+const NUM_KEYS_SECOND: &[KeySpec] = &[KeySpec::KeyNum(1)];
+const DESTINATION_NUM_KEYS: &[KeySpec] = &[KeySpec::Index(0), KeySpec::KeyNum(1)];
+const STREAMS: &[KeySpec] = &[KeySpec::Streams];
+const SORT: &[KeySpec] = &[KeySpec::Index(0), KeySpec::Keyword(b"STORE")];
+const NONE: &[KeySpec] = &[];
+
+/// Key specifications of the built-in commands,
+/// or `None` if the command is missing from the table, e.g. a module command
+fn key_specs(command_name: &str) -> Option<&'static [KeySpec]> {
+    let key_specs = match command_name {
+        "APPEND"
+        | "BITCOUNT"
+        | "BITFIELD"
+        | "BITFIELD_RO"
+        | "BITPOS"
+        | "DECR"
+        | "DECRBY"
+        | "DUMP"
+        | "EXPIRE"
+        | "EXPIREAT"
+        | "EXPIRETIME"
+        | "GEOADD"
+        | "GEODIST"
+        | "GEOHASH"
+        | "GEOPOS"
+        | "GEORADIUS"
+        | "GEORADIUSBYMEMBER"
+        | "GEORADIUSBYMEMBER_RO"
+        | "GEORADIUS_RO"
+        | "GEOSEARCH"
+        | "GET"
+        | "GETBIT"
+        | "GETDEL"
+        | "GETEX"
+        | "GETRANGE"
+        | "GETSET"
+        | "HDEL"
+        | "HEXISTS"
+        | "HEXPIRE"
+        | "HEXPIREAT"
+        | "HEXPIRETIME"
+        | "HGET"
+        | "HGETALL"
+        | "HINCRBY"
+        | "HINCRBYFLOAT"
+        | "HKEYS"
+        | "HLEN"
+        | "HMGET"
+        | "HMSET"
+        | "HPERSIST"
+        | "HPEXPIRE"
+        | "HPEXPIREAT"
+        | "HPEXPIRETIME"
+        | "HPTTL"
+        | "HRANDFIELD"
+        | "HSCAN"
+        | "HSET"
+        | "HSETNX"
+        | "HSTRLEN"
+        | "HTTL"
+        | "HVALS"
+        | "INCR"
+        | "INCRBY"
+        | "INCRBYFLOAT"
+        | "LINDEX"
+        | "LINSERT"
+        | "LLEN"
+        | "LPOP"
+        | "LPOS"
+        | "LPUSH"
+        | "LPUSHX"
+        | "LRANGE"
+        | "LREM"
+        | "LSET"
+        | "LTRIM"
+        | "MOVE"
+        | "PERSIST"
+        | "PEXPIRE"
+        | "PEXPIREAT"
+        | "PEXPIRETIME"
+        | "PFADD"
+        | "PSETEX"
+        | "PTTL"
+        | "RESTORE"
+        | "RPOP"
+        | "RPUSH"
+        | "RPUSHX"
+        | "SADD"
+        | "SCARD"
+        | "SET"
+        | "SETBIT"
+        | "SETEX"
+        | "SETNX"
+        | "SETRANGE"
+        | "SISMEMBER"
+        | "SMEMBERS"
+        | "SMISMEMBER"
+        | "SORT_RO"
+        | "SPOP"
+        | "SRANDMEMBER"
+        | "SREM"
+        | "SSCAN"
+        | "STRLEN"
+        | "SUBSTR"
+        | "TTL"
+        | "TYPE"
+        | "XACK"
+        | "XADD"
+        | "XAUTOCLAIM"
+        | "XCLAIM"
+        | "XDEL"
+        | "XLEN"
+        | "XPENDING"
+        | "XRANGE"
+        | "XREVRANGE"
+        | "XSETID"
+        | "XTRIM"
+        | "ZADD"
+        | "ZCARD"
+        | "ZCOUNT"
+        | "ZINCRBY"
+        | "ZLEXCOUNT"
+        | "ZMSCORE"
+        | "ZPOPMAX"
+        | "ZPOPMIN"
+        | "ZRANDMEMBER"
+        | "ZRANGE"
+        | "ZRANGEBYLEX"
+        | "ZRANGEBYSCORE"
+        | "ZRANK"
+        | "ZREM"
+        | "ZREMRANGEBYLEX"
+        | "ZREMRANGEBYRANK"
+        | "ZREMRANGEBYSCORE"
+        | "ZREVRANGE"
+        | "ZREVRANGEBYLEX"
+        | "ZREVRANGEBYSCORE"
+        | "ZREVRANK"
+        | "ZSCAN"
+        | "ZSCORE" => FIRST,
+        // sub commands
+        "MEMORY" | "OBJECT" | "XGROUP" | "XINFO" => SECOND,
+        "BLMOVE" | "BRPOPLPUSH" | "COPY" | "GEOSEARCHSTORE" | "LCS" | "LMOVE" | "RENAME"
+        | "RENAMENX" | "RPOPLPUSH" | "SMOVE" | "ZRANGESTORE" => FIRST_TWO,
+        "DEL" | "EXISTS" | "MGET" | "PFCOUNT" | "PFMERGE" | "SDIFF" | "SDIFFSTORE" | "SINTER"
+        | "SINTERSTORE" | "SUNION" | "SUNIONSTORE" | "TOUCH" | "UNLINK" | "WATCH" => ALL,
+        "BITOP" => ALL_BUT_FIRST,
+        "BLPOP" | "BRPOP" | "BZPOPMAX" | "BZPOPMIN" => ALL_BUT_LAST,
+        "MSET" | "MSETNX" => PAIRS,
+        "LMPOP" | "SINTERCARD" | "ZDIFF" | "ZINTER" | "ZINTERCARD" | "ZMPOP" | "ZUNION" => {
+            NUM_KEYS_FIRST
+        }
+        "BLMPOP" | "BZMPOP" | "EVAL" | "EVALSHA" | "EVALSHA_RO" | "EVAL_RO" | "FCALL"
+        | "FCALL_RO" => NUM_KEYS_SECOND,
+        "ZDIFFSTORE" | "ZINTERSTORE" | "ZUNIONSTORE" => DESTINATION_NUM_KEYS,
+        "XREAD" | "XREADGROUP" => STREAMS,
+        "SORT" => SORT,
+        // commands without key, or taking key patterns
+        "ACL" | "ASKING" | "AUTH" | "BGREWRITEAOF" | "BGSAVE" | "CLIENT" | "CLUSTER"
+        | "COMMAND" | "CONFIG" | "DBSIZE" | "DEBUG" | "DISCARD" | "ECHO" | "EXEC" | "FAILOVER"
+        | "FLUSHALL" | "FLUSHDB" | "FUNCTION" | "HELLO" | "INFO" | "KEYS" | "LASTSAVE"
+        | "LATENCY" | "LOLWUT" | "MODULE" | "MONITOR" | "MULTI" | "PING" | "PSUBSCRIBE"
+        | "PUBLISH" | "PUBSUB" | "PUNSUBSCRIBE" | "QUIT" | "RANDOMKEY" | "READONLY"
+        | "READWRITE" | "REPLICAOF" | "RESET" | "ROLE" | "SAVE" | "SCAN" | "SCRIPT" | "SELECT"
+        | "SENTINEL" | "SHUTDOWN" | "SLAVEOF" | "SLOWLOG" | "SPUBLISH" | "SSUBSCRIBE"
+        | "SUBSCRIBE" | "SUNSUBSCRIBE" | "SWAPDB" | "TIME" | "UNSUBSCRIBE" | "UNWATCH" | "WAIT"
+        | "WAITAOF" => NONE,
+        _ => return None,
+    };
+
+    Some(key_specs)
+}
+
+fn prefix_arg(prefix: &[u8], arg: &mut Vec<u8>) {
+    arg.splice(0..0, prefix.iter().copied());
+}
+
+/// Key positions given by the `first_key`, `last_key` & `step` fields of a command metadata
+///
+/// # Return
+/// `None` if the keys cannot be located without the server, i.e. for commands with movable keys
+fn command_info_key_spec(command_info: &CommandInfo, num_args: usize) -> Option<Vec<KeySpec>> {
+    if command_info.flags.iter().any(|flag| flag == "movablekeys") {
+        return None;
+    }
+
+    if command_info.first_key == 0 || command_info.step == 0 {
+        return Some(Vec::new());
+    }
+
+    // positions include the command name
+    let trailing = if command_info.last_key >= 0 {
+        num_args.saturating_sub(command_info.last_key as usize)
+    } else {
+        command_info.last_key.unsigned_abs() - 1
+    };
+
+    Some(vec![KeySpec::Range {
+        first: command_info.first_key - 1,
+        trailing,
+        step: command_info.step,
+    }])
+}
+
+/// Prepend `prefix` to each key argument of `command`
+///
+/// Commands missing from the built-in table, e.g. module commands,
+/// are prefixed from their metadata in `command_info_cache`.
+///
+/// # Errors
+/// [`Error::Client`] if the keys of the command cannot be located:
+/// the command is neither in the built-in table nor in `command_info_cache`,
+/// or its keys are movable.
+pub(crate) fn prefix_command(
+    prefix: &[u8],
+    command: &mut Command,
+    command_info_cache: &CommandInfoCache,
+) -> Result<()> {
+    let cached_key_specs;
+    let command_key_specs: &[KeySpec] = match key_specs(command.name) {
+        Some(key_specs) => key_specs,
+        None => {
+            cached_key_specs = get_cached_command_info(command_info_cache, command)
+                .and_then(|command_info| command_info_key_spec(&command_info, command.args.len()))
+                .ok_or_else(|| {
+                    Error::Client(format!(
+                        "Cannot locate the keys of command {} to prefix them. \
+                        Fetch its metadata with `Client::cached_command_info` beforehand",
+                        command.name
+                    ))
+                })?;
+            &cached_key_specs
+        }
+    };
+
+    let args = command.args.args_mut();
+
+    for key_spec in command_key_specs {
+        match key_spec {
+            KeySpec::Index(index) => {
+                // sub commands without key, e.g. OBJECT HELP, have less arguments
+                if let Some(arg) = args.get_mut(*index) {
+                    prefix_arg(prefix, arg);
+                }
+            }
+            KeySpec::Range {
+                first,
+                trailing,
+                step,
+            } => {
+                let end = args.len().saturating_sub(*trailing);
+                if *first < end {
+                    for arg in args[*first..end].iter_mut().step_by(*step) {
+                        prefix_arg(prefix, arg);
+                    }
+                }
+            }
+            KeySpec::KeyNum(index) => {
+                let num_keys = args
+                    .get(*index)
+                    .and_then(|num_keys| atoi::atoi::<usize>(num_keys))
+                    .unwrap_or_default();
+                let end = (index + 1 + num_keys).min(args.len());
+                for arg in &mut args[index + 1..end] {
+                    prefix_arg(prefix, arg);
+                }
+            }
+            KeySpec::Streams => {
+                if let Some(position) = args
+                    .iter()
+                    .position(|arg| arg.eq_ignore_ascii_case(b"STREAMS"))
+                {
+                    let streams = &mut args[position + 1..];
+                    let num_keys = streams.len() / 2;
+                    for arg in &mut streams[..num_keys] {
+                        prefix_arg(prefix, arg);
+                    }
+                }
+            }
+            KeySpec::Keyword(keyword) => {
+                // skip the first argument, which may be a key named like the keyword
+                if let Some(position) = args
+                    .iter()
+                    .skip(1)
+                    .rposition(|arg| arg.eq_ignore_ascii_case(keyword))
+                {
+                    if let Some(arg) = args.get_mut(position + 2) {
+                        prefix_arg(prefix, arg);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Index of the first key argument of `command`, if any
+pub(crate) fn first_key_index(command: &Command) -> Option<usize> {
+    let args = &command.args;
+
+    key_specs(command.name)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|key_spec| match key_spec {
+            KeySpec::Index(index) => Some(*index),
+            KeySpec::Range {
+                first, trailing, ..
+            } => Some(*first).filter(|first| *first < args.len().saturating_sub(*trailing)),
+            KeySpec::KeyNum(index) => args
+                .get(*index)
+                .and_then(|num_keys| atoi::atoi::<usize>(num_keys))
+                .filter(|num_keys| *num_keys > 0)
+                .map(|_| index + 1),
+            KeySpec::Streams => args
+                .iter()
+                .position(|arg| arg.eq_ignore_ascii_case(b"STREAMS"))
+                .map(|position| position + 1),
+            // always after the first key
+            KeySpec::Keyword(_) => None,
+        })
+        .min()
+        .filter(|index| *index < args.len())
+}
+
+/// Prepend `prefix` to the key part of keyspace notification channels or patterns
+pub(crate) fn prefix_channel(prefix: &[u8], channel: &mut Vec<u8>) {
+    if let Some(key_index) = keyspace_key_index(channel) {
+        channel.splice(key_index..key_index, prefix.iter().copied());
+    }
+}
+
+/// Strip `prefix` from the key part of keyspace notification channels or patterns
+pub(crate) fn unprefix_channel(prefix: &[u8], channel: &mut Vec<u8>) {
+    if let Some(key_index) = keyspace_key_index(channel) {
+        if channel[key_index..].starts_with(prefix) {
+            channel.drain(key_index..key_index + prefix.len());
+        }
+    }
+}
+
+/// Strip `prefix` from keyspace notifications
+///
+/// # Return
+/// `false` if the message is a keyevent notification of a key outside the prefix
+pub(crate) fn unprefix_message(prefix: &[u8], message: &mut PubSubMessage) -> bool {
+    unprefix_channel(prefix, &mut message.channel);
+    unprefix_channel(prefix, &mut message.pattern);
+
+    if message.channel.starts_with(KEYEVENT_CHANNEL_PREFIX) {
+        if message.payload.starts_with(prefix) {
+            message.payload.drain(..prefix.len());
+        } else {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// index of the key in a channel like `__keyspace@<db>__:<key>`
+fn keyspace_key_index(channel: &[u8]) -> Option<usize> {
+    if channel.starts_with(KEYSPACE_CHANNEL_PREFIX) {
+        channel
+            .windows(3)
+            .position(|w| w == b"__:")
+            .map(|position| position + 3)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,215 @@
+use crate::{
+    client::{PubSubMessage, PubSubStream},
+    Error, Result,
+};
+use futures_util::{Stream, StreamExt};
+use std::{
+    fmt,
+    ops::BitOr,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+const KEYSPACE_CHANNEL_PREFIX: &str = "__keyspace@";
+const KEYEVENT_CHANNEL_PREFIX: &str = "__keyevent@";
+
+/// Classes of [keyspace notifications](https://redis.io/docs/manual/keyspace-notifications/),
+/// as configured by the `notify-keyspace-events` server parameter
+///
+/// Flags can be combined with the `|` operator, e.g. `KeyspaceEventFlags::KEYSPACE | KeyspaceEventFlags::GENERIC`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KeyspaceEventFlags(u16);
+
+impl KeyspaceEventFlags {
+    /// Keyspace events, published in `__keyspace@<db>__:<key>` channels (`K`)
+    pub const KEYSPACE: Self = Self(1);
+    /// Keyevent events, published in `__keyevent@<db>__:<event>` channels (`E`)
+    pub const KEYEVENT: Self = Self(1 << 1);
+    /// Generic commands (non-type specific) like `DEL`, `EXPIRE`, `RENAME`, ... (`g`)
+    pub const GENERIC: Self = Self(1 << 2);
+    /// String commands (`$`)
+    pub const STRING: Self = Self(1 << 3);
+    /// List commands (`l`)
+    pub const LIST: Self = Self(1 << 4);
+    /// Set commands (`s`)
+    pub const SET: Self = Self(1 << 5);
+    /// Hash commands (`h`)
+    pub const HASH: Self = Self(1 << 6);
+    /// Sorted set commands (`z`)
+    pub const ZSET: Self = Self(1 << 7);
+    /// Expired events, every time a key expires (`x`)
+    pub const EXPIRED: Self = Self(1 << 8);
+    /// Evicted events, every time a key is evicted for maxmemory (`e`)
+    pub const EVICTED: Self = Self(1 << 9);
+    /// Stream commands (`t`)
+    pub const STREAM: Self = Self(1 << 10);
+    /// Key-miss events, when a key that doesn't exist is accessed (`m`)
+    pub const KEY_MISS: Self = Self(1 << 11);
+    /// New key events, when a key is created (`n`)
+    pub const NEW: Self = Self(1 << 12);
+    /// Alias for `g$lshzxet`, all the events but key-miss and new key events (`A`)
+    pub const ALL: Self = Self(
+        Self::GENERIC.0
+            | Self::STRING.0
+            | Self::LIST.0
+            | Self::SET.0
+            | Self::HASH.0
+            | Self::ZSET.0
+            | Self::EXPIRED.0
+            | Self::EVICTED.0
+            | Self::STREAM.0,
+    );
+
+    const FLAG_CHARS: [(Self, char); 13] = [
+        (Self::KEYSPACE, 'K'),
+        (Self::KEYEVENT, 'E'),
+        (Self::GENERIC, 'g'),
+        (Self::STRING, '$'),
+        (Self::LIST, 'l'),
+        (Self::SET, 's'),
+        (Self::HASH, 'h'),
+        (Self::ZSET, 'z'),
+        (Self::EXPIRED, 'x'),
+        (Self::EVICTED, 'e'),
+        (Self::STREAM, 't'),
+        (Self::KEY_MISS, 'm'),
+        (Self::NEW, 'n'),
+    ];
+
+    /// Check if all the flags of `other` are set
+    #[inline]
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Check if at least one event class is set, in addition to `KEYSPACE` and `KEYEVENT`
+    #[inline]
+    pub(crate) fn has_event_classes(&self) -> bool {
+        self.0 & !(Self::KEYSPACE.0 | Self::KEYEVENT.0) != 0
+    }
+}
+
+impl BitOr for KeyspaceEventFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+/// Value of the `notify-keyspace-events` server parameter, e.g. `Kg$`
+impl fmt::Display for KeyspaceEventFlags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (flag, c) in Self::FLAG_CHARS {
+            if self.contains(flag) {
+                fmt::Write::write_char(f, c)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A keyspace notification, decoded from a keyspace or a keyevent channel
+///
+/// See [`Client::keyspace_notifications`](crate::client::Client::keyspace_notifications)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyspaceEvent {
+    /// Database of the key
+    pub db: usize,
+    /// The key which has been modified
+    pub key: Vec<u8>,
+    /// Name of the event, usually the name of the command, e.g. `set`, `del`, `expired`, ...
+    pub event: String,
+}
+
+impl KeyspaceEvent {
+    /// Decode a message received from a `__keyspace@<db>__:<key>`
+    /// or a `__keyevent@<db>__:<event>` channel
+    pub fn from_message(message: PubSubMessage) -> Result<Self> {
+        let PubSubMessage {
+            channel, payload, ..
+        } = message;
+
+        let (is_keyspace, rest) =
+            if let Some(rest) = channel.strip_prefix(KEYSPACE_CHANNEL_PREFIX.as_bytes()) {
+                (true, rest)
+            } else if let Some(rest) = channel.strip_prefix(KEYEVENT_CHANNEL_PREFIX.as_bytes()) {
+                (false, rest)
+            } else {
+                return Err(Error::Client(format!(
+                    "Cannot parse keyspace notification channel `{}`",
+                    String::from_utf8_lossy(&channel)
+                )));
+            };
+
+        let Some(position) = rest.windows(3).position(|w| w == b"__:") else {
+            return Err(Error::Client(format!(
+                "Cannot parse keyspace notification channel `{}`",
+                String::from_utf8_lossy(&channel)
+            )));
+        };
+
+        let db = atoi::atoi::<usize>(&rest[..position]).ok_or_else(|| {
+            Error::Client(format!(
+                "Cannot parse database of keyspace notification channel `{}`",
+                String::from_utf8_lossy(&channel)
+            ))
+        })?;
+        let suffix = rest[position + 3..].to_vec();
+
+        let (key, event) = if is_keyspace {
+            (suffix, payload)
+        } else {
+            (payload, suffix)
+        };
+
+        Ok(Self {
+            db,
+            key,
+            event: String::from_utf8(event)?,
+        })
+    }
+}
+
+/// Stream of [`KeyspaceEvent`](KeyspaceEvent) returned by
+/// [`Client::keyspace_notifications`](crate::client::Client::keyspace_notifications)
+///
+/// When both keyspace and keyevent notifications are subscribed,
+/// each change of a key is received twice, once for each channel.
+pub struct KeyspaceNotificationStream {
+    inner: PubSubStream,
+}
+
+impl KeyspaceNotificationStream {
+    pub(crate) fn new(inner: PubSubStream) -> Self {
+        Self { inner }
+    }
+
+    /// Close the stream by cancelling the pattern subscriptions
+    pub async fn close(self) -> Result<()> {
+        self.inner.close().await
+    }
+}
+
+impl Stream for KeyspaceNotificationStream {
+    type Item = Result<KeyspaceEvent>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        self.get_mut()
+            .inner
+            .poll_next_unpin(cx)
+            .map(|message| message.map(|message| message.and_then(KeyspaceEvent::from_message)))
+    }
+}
+
+/// Patterns matching all the keyspace and/or keyevent channels of `db`
+pub(crate) fn keyspace_patterns(db: usize, events: KeyspaceEventFlags) -> Vec<String> {
+    let mut patterns = Vec::new();
+    if events.contains(KeyspaceEventFlags::KEYSPACE) {
+        patterns.push(format!("{KEYSPACE_CHANNEL_PREFIX}{db}__:*"));
+    }
+    if events.contains(KeyspaceEventFlags::KEYEVENT) {
+        patterns.push(format!("{KEYEVENT_CHANNEL_PREFIX}{db}__:*"));
+    }
+    patterns
+}
@@ -1,6 +1,6 @@
 use smallvec::SmallVec;
 
-use crate::{resp::Command, PushSender, PubSubSender, RetryReason, network::{ResultSender, ResultsSender}};
+use crate::{resp::Command, PushSender, PubSubSender, RetryReason, network::{ResultSender, ResultsSender, StreamSender, WriteSender}};
 
 #[cfg(debug_assertions)]
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -14,6 +14,9 @@ pub(crate) enum Commands {
     None,
     Single(Command, Option<ResultSender>),
     Batch(Vec<Command>, ResultsSender),
+    /// Batch whose replies are discarded, the sender is notified once the batch has been written
+    BatchForget(Vec<Command>, Option<WriteSender>),
+    Stream(Vec<Command>, StreamSender),
 }
 
 impl Commands {
@@ -21,7 +24,9 @@ impl Commands {
         match &self {
             Commands::None => 0,
             Commands::Single(_, _) => 1,
-            Commands::Batch(commands, _) => commands.len(),
+            Commands::Batch(commands, _)
+            | Commands::BatchForget(commands, _)
+            | Commands::Stream(commands, _) => commands.len(),
         }
     }
 }
@@ -34,7 +39,9 @@ impl IntoIterator for Commands {
         match self {
             Commands::None => CommandsIterator::Single(None),
             Commands::Single(command, _) => CommandsIterator::Single(Some(command)),
-            Commands::Batch(commands, _) => CommandsIterator::Batch(commands.into_iter()),
+            Commands::Batch(commands, _)
+            | Commands::BatchForget(commands, _)
+            | Commands::Stream(commands, _) => CommandsIterator::Batch(commands.into_iter()),
         }
     }
 }
@@ -47,7 +54,9 @@ impl<'a> IntoIterator for &'a Commands {
         match self {
             Commands::None => RefCommandsIterator::Single(None),
             Commands::Single(command, _) => RefCommandsIterator::Single(Some(command)),
-            Commands::Batch(commands, _) => RefCommandsIterator::Batch(commands.iter()),
+            Commands::Batch(commands, _)
+            | Commands::BatchForget(commands, _)
+            | Commands::Stream(commands, _) => RefCommandsIterator::Batch(commands.iter()),
         }
     }
 }
@@ -60,7 +69,9 @@ impl<'a> IntoIterator for &'a mut Commands {
         match self {
             Commands::None => CommandsIteratorMut::Single(None),
             Commands::Single(command, _) => CommandsIteratorMut::Single(Some(command)),
-            Commands::Batch(commands, _) => CommandsIteratorMut::Batch(commands.iter_mut()),
+            Commands::Batch(commands, _)
+            | Commands::BatchForget(commands, _)
+            | Commands::Stream(commands, _) => CommandsIteratorMut::Batch(commands.iter_mut()),
         }
     }
 }
@@ -117,10 +128,12 @@ impl<'a> Iterator for CommandsIteratorMut<'a> {
 #[derive(Debug)]
 pub(crate) struct Message {
     pub commands: Commands,
-    pub pub_sub_senders: Option<Vec<(Vec<u8>, PubSubSender)>>,
+    /// channels with their sender, and whether subscription confirmations are forwarded to it
+    pub pub_sub_senders: Option<Vec<(Vec<u8>, PubSubSender, bool)>>,
     pub push_sender: Option<PushSender>,
     pub retry_reasons: Option<SmallVec<[RetryReason; 10]>>,
     pub retry_on_error: bool,
+    pub max_attempts: Option<usize>,
     #[cfg(debug_assertions)]
     #[allow(unused)]
     pub (crate) message_seq: usize,
@@ -135,6 +148,7 @@ impl Message {
             push_sender: None,
             retry_reasons: None,
             retry_on_error,
+            max_attempts: None,
             #[cfg(debug_assertions)]
             message_seq: MESSAGE_SEQUENCE_COUNTER.fetch_add(1, Ordering::SeqCst),
         }
@@ -148,6 +162,7 @@ impl Message {
             push_sender: None,
             retry_reasons: None,
             retry_on_error,
+            max_attempts: None,
             #[cfg(debug_assertions)]
             message_seq: MESSAGE_SEQUENCE_COUNTER.fetch_add(1, Ordering::SeqCst),
         }
@@ -161,16 +176,56 @@ impl Message {
             push_sender: None,
             retry_reasons: None,
             retry_on_error,
+            max_attempts: None,
             #[cfg(debug_assertions)]
             message_seq: MESSAGE_SEQUENCE_COUNTER.fetch_add(1, Ordering::SeqCst),
         }
     }
 
+    #[inline(always)]
+    pub fn batch_forget(
+        commands: Vec<Command>,
+        write_sender: WriteSender,
+        retry_on_error: bool,
+    ) -> Self {
+        Message {
+            commands: Commands::BatchForget(commands, Some(write_sender)),
+            pub_sub_senders: None,
+            push_sender: None,
+            retry_reasons: None,
+            retry_on_error,
+            max_attempts: None,
+            #[cfg(debug_assertions)]
+            message_seq: MESSAGE_SEQUENCE_COUNTER.fetch_add(1, Ordering::SeqCst),
+        }
+    }
+
+    // results already streamed to the caller cannot be recalled: the batch is never retried
+    #[inline(always)]
+    pub fn stream(commands: Vec<Command>, stream_sender: StreamSender) -> Self {
+        Message {
+            commands: Commands::Stream(commands, stream_sender),
+            pub_sub_senders: None,
+            push_sender: None,
+            retry_reasons: None,
+            retry_on_error: false,
+            max_attempts: None,
+            #[cfg(debug_assertions)]
+            message_seq: MESSAGE_SEQUENCE_COUNTER.fetch_add(1, Ordering::SeqCst),
+        }
+    }
+
+    #[inline(always)]
+    pub fn max_attempts(mut self, max_attempts: Option<usize>) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
     #[inline(always)]
     pub fn pub_sub(
         command: Command,
         result_sender: ResultSender,
-        pub_sub_senders: Vec<(Vec<u8>, PubSubSender)>,
+        pub_sub_senders: Vec<(Vec<u8>, PubSubSender, bool)>,
     ) -> Self {
         Message {
             commands: Commands::Single(command, Some(result_sender)),
@@ -178,6 +233,7 @@ impl Message {
             push_sender: None,
             retry_reasons: None,
             retry_on_error: true,
+            max_attempts: None,
             #[cfg(debug_assertions)]
             message_seq: MESSAGE_SEQUENCE_COUNTER.fetch_add(1, Ordering::SeqCst),
         }
@@ -195,19 +251,21 @@ impl Message {
             push_sender: Some(push_sender),
             retry_reasons: None,
             retry_on_error: true,
+            max_attempts: None,
             #[cfg(debug_assertions)]
             message_seq: MESSAGE_SEQUENCE_COUNTER.fetch_add(1, Ordering::SeqCst),
         }
     }
 
     #[inline(always)]
-    pub fn client_tracking_invalidation(push_sender: PushSender) -> Self {
+    pub fn push(push_sender: PushSender) -> Self {
         Message {
             commands: Commands::None,
             pub_sub_senders: None,
             push_sender: Some(push_sender),
             retry_reasons: None,
             retry_on_error: false,
+            max_attempts: None,
             #[cfg(debug_assertions)]
             message_seq: MESSAGE_SEQUENCE_COUNTER.fetch_add(1, Ordering::SeqCst),
         }
@@ -54,10 +54,13 @@ underlying connection. It should be the prefered mode for Web applications.
 Beware that using [`Client`](Client) in a multiplexer mode, by cloning an instance across multiple threads,
 is not suitable for using [blocking commands](crate::commands::BlockingCommands)
 because they monopolize the whole connection which cannot be shared anymore.
+Blocking commands sent with [`Client::send`](Client::send) on a shared connection are logged as a warning,
+or rejected with [`Config::strict_blocking_commands`](Config::strict_blocking_commands).
 
 Moreover using the [`watch`](crate::commands::TransactionCommands::watch) command is not compatible
 with the multiplexer mode is either. Indeed, it's the shared connection that will be watched, not only
 the [`Client`](Client) instance through which the [`watch`](crate::commands::TransactionCommands::watch) command is sent.
+[`Transaction::watch`](Transaction::watch) rejects a connection shared by clones of the client.
 
 ### Managing multiplexed subscriptions
 
@@ -152,6 +155,11 @@ async fn main() -> Result<()> {
 }
 ```
 
+## The sync client
+With the `sync` feature, [`SyncClient`](SyncClient) offers a blocking facade over [`Client`](Client)
+for non-async callers, like simple tools and scripts.
+It must not be used from within an async runtime.
+
 # Configuration
 
 A [`Client`](Client) instance can be configured with the [`Config`](Config) struct:
@@ -159,6 +167,9 @@ A [`Client`](Client) instance can be configured with the [`Config`](Config) stru
 * [`TlsConfig`](TlsConfig)
 * [`ServerConfig`](ServerConfig) (Standalone, Sentinel or Cluster)
 
+A [`Config`](Config) instance can also be built and validated with a [`ConfigBuilder`](ConfigBuilder),
+returned by [`Config::builder`](Config::builder).
+
 [`IntoConfig`] is a convenient trait to convert more known types to a [`Config`](Config) instance:
 * &[`str`](https://doc.rust-lang.org/std/primitive.str.html): host and port separated by a colon
 * `(impl Into<String>, u16)`: a pair of host and port
@@ -181,6 +192,15 @@ redis|rediss://[[<username>]:<password>@]<host>[:<port>][/<database>]
 redis|rediss[+cluster]://[[<username>]:<password>@]<host1>[:<port1>][,<host2>:[<port2>][,<hostN>:[<portN>]]]
 ```
 
+In cluster mode, the client maintains a connection to each master node
+and routes each command directly to the node owning the hash slot of its keys:
+* `MOVED` redirections refresh the slot map before the command is retried,
+* `ASK` redirections are followed by sending `ASKING` to the target node first,
+* commands whose keys hash to different slots are rejected with an [`Error::Client`](crate::Error::Client),
+  unless their request policy allows them to be split across shards (e.g. `MGET`, `MSET`, `DEL`).
+  Use [hash tags](https://redis.io/docs/reference/cluster-spec/#hash-tags) (`{user1}:name`, `{user1}:email`)
+  to force keys into the same slot.
+
 ### Sentinel
 
 ```text
@@ -205,6 +225,8 @@ of the struct [`Config`](Config) or its dependencies:
 * [`connect_timeout`](Config::connect_timeout) - The time to attempt a connection before timing out (default `10,000` ms).
 * [`command_timeout`](Config::command_timeout) - If a command does not return a reply within a set number of milliseconds,
    a timeout error will be thrown. If set to 0, no timeout is apply (default `0`).
+* [`close_timeout`](Config::close_timeout) - When the client is closed, the time to wait for the replies
+  of the commands already sent before closing the connection (default `5,000` ms).
 * [`auto_resubscribe`](Config::auto_resubscribe) - When the client reconnects, channels subscribed in the previous connection will be
  resubscribed automatically if `auto_resubscribe` is `true` (default `true`).
 * [`auto_remonitor`](Config::auto_remonitor) - When the client reconnects, if in `monitor` mode, the
@@ -213,6 +235,14 @@ of the struct [`Config`](Config) or its dependencies:
   it easier to identity the connection in client list.
 * [`keep_alive`](Config::keep_alive) - Enable/disable keep-alive functionality (default `None`)
 * [`no_delay`](Config::no_delay) - Enable/disable the use of Nagle's algorithm (default `true`)
+* [`pub_sub_channel_capacity`](Config::pub_sub_channel_capacity) - Maximum number of messages buffered
+  for each pub/sub stream (default unbounded).
+* [`pub_sub_backpressure_policy`](Config::pub_sub_backpressure_policy) - Policy applied when a pub/sub stream buffer is full:
+  `backpressure`, `drop_oldest` or `drop_newest` (default `backpressure`).
+* [`compression`](Config::compression) - Transparent compression of large string values
+  (`lz4` or `zstd` feature, default `None`).
+* [`key_prefix`](Config::key_prefix) - Prefix transparently prepended to the keys of each command,
+  to isolate multiple tenants (default `None`).
 * [`max_command_attempts`](Config::max_command_attempts) - Maximum number of retry attempts to send a command to the Redis server (default `3`).
 * [`retry_on_error`](Config::retry_on_error) - Defines the default strategy for retries on network error (default `false`). 
 * [`wait_between_failures`](SentinelConfig::wait_between_failures) - (Sentinel only) Waiting time after 
@@ -289,6 +319,44 @@ async fn main() -> Result<()> {
 }
 ```
 
+### Typed pipelines
+
+A [`TypedPipeline`](TypedPipeline), created with [`create_typed_pipeline`](Client::create_typed_pipeline),
+records the response type of each [queued](TypedBatchPreparedCommand::queue) command,
+so that [`execute`](TypedPipeline::execute) returns the matching tuple (up to 16 elements) without any type annotation.
+Destructuring the result with the wrong number of elements is then detected at compile time.
+
+```
+use rustis::{
+    client::{Client, TypedBatchPreparedCommand},
+    commands::StringCommands,
+    Result,
+};
+
+#[cfg_attr(feature = "tokio-runtime", tokio::main)]
+#[cfg_attr(feature = "async-std-runtime", async_std::main)]
+async fn main() -> Result<()> {
+    let client = Client::connect("127.0.0.1:6379").await?;
+
+    let (value1, value2) = client
+        .create_typed_pipeline()
+        .set("key1", "value1")
+        .forget()
+        .set("key2", "value2")
+        .forget()
+        .get::<_, String>("key1")
+        .queue()
+        .get::<_, String>("key2")
+        .queue()
+        .execute()
+        .await?;
+    assert_eq!("value1", value1);
+    assert_eq!("value2", value2);
+
+    Ok(())
+}
+```
+
 # Transactions
 [Redis Transactions](https://redis.io/docs/manual/transactions/) allow the execution of a group of commands in a single step.
 
@@ -373,6 +441,16 @@ Mulitplexed [`Client`](Client) instances must be decidated to Pub/Sub once a sub
 Indeed, because subscription blocks the multiplexed client shared connection,
 other callers would be blocked when sending regular commands.
 
+### Cluster
+
+In cluster mode, sharded subscriptions are routed to the master node owning
+the hash slot of each shard channel, as [`spublish`](crate::commands::PubSubCommands::spublish) is.
+Regular subscriptions are sent to a single node, which receives the messages published on the whole cluster.
+
+A [`PubSubStream`](PubSubStream) can mix sharded and regular subscriptions, but its messages may then
+come from several nodes. When the slot of a shard channel is migrated to another node,
+the sharded subscription is ended by the server and must be renewed by the caller.
+
 ### Example
 
 ```
@@ -445,13 +523,29 @@ async fn main() -> Result<()> {
     Ok(())
 }
 ```
+
+### Subscription Events
+
+[`PubSubStream`](PubSubStream) only yields the published messages.
+To also get the subscription and unsubscription confirmations of the server, for example to know
+when messages start to be received, call [`subscribe_with_events`](crate::commands::PubSubCommands::subscribe_with_events):
+the returned [`PubSubEventStream`](PubSubEventStream) yields [`PubSubEvent`](PubSubEvent) items.
 */
 
 #[allow(clippy::module_inception)]
 mod client;
 mod client_state;
 mod client_tracking_invalidation_stream;
+mod cluster_node_info;
+#[cfg(feature = "tracing")]
+mod command_span;
+mod compression;
 mod config;
+mod health_status;
+mod interceptor;
+mod key_metadata;
+mod key_prefix;
+mod keyspace_notifications;
 mod message;
 mod monitor_stream;
 mod pipeline;
@@ -460,12 +554,30 @@ mod pipeline;
 mod pooled_client_manager;
 mod prepared_command;
 mod pub_sub_stream;
+mod push_message;
+mod script;
+mod script_cache;
+mod server_version;
+#[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+#[cfg(feature = "sync")]
+mod sync_client;
 mod transaction;
+mod typed_pipeline;
+mod xread_stream;
 
 pub use client::*;
 pub use client_state::*;
 pub(crate) use client_tracking_invalidation_stream::*;
+pub use cluster_node_info::*;
+#[cfg(feature = "tracing")]
+pub(crate) use command_span::*;
+pub use compression::*;
 pub use config::*;
+pub use health_status::*;
+pub(crate) use interceptor::*;
+pub use key_metadata::*;
+pub(crate) use key_prefix::*;
+pub use keyspace_notifications::*;
 pub(crate) use message::*;
 pub use monitor_stream::*;
 pub use pipeline::*;
@@ -474,4 +586,13 @@ pub use pipeline::*;
 pub use pooled_client_manager::*;
 pub use prepared_command::*;
 pub use pub_sub_stream::*;
+pub use push_message::*;
+pub use script::*;
+pub use script_cache::*;
+pub use server_version::*;
+#[cfg_attr(docsrs, doc(cfg(feature = "sync")))]
+#[cfg(feature = "sync")]
+pub use sync_client::*;
 pub use transaction::*;
+pub use typed_pipeline::*;
+pub use xread_stream::*;
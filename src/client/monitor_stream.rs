@@ -2,14 +2,16 @@ use crate::{
     client::{Client, ClientPreparedCommand},
     commands::ConnectionCommands,
     network::PushReceiver,
-    Result,
+    Error, Result,
 };
 use futures_util::{Stream, StreamExt};
 use log::error;
 use serde::{de, Deserialize, Deserializer};
 use std::{
+    collections::HashSet,
     net::SocketAddr,
     pin::Pin,
+    str::FromStr,
     task::{Context, Poll},
 };
 
@@ -19,6 +21,7 @@ pub struct MonitorStream {
     closed: bool,
     receiver: PushReceiver,
     client: Client,
+    filter: MonitorFilter,
 }
 
 impl MonitorStream {
@@ -27,9 +30,19 @@ impl MonitorStream {
             closed: false,
             receiver,
             client,
+            filter: MonitorFilter::default(),
         }
     }
 
+    /// Only yield the events matching the filter.
+    ///
+    /// Events are filtered client-side: the server still sends all the events.
+    #[must_use]
+    pub fn filter(mut self, filter: MonitorFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
     pub async fn close(&mut self) -> Result<()> {
         self.client.reset().await?;
         self.closed = true;
@@ -41,24 +54,32 @@ impl Stream for MonitorStream {
     type Item = MonitoredCommandInfo;
 
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
-        if self.closed {
-            Poll::Ready(None)
-        } else {
-            match self.get_mut().receiver.poll_next_unpin(cx) {
+        let this = self.get_mut();
+
+        if this.closed {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            match this.receiver.poll_next_unpin(cx) {
                 Poll::Ready(bytes) => match bytes {
                     Some(bytes) => match bytes {
-                        Ok(resp_buf) => match resp_buf.to() {
-                            Ok(info) => Poll::Ready(Some(info)),
+                        Ok(resp_buf) => match resp_buf.to::<MonitoredCommandInfo>() {
+                            Ok(info) => {
+                                if this.filter.matches(&info) {
+                                    return Poll::Ready(Some(info));
+                                }
+                            }
                             Err(e) => {
                                 error!("Error will receiving data in monitor stream: {e}");
-                                Poll::Ready(None)
+                                return Poll::Ready(None);
                             }
                         },
-                        Err(_) => Poll::Ready(None),
+                        Err(_) => return Poll::Ready(None),
                     },
-                    None => Poll::Ready(None),
+                    None => return Poll::Ready(None),
                 },
-                Poll::Pending => Poll::Pending,
+                Poll::Pending => return Poll::Pending,
             }
         }
     }
@@ -74,48 +95,163 @@ impl Drop for MonitorStream {
     }
 }
 
+/// Client-side filter of a [`MonitorStream`](MonitorStream)
+///
+/// `None` fields match any event.
+#[derive(Debug, Clone, Default)]
+pub struct MonitorFilter {
+    /// Command names to keep (case insensitive)
+    pub commands: Option<HashSet<String>>,
+    /// Database to keep
+    pub database: Option<usize>,
+}
+
+impl MonitorFilter {
+    /// Keep only the events of the given commands
+    #[must_use]
+    pub fn commands<C, I>(mut self, commands: I) -> Self
+    where
+        C: Into<String>,
+        I: IntoIterator<Item = C>,
+    {
+        self.commands = Some(
+            commands
+                .into_iter()
+                .map(|c| c.into().to_uppercase())
+                .collect(),
+        );
+        self
+    }
+
+    /// Keep only the events of the given database
+    #[must_use]
+    pub fn database(mut self, database: usize) -> Self {
+        self.database = Some(database);
+        self
+    }
+
+    /// Check if an event matches the filter
+    pub fn matches(&self, info: &MonitoredCommandInfo) -> bool {
+        if let Some(database) = self.database {
+            if info.database != database {
+                return false;
+            }
+        }
+
+        if let Some(commands) = &self.commands {
+            if !commands.contains(&info.command.to_uppercase()) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
 /// Result for the [`monitor`](crate::commands::BlockingCommands::monitor) command.
 #[derive(Debug)]
 pub struct MonitoredCommandInfo {
+    /// Unix timestamp of the command, in seconds with a microsecond precision
     pub unix_timestamp_millis: f64,
+    /// Database of the command
     pub database: usize,
+    /// Address of the client which sent the command:
+    /// `host:port`, the path of a unix socket, `lua` or `unknown`
+    pub client_addr: String,
+    /// Address of the client which sent the command,
+    /// or `0.0.0.0:0` if it is not a socket address, e.g. `lua` or a unix socket
+    #[deprecated(note = "use `MonitoredCommandInfo::client_addr` instead")]
     pub server_addr: SocketAddr,
+    /// Command name
     pub command: String,
+    /// Command arguments, unquoted and unescaped
     pub command_args: Vec<String>,
 }
 
+impl MonitoredCommandInfo {
+    /// Parse a line sent by the server in [`MONITOR`](https://redis.io/commands/monitor/) mode
+    ///
+    /// format: `<timestamp> [<db> <client addr>] "<command>" "<arg1>" ... "<argN>"`
+    pub fn parse(line: &str) -> Result<Self> {
+        Self::parse_parts(line)
+            .ok_or_else(|| Error::Client(format!("Cannot parse result from MONITOR event: {line}")))
+    }
+
+    fn parse_parts(line: &str) -> Option<Self> {
+        let (unix_timestamp_millis, rest) = line.split_once(' ')?;
+        let rest = rest.strip_prefix('[')?;
+        let (database, rest) = rest.split_once(' ')?;
+        let (client_addr, rest) = rest.split_once("] ")?;
+
+        let mut args = parse_quoted_args(rest)?.into_iter();
+
+        #[allow(deprecated)]
+        Some(Self {
+            unix_timestamp_millis: unix_timestamp_millis.parse::<f64>().ok()?,
+            database: database.parse::<usize>().ok()?,
+            client_addr: client_addr.to_owned(),
+            server_addr: client_addr
+                .parse::<SocketAddr>()
+                .unwrap_or_else(|_| SocketAddr::from(([0, 0, 0, 0], 0))),
+            command: args.next()?,
+            command_args: args.collect(),
+        })
+    }
+}
+
+/// Parse space separated arguments quoted and escaped by the server
+/// (`\"`, `\\`, `\n`, `\r`, `\t`, `\a`, `\b` and `\xHH` escape sequences).
+fn parse_quoted_args(str: &str) -> Option<Vec<String>> {
+    let mut args = Vec::new();
+    let mut bytes = str.as_bytes().iter().copied();
+
+    loop {
+        match bytes.next() {
+            Some(b' ') => continue,
+            Some(b'"') => (),
+            Some(_) => return None,
+            None => return Some(args),
+        }
+
+        let mut arg = Vec::new();
+        loop {
+            match bytes.next()? {
+                b'"' => break,
+                b'\\' => match bytes.next()? {
+                    b'n' => arg.push(b'\n'),
+                    b'r' => arg.push(b'\r'),
+                    b't' => arg.push(b'\t'),
+                    b'a' => arg.push(0x07),
+                    b'b' => arg.push(0x08),
+                    b'x' => {
+                        let hex = [bytes.next()?, bytes.next()?];
+                        let hex = std::str::from_utf8(&hex).ok()?;
+                        arg.push(u8::from_str_radix(hex, 16).ok()?);
+                    }
+                    b => arg.push(b),
+                },
+                b => arg.push(b),
+            }
+        }
+
+        args.push(String::from_utf8_lossy(&arg).into_owned());
+    }
+}
+
+impl FromStr for MonitoredCommandInfo {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Self> {
+        Self::parse(line)
+    }
+}
+
 impl<'de> Deserialize<'de> for MonitoredCommandInfo {
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
         let line = <&str>::deserialize(deserializer)?;
-        let mut parts = line.split(' ');
-
-        let info = match (parts.next(), parts.next(), parts.next(), parts.next()) {
-            (Some(unix_timestamp_millis), Some(database), Some(server_addr), Some(command)) => {
-                let database = &database[1..];
-                let server_addr = &server_addr[..server_addr.len() - 1];
-                match (
-                    unix_timestamp_millis.parse::<f64>(),
-                    server_addr.parse::<SocketAddr>(),
-                    database.parse::<usize>(),
-                ) {
-                    (Ok(unix_timestamp_millis), Ok(server_addr), Ok(database)) => Some(Self {
-                        unix_timestamp_millis,
-                        database,
-                        server_addr,
-                        command: command[1..command.len() - 1].to_owned(),
-                        command_args: parts.map(|a| a[1..a.len() - 1].to_owned()).collect(),
-                    }),
-                    _ => None,
-                }
-            }
-            _ => None,
-        };
-
-        info.ok_or_else(|| {
-            de::Error::custom(format!("Cannot parse result from MONITOR event: {line}"))
-        })
+        Self::parse(line).map_err(de::Error::custom)
     }
 }
@@ -1,3 +1,5 @@
+#[cfg(feature = "debug-commands")]
+use crate::commands::DebugCommands;
 #[cfg(feature = "redis-graph")]
 use crate::commands::GraphCommands;
 #[cfg(feature = "redis-json")]
@@ -13,11 +15,11 @@ use crate::commands::{
 use crate::{
     client::{Client, PreparedCommand},
     commands::{
-        BitmapCommands, ClusterCommands, ConnectionCommands, GenericCommands, GeoCommands,
-        HashCommands, HyperLogLogCommands, ListCommands, ScriptingCommands, ServerCommands,
-        SetCommands, SortedSetCommands, StreamCommands, StringCommands,
+        BitmapCommands, ClusterCommands, CommandCommands, ConnectionCommands, GenericCommands,
+        GeoCommands, HashCommands, HyperLogLogCommands, ListCommands, ScriptingCommands,
+        ServerCommands, SetCommands, SortedSetCommands, StreamCommands, StringCommands,
     },
-    resp::{Command, RespBatchDeserializer, Response},
+    resp::{Command, RespBatchDeserializer, RespBuf, Response},
     Result,
 };
 use serde::de::DeserializeOwned;
@@ -69,6 +71,9 @@ impl<'a> Pipeline<'a> {
     ///
     /// The most generic type that can be requested as a result is `Vec<resp::Value>`
     ///
+    /// An empty pipeline, or a pipeline where all commands have been forgotten,
+    /// is deserialized from a nil response, for example to `()`.
+    ///
     /// # Example
     /// ```
     /// use rustis::{
@@ -96,27 +101,76 @@ impl<'a> Pipeline<'a> {
     /// }
     /// ```    
     pub async fn execute<T: DeserializeOwned>(self) -> Result<T> {
-        let num_commands = self.commands.len();
+        let mut results = self.execute_raw().await?;
+
+        match results.len() {
+            // empty pipeline or only forgotten commands
+            0 => RespBuf::nil().to(),
+            1 => results.pop().unwrap().to(),
+            _ => {
+                let deserializer = RespBatchDeserializer::new(&results);
+                T::deserialize(&deserializer)
+            }
+        }
+    }
+
+    /// Execute the pipeline by sending the queued commands
+    /// as a whole batch to the Redis server, and forget all their responses.
+    ///
+    /// Commands are sent in order, and only the write of the batch to the socket is awaited:
+    /// this fits write-only pipelines, like cache warming,
+    /// where waiting for and buffering the responses would be wasted.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs while writing the batch.
+    /// Errors replied by the server for individual commands are not reported.
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::{
+    ///     client::{Client, Pipeline, BatchPreparedCommand},
+    ///     commands::StringCommands,
+    ///     Result,
+    /// };
+    ///
+    /// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+    /// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+    /// async fn main() -> Result<()> {
+    ///     let client = Client::connect("127.0.0.1:6379").await?;
+    ///
+    ///     let mut pipeline = client.create_pipeline();
+    ///     pipeline.set("key1", "value1").forget();
+    ///     pipeline.set("key2", "value2").forget();
+    ///     pipeline.execute_and_forget().await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn execute_and_forget(self) -> Result<()> {
+        if self.commands.is_empty() {
+            return Ok(());
+        }
+
+        self.client
+            .send_batch_and_forget(self.commands, self.retry_on_error)
+            .await
+    }
+
+    /// Send the queued commands and return the raw results
+    /// of the commands which have not been forgotten
+    pub(crate) async fn execute_raw(self) -> Result<Vec<RespBuf>> {
+        if self.commands.is_empty() {
+            return Ok(Vec::new());
+        }
+
         let results = self
             .client
             .send_batch(self.commands, self.retry_on_error)
             .await?;
 
-        if num_commands > 1 {
-            let mut filtered_results = zip(results, self.forget_flags.iter())
-                .filter_map(|(value, forget_flag)| if *forget_flag { None } else { Some(value) })
-                .collect::<Vec<_>>();
-
-            if filtered_results.len() == 1 {
-                let result = filtered_results.pop().unwrap();
-                result.to()
-            } else {
-                let deserializer = RespBatchDeserializer::new(&filtered_results);
-                T::deserialize(&deserializer)
-            }
-        } else {
-            results[0].to()
-        }
+        Ok(zip(results, self.forget_flags.iter())
+            .filter_map(|(value, forget_flag)| if *forget_flag { None } else { Some(value) })
+            .collect())
     }
 }
 
@@ -150,6 +204,7 @@ impl<'a, 'b> BitmapCommands<'a> for &'a mut Pipeline<'b> {}
 #[cfg(feature = "redis-bloom")]
 impl<'a, 'b> BloomCommands<'a> for &'a mut Pipeline<'b> {}
 impl<'a, 'b> ClusterCommands<'a> for &'a mut Pipeline<'b> {}
+impl<'a, 'b> CommandCommands<'a> for &'a mut Pipeline<'b> {}
 impl<'a, 'b> ConnectionCommands<'a> for &'a mut Pipeline<'b> {}
 #[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
 #[cfg(feature = "redis-bloom")]
@@ -157,6 +212,9 @@ impl<'a, 'b> CountMinSketchCommands<'a> for &'a mut Pipeline<'b> {}
 #[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
 #[cfg(feature = "redis-bloom")]
 impl<'a, 'b> CuckooCommands<'a> for &'a mut Pipeline<'b> {}
+#[cfg_attr(docsrs, doc(cfg(feature = "debug-commands")))]
+#[cfg(feature = "debug-commands")]
+impl<'a, 'b> DebugCommands<'a> for &'a mut Pipeline<'b> {}
 impl<'a, 'b> GenericCommands<'a> for &'a mut Pipeline<'b> {}
 impl<'a, 'b> GeoCommands<'a> for &'a mut Pipeline<'b> {}
 #[cfg_attr(docsrs, doc(cfg(feature = "redis-graph")))]
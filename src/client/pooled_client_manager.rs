@@ -1,21 +1,187 @@
 use crate::{
     client::{Client, Config, IntoConfig},
     commands::ConnectionCommands,
+    network::ReconnectReceiver,
+    resp::Command,
     Error, Future, Result,
 };
-use bb8::ManageConnection;
+use bb8::{ManageConnection, Pool, RunError};
+use futures_util::future::try_join_all;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::sync::broadcast::error::TryRecvError;
+
+const POOLED_CLIENT_STATE_KEY: &str = "rustis::PooledClientState";
+
+type MetricsCallback = dyn Fn(PooledClientEvent) + Send + Sync;
+
+/// Event reported to the metrics callback of a [`PooledClientManager`](PooledClientManager)
+///
+/// See [`PooledClientManager::metrics`](PooledClientManager::metrics)
+#[derive(Debug)]
+pub enum PooledClientEvent<'a> {
+    /// A new client has been connected
+    Connected,
+    /// A new client failed to connect
+    ConnectionFailed(&'a Error),
+    /// A client has been validated before being checked out of the pool
+    Validated {
+        /// Total number of reconnections of the client since its creation
+        reconnections: usize,
+        /// Round trip time of the `PING` command, if [`ping_on_checkout`](PooledClientManager::ping_on_checkout) is enabled
+        ping_latency: Option<Duration>,
+        /// RESP protocol version currently negotiated by the client,
+        /// see [`Client::protocol`](Client::protocol)
+        protocol: Option<usize>,
+    },
+    /// A client failed its validation and is discarded by the pool
+    ValidationFailed(&'a Error),
+    /// A client has been dropped by the pool
+    Dropped {
+        /// Total number of reconnections of the client since its creation
+        reconnections: usize,
+    },
+}
 
 /// An object which manages a pool of clients, based on [bb8](https://docs.rs/bb8/latest/bb8/)
 pub struct PooledClientManager {
     config: Config,
+    ping_on_checkout: bool,
+    metrics: Option<Arc<MetricsCallback>>,
+    init_commands: Vec<Command>,
 }
 
 impl PooledClientManager {
     pub fn new(config: impl IntoConfig) -> Result<Self> {
         Ok(Self {
             config: config.into_config()?,
+            ping_on_checkout: true,
+            metrics: None,
+            init_commands: Vec::new(),
         })
     }
+
+    /// Database selected by each new client of the pool,
+    /// overriding the database of the [`Config`](Config).
+    ///
+    /// As for [`Config::database`](Config::database), the database is selected again
+    /// each time a client reconnects.
+    #[must_use]
+    pub fn database(mut self, database: usize) -> Self {
+        self.config.database = database;
+        self
+    }
+
+    /// Commands sent to each new client of the pool before it is handed out,
+    /// e.g. `CLIENT SETNAME` or `READONLY`.
+    ///
+    /// If one of these commands fails, the client is discarded
+    /// and the error is returned to the pool as a connection error.
+    ///
+    /// # Remark
+    /// These commands are only sent once, at the creation of the client,
+    /// and are not sent again when the client reconnects.
+    #[must_use]
+    pub fn init_commands(mut self, init_commands: Vec<Command>) -> Self {
+        self.init_commands = init_commands;
+        self
+    }
+
+    /// Validate each client with a `PING` command before handing it out (default `true`).
+    ///
+    /// A client which fails to reply is discarded by the pool.
+    /// Validation only happens if the pool has been built with
+    /// [`test_on_check_out`](https://docs.rs/bb8/latest/bb8/struct.Builder.html#method.test_on_check_out)
+    /// enabled, which is the default.
+    #[must_use]
+    pub fn ping_on_checkout(mut self, ping_on_checkout: bool) -> Self {
+        self.ping_on_checkout = ping_on_checkout;
+        self
+    }
+
+    /// Attach a callback invoked each time a client is created, validated or dropped by the pool.
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::{client::{PooledClientEvent, PooledClientManager}, Result};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let manager = PooledClientManager::new("127.0.0.1:6379")?.metrics(|event| {
+    ///         if let PooledClientEvent::Validated {
+    ///             reconnections,
+    ///             ping_latency,
+    ///             protocol,
+    ///         } = event
+    ///         {
+    ///             println!(
+    ///                 "reconnections: {reconnections}, ping latency: {ping_latency:?}, protocol: {protocol:?}"
+    ///             );
+    ///         }
+    ///     });
+    ///     let pool = rustis::bb8::Pool::builder().build(manager).await?;
+    ///     let _client = pool.get().await.unwrap();
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[must_use]
+    pub fn metrics(mut self, callback: impl Fn(PooledClientEvent) + Send + Sync + 'static) -> Self {
+        self.metrics = Some(Arc::new(callback));
+        self
+    }
+
+    /// Eagerly create and validate `num_clients` clients of `pool`,
+    /// to avoid paying the connection latency on the first requests.
+    ///
+    /// The clients are checked out concurrently, pinged, then returned to the pool.
+    /// `num_clients` should not exceed the `max_size` of the pool,
+    /// otherwise the checkouts wait until the connection timeout of the pool.
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::{client::PooledClientManager, Result};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let manager = PooledClientManager::new("127.0.0.1:6379")?;
+    ///     let pool = rustis::bb8::Pool::builder()
+    ///         .max_size(10)
+    ///         .build(manager)
+    ///         .await?;
+    ///
+    ///     // at application boot, before serving traffic
+    ///     PooledClientManager::warmup(&pool, 10).await?;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn warmup(pool: &Pool<PooledClientManager>, num_clients: usize) -> Result<()> {
+        let clients = try_join_all((0..num_clients).map(|_| async {
+            let client = pool.get().await.map_err(|e| match e {
+                RunError::User(e) => e,
+                RunError::TimedOut => {
+                    Error::Timeout("Timeout while checking out a client from the pool".to_owned())
+                }
+            })?;
+            client.ping::<String>(Default::default()).await?;
+            Ok::<_, Error>(client)
+        }))
+        .await?;
+
+        // clients are returned to the pool
+        drop(clients);
+
+        Ok(())
+    }
+
+    fn report(&self, event: PooledClientEvent) {
+        if let Some(metrics) = &self.metrics {
+            metrics(event);
+        }
+    }
 }
 
 impl ManageConnection for PooledClientManager {
@@ -28,7 +194,33 @@ impl ManageConnection for PooledClientManager {
         Self: 'a,
     {
         let config = self.config.clone();
-        Box::pin(async move { Client::connect(config).await })
+        Box::pin(async move {
+            let client = match Client::connect(config).await {
+                Ok(client) => client,
+                Err(e) => {
+                    self.report(PooledClientEvent::ConnectionFailed(&e));
+                    return Err(e);
+                }
+            };
+
+            for command in &self.init_commands {
+                if let Err(e) = client.send(command.clone(), None).await {
+                    self.report(PooledClientEvent::ConnectionFailed(&e));
+                    return Err(e);
+                }
+            }
+
+            {
+                let mut client_state = client.get_client_state_mut();
+                let state =
+                    client_state.get_state_mut::<PooledClientState>(POOLED_CLIENT_STATE_KEY)?;
+                state.reconnect_receiver = Some(client.on_reconnect());
+                state.metrics.clone_from(&self.metrics);
+            }
+
+            self.report(PooledClientEvent::Connected);
+            Ok(client)
+        })
     }
 
     fn is_valid<'s, 'c, 'a>(&'s self, client: &'c mut Client) -> Future<'a, ()>
@@ -38,7 +230,29 @@ impl ManageConnection for PooledClientManager {
         Self: 'a,
     {
         Box::pin(async move {
-            client.ping(Default::default()).await?;
+            let ping_latency = if self.ping_on_checkout {
+                let start = Instant::now();
+                if let Err(e) = client.ping::<String>(Default::default()).await {
+                    self.report(PooledClientEvent::ValidationFailed(&e));
+                    return Err(e);
+                }
+                Some(start.elapsed())
+            } else {
+                None
+            };
+
+            if self.metrics.is_some() {
+                let reconnections = client
+                    .get_client_state_mut()
+                    .get_state_mut::<PooledClientState>(POOLED_CLIENT_STATE_KEY)?
+                    .reconnections();
+                self.report(PooledClientEvent::Validated {
+                    reconnections,
+                    ping_latency,
+                    protocol: client.protocol(),
+                });
+            }
+
             Ok(())
         })
     }
@@ -47,3 +261,37 @@ impl ManageConnection for PooledClientManager {
         false
     }
 }
+
+/// State attached to each pooled client, dropped with the client
+#[derive(Default)]
+struct PooledClientState {
+    reconnect_receiver: Option<ReconnectReceiver>,
+    num_reconnections: usize,
+    metrics: Option<Arc<MetricsCallback>>,
+}
+
+impl PooledClientState {
+    fn reconnections(&mut self) -> usize {
+        if let Some(reconnect_receiver) = &mut self.reconnect_receiver {
+            loop {
+                match reconnect_receiver.try_recv() {
+                    Ok(()) => self.num_reconnections += 1,
+                    Err(TryRecvError::Lagged(n)) => self.num_reconnections += n as usize,
+                    Err(TryRecvError::Empty) | Err(TryRecvError::Closed) => break,
+                }
+            }
+        }
+
+        self.num_reconnections
+    }
+}
+
+impl Drop for PooledClientState {
+    fn drop(&mut self) {
+        if let Some(metrics) = self.metrics.take() {
+            metrics(PooledClientEvent::Dropped {
+                reconnections: self.reconnections(),
+            });
+        }
+    }
+}
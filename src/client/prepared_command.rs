@@ -25,6 +25,12 @@ where
     pub custom_converter: Option<Box<CustomConverter<'a, R>>>,
     /// Flag to retry sending the command on network error.
     pub retry_on_error: Option<bool>,
+    /// Maximum number of attempts to send the command, overriding
+    /// [`Config::max_command_attempts`](crate::client::Config::max_command_attempts).
+    pub max_attempts: Option<usize>,
+    /// Flag to apply [`Config::compression`](crate::client::Config::compression)
+    /// to the values of the command and its response.
+    pub(crate) compress_values: bool,
 }
 
 impl<'a, E, R> PreparedCommand<'a, E, R>
@@ -40,6 +46,8 @@ where
             command,
             custom_converter: None,
             retry_on_error: None,
+            max_attempts: None,
+            compress_values: false,
         }
     }
 
@@ -57,12 +65,67 @@ where
         self
     }
 
+    /// Set the retry policy of this command on network error,
+    /// overriding [`Config::retry_on_error`](crate::client::Config::retry_on_error)
+    /// and [`Config::max_command_attempts`](crate::client::Config::max_command_attempts).
+    ///
+    /// See [`RetryPolicy`]
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        match policy {
+            RetryPolicy::Never => {
+                self.retry_on_error = Some(false);
+                self.max_attempts = None;
+            }
+            RetryPolicy::OnError { max } => {
+                self.retry_on_error = Some(true);
+                self.max_attempts = Some(max);
+            }
+            RetryPolicy::OnReconnect => {
+                self.retry_on_error = Some(true);
+                self.max_attempts = None;
+            }
+        }
+        self
+    }
+
+    /// Opt in the [`Config::compression`](crate::client::Config::compression) of the values
+    pub(crate) fn compress_values(mut self) -> Self {
+        self.compress_values = true;
+        self
+    }
+
     /// Get a reference to the command to send
     pub fn command(&self) -> &Command {
         &self.command
     }
 }
 
+/// Retry policy of a command on network error
+///
+/// When the connection to the server is lost, a command already sent may have been
+/// executed by the server before its reply could be read:
+/// retrying it on the new connection may execute it twice.
+/// This is harmless for idempotent commands (`GET`, `SET`, `HSET`...),
+/// but non-idempotent commands (`INCR`, `LPUSH`, `XADD`...) should opt out
+/// with [`RetryPolicy::Never`] when retries are enabled by
+/// [`Config::retry_on_error`](crate::client::Config::retry_on_error).
+///
+/// See [`PreparedCommand::retry`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryPolicy {
+    /// Never retry the command: on network error, the command fails.
+    Never,
+    /// Retry the command after reconnection,
+    /// up to `max` attempts in total.
+    OnError {
+        /// Maximum number of attempts to send the command
+        max: usize,
+    },
+    /// Retry the command after reconnection, up to
+    /// [`Config::max_command_attempts`](crate::client::Config::max_command_attempts) attempts in total.
+    OnReconnect,
+}
+
 /// Shortcut function to creating a [`PreparedCommand`](PreparedCommand).
 pub(crate) fn prepare_command<'a, E, R: Response>(executor: E, command: Command) -> PreparedCommand<'a, E, R> {
     PreparedCommand::new(executor, command)
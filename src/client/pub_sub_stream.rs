@@ -1,13 +1,13 @@
 use crate::{
-    client::{Client, ClientPreparedCommand},
+    client::{unprefix_channel, unprefix_message, Client, ClientPreparedCommand},
     commands::InternalPubSubCommands,
     network::PubSubSender,
-    resp::{ByteBufSeed, CommandArgs, SingleArg, SingleArgCollection},
+    resp::{ByteBufSeed, CommandArgs, SingleArg, SingleArgCollection, Value},
     PubSubReceiver, Result,
 };
 use futures_util::{Stream, StreamExt};
 use serde::{
-    de::{self, Visitor},
+    de::{self, DeserializeOwned, Visitor},
     Deserialize,
 };
 use std::{
@@ -24,6 +24,43 @@ pub struct PubSubMessage {
     pub payload: Vec<u8>,
 }
 
+impl PubSubMessage {
+    /// Raw bytes of the pattern, empty if the message was not received through a pattern subscription
+    #[inline]
+    pub fn pattern_bytes(&self) -> &[u8] {
+        &self.pattern
+    }
+
+    /// Raw bytes of the channel
+    #[inline]
+    pub fn channel_bytes(&self) -> &[u8] {
+        &self.channel
+    }
+
+    /// Raw bytes of the payload, without any UTF-8 validation
+    #[inline]
+    pub fn payload_bytes(&self) -> &[u8] {
+        &self.payload
+    }
+
+    /// Convert the payload to a Rust type `T`,
+    /// e.g. `String`, a number or [`BulkString`](crate::resp::BulkString) for binary payloads
+    ///
+    /// # Errors
+    /// Any parsing error ([`Error::Client`](crate::Error::Client)), e.g. an invalid UTF-8 payload read as a `String`
+    pub fn get_payload<T: DeserializeOwned>(&self) -> Result<T> {
+        Value::BulkString(self.payload.clone()).into()
+    }
+
+    /// Convert the channel to a Rust type `T`
+    ///
+    /// # Errors
+    /// Any parsing error ([`Error::Client`](crate::Error::Client)), e.g. an invalid UTF-8 channel read as a `String`
+    pub fn get_channel<T: DeserializeOwned>(&self) -> Result<T> {
+        Value::BulkString(self.channel.clone()).into()
+    }
+}
+
 impl<'de> Deserialize<'de> for PubSubMessage {
     #[inline]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
@@ -84,6 +121,112 @@ impl<'de> Deserialize<'de> for PubSubMessage {
     }
 }
 
+/// Pub/Sub event that can be streamed from [`PubSubEventStream`](PubSubEventStream)
+#[derive(Debug)]
+pub enum PubSubEvent {
+    /// Message received on a subscribed channel, pattern or shard channel
+    Message(PubSubMessage),
+    /// Subscription to a channel, pattern or shard channel confirmed by the server,
+    /// messages published from now on are received
+    Subscribed {
+        /// Channel, pattern or shard channel
+        channel: Vec<u8>,
+        /// Number of channels and patterns the connection is currently subscribed to
+        count: usize,
+    },
+    /// Unsubscription from a channel, pattern or shard channel confirmed by the server
+    Unsubscribed {
+        /// Channel, pattern or shard channel
+        channel: Vec<u8>,
+        /// Number of channels and patterns the connection is currently subscribed to
+        count: usize,
+    },
+}
+
+impl<'de> Deserialize<'de> for PubSubEvent {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct PubSubEventVisitor;
+
+        impl<'de> Visitor<'de> for PubSubEventVisitor {
+            type Value = PubSubEvent;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("PubSubEvent")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> std::result::Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let Some(kind) = seq.next_element::<&str>()? else {
+                    return Err(de::Error::invalid_length(0, &"more elements in sequence"));
+                };
+
+                let Ok(Some(channel_or_pattern)) = seq.next_element_seed(ByteBufSeed) else {
+                    return Err(de::Error::invalid_length(1, &"more elements in sequence"));
+                };
+
+                match kind {
+                    "subscribe" | "psubscribe" | "ssubscribe" | "unsubscribe" | "punsubscribe"
+                    | "sunsubscribe" => {
+                        let Some(count) = seq.next_element::<usize>()? else {
+                            return Err(de::Error::invalid_length(2, &"more elements in sequence"));
+                        };
+
+                        if kind.ends_with("unsubscribe") {
+                            Ok(PubSubEvent::Unsubscribed {
+                                channel: channel_or_pattern,
+                                count,
+                            })
+                        } else {
+                            Ok(PubSubEvent::Subscribed {
+                                channel: channel_or_pattern,
+                                count,
+                            })
+                        }
+                    }
+                    "message" | "smessage" => {
+                        let Ok(Some(payload)) = seq.next_element_seed(ByteBufSeed) else {
+                            return Err(de::Error::invalid_length(2, &"more elements in sequence"));
+                        };
+
+                        Ok(PubSubEvent::Message(PubSubMessage {
+                            pattern: vec![],
+                            channel: channel_or_pattern,
+                            payload,
+                        }))
+                    }
+                    "pmessage" => {
+                        let Ok(Some(channel)) = seq.next_element_seed(ByteBufSeed) else {
+                            return Err(de::Error::invalid_length(2, &"more elements in sequence"));
+                        };
+
+                        let Ok(Some(payload)) = seq.next_element_seed(ByteBufSeed) else {
+                            return Err(de::Error::invalid_length(3, &"more elements in sequence"));
+                        };
+
+                        Ok(PubSubEvent::Message(PubSubMessage {
+                            pattern: channel_or_pattern,
+                            channel,
+                            payload,
+                        }))
+                    }
+                    _ => Err(de::Error::invalid_value(
+                        de::Unexpected::Str(kind),
+                        &"message, smessage, pmessage or a subscription confirmation",
+                    )),
+                }
+            }
+        }
+
+        deserializer.deserialize_seq(PubSubEventVisitor)
+    }
+}
+
 /// Stream to get messages from the channels or patterns [`subscribed`](https://redis.io/docs/manual/pubsub/) to
 ///
 /// # Example
@@ -119,6 +262,7 @@ impl<'de> Deserialize<'de> for PubSubMessage {
 /// ```
 pub struct PubSubStream {
     closed: bool,
+    with_events: bool,
     channels: CommandArgs,
     patterns: CommandArgs,
     shardchannels: CommandArgs,
@@ -136,6 +280,7 @@ impl PubSubStream {
     ) -> Self {
         Self {
             closed: false,
+            with_events: false,
             channels,
             patterns: CommandArgs::default(),
             shardchannels: CommandArgs::default(),
@@ -153,6 +298,7 @@ impl PubSubStream {
     ) -> Self {
         Self {
             closed: false,
+            with_events: false,
             channels: CommandArgs::default(),
             patterns,
             shardchannels: CommandArgs::default(),
@@ -170,6 +316,7 @@ impl PubSubStream {
     ) -> Self {
         Self {
             closed: false,
+            with_events: false,
             channels: CommandArgs::default(),
             patterns: CommandArgs::default(),
             shardchannels,
@@ -180,15 +327,23 @@ impl PubSubStream {
     }
 
     /// Subscribe to additional channels
+    ///
+    /// Channels to which the stream is already subscribed are skipped,
+    /// no command is sent if all the channels are already subscribed.
     pub async fn subscribe<C, CC>(&mut self, channels: CC) -> Result<()>
     where
         C: SingleArg + Send,
         CC: SingleArgCollection<C>,
     {
-        let channels = CommandArgs::default().arg(channels).build();
+        let mut channels = CommandArgs::default().arg(channels).build();
+        self.client.prefix_channels(&mut channels);
+        let channels = new_subscriptions(&self.channels, &channels);
+        if channels.is_empty() {
+            return Ok(());
+        }
 
         self.client
-            .subscribe_from_pub_sub_sender(&channels, &self.sender)
+            .subscribe_from_pub_sub_sender(&channels, &self.sender, self.with_events)
             .await?;
 
         let mut existing_channels = CommandArgs::default();
@@ -199,15 +354,23 @@ impl PubSubStream {
     }
 
     /// Subscribe to additional patterns
+    ///
+    /// Patterns to which the stream is already subscribed are skipped,
+    /// no command is sent if all the patterns are already subscribed.
     pub async fn psubscribe<P, PP>(&mut self, patterns: PP) -> Result<()>
     where
         P: SingleArg + Send,
         PP: SingleArgCollection<P>,
     {
-        let patterns = CommandArgs::default().arg(patterns).build();
+        let mut patterns = CommandArgs::default().arg(patterns).build();
+        self.client.prefix_channels(&mut patterns);
+        let patterns = new_subscriptions(&self.patterns, &patterns);
+        if patterns.is_empty() {
+            return Ok(());
+        }
 
         self.client
-            .psubscribe_from_pub_sub_sender(&patterns, &self.sender)
+            .psubscribe_from_pub_sub_sender(&patterns, &self.sender, self.with_events)
             .await?;
 
         let mut existing_patterns = CommandArgs::default();
@@ -218,15 +381,22 @@ impl PubSubStream {
     }
 
     /// Subscribe to additional shardchannels
+    ///
+    /// Shardchannels to which the stream is already subscribed are skipped,
+    /// no command is sent if all the shardchannels are already subscribed.
     pub async fn ssubscribe<C, CC>(&mut self, shardchannels: CC) -> Result<()>
     where
         C: SingleArg + Send,
         CC: SingleArgCollection<C>,
     {
         let shardchannels = CommandArgs::default().arg(shardchannels).build();
+        let shardchannels = new_subscriptions(&self.shardchannels, &shardchannels);
+        if shardchannels.is_empty() {
+            return Ok(());
+        }
 
         self.client
-            .ssubscribe_from_pub_sub_sender(&shardchannels, &self.sender)
+            .ssubscribe_from_pub_sub_sender(&shardchannels, &self.sender, self.with_events)
             .await?;
 
         let mut existing_shardchannels = CommandArgs::default();
@@ -236,6 +406,26 @@ impl PubSubStream {
         Ok(())
     }
 
+    /// Channels to which the stream is subscribed
+    ///
+    /// Keyspace notification channels include the [`key_prefix`](crate::client::Config::key_prefix)
+    /// of the client, as sent to the server.
+    pub fn subscribed_channels(&self) -> Vec<String> {
+        to_strings(&self.channels)
+    }
+
+    /// Patterns to which the stream is subscribed
+    ///
+    /// See [`subscribed_channels`](PubSubStream::subscribed_channels)
+    pub fn subscribed_patterns(&self) -> Vec<String> {
+        to_strings(&self.patterns)
+    }
+
+    /// Shardchannels to which the stream is subscribed
+    pub fn subscribed_shardchannels(&self) -> Vec<String> {
+        to_strings(&self.shardchannels)
+    }
+
     /// Close the stream by cancelling all subscriptions
     /// Calling `close` allows to wait for all the unsubscriptions.
     /// `drop` will achieve the same process but silently in background
@@ -264,6 +454,23 @@ impl PubSubStream {
     }
 }
 
+/// Channels or patterns of `args` not found in `existing`, without duplicates
+pub(crate) fn new_subscriptions(existing: &CommandArgs, args: &CommandArgs) -> CommandArgs {
+    let mut new_args = CommandArgs::default();
+    for arg in args {
+        if !existing.into_iter().any(|e| e == arg) && !(&new_args).into_iter().any(|a| a == arg) {
+            new_args.arg(arg);
+        }
+    }
+    new_args
+}
+
+fn to_strings(args: &CommandArgs) -> Vec<String> {
+    args.into_iter()
+        .map(|arg| String::from_utf8_lossy(arg).into_owned())
+        .collect()
+}
+
 impl Stream for PubSubStream {
     type Item = Result<PubSubMessage>;
 
@@ -271,11 +478,27 @@ impl Stream for PubSubStream {
         if self.closed {
             Poll::Ready(None)
         } else {
-            match self.get_mut().receiver.poll_next_unpin(cx) {
-                Poll::Ready(Some(Ok(message))) => Poll::Ready(Some(message.to())),
-                Poll::Ready(None) => Poll::Ready(None),
-                Poll::Ready(Some(Err(e))) => Poll::Ready(Some(Err(e))),
-                Poll::Pending => Poll::Pending,
+            let this = self.get_mut();
+            loop {
+                match this.receiver.poll_next_unpin(cx) {
+                    Poll::Ready(Some(Ok(message))) => {
+                        let Some(key_prefix) = this.client.key_prefix() else {
+                            return Poll::Ready(Some(message.to()));
+                        };
+
+                        match message.to::<PubSubMessage>() {
+                            Ok(mut message) => {
+                                if unprefix_message(key_prefix, &mut message) {
+                                    return Poll::Ready(Some(Ok(message)));
+                                }
+                            }
+                            Err(e) => return Poll::Ready(Some(Err(e))),
+                        }
+                    }
+                    Poll::Ready(None) => return Poll::Ready(None),
+                    Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                    Poll::Pending => return Poll::Pending,
+                }
             }
         }
     }
@@ -307,3 +530,143 @@ impl Drop for PubSubStream {
         }
     }
 }
+
+/// Stream of the [`PubSubEvent`]s of the channels or patterns [`subscribed`](https://redis.io/docs/manual/pubsub/) to
+///
+/// Contrary to [`PubSubStream`], subscription and unsubscription confirmations are streamed
+/// along with the messages: once a [`PubSubEvent::Subscribed`] event is received,
+/// messages published on its channel are guaranteed to be received.
+///
+/// # Example
+/// ```
+/// use rustis::{
+///     client::{Client, PubSubEvent},
+///     commands::PubSubCommands,
+///     Result,
+/// };
+/// use futures_util::StreamExt;
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let pub_sub_client = Client::connect("127.0.0.1:6379").await?;
+///     let regular_client = Client::connect("127.0.0.1:6379").await?;
+///
+///     let mut pub_sub_stream = pub_sub_client.subscribe_with_events("mychannel").await?;
+///
+///     let event = pub_sub_stream.next().await.unwrap()?;
+///     assert!(matches!(event, PubSubEvent::Subscribed { count: 1, .. }));
+///
+///     regular_client.publish("mychannel", "mymessage").await?;
+///
+///     let event = pub_sub_stream.next().await.unwrap()?;
+///     let PubSubEvent::Message(message) = event else { panic!() };
+///     assert_eq!(b"mymessage".to_vec(), message.payload);
+///
+///     pub_sub_stream.close().await?;
+///
+///     Ok(())
+/// }
+/// ```
+pub struct PubSubEventStream {
+    inner: PubSubStream,
+}
+
+impl PubSubEventStream {
+    pub(crate) fn new(mut inner: PubSubStream) -> Self {
+        inner.with_events = true;
+        Self { inner }
+    }
+
+    /// Subscribe to additional channels
+    pub async fn subscribe<C, CC>(&mut self, channels: CC) -> Result<()>
+    where
+        C: SingleArg + Send,
+        CC: SingleArgCollection<C>,
+    {
+        self.inner.subscribe(channels).await
+    }
+
+    /// Subscribe to additional patterns
+    pub async fn psubscribe<P, PP>(&mut self, patterns: PP) -> Result<()>
+    where
+        P: SingleArg + Send,
+        PP: SingleArgCollection<P>,
+    {
+        self.inner.psubscribe(patterns).await
+    }
+
+    /// Subscribe to additional shardchannels
+    pub async fn ssubscribe<C, CC>(&mut self, shardchannels: CC) -> Result<()>
+    where
+        C: SingleArg + Send,
+        CC: SingleArgCollection<C>,
+    {
+        self.inner.ssubscribe(shardchannels).await
+    }
+
+    /// See [`PubSubStream::subscribed_channels`]
+    pub fn subscribed_channels(&self) -> Vec<String> {
+        self.inner.subscribed_channels()
+    }
+
+    /// See [`PubSubStream::subscribed_patterns`]
+    pub fn subscribed_patterns(&self) -> Vec<String> {
+        self.inner.subscribed_patterns()
+    }
+
+    /// See [`PubSubStream::subscribed_shardchannels`]
+    pub fn subscribed_shardchannels(&self) -> Vec<String> {
+        self.inner.subscribed_shardchannels()
+    }
+
+    /// Close the stream by cancelling all subscriptions
+    ///
+    /// See [`PubSubStream::close`]
+    pub async fn close(self) -> Result<()> {
+        self.inner.close().await
+    }
+}
+
+impl Stream for PubSubEventStream {
+    type Item = Result<PubSubEvent>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = &mut self.get_mut().inner;
+        if this.closed {
+            return Poll::Ready(None);
+        }
+
+        loop {
+            match this.receiver.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(resp_buf))) => match resp_buf.to::<PubSubEvent>() {
+                    Ok(PubSubEvent::Message(mut message)) => {
+                        let Some(key_prefix) = this.client.key_prefix() else {
+                            return Poll::Ready(Some(Ok(PubSubEvent::Message(message))));
+                        };
+
+                        if unprefix_message(key_prefix, &mut message) {
+                            return Poll::Ready(Some(Ok(PubSubEvent::Message(message))));
+                        }
+                    }
+                    Ok(PubSubEvent::Subscribed { mut channel, count }) => {
+                        if let Some(key_prefix) = this.client.key_prefix() {
+                            unprefix_channel(key_prefix, &mut channel);
+                        }
+                        return Poll::Ready(Some(Ok(PubSubEvent::Subscribed { channel, count })));
+                    }
+                    Ok(PubSubEvent::Unsubscribed { mut channel, count }) => {
+                        if let Some(key_prefix) = this.client.key_prefix() {
+                            unprefix_channel(key_prefix, &mut channel);
+                        }
+                        return Poll::Ready(Some(Ok(PubSubEvent::Unsubscribed { channel, count })));
+                    }
+                    result => return Poll::Ready(Some(result)),
+                },
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
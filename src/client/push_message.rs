@@ -0,0 +1,52 @@
+use crate::resp::Value;
+use serde::{
+    de::{self, Visitor},
+    Deserialize,
+};
+use std::fmt;
+
+/// [RESP3](https://github.com/antirez/RESP3/blob/master/spec.md#push-type) push message
+/// received out of band by a handler registered with [`Client::on_push`](crate::client::Client::on_push)
+#[derive(Debug)]
+pub struct PushMessage {
+    /// kind of push message (e.g. `invalidate`, `message`, `pmessage`, `smessage`)
+    pub kind: String,
+    /// elements following the kind in the push message
+    pub data: Vec<Value>,
+}
+
+impl<'de> Deserialize<'de> for PushMessage {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct PushMessageVisitor;
+
+        impl<'de> Visitor<'de> for PushMessageVisitor {
+            type Value = PushMessage;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("PushMessage")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> std::result::Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let Some(kind) = seq.next_element::<String>()? else {
+                    return Err(de::Error::invalid_length(0, &"more elements in sequence"));
+                };
+
+                let mut data = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+                while let Some(value) = seq.next_element::<Value>()? {
+                    data.push(value);
+                }
+
+                Ok(PushMessage { kind, data })
+            }
+        }
+
+        deserializer.deserialize_seq(PushMessageVisitor)
+    }
+}
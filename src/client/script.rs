@@ -0,0 +1,108 @@
+use crate::{
+    client::{prepare_command, Client},
+    commands::ScriptingCommands,
+    resp::{cmd, CommandArgs, PrimitiveResponse, SingleArg, SingleArgCollection},
+    Error, RedisError, RedisErrorKind, Result,
+};
+use serde::de::DeserializeOwned;
+use std::sync::OnceLock;
+
+/// A Lua script called by its SHA1 digest with [`EVALSHA`](https://redis.io/commands/evalsha/),
+/// falling back to [`EVAL`](https://redis.io/commands/eval/)
+/// when the server does not know the script (`NOSCRIPT` error, e.g. after a `SCRIPT FLUSH`).
+///
+/// The SHA1 digest is computed client-side, so the script does not need to be loaded
+/// before being called: the fallback to `EVAL` also adds the script to the scripts cache
+/// of the server, so the following calls are served by `EVALSHA`.
+///
+/// To manage several named scripts, see [`ScriptCache`](crate::client::ScriptCache).
+///
+/// # Example
+/// ```
+/// use rustis::{client::{Client, Script}, Result};
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///
+///     let script = Script::new("return ARGV[1]");
+///     let value: String = script.eval(&client, Vec::<String>::new(), "hello").await?;
+///     assert_eq!("hello", value);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Script {
+    source: String,
+    sha1: OnceLock<String>,
+}
+
+impl Script {
+    /// Creates a new script from its Lua source
+    #[must_use]
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+            sha1: OnceLock::new(),
+        }
+    }
+
+    /// Lua source of the script
+    #[must_use]
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Hexadecimal SHA1 digest of the script, as computed by the server on `SCRIPT LOAD`
+    #[must_use]
+    pub fn sha1(&self) -> &str {
+        self.sha1
+            .get_or_init(|| sha1_smol::Sha1::from(&self.source).digest().to_string())
+    }
+
+    /// Loads the script into the scripts cache of the server
+    /// with [`SCRIPT LOAD`](https://redis.io/commands/script-load/).
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs while loading the script
+    pub async fn load(&self, client: &Client) -> Result<()> {
+        let _sha1: String = client.script_load(&self.source).await?;
+        Ok(())
+    }
+
+    /// Calls the script with [`EVALSHA`](https://redis.io/commands/evalsha/),
+    /// or with [`EVAL`](https://redis.io/commands/eval/) if the server does not know it yet.
+    ///
+    /// # Return
+    /// The return value of the script
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs while calling the script
+    pub async fn eval<R, K, KK, A, AA>(&self, client: &Client, keys: KK, args: AA) -> Result<R>
+    where
+        R: PrimitiveResponse + DeserializeOwned + Send,
+        K: SingleArg,
+        KK: SingleArgCollection<K>,
+        A: SingleArg,
+        AA: SingleArgCollection<A>,
+    {
+        let params = CommandArgs::default()
+            .arg(keys.num_args())
+            .arg(keys)
+            .arg(args)
+            .build();
+
+        let result: Result<R> =
+            prepare_command(client, cmd("EVALSHA").arg(self.sha1()).arg(&params)).await;
+
+        match result {
+            Err(Error::Redis(RedisError {
+                kind: RedisErrorKind::NoScript,
+                ..
+            })) => prepare_command(client, cmd("EVAL").arg(&self.source).arg(params)).await,
+            result => result,
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use crate::{
+    client::{Client, Script},
+    network::ReconnectReceiver,
+    resp::{PrimitiveResponse, SingleArg, SingleArgCollection},
+    Error, Result,
+};
+use serde::de::DeserializeOwned;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Mutex,
+};
+use tokio::sync::broadcast::error::TryRecvError;
+
+/// Cache of named Lua scripts, loaded once with [`SCRIPT LOAD`](https://redis.io/commands/script-load/)
+/// and called by their SHA1 digest with [`EVALSHA`](https://redis.io/commands/evalsha/).
+///
+/// Scripts are reloaded automatically:
+/// * after the client reconnects, before the next call,
+/// * when the server replies with a `NOSCRIPT` error (e.g. after a `SCRIPT FLUSH`),
+///   by falling back to [`EVAL`](https://redis.io/commands/eval/) like [`Script::eval`](Script::eval).
+///
+/// # Example
+/// ```
+/// use rustis::{client::{Client, ScriptCache}, Result};
+///
+/// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+/// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+/// async fn main() -> Result<()> {
+///     let client = Client::connect("127.0.0.1:6379").await?;
+///
+///     let scripts = ScriptCache::new()
+///         .script("echo", "return ARGV[1]")
+///         .script("get", "return redis.call('GET', KEYS[1])");
+///     scripts.load(&client).await?;
+///
+///     let value: String = scripts.call(&client, "echo", Vec::<String>::new(), "hello").await?;
+///     assert_eq!("hello", value);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Default)]
+pub struct ScriptCache {
+    scripts: HashMap<String, Script>,
+    loaded: Mutex<HashSet<String>>,
+    reconnect_receiver: Mutex<Option<ReconnectReceiver>>,
+}
+
+impl ScriptCache {
+    /// Creates an empty script cache
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a Lua script under the given name
+    #[must_use]
+    pub fn script(mut self, name: impl Into<String>, source: impl Into<String>) -> Self {
+        self.scripts.insert(name.into(), Script::new(source));
+        self
+    }
+
+    /// SHA1 digest of a registered script
+    pub fn sha1(&self, name: &str) -> Option<String> {
+        self.scripts
+            .get(name)
+            .map(|script| script.sha1().to_owned())
+    }
+
+    /// Loads all the registered scripts into the scripts cache of the server.
+    ///
+    /// The client is then watched for reconnections:
+    /// scripts are reloaded before the first call following a reconnection.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs while loading the scripts
+    pub async fn load(&self, client: &Client) -> Result<()> {
+        *self.reconnect_receiver.lock().unwrap() = Some(client.on_reconnect());
+
+        for (name, script) in &self.scripts {
+            self.load_script(client, name, script).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Calls a registered script with [`EVALSHA`](https://redis.io/commands/evalsha/)
+    ///
+    /// The script is loaded first if it has not been loaded yet,
+    /// and reloaded if the server does not know it anymore (`NOSCRIPT` error).
+    ///
+    /// # Return
+    /// The return value of the script
+    ///
+    /// # Errors
+    /// [`Error::Client`](crate::Error::Client) if no script has been registered under this name,
+    /// or any Redis driver [`Error`](crate::Error) that occurs while calling the script
+    pub async fn call<R, K, KK, A, AA>(
+        &self,
+        client: &Client,
+        name: &str,
+        keys: KK,
+        args: AA,
+    ) -> Result<R>
+    where
+        R: PrimitiveResponse + DeserializeOwned + Send,
+        K: SingleArg,
+        KK: SingleArgCollection<K>,
+        A: SingleArg,
+        AA: SingleArgCollection<A>,
+    {
+        let Some(script) = self.scripts.get(name) else {
+            return Err(Error::Client(format!("Unknown script `{name}`")));
+        };
+
+        if self.has_reconnected() {
+            self.loaded.lock().unwrap().clear();
+        }
+
+        if !self.loaded.lock().unwrap().contains(name) {
+            self.load_script(client, name, script).await?;
+        }
+
+        script.eval(client, keys, args).await
+    }
+
+    async fn load_script(&self, client: &Client, name: &str, script: &Script) -> Result<()> {
+        script.load(client).await?;
+        self.loaded.lock().unwrap().insert(name.to_owned());
+        Ok(())
+    }
+
+    fn has_reconnected(&self) -> bool {
+        let mut reconnect_receiver = self.reconnect_receiver.lock().unwrap();
+        let Some(reconnect_receiver) = reconnect_receiver.as_mut() else {
+            return false;
+        };
+
+        let mut reconnected = false;
+        loop {
+            match reconnect_receiver.try_recv() {
+                Ok(()) | Err(TryRecvError::Lagged(_)) => reconnected = true,
+                Err(TryRecvError::Empty) | Err(TryRecvError::Closed) => return reconnected,
+            }
+        }
+    }
+}
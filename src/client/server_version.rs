@@ -0,0 +1,114 @@
+use crate::Error;
+use std::fmt;
+
+/// Version of a Redis server, as returned by [`Client::server_version`](crate::client::Client::server_version)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    /// Major version, e.g. `7` for `7.2.4`
+    pub major: u8,
+    /// Minor version, e.g. `2` for `7.2.4`
+    pub minor: u8,
+    /// Revision, e.g. `4` for `7.2.4`
+    pub revision: u8,
+}
+
+impl Version {
+    /// Builds a version from its components
+    #[must_use]
+    pub const fn new(major: u8, minor: u8, revision: u8) -> Self {
+        Self {
+            major,
+            minor,
+            revision,
+        }
+    }
+}
+
+impl TryFrom<&str> for Version {
+    type Error = Error;
+
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        let mut split = value.split('.');
+
+        let (Some(major), Some(minor), Some(revision), None) =
+            (split.next(), split.next(), split.next(), split.next())
+        else {
+            return Err(Error::Client(
+                "Cannot parse Redis server version".to_owned(),
+            ));
+        };
+
+        let (Some(major), Some(minor), Some(revision)) = (
+            atoi::atoi(major.as_bytes()),
+            atoi::atoi(minor.as_bytes()),
+            atoi::atoi(revision.as_bytes()),
+        ) else {
+            return Err(Error::Client(
+                "Cannot parse Redis server version".to_owned(),
+            ));
+        };
+
+        Ok(Version {
+            major,
+            minor,
+            revision,
+        })
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.revision)
+    }
+}
+
+/// Server features which depend on the version of the Redis server
+///
+/// See [`Client::supports`](crate::client::Client::supports)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ServerFeature {
+    /// [Functions](https://redis.io/docs/manual/programmability/functions-intro/), since Redis 7.0
+    Functions,
+    /// [Sharded pub/sub](https://redis.io/docs/manual/pubsub/#sharded-pubsub), since Redis 7.0
+    ShardedPubSub,
+    /// [`CLUSTER SHARDS`](https://redis.io/commands/cluster-shards/), since Redis 7.0
+    ClusterShards,
+    /// [`CLIENT SETINFO`](https://redis.io/commands/client-setinfo/), since Redis 7.2
+    ClientSetInfo,
+    /// Expiration of hash fields, e.g. [`hexpire`](crate::commands::HashCommands::hexpire), since Redis 7.4
+    HashFieldExpiration,
+}
+
+impl ServerFeature {
+    /// Minimum version of the Redis server which supports the feature
+    #[must_use]
+    pub const fn min_version(self) -> Version {
+        match self {
+            ServerFeature::Functions
+            | ServerFeature::ShardedPubSub
+            | ServerFeature::ClusterShards => Version::new(7, 0, 0),
+            ServerFeature::ClientSetInfo => Version::new(7, 2, 0),
+            ServerFeature::HashFieldExpiration => Version::new(7, 4, 0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ServerFeature, Version};
+
+    #[test]
+    fn version() {
+        let version: Version = "7.0.0".try_into().unwrap();
+        assert_eq!((7, 0, 0), (version.major, version.minor, version.revision));
+        assert_eq!("7.0.0", version.to_string());
+    }
+
+    #[test]
+    fn min_version() {
+        let version: Version = "7.2.4".try_into().unwrap();
+        assert!(version >= ServerFeature::Functions.min_version());
+        assert!(version >= ServerFeature::ClientSetInfo.min_version());
+        assert!(version < ServerFeature::HashFieldExpiration.min_version());
+    }
+}
@@ -0,0 +1,206 @@
+#[cfg(feature = "debug-commands")]
+use crate::commands::DebugCommands;
+#[cfg(feature = "redis-graph")]
+use crate::commands::GraphCommands;
+#[cfg(feature = "redis-json")]
+use crate::commands::JsonCommands;
+#[cfg(feature = "redis-search")]
+use crate::commands::SearchCommands;
+#[cfg(feature = "redis-time-series")]
+use crate::commands::TimeSeriesCommands;
+#[cfg(feature = "redis-bloom")]
+use crate::commands::{
+    BloomCommands, CountMinSketchCommands, CuckooCommands, TDigestCommands, TopKCommands,
+};
+use crate::{
+    client::{Client, ClientPreparedCommand, IntoConfig, PreparedCommand},
+    commands::{
+        BitmapCommands, ClusterCommands, CommandCommands, ConnectionCommands, GenericCommands,
+        GeoCommands, HashCommands, HyperLogLogCommands, ListCommands, ScriptingCommands,
+        SentinelCommands, ServerCommands, SetCommands, SortedSetCommands, StreamCommands,
+        StringCommands, TransactionCommands,
+    },
+    resp::{Command, RespBuf, Response},
+    Error, Result,
+};
+use serde::de::DeserializeOwned;
+use std::future::{Future, IntoFuture};
+use tokio::runtime::Runtime;
+
+/// Blocking facade over a [`Client`](Client), for non-async callers
+///
+/// A `SyncClient` owns its own [tokio](https://tokio.rs/) runtime and blocks the calling thread
+/// until each command has been executed by the underlying async [`Client`](Client).
+///
+/// All the command traits available for [`Client`](Client) are implemented for `SyncClient`,
+/// except the ones returning streams (pub/sub, blocking commands).
+/// Prepared commands are executed with [`execute`](SyncPreparedCommand::execute)
+/// instead of being awaited.
+///
+/// This facade is meant for simple tools and scripts.
+/// It must not be used from within an async runtime:
+/// blocking the thread of an async task panics.
+///
+/// # Example
+/// ```no_run
+/// use rustis::{
+///     client::{SyncClient, SyncPreparedCommand},
+///     commands::StringCommands,
+///     Result,
+/// };
+///
+/// fn main() -> Result<()> {
+///     let client = SyncClient::connect("127.0.0.1:6379")?;
+///
+///     client.set("key", "value").execute()?;
+///     let value: String = client.get("key").execute()?;
+///     println!("value: {value:?}");
+///
+///     client.close()
+/// }
+/// ```
+pub struct SyncClient {
+    // declared first to be dropped while the runtime is still alive
+    client: Client,
+    runtime: Runtime,
+}
+
+impl SyncClient {
+    /// Connects to the Redis server, blocking the calling thread.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the connection operation
+    pub fn connect(config: impl IntoConfig) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .map_err(|e| Error::Client(format!("Cannot start tokio runtime: {e}")))?;
+        let client = runtime.block_on(Client::connect(config))?;
+
+        Ok(Self { client, runtime })
+    }
+
+    /// Closes the connection, blocking the calling thread.
+    ///
+    /// See [`Client::close`](Client::close)
+    pub fn close(self) -> Result<()> {
+        let Self { client, runtime } = self;
+        runtime.block_on(client.close())
+    }
+
+    /// Returns the underlying async [`Client`](Client)
+    #[inline]
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Runs a future on the runtime of this client, blocking the calling thread.
+    ///
+    /// Useful to run the async API of the underlying [`Client`](Client),
+    /// e.g. to execute a [`Pipeline`](crate::client::Pipeline).
+    #[inline]
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// Sends an arbitrary command to the server, blocking the calling thread.
+    ///
+    /// See [`Client::send`](Client::send)
+    pub fn send(&self, command: Command, retry_on_error: Option<bool>) -> Result<RespBuf> {
+        self.runtime
+            .block_on(self.client.send(command, retry_on_error))
+    }
+}
+
+/// Extension trait dedicated to [`PreparedCommand`](crate::client::PreparedCommand)
+/// to add specific methods for the [`SyncClient`](crate::client::SyncClient) executor
+pub trait SyncPreparedCommand<'a, R> {
+    /// Send command and block until its response is received
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occur during the send operation
+    fn execute(self) -> Result<R>;
+
+    /// Send command and forget its response
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occur during the send operation
+    fn forget(self) -> Result<()>;
+}
+
+impl<'a, R> SyncPreparedCommand<'a, R> for PreparedCommand<'a, &'a SyncClient, R>
+where
+    R: Response + DeserializeOwned + Send + 'a,
+{
+    fn execute(self) -> Result<R> {
+        let sync_client = self.executor;
+        sync_client
+            .runtime
+            .block_on(into_client_command(self).into_future())
+    }
+
+    fn forget(self) -> Result<()> {
+        into_client_command(self).forget()
+    }
+}
+
+fn into_client_command<'a, R: Response>(
+    prepared_command: PreparedCommand<'a, &'a SyncClient, R>,
+) -> PreparedCommand<'a, &'a Client, R> {
+    let mut client_command =
+        PreparedCommand::new(&prepared_command.executor.client, prepared_command.command);
+    client_command.custom_converter = prepared_command.custom_converter;
+    client_command.retry_on_error = prepared_command.retry_on_error;
+    client_command.max_attempts = prepared_command.max_attempts;
+    client_command.compress_values = prepared_command.compress_values;
+    client_command
+}
+
+impl<'a> BitmapCommands<'a> for &'a SyncClient {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+#[cfg(feature = "redis-bloom")]
+impl<'a> BloomCommands<'a> for &'a SyncClient {}
+impl<'a> ClusterCommands<'a> for &'a SyncClient {}
+impl<'a> CommandCommands<'a> for &'a SyncClient {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+#[cfg(feature = "redis-bloom")]
+impl<'a> CountMinSketchCommands<'a> for &'a SyncClient {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+#[cfg(feature = "redis-bloom")]
+impl<'a> CuckooCommands<'a> for &'a SyncClient {}
+impl<'a> ConnectionCommands<'a> for &'a SyncClient {}
+#[cfg_attr(docsrs, doc(cfg(feature = "debug-commands")))]
+#[cfg(feature = "debug-commands")]
+impl<'a> DebugCommands<'a> for &'a SyncClient {}
+impl<'a> GenericCommands<'a> for &'a SyncClient {}
+impl<'a> GeoCommands<'a> for &'a SyncClient {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-graph")))]
+#[cfg(feature = "redis-graph")]
+impl<'a> GraphCommands<'a> for &'a SyncClient {}
+impl<'a> HashCommands<'a> for &'a SyncClient {}
+impl<'a> HyperLogLogCommands<'a> for &'a SyncClient {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-json")))]
+#[cfg(feature = "redis-json")]
+impl<'a> JsonCommands<'a> for &'a SyncClient {}
+impl<'a> ListCommands<'a> for &'a SyncClient {}
+impl<'a> ScriptingCommands<'a> for &'a SyncClient {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[cfg(feature = "redis-search")]
+impl<'a> SearchCommands<'a> for &'a SyncClient {}
+impl<'a> SentinelCommands<'a> for &'a SyncClient {}
+impl<'a> ServerCommands<'a> for &'a SyncClient {}
+impl<'a> SetCommands<'a> for &'a SyncClient {}
+impl<'a> SortedSetCommands<'a> for &'a SyncClient {}
+impl<'a> StreamCommands<'a> for &'a SyncClient {}
+impl<'a> StringCommands<'a> for &'a SyncClient {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+#[cfg(feature = "redis-bloom")]
+impl<'a> TDigestCommands<'a> for &'a SyncClient {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-time-series")))]
+#[cfg(feature = "redis-time-series")]
+impl<'a> TimeSeriesCommands<'a> for &'a SyncClient {}
+impl<'a> TransactionCommands<'a> for &'a SyncClient {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+#[cfg(feature = "redis-bloom")]
+impl<'a> TopKCommands<'a> for &'a SyncClient {}
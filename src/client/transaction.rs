@@ -3,6 +3,8 @@ use serde::{
     forward_to_deserialize_any, Deserializer,
 };
 
+#[cfg(feature = "debug-commands")]
+use crate::commands::DebugCommands;
 #[cfg(feature = "redis-graph")]
 use crate::commands::GraphCommands;
 #[cfg(feature = "redis-json")]
@@ -18,11 +20,11 @@ use crate::commands::{
 use crate::{
     client::{BatchPreparedCommand, Client, PreparedCommand},
     commands::{
-        BitmapCommands, GenericCommands, GeoCommands, HashCommands, HyperLogLogCommands,
-        ListCommands, ScriptingCommands, ServerCommands, SetCommands, SortedSetCommands,
-        StreamCommands, StringCommands,
+        BitmapCommands, CommandCommands, GenericCommands, GeoCommands, HashCommands,
+        HyperLogLogCommands, ListCommands, ScriptingCommands, ServerCommands, SetCommands,
+        SortedSetCommands, StreamCommands, StringCommands,
     },
-    resp::{cmd, Command, RespDeserializer, Response},
+    resp::{check_tuple_len, cmd, Command, Response, SingleArg, SingleArgCollection},
     Error, Result,
 };
 use std::{fmt, marker::PhantomData};
@@ -33,6 +35,7 @@ pub struct Transaction<'a> {
     commands: Vec<Command>,
     forget_flags: Vec<bool>,
     retry_on_error: Option<bool>,
+    watched: bool,
 }
 
 impl<'a> Transaction<'a> {
@@ -42,6 +45,7 @@ impl<'a> Transaction<'a> {
             commands: vec![cmd("MULTI")],
             forget_flags: Vec::new(),
             retry_on_error: None,
+            watched: false,
         }
     }
 
@@ -64,6 +68,116 @@ impl<'a> Transaction<'a> {
         self.forget_flags.push(true);
     }
 
+    /// Marks the given keys to be watched for conditional execution of the transaction,
+    /// e.g. for optimistic locking.
+    ///
+    /// [`WATCH`](https://redis.io/commands/watch/) is sent right away, outside of the `MULTI` block:
+    /// the watched keys can then be read before queuing the commands of the transaction.
+    /// If one of the watched keys is modified by another connection before the transaction is
+    /// [executed](Transaction::execute), the execution returns [`Error::Aborted`](crate::Error::Aborted).
+    ///
+    /// # Errors
+    /// * An [`Error::Client`](crate::Error::Client) if the connection is shared by clones of the client,
+    ///   since the whole connection would be watched, not only this transaction
+    /// * Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::{
+    ///     client::{BatchPreparedCommand, Client},
+    ///     commands::StringCommands,
+    ///     Error, Result,
+    /// };
+    ///
+    /// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+    /// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+    /// async fn main() -> Result<()> {
+    ///     let client = Client::connect("127.0.0.1:6379").await?;
+    ///     client.set("counter", 1).await?;
+    ///
+    ///     loop {
+    ///         let mut transaction = client.create_transaction();
+    ///         transaction.watch("counter").await?;
+    ///
+    ///         let value: i64 = client.get("counter").await?;
+    ///         transaction.set("counter", value + 1).queue();
+    ///
+    ///         match transaction.execute::<()>().await {
+    ///             Ok(()) => break,
+    ///             // the counter has been modified concurrently, try again
+    ///             Err(Error::Aborted) => continue,
+    ///             Err(e) => return Err(e),
+    ///         }
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn watch<K, KK>(&mut self, keys: KK) -> Result<()>
+    where
+        K: SingleArg,
+        KK: SingleArgCollection<K>,
+    {
+        if self.client.is_connection_shared() {
+            return Err(Error::Client(
+                "WATCH cannot be sent on a connection shared by multiple clients".to_owned(),
+            ));
+        }
+
+        self.client
+            .send(cmd("WATCH").arg(keys), self.retry_on_error)
+            .await?
+            .to::<()>()?;
+        self.watched = true;
+
+        Ok(())
+    }
+
+    /// Flushes all the keys previously watched with [`watch`](Transaction::watch).
+    ///
+    /// [`UNWATCH`](https://redis.io/commands/unwatch/) is sent right away,
+    /// there's no need to call it once the transaction is executed.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    pub async fn unwatch(&mut self) -> Result<()> {
+        self.client
+            .send(cmd("UNWATCH"), self.retry_on_error)
+            .await?
+            .to::<()>()?;
+        self.watched = false;
+
+        Ok(())
+    }
+
+    /// Abandon the transaction and all its queued commands.
+    ///
+    /// Commands, including `MULTI`, are only sent to the Redis server when the transaction
+    /// is [executed](Transaction::execute): the queued commands are simply dropped.
+    ///
+    /// If keys have been [watched](Transaction::watch), [`DISCARD`](https://redis.io/commands/discard/)
+    /// is sent within an empty `MULTI` block to flush them,
+    /// leaving the connection ready for a new transaction.
+    ///
+    /// # Errors
+    /// Any Redis driver [`Error`](crate::Error) that occurs while sending `MULTI` and `DISCARD`
+    pub async fn discard(self) -> Result<()> {
+        if !self.watched {
+            return Ok(());
+        }
+
+        let results = self
+            .client
+            .send_batch(vec![cmd("MULTI"), cmd("DISCARD")], self.retry_on_error)
+            .await?;
+
+        for result in results {
+            result.to::<()>()?;
+        }
+
+        Ok(())
+    }
+
     /// Execute the transaction by the sending the queued command
     /// as a whole batch to the Redis server.
     ///
@@ -74,6 +188,13 @@ impl<'a> Transaction<'a> {
     ///
     /// The most generic type that can be requested as a result is `Vec<resp::Value>`
     ///
+    /// # Errors
+    /// * [`Error::Aborted`](crate::Error::Aborted) if a [watched](Transaction::watch) key
+    ///   has been modified before the execution
+    /// * An [`Error::Client`](crate::Error::Client) if `WATCH` has been queued in the transaction:
+    ///   it must be sent before with [`watch`](Transaction::watch)
+    /// * Any Redis driver [`Error`](crate::Error) that occurs during the send operation
+    ///
     /// # Example
     /// ```
     /// use rustis::{
@@ -100,6 +221,13 @@ impl<'a> Transaction<'a> {
     /// }
     /// ```
     pub async fn execute<T: DeserializeOwned>(mut self) -> Result<T> {
+        // WATCH inside MULTI is refused by the server and would abort the whole transaction
+        if self.commands.iter().any(|command| command.name == "WATCH") {
+            return Err(Error::Client(
+                "WATCH cannot be queued in a transaction, use Transaction::watch".to_owned(),
+            ));
+        }
+
         self.commands.push(cmd("EXEC"));
 
         let num_commands = self.commands.len();
@@ -120,7 +248,7 @@ impl<'a> Transaction<'a> {
 
         // EXEC
         if let Some(result) = iter.next() {
-            let mut deserializer = RespDeserializer::new(&result);
+            let mut deserializer = result.deserializer();
             match TransactionResultSeed::new(self.forget_flags).deserialize(&mut deserializer) {
                 Ok(Some(t)) => Ok(t),
                 Ok(None) => Err(Error::Aborted),
@@ -229,10 +357,31 @@ where
         visitor.visit_seq(self)
     }
 
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        let queued = self.forget_flags.as_slice().iter().filter(|f| !**f).count();
+        check_tuple_len(len, queued)?;
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_tuple(len, visitor)
+    }
+
     forward_to_deserialize_any! {
         bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str
-        bytes byte_buf unit_struct newtype_struct string tuple
-        tuple_struct map struct enum identifier ignored_any unit option
+        bytes byte_buf unit_struct newtype_struct string
+        map struct enum identifier ignored_any unit option
     }
 }
 
@@ -278,12 +427,16 @@ impl<'a, 'b> BitmapCommands<'a> for &'a mut Transaction<'b> {}
 #[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
 #[cfg(feature = "redis-bloom")]
 impl<'a, 'b> BloomCommands<'a> for &'a mut Transaction<'b> {}
+impl<'a, 'b> CommandCommands<'a> for &'a mut Transaction<'b> {}
 #[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
 #[cfg(feature = "redis-bloom")]
 impl<'a, 'b> CountMinSketchCommands<'a> for &'a mut Transaction<'b> {}
 #[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
 #[cfg(feature = "redis-bloom")]
 impl<'a, 'b> CuckooCommands<'a> for &'a mut Transaction<'b> {}
+#[cfg_attr(docsrs, doc(cfg(feature = "debug-commands")))]
+#[cfg(feature = "debug-commands")]
+impl<'a, 'b> DebugCommands<'a> for &'a mut Transaction<'b> {}
 impl<'a, 'b> GenericCommands<'a> for &'a mut Transaction<'b> {}
 impl<'a, 'b> GeoCommands<'a> for &'a mut Transaction<'b> {}
 #[cfg_attr(docsrs, doc(cfg(feature = "redis-graph")))]
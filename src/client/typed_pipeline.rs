@@ -0,0 +1,254 @@
+#[cfg(feature = "debug-commands")]
+use crate::commands::DebugCommands;
+#[cfg(feature = "redis-graph")]
+use crate::commands::GraphCommands;
+#[cfg(feature = "redis-json")]
+use crate::commands::JsonCommands;
+#[cfg(feature = "redis-search")]
+use crate::commands::SearchCommands;
+#[cfg(feature = "redis-time-series")]
+use crate::commands::TimeSeriesCommands;
+#[cfg(feature = "redis-bloom")]
+use crate::commands::{
+    BloomCommands, CountMinSketchCommands, CuckooCommands, TDigestCommands, TopKCommands,
+};
+use crate::{
+    client::{Client, Pipeline, PreparedCommand},
+    commands::{
+        BitmapCommands, ClusterCommands, CommandCommands, ConnectionCommands, GenericCommands,
+        GeoCommands, HashCommands, HyperLogLogCommands, ListCommands, ScriptingCommands,
+        ServerCommands, SetCommands, SortedSetCommands, StreamCommands, StringCommands,
+    },
+    resp::{RespBuf, Response},
+    Error, Result,
+};
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+
+/// Represents a Redis command pipeline which tracks, at compile time,
+/// the type of each queued command response.
+///
+/// Each call to [`queue`](TypedBatchPreparedCommand::queue) appends the
+/// response type of the command to the tuple `T`, so that
+/// [`execute`](TypedPipeline::execute) returns exactly one value per queued command.
+/// Up to 16 commands can be queued.
+///
+/// For pipelines built dynamically, use the untyped [`Pipeline`](crate::client::Pipeline).
+pub struct TypedPipeline<'a, T = ()> {
+    pipeline: Pipeline<'a>,
+    phantom: PhantomData<T>,
+}
+
+impl<'a> TypedPipeline<'a, ()> {
+    pub(crate) fn new(client: &'a Client) -> TypedPipeline<'a, ()> {
+        TypedPipeline {
+            pipeline: Pipeline::new(client),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> TypedPipeline<'a, T> {
+    /// Set a flag to override default `retry_on_error` behavior.
+    ///
+    /// See [Config::retry_on_error](crate::client::Config::retry_on_error)
+    pub fn retry_on_error(mut self, retry_on_error: bool) -> Self {
+        self.pipeline.retry_on_error(retry_on_error);
+        self
+    }
+
+    /// Execute the pipeline by the sending the queued command
+    /// as a whole batch to the Redis server.
+    ///
+    /// # Return
+    /// A tuple with one element per [queued](TypedBatchPreparedCommand::queue) command,
+    /// in the order the commands have been queued.
+    ///
+    /// # Example
+    /// ```
+    /// use rustis::{
+    ///     client::{Client, TypedBatchPreparedCommand},
+    ///     commands::{GenericCommands, ListCommands, StringCommands},
+    ///     Result,
+    /// };
+    ///
+    /// #[cfg_attr(feature = "tokio-runtime", tokio::main)]
+    /// #[cfg_attr(feature = "async-std-runtime", async_std::main)]
+    /// async fn main() -> Result<()> {
+    ///     let client = Client::connect("127.0.0.1:6379").await?;
+    ///
+    ///     let (value, len) = client
+    ///         .create_typed_pipeline()
+    ///         .set("key", "value")
+    ///         .forget()
+    ///         .del("list")
+    ///         .forget()
+    ///         .get::<_, String>("key")
+    ///         .queue()
+    ///         .rpush("list", ["a", "b"])
+    ///         .queue()
+    ///         .execute()
+    ///         .await?;
+    ///
+    ///     assert_eq!("value", value);
+    ///     assert_eq!(2, len);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn execute(self) -> Result<T>
+    where
+        T: TypedPipelineResults,
+    {
+        let results = self.pipeline.execute_raw().await?;
+        T::from_results(results)
+    }
+}
+
+/// Tuple of responses which can be returned by [`TypedPipeline::execute`]
+pub trait TypedPipelineResults: Sized {
+    #[doc(hidden)]
+    fn from_results(results: Vec<RespBuf>) -> Result<Self>;
+}
+
+/// Tuple of responses to which the response type `R` of a newly queued command can be appended
+pub trait TypedPipelineAppend<R>: TypedPipelineResults {
+    /// Resulting tuple
+    type Output: TypedPipelineResults;
+}
+
+fn next_result<T: DeserializeOwned>(results: &mut impl Iterator<Item = RespBuf>) -> Result<T> {
+    match results.next() {
+        Some(result) => result.to(),
+        None => Err(Error::Client(
+            "Missing result in typed pipeline response".to_owned(),
+        )),
+    }
+}
+
+macro_rules! impl_typed_pipeline_results {
+    ($($name:ident)*) => {
+        impl<$($name: DeserializeOwned,)*> TypedPipelineResults for ($($name,)*) {
+            #[allow(unused_mut, unused_variables)]
+            fn from_results(results: Vec<RespBuf>) -> Result<Self> {
+                let mut results = results.into_iter();
+                Ok(($(next_result::<$name>(&mut results)?,)*))
+            }
+        }
+    };
+}
+
+macro_rules! impl_typed_pipeline_append {
+    ($($name:ident)*) => {
+        impl_typed_pipeline_results!($($name)*);
+
+        impl<$($name: DeserializeOwned,)* R: DeserializeOwned> TypedPipelineAppend<R> for ($($name,)*) {
+            type Output = ($($name,)* R,);
+        }
+    };
+}
+
+impl_typed_pipeline_append!();
+impl_typed_pipeline_append!(T1);
+impl_typed_pipeline_append!(T1 T2);
+impl_typed_pipeline_append!(T1 T2 T3);
+impl_typed_pipeline_append!(T1 T2 T3 T4);
+impl_typed_pipeline_append!(T1 T2 T3 T4 T5);
+impl_typed_pipeline_append!(T1 T2 T3 T4 T5 T6);
+impl_typed_pipeline_append!(T1 T2 T3 T4 T5 T6 T7);
+impl_typed_pipeline_append!(T1 T2 T3 T4 T5 T6 T7 T8);
+impl_typed_pipeline_append!(T1 T2 T3 T4 T5 T6 T7 T8 T9);
+impl_typed_pipeline_append!(T1 T2 T3 T4 T5 T6 T7 T8 T9 T10);
+impl_typed_pipeline_append!(T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11);
+impl_typed_pipeline_append!(T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12);
+impl_typed_pipeline_append!(T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 T13);
+impl_typed_pipeline_append!(T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 T13 T14);
+impl_typed_pipeline_append!(T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 T13 T14 T15);
+impl_typed_pipeline_results!(T1 T2 T3 T4 T5 T6 T7 T8 T9 T10 T11 T12 T13 T14 T15 T16);
+
+/// Extension trait dedicated to [`PreparedCommand`](crate::client::PreparedCommand)
+/// to add specific methods for the [`TypedPipeline`](crate::client::TypedPipeline) executor
+pub trait TypedBatchPreparedCommand<'a, T, R> {
+    /// Queue a command and append its response type to the pipeline results.
+    fn queue(self) -> TypedPipeline<'a, T::Output>
+    where
+        T: TypedPipelineAppend<R>;
+
+    /// Queue a command and forget its response.
+    fn forget(self) -> TypedPipeline<'a, T>;
+}
+
+impl<'a, 'b, T, R: Response> TypedBatchPreparedCommand<'b, T, R>
+    for PreparedCommand<'a, TypedPipeline<'b, T>, R>
+{
+    /// Queue a command and append its response type to the pipeline results.
+    #[inline]
+    fn queue(self) -> TypedPipeline<'b, T::Output>
+    where
+        T: TypedPipelineAppend<R>,
+    {
+        let mut pipeline = self.executor.pipeline;
+        pipeline.queue(self.command);
+        TypedPipeline {
+            pipeline,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Queue a command and forget its response.
+    #[inline]
+    fn forget(self) -> TypedPipeline<'b, T> {
+        let mut pipeline = self.executor.pipeline;
+        pipeline.forget(self.command);
+        TypedPipeline {
+            pipeline,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, 'b, T> BitmapCommands<'a> for TypedPipeline<'b, T> {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+#[cfg(feature = "redis-bloom")]
+impl<'a, 'b, T> BloomCommands<'a> for TypedPipeline<'b, T> {}
+impl<'a, 'b, T> ClusterCommands<'a> for TypedPipeline<'b, T> {}
+impl<'a, 'b, T> CommandCommands<'a> for TypedPipeline<'b, T> {}
+impl<'a, 'b, T> ConnectionCommands<'a> for TypedPipeline<'b, T> {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+#[cfg(feature = "redis-bloom")]
+impl<'a, 'b, T> CountMinSketchCommands<'a> for TypedPipeline<'b, T> {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+#[cfg(feature = "redis-bloom")]
+impl<'a, 'b, T> CuckooCommands<'a> for TypedPipeline<'b, T> {}
+#[cfg_attr(docsrs, doc(cfg(feature = "debug-commands")))]
+#[cfg(feature = "debug-commands")]
+impl<'a, 'b, T> DebugCommands<'a> for TypedPipeline<'b, T> {}
+impl<'a, 'b, T> GenericCommands<'a> for TypedPipeline<'b, T> {}
+impl<'a, 'b, T> GeoCommands<'a> for TypedPipeline<'b, T> {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-graph")))]
+#[cfg(feature = "redis-graph")]
+impl<'a, 'b, T> GraphCommands<'a> for TypedPipeline<'b, T> {}
+impl<'a, 'b, T> HashCommands<'a> for TypedPipeline<'b, T> {}
+impl<'a, 'b, T> HyperLogLogCommands<'a> for TypedPipeline<'b, T> {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-json")))]
+#[cfg(feature = "redis-json")]
+impl<'a, 'b, T> JsonCommands<'a> for TypedPipeline<'b, T> {}
+impl<'a, 'b, T> ListCommands<'a> for TypedPipeline<'b, T> {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-search")))]
+#[cfg(feature = "redis-search")]
+impl<'a, 'b, T> SearchCommands<'a> for TypedPipeline<'b, T> {}
+impl<'a, 'b, T> SetCommands<'a> for TypedPipeline<'b, T> {}
+impl<'a, 'b, T> ScriptingCommands<'a> for TypedPipeline<'b, T> {}
+impl<'a, 'b, T> ServerCommands<'a> for TypedPipeline<'b, T> {}
+impl<'a, 'b, T> SortedSetCommands<'a> for TypedPipeline<'b, T> {}
+impl<'a, 'b, T> StreamCommands<'a> for TypedPipeline<'b, T> {}
+impl<'a, 'b, T> StringCommands<'a> for TypedPipeline<'b, T> {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+#[cfg(feature = "redis-bloom")]
+impl<'a, 'b, T> TDigestCommands<'a> for TypedPipeline<'b, T> {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-time-series")))]
+#[cfg(feature = "redis-time-series")]
+impl<'a, 'b, T> TimeSeriesCommands<'a> for TypedPipeline<'b, T> {}
+#[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
+#[cfg(feature = "redis-bloom")]
+impl<'a, 'b, T> TopKCommands<'a> for TypedPipeline<'b, T> {}
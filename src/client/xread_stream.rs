@@ -0,0 +1,236 @@
+use crate::{
+    client::Client,
+    commands::{StreamCommands, StreamEntry, XReadGroupOptions, XReadOptions},
+    resp::{PrimitiveResponse, SingleArg, SingleArgCollection},
+    Future, Result,
+};
+use futures_util::{FutureExt, Stream};
+use serde::de::DeserializeOwned;
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+type XReadFuture<V> = Future<'static, Vec<(String, Vec<StreamEntry<V>>)>>;
+
+/// Stream of the entries of a Redis stream, read continuously with [`XREAD`](https://redis.io/commands/xread/)
+///
+/// Each read blocks the connection until new entries are available (`BLOCK 0`).
+/// The ID of the last entry returned is used as the starting point of the next read.
+///
+/// The stream ends after the first error.
+///
+/// See [`xread_stream`](crate::commands::BlockingCommands::xread_stream)
+pub struct XReadStream<V>
+where
+    V: PrimitiveResponse + DeserializeOwned + Send + 'static,
+{
+    client: Client,
+    key: String,
+    last_id: String,
+    count: Option<usize>,
+    entries: VecDeque<StreamEntry<V>>,
+    future: Option<XReadFuture<V>>,
+    closed: bool,
+}
+
+impl<V> XReadStream<V>
+where
+    V: PrimitiveResponse + DeserializeOwned + Send + 'static,
+{
+    pub(crate) fn new(client: Client, key: String, last_id: String, count: Option<usize>) -> Self {
+        Self {
+            client,
+            key,
+            last_id,
+            count,
+            entries: VecDeque::new(),
+            future: None,
+            closed: false,
+        }
+    }
+
+    /// ID of the last entry returned by the stream
+    pub fn last_id(&self) -> &str {
+        &self.last_id
+    }
+
+    fn read(&self) -> XReadFuture<V> {
+        let client = self.client.clone();
+        let key = self.key.clone();
+        let last_id = self.last_id.clone();
+        let mut options = XReadOptions::default().block(0);
+        if let Some(count) = self.count {
+            options = options.count(count);
+        }
+
+        Box::pin(async move { client.xread(options, key, last_id).await })
+    }
+}
+
+impl<V> Stream for XReadStream<V>
+where
+    V: PrimitiveResponse + DeserializeOwned + Send + Unpin + 'static,
+{
+    type Item = Result<StreamEntry<V>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if this.closed {
+                return Poll::Ready(None);
+            }
+
+            if let Some(entry) = this.entries.pop_front() {
+                this.last_id.clone_from(&entry.stream_id);
+                return Poll::Ready(Some(Ok(entry)));
+            }
+
+            let mut future = match this.future.take() {
+                Some(future) => future,
+                None => this.read(),
+            };
+
+            match future.poll_unpin(cx) {
+                Poll::Ready(Ok(results)) => {
+                    this.entries
+                        .extend(results.into_iter().flat_map(|(_key, entries)| entries));
+                }
+                Poll::Ready(Err(e)) => {
+                    this.closed = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+                Poll::Pending => {
+                    this.future = Some(future);
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
+
+/// Stream of the entries of a Redis stream, delivered to a consumer of a consumer group,
+/// read continuously with [`XREADGROUP`](https://redis.io/commands/xreadgroup/)
+///
+/// Each read blocks the connection until new entries are available (`BLOCK 0`)
+/// and only returns entries never delivered to other consumers (`>` ID).
+/// Entries are added to the pending entries list of the consumer
+/// and must be acknowledged with [`ack`](XReadGroupStream::ack).
+///
+/// The stream ends after the first error.
+///
+/// See [`xreadgroup_stream`](crate::commands::BlockingCommands::xreadgroup_stream)
+pub struct XReadGroupStream<V>
+where
+    V: PrimitiveResponse + DeserializeOwned + Send + 'static,
+{
+    client: Client,
+    group: String,
+    consumer: String,
+    key: String,
+    count: Option<usize>,
+    entries: VecDeque<StreamEntry<V>>,
+    future: Option<XReadFuture<V>>,
+    closed: bool,
+}
+
+impl<V> XReadGroupStream<V>
+where
+    V: PrimitiveResponse + DeserializeOwned + Send + 'static,
+{
+    pub(crate) fn new(
+        client: Client,
+        group: String,
+        consumer: String,
+        key: String,
+        count: Option<usize>,
+    ) -> Self {
+        Self {
+            client,
+            group,
+            consumer,
+            key,
+            count,
+            entries: VecDeque::new(),
+            future: None,
+            closed: false,
+        }
+    }
+
+    /// Acknowledge entries returned by the stream,
+    /// removing them from the pending entries list of the consumer group.
+    ///
+    /// # Return
+    /// The number of entries successfully acknowledged.
+    ///
+    /// # Note
+    /// The acknowledgement is sent on the same connection as the reads:
+    /// it is processed by the server once the current blocking read returns.
+    /// Use another client to acknowledge entries while the stream is waiting for new entries.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/xack/>](https://redis.io/commands/xack/)
+    pub async fn ack<I, II>(&self, ids: II) -> Result<usize>
+    where
+        I: SingleArg,
+        II: SingleArgCollection<I>,
+    {
+        self.client.xack(&self.key, &self.group, ids).await
+    }
+
+    fn read(&self) -> XReadFuture<V> {
+        let client = self.client.clone();
+        let group = self.group.clone();
+        let consumer = self.consumer.clone();
+        let key = self.key.clone();
+        let mut options = XReadGroupOptions::default().block(0);
+        if let Some(count) = self.count {
+            options = options.count(count);
+        }
+
+        Box::pin(async move { client.xreadgroup(group, consumer, options, key, ">").await })
+    }
+}
+
+impl<V> Stream for XReadGroupStream<V>
+where
+    V: PrimitiveResponse + DeserializeOwned + Send + Unpin + 'static,
+{
+    type Item = Result<StreamEntry<V>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if this.closed {
+                return Poll::Ready(None);
+            }
+
+            if let Some(entry) = this.entries.pop_front() {
+                return Poll::Ready(Some(Ok(entry)));
+            }
+
+            let mut future = match this.future.take() {
+                Some(future) => future,
+                None => this.read(),
+            };
+
+            match future.poll_unpin(cx) {
+                Poll::Ready(Ok(results)) => {
+                    this.entries
+                        .extend(results.into_iter().flat_map(|(_key, entries)| entries));
+                }
+                Poll::Ready(Err(e)) => {
+                    this.closed = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+                Poll::Pending => {
+                    this.future = Some(future);
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
@@ -35,10 +35,29 @@ pub trait BitmapCommands<'a> {
     /// A collection with each entry being the corresponding result of the sub command
     /// given at the same position. OVERFLOW subcommands don't count as generating a reply.
     ///
+    /// An entry is `None` when a `SET` or `INCRBY` sub command is not executed
+    /// because of an overflow with the [`Fail`](BitFieldOverflow::Fail) behavior.
+    ///
+    /// # Example
+    /// ```
+    /// # use rustis::commands::{BitFieldOffset, BitFieldOverflow, BitFieldSubCommand, BitFieldType};
+    /// // BITFIELD key GET u8 0 SET i16 #1 100 OVERFLOW SAT INCRBY u4 #2 1
+    /// let sub_commands = [
+    ///     BitFieldSubCommand::get(BitFieldType::Unsigned(8), BitFieldOffset::Bit(0)),
+    ///     BitFieldSubCommand::set(BitFieldType::Signed(16), BitFieldOffset::Multiplier(1), 100),
+    ///     BitFieldSubCommand::overflow(BitFieldOverflow::Sat),
+    ///     BitFieldSubCommand::incr_by(BitFieldType::Unsigned(4), BitFieldOffset::Multiplier(2), 1),
+    /// ];
+    /// ```
+    ///
     /// # See Also
     /// [<https://redis.io/commands/bitfield/>](https://redis.io/commands/bitfield/)
     #[must_use]
-    fn bitfield<K, C, E, O>(self, key: K, sub_commands: C) -> PreparedCommand<'a, Self, Vec<u64>>
+    fn bitfield<K, C, E, O>(
+        self,
+        key: K,
+        sub_commands: C,
+    ) -> PreparedCommand<'a, Self, Vec<Option<i64>>>
     where
         Self: Sized,
         K: SingleArg,
@@ -64,7 +83,7 @@ pub trait BitmapCommands<'a> {
         self,
         key: K,
         get_commands: C,
-    ) -> PreparedCommand<'a, Self, Vec<u64>>
+    ) -> PreparedCommand<'a, Self, Vec<i64>>
     where
         Self: Sized,
         K: SingleArg,
@@ -100,16 +119,18 @@ pub trait BitmapCommands<'a> {
         prepare_command(self, cmd("BITOP").arg(operation).arg(dest_key).arg(keys))
     }
 
-    /// Perform a bitwise operation between multiple keys (containing string values)
-    /// and store the result in the destination key.
+    /// Return the position of the first bit set to 1 or 0 in a string.
     ///
     /// # Return
-    /// The position of the first bit set to 1 or 0 according to the request.
+    /// The position of the first bit set to 1 or 0 according to the request,
+    /// or `-1` if no bit set to 1 is found in the string or in the range
+    /// (looking for a bit set to 0 in a string of ones without range
+    /// returns the first bit after the string)
     ///
     /// # See Also
     /// [<https://redis.io/commands/bitpos/>](https://redis.io/commands/bitpos/)
     #[must_use]
-    fn bitpos<K>(self, key: K, bit: u64, range: BitRange) -> PreparedCommand<'a, Self, usize>
+    fn bitpos<K>(self, key: K, bit: u64, range: BitRange) -> PreparedCommand<'a, Self, isize>
     where
         Self: Sized,
         K: SingleArg,
@@ -150,7 +171,11 @@ pub trait BitmapCommands<'a> {
     }
 }
 
-/// Interval options for the [`bitcount`](BitmapCommands::bitcount) command
+/// Interval options for the [`bitcount`](BitmapCommands::bitcount)
+/// and [`bitpos`](BitmapCommands::bitpos) commands
+///
+/// Start and end are byte indexes unless the [`Bit`](BitUnit::Bit) unit is specified.
+/// Negative values are indexes from the end of the string.
 #[derive(Default)]
 pub struct BitRange {
     command_args: CommandArgs,
@@ -201,7 +226,7 @@ where
     O: SingleArg,
 {
     Get(BitFieldGetSubCommand<E, O>),
-    Set(E, O, u64),
+    Set(E, O, i64),
     IncrBy(E, O, i64),
     Overflow(BitFieldOverflow),
 }
@@ -219,7 +244,7 @@ where
 
     /// Set the specified bit field and returns its old value.
     #[must_use]
-    pub fn set(encoding: E, offset: O, value: u64) -> Self {
+    pub fn set(encoding: E, offset: O, value: i64) -> Self {
         Self::Set(encoding, offset, value)
     }
 
@@ -230,6 +255,7 @@ where
         Self::IncrBy(encoding, offset, increment)
     }
 
+    /// Changes the overflow behavior of the following `SET` and `INCRBY` sub-commands.
     #[must_use]
     pub fn overflow(overflow: BitFieldOverflow) -> Self {
         Self::Overflow(overflow)
@@ -283,10 +309,57 @@ where
     }
 }
 
+/// Integer type of a bit field,
+/// for the [`bitfield`](BitmapCommands::bitfield) sub-commands
+///
+/// `Signed` widths go up to 64 bits, `Unsigned` widths up to 63 bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitFieldType {
+    /// Signed integer of the given width in bits, e.g. `i8`
+    Signed(u8),
+    /// Unsigned integer of the given width in bits, e.g. `u4`
+    Unsigned(u8),
+}
+
+impl SingleArg for BitFieldType {}
+
+impl ToArgs for BitFieldType {
+    fn write_args(&self, args: &mut CommandArgs) {
+        args.arg(match self {
+            BitFieldType::Signed(width) => format!("i{width}"),
+            BitFieldType::Unsigned(width) => format!("u{width}"),
+        });
+    }
+}
+
+/// Offset of a bit field,
+/// for the [`bitfield`](BitmapCommands::bitfield) sub-commands
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitFieldOffset {
+    /// Offset in bits
+    Bit(u64),
+    /// Offset multiplied by the width of the bit field type, e.g. `#2`
+    Multiplier(u64),
+}
+
+impl SingleArg for BitFieldOffset {}
+
+impl ToArgs for BitFieldOffset {
+    fn write_args(&self, args: &mut CommandArgs) {
+        match self {
+            BitFieldOffset::Bit(offset) => args.arg(*offset),
+            BitFieldOffset::Multiplier(multiplier) => args.arg(format!("#{multiplier}")),
+        };
+    }
+}
+
 /// Option for the [`BitFieldSubCommand`](BitFieldSubCommand) sub-command.
 pub enum BitFieldOverflow {
+    /// Wrap around, with modulo arithmetic (default)
     Wrap,
+    /// Saturate to the minimum or maximum value of the type
     Sat,
+    /// Do not execute the operation, `None` is returned
     Fail,
 }
 
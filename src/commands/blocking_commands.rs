@@ -1,8 +1,8 @@
 use crate::{
-    client::{prepare_command, MonitorStream, PreparedCommand},
+    client::{prepare_command, MonitorStream, PreparedCommand, XReadGroupStream, XReadStream},
     commands::{LMoveWhere, ZMPopResult, ZWhere},
     resp::{cmd, deserialize_vec_of_triplets, PrimitiveResponse, SingleArg, SingleArgCollection},
-    Future,
+    Future, Result,
 };
 use serde::{
     de::{DeserializeOwned, Visitor},
@@ -68,9 +68,11 @@ where
 pub trait BlockingCommands<'a> {
     /// This command is the blocking variant of [`lmove`](crate::commands::ListCommands::lmove).
     ///
+    /// `timeout` is in seconds, `0` blocks indefinitely.
+    ///
     /// # Return
     /// the element being popped from `source` and pushed to `destination`.
-    /// If timeout is reached, a None reply is returned.
+    /// If timeout is reached, a None reply is returned (use an `Option<E>` response type to handle this case).
     ///
     /// # See Also
     /// [<https://redis.io/commands/blmove/>](https://redis.io/commands/blmove/)
@@ -284,4 +286,67 @@ pub trait BlockingCommands<'a> {
     /// [<https://redis.io/commands/monitor/>](https://redis.io/commands/monitor/)
     #[must_use]
     fn monitor(self) -> Future<'a, MonitorStream>;
+
+    /// Read continuously the entries of a stream with [`XREAD`](https://redis.io/commands/xread/),
+    /// starting after `last_id`.
+    ///
+    /// Each read is sent with `BLOCK 0` and the ID of the last entry received
+    /// is automatically used for the next read.
+    ///
+    /// # Arguments
+    /// * `key` - The key of the stream.
+    /// * `last_id` - Only entries with an ID greater than this ID are returned (`$` for new entries only).
+    /// * `count` - Optional maximum number of entries to read in one call.
+    ///
+    /// # Note
+    /// Blocking reads hold the connection until new entries arrive:
+    /// this stream must be created from a dedicated client, without any
+    /// [`command_timeout`](crate::client::Config::command_timeout).
+    ///
+    /// # Errors
+    /// [`Error::Client`](crate::Error::Client) if the connection is shared by clones of this client
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/xread/>](https://redis.io/commands/xread/)
+    fn xread_stream<V>(
+        self,
+        key: impl Into<String>,
+        last_id: impl Into<String>,
+        count: Option<usize>,
+    ) -> Result<XReadStream<V>>
+    where
+        V: PrimitiveResponse + DeserializeOwned + Send + 'static;
+
+    /// Read continuously the entries of a stream delivered to a consumer of a consumer group
+    /// with [`XREADGROUP`](https://redis.io/commands/xreadgroup/).
+    ///
+    /// Each read is sent with `BLOCK 0` and the `>` ID, so only entries
+    /// never delivered to any other consumer are returned.
+    /// Entries can be acknowledged with [`XReadGroupStream::ack`](XReadGroupStream::ack).
+    ///
+    /// # Arguments
+    /// * `group` - The consumer group.
+    /// * `consumer` - The consumer name.
+    /// * `key` - The key of the stream.
+    /// * `count` - Optional maximum number of entries to read in one call.
+    ///
+    /// # Note
+    /// Blocking reads hold the connection until new entries arrive:
+    /// this stream must be created from a dedicated client, without any
+    /// [`command_timeout`](crate::client::Config::command_timeout).
+    ///
+    /// # Errors
+    /// [`Error::Client`](crate::Error::Client) if the connection is shared by clones of this client
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/xreadgroup/>](https://redis.io/commands/xreadgroup/)
+    fn xreadgroup_stream<V>(
+        self,
+        group: impl Into<String>,
+        consumer: impl Into<String>,
+        key: impl Into<String>,
+        count: Option<usize>,
+    ) -> Result<XReadGroupStream<V>>
+    where
+        V: PrimitiveResponse + DeserializeOwned + Send + 'static;
 }
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, fmt};
+use std::{collections::HashMap, fmt, str::FromStr};
 
 use crate::{
     client::{prepare_command, PreparedCommand},
@@ -6,6 +6,7 @@ use crate::{
         cmd, deserialize_vec_of_pairs, CollectionResponse, CommandArgs, KeyValueArgsCollection,
         PrimitiveResponse, SingleArg, SingleArgCollection, ToArgs,
     },
+    Error,
 };
 use serde::{
     de::{self, DeserializeOwned},
@@ -103,13 +104,20 @@ pub trait ClusterCommands<'a> {
 
     /// Returns the number of keys in the specified Redis Cluster hash slot.
     ///
+    /// # Arguments
+    /// * `slot` - The hash slot, between `0` and `16383`.
+    ///
+    /// In cluster mode, the command is sent to the master node serving the hash slot,
+    /// and a hash slot out of range is rejected with an [`Error::Client`](crate::Error::Client)
+    /// before being sent.
+    ///
     /// # Return
     /// The number of keys in the specified hash slot, or an error if the hash slot is invalid.
     ///
     /// # See Also
     /// [<https://redis.io/commands/cluster-countkeysinslot/>](https://redis.io/commands/cluster-countkeysinslot/)
     #[must_use]
-    fn cluster_countkeysinslot(self, slot: usize) -> PreparedCommand<'a, Self, usize>
+    fn cluster_countkeysinslot(self, slot: u16) -> PreparedCommand<'a, Self, usize>
     where
         Self: Sized,
     {
@@ -198,12 +206,25 @@ pub trait ClusterCommands<'a> {
     /// The maximum number of keys to return is specified via the count argument,
     /// so that it is possible for the user of this API to batch-processing keys.
     ///
+    /// # Arguments
+    /// * `slot` - The hash slot, between `0` and `16383`.
+    /// * `count` - The maximum number of keys to return.
+    ///
+    /// In cluster mode, the command is sent to the master node serving the hash slot,
+    /// and a hash slot out of range is rejected with an [`Error::Client`](crate::Error::Client)
+    /// before being sent.
+    ///
+    /// # Return
+    /// The key names, or an error if the hash slot is invalid.
+    ///
     /// # See Also
     /// [<https://redis.io/commands/cluster-getkeysinslot/>](https://redis.io/commands/cluster-getkeysinslot/)
     #[must_use]
-    fn cluster_getkeysinslot(self, slot: u16, count: usize) -> PreparedCommand<'a, Self, ()>
+    fn cluster_getkeysinslot<K, A>(self, slot: u16, count: usize) -> PreparedCommand<'a, Self, A>
     where
         Self: Sized,
+        K: PrimitiveResponse + DeserializeOwned,
+        A: CollectionResponse<K> + DeserializeOwned,
     {
         prepare_command(
             self,
@@ -316,16 +337,15 @@ pub trait ClusterCommands<'a> {
     /// in order to store on disk the cluster state (however the on disk cluster state has a few additional info appended at the end).
     ///
     /// # Return
-    /// The serialized cluster configuration.
-    /// The output of the command is just a space-separated CSV string, where each line represents a node in the cluster.
+    /// The serialized cluster configuration,
+    /// parsed from the space-separated CSV string where each line represents a node in the cluster.
     ///
     /// # See Also
     /// [<https://redis.io/commands/cluster-nodes/>](https://redis.io/commands/cluster-nodes/)
     #[must_use]
-    fn cluster_nodes<R>(self) -> PreparedCommand<'a, Self, R>
+    fn cluster_nodes(self) -> PreparedCommand<'a, Self, ClusterNodesResult>
     where
         Self: Sized,
-        R: PrimitiveResponse,
     {
         prepare_command(self, cmd("CLUSTER").arg("NODES"))
     }
@@ -333,7 +353,7 @@ pub trait ClusterCommands<'a> {
     /// The command provides a list of replica nodes replicating from the specified master node.
     ///
     /// # Return
-    /// The command returns data in the same format as [`cluster_nodes`](ClusterCommands::cluster_nodes).
+    /// The replica nodes, in the same format as [`cluster_nodes`](ClusterCommands::cluster_nodes).
     ///
     /// # See Also
     /// [<https://redis.io/commands/cluster-replicas/>](https://redis.io/commands/cluster-replicas/)
@@ -342,7 +362,7 @@ pub trait ClusterCommands<'a> {
     where
         Self: Sized,
         I: SingleArg,
-        R: PrimitiveResponse,
+        R: CollectionResponse<ClusterNodeDescription>,
     {
         prepare_command(self, cmd("CLUSTER").arg("REPLICAS").arg(node_id))
     }
@@ -893,3 +913,200 @@ impl<'de> Deserialize<'de> for LegacyClusterNodeResult {
         deserializer.deserialize_seq(Visitor)
     }
 }
+
+/// Result for the [`cluster_nodes`](ClusterCommands::cluster_nodes) command.
+#[derive(Debug, Default)]
+pub struct ClusterNodesResult {
+    /// One entry per line of the serialized cluster configuration
+    pub nodes: Vec<ClusterNodeDescription>,
+}
+
+impl FromStr for ClusterNodesResult {
+    type Err = Error;
+
+    fn from_str(str: &str) -> crate::Result<Self> {
+        let nodes = str
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(ClusterNodeDescription::from_str)
+            .collect::<crate::Result<_>>()?;
+
+        Ok(ClusterNodesResult { nodes })
+    }
+}
+
+impl<'de> Deserialize<'de> for ClusterNodesResult {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let nodes = <&str>::deserialize(deserializer)?;
+        ClusterNodesResult::from_str(nodes).map_err(de::Error::custom)
+    }
+}
+
+/// A node of the cluster, as described by a line of the
+/// [`cluster_nodes`](ClusterCommands::cluster_nodes) and
+/// [`cluster_replicas`](ClusterCommands::cluster_replicas) commands.
+///
+/// # See Also
+/// [<https://redis.io/commands/cluster-nodes/#serialization-format>](https://redis.io/commands/cluster-nodes/#serialization-format)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClusterNodeDescription {
+    /// The node ID, a 40 characters random string generated when a node is created
+    /// and never changed again (unless `CLUSTER RESET HARD` is used).
+    pub id: String,
+
+    /// The IP address of the node, empty if the address is unknown (`noaddr` flag).
+    pub ip: String,
+
+    /// The port of the node that clients should contact to run queries.
+    pub port: u16,
+
+    /// The cluster bus port of the node.
+    pub cluster_bus_port: u16,
+
+    /// The announced hostname of the node, if any.
+    pub hostname: Option<String>,
+
+    /// A list of comma separated flags:
+    /// `myself`, `master`, `slave`, `fail?`, `fail`, `handshake`, `noaddr`, `nofailover`, `noflags`.
+    pub flags: Vec<String>,
+
+    /// If the node is a replica, the node ID of its master.
+    pub master_id: Option<String>,
+
+    /// Unix time at which the currently active ping was sent, in milliseconds,
+    /// or zero if there are no pending pings.
+    pub ping_sent: u64,
+
+    /// Unix time the last pong was received, in milliseconds.
+    pub pong_recv: u64,
+
+    /// The configuration epoch (or version) of the current node (or of the current master if the node is a replica).
+    pub config_epoch: u64,
+
+    /// The state of the link used for the node-to-node cluster bus.
+    pub link_state: ClusterLinkState,
+
+    /// Ranges of hash slots served by the node, bounds included.
+    /// A single slot is reported as a range with equal bounds.
+    pub slots: Vec<(u16, u16)>,
+
+    /// Hash slots being migrated from this node to another node: `(slot, destination node id)`.
+    pub migrating_slots: Vec<(u16, String)>,
+
+    /// Hash slots being imported from another node to this node: `(slot, source node id)`.
+    pub importing_slots: Vec<(u16, String)>,
+}
+
+impl ClusterNodeDescription {
+    /// `true` if the node is a master
+    pub fn is_master(&self) -> bool {
+        self.flags.iter().any(|f| f == "master")
+    }
+
+    /// `true` if the node is the node the command was sent to
+    pub fn is_myself(&self) -> bool {
+        self.flags.iter().any(|f| f == "myself")
+    }
+}
+
+impl FromStr for ClusterNodeDescription {
+    type Err = Error;
+
+    fn from_str(line: &str) -> crate::Result<Self> {
+        let error = || Error::Client(format!("Cannot parse CLUSTER NODES line: {line}"));
+        let number = |str: &str| str.parse::<u64>().map_err(|_| error());
+        let slot = |str: &str| str.parse::<u16>().map_err(|_| error());
+
+        let mut fields = line.split_whitespace();
+        let mut next_field = || fields.next().ok_or_else(error);
+
+        let id = next_field()?.to_owned();
+
+        // ip:port@cport[,hostname]
+        let address = next_field()?;
+        let (address, hostname) = match address.split_once(',') {
+            Some((address, hostname)) if !hostname.is_empty() => {
+                (address, Some(hostname.to_owned()))
+            }
+            Some((address, _)) => (address, None),
+            None => (address, None),
+        };
+        let (address, cluster_bus_port) = address.rsplit_once('@').ok_or_else(error)?;
+        let (ip, port) = address.rsplit_once(':').ok_or_else(error)?;
+        let port = port.parse::<u16>().map_err(|_| error())?;
+        let cluster_bus_port = cluster_bus_port.parse::<u16>().map_err(|_| error())?;
+
+        let flags = next_field()?.split(',').map(ToOwned::to_owned).collect();
+        let master_id = match next_field()? {
+            "-" => None,
+            master_id => Some(master_id.to_owned()),
+        };
+        let ping_sent = number(next_field()?)?;
+        let pong_recv = number(next_field()?)?;
+        let config_epoch = number(next_field()?)?;
+        let link_state = match next_field()? {
+            "connected" => ClusterLinkState::Connected,
+            "disconnected" => ClusterLinkState::Disconnected,
+            _ => return Err(error()),
+        };
+
+        let mut slots = Vec::new();
+        let mut migrating_slots = Vec::new();
+        let mut importing_slots = Vec::new();
+
+        for field in fields {
+            if let Some(field) = field.strip_prefix('[').and_then(|f| f.strip_suffix(']')) {
+                if let Some((s, node_id)) = field.split_once("->-") {
+                    migrating_slots.push((slot(s)?, node_id.to_owned()));
+                } else if let Some((s, node_id)) = field.split_once("-<-") {
+                    importing_slots.push((slot(s)?, node_id.to_owned()));
+                } else {
+                    return Err(error());
+                }
+            } else if let Some((start, end)) = field.split_once('-') {
+                slots.push((slot(start)?, slot(end)?));
+            } else {
+                let s = slot(field)?;
+                slots.push((s, s));
+            }
+        }
+
+        Ok(ClusterNodeDescription {
+            id,
+            ip: ip.to_owned(),
+            port,
+            cluster_bus_port,
+            hostname,
+            flags,
+            master_id,
+            ping_sent,
+            pong_recv,
+            config_epoch,
+            link_state,
+            slots,
+            migrating_slots,
+            importing_slots,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for ClusterNodeDescription {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let line = <&str>::deserialize(deserializer)?;
+        ClusterNodeDescription::from_str(line.trim()).map_err(de::Error::custom)
+    }
+}
+
+/// State of the cluster bus link of a node, for the [`cluster_nodes`](ClusterCommands::cluster_nodes) command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClusterLinkState {
+    Connected,
+    Disconnected,
+}
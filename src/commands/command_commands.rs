@@ -0,0 +1,583 @@
+use crate::{
+    client::{prepare_command, PreparedCommand},
+    resp::{
+        cmd, CollectionResponse, CommandArgs, KeyValueCollectionResponse, SingleArg,
+        SingleArgCollection, ToArgs,
+    },
+    Error, Result,
+};
+use serde::{de, Deserialize, Deserializer};
+use std::{collections::HashMap, str::FromStr};
+
+/// A group of Redis commands to introspect the commands of the server
+///
+/// The key positions returned by [`command_info`](CommandCommands::command_info)
+/// drive the key prefixing and the cluster routing of commands.
+/// They are cached by the client, see [`Client::cached_command_info`](crate::client::Client::cached_command_info).
+///
+/// # See Also
+/// [Redis Server Management Commands](https://redis.io/commands/?group=server)
+pub trait CommandCommands<'a> {
+    /// Return an array with details about every Redis command.
+    ///
+    /// # Return
+    /// A nested list of command details.
+    /// The order of commands in the array is random.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/command/>](https://redis.io/commands/command/)
+    fn command(self) -> PreparedCommand<'a, Self, Vec<CommandInfo>>
+    where
+        Self: Sized,
+    {
+        prepare_command(self, cmd("COMMAND"))
+    }
+
+    /// Number of total commands in this Redis server.
+    ///
+    /// # Return
+    /// number of commands returned by [`command`](CommandCommands::command)
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/command-count/>](https://redis.io/commands/command-count/)
+    fn command_count(self) -> PreparedCommand<'a, Self, usize>
+    where
+        Self: Sized,
+    {
+        prepare_command(self, cmd("COMMAND").arg("COUNT"))
+    }
+
+    /// Number of total commands in this Redis server.
+    ///
+    /// # Return
+    /// map key=command name, value=command doc
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/command-docs/>](https://redis.io/commands/command-docs/)
+    fn command_docs<N, NN, DD>(self, command_names: NN) -> PreparedCommand<'a, Self, DD>
+    where
+        Self: Sized,
+        N: SingleArg,
+        NN: SingleArgCollection<N>,
+        DD: KeyValueCollectionResponse<String, CommandDoc>,
+    {
+        prepare_command(self, cmd("COMMAND").arg("DOCS").arg(command_names))
+    }
+
+    /// A helper command to let you find the keys from a full Redis command.
+    ///
+    /// # Return
+    /// list of keys from your command.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/command-_getkeys/>](https://redis.io/commands/command-_getkeys/)
+    fn command_getkeys<A, AA, KK>(self, args: AA) -> PreparedCommand<'a, Self, KK>
+    where
+        Self: Sized,
+        A: SingleArg,
+        AA: SingleArgCollection<A>,
+        KK: CollectionResponse<String>,
+    {
+        prepare_command(self, cmd("COMMAND").arg("GETKEYS").arg(args))
+    }
+
+    /// A helper command to let you find the keys from a full Redis command together with flags indicating what each key is used for.
+    ///
+    /// # Return
+    /// map of keys with their flags from your command.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/command-getkeysandflags/>](https://redis.io/commands/command-getkeysandflags/)
+    fn command_getkeysandflags<A, AA, KK>(self, args: AA) -> PreparedCommand<'a, Self, KK>
+    where
+        Self: Sized,
+        A: SingleArg,
+        AA: SingleArgCollection<A>,
+        KK: KeyValueCollectionResponse<String, Vec<String>>,
+    {
+        prepare_command(self, cmd("COMMAND").arg("GETKEYSANDFLAGS").arg(args))
+    }
+
+    /// Return an array with details about multiple Redis command.
+    ///
+    /// # Return
+    /// A nested list of command details, in the order of the requested commands.
+    /// `None` for a command unknown to the server.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/command-info/>](https://redis.io/commands/command-info/)
+    fn command_info<N, NN>(
+        self,
+        command_names: NN,
+    ) -> PreparedCommand<'a, Self, Vec<Option<CommandInfo>>>
+    where
+        Self: Sized,
+        N: SingleArg,
+        NN: SingleArgCollection<N>,
+    {
+        prepare_command(self, cmd("COMMAND").arg("INFO").arg(command_names))
+    }
+
+    /// Return an array of the server's command names based on optional filters
+    ///
+    /// # Return
+    /// an array of the server's command names.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/command-list/>](https://redis.io/commands/command-list/)
+    fn command_list<CC>(self, options: CommandListOptions) -> PreparedCommand<'a, Self, CC>
+    where
+        Self: Sized,
+        CC: CollectionResponse<String>,
+    {
+        prepare_command(self, cmd("COMMAND").arg("LIST").arg(options))
+    }
+}
+
+/// Command info result for the [`command`](CommandCommands::command) command.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CommandInfo {
+    /// This is the command's name in lowercase.
+    pub name: String,
+    /// Arity is the number of arguments a command expects. It follows a simple pattern:
+    /// - A positive integer means a fixed number of arguments.
+    /// - A negative integer means a minimal number of arguments.
+    pub arity: isize,
+    /// Command flags are an array.
+    /// See [COMMAND documentation](https://redis.io/commands/command/) for the list of flags
+    pub flags: Vec<String>,
+    /// The position of the command's first key name argument.
+    /// For most commands, the first key's position is 1. Position 0 is always the command name itself.
+    pub first_key: usize,
+    /// The position of the command's last key name argument.
+    pub last_key: isize,
+    /// The step, or increment, between the first key and the position of the next key.
+    pub step: usize,
+    /// [From Redis 6.0] This is an array of simple strings that are the ACL categories to which the command belongs.
+    pub acl_categories: Vec<String>,
+    /// [From Redis 7.0] Helpful information about the command. To be used by clients/proxies.
+    /// See [<https://redis.io/docs/reference/command-tips/>](https://redis.io/docs/reference/command-tips/)
+    #[serde(default)]
+    pub command_tips: Vec<CommandTip>,
+    /// [From Redis 7.0] This is an array consisting of the command's key specifications.
+    /// See [<https://redis.io/docs/reference/key-specs/>](https://redis.io/docs/reference/key-specs/)
+    #[serde(default)]
+    pub key_specifications: Vec<KeySpecification>,
+    #[serde(default)]
+    pub sub_commands: Vec<CommandInfo>,
+}
+
+/// Get additional information about a command
+///
+/// See <https://redis.io/docs/reference/command-tips/>
+#[derive(Debug, Clone)]
+pub enum CommandTip {
+    NonDeterministricOutput,
+    NonDeterministricOutputOrder,
+    RequestPolicy(RequestPolicy),
+    ResponsePolicy(ResponsePolicy),
+}
+
+impl<'de> Deserialize<'de> for CommandTip {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let tip = <&str>::deserialize(deserializer)?;
+        match tip {
+            "nondeterministic_output" => Ok(CommandTip::NonDeterministricOutput),
+            "nondeterministic_output_order" => Ok(CommandTip::NonDeterministricOutputOrder),
+            _ => {
+                let mut parts = tip.split(':');
+                match (parts.next(), parts.next(), parts.next()) {
+                    (Some("request_policy"), Some(policy), None) => {
+                        match RequestPolicy::from_str(policy) {
+                            Ok(request_policy) => Ok(CommandTip::RequestPolicy(request_policy)),
+                            Err(_) => Err(de::Error::invalid_value(
+                                de::Unexpected::Str(policy),
+                                &"a valid RequestPolicy value",
+                            )),
+                        }
+                    }
+                    (Some("response_policy"), Some(policy), None) => {
+                        match ResponsePolicy::from_str(policy) {
+                            Ok(response_policy) => Ok(CommandTip::ResponsePolicy(response_policy)),
+                            Err(_) => Err(de::Error::invalid_value(
+                                de::Unexpected::Str(policy),
+                                &"a valid ResponsePolicy value",
+                            )),
+                        }
+                    }
+                    _ => Err(de::Error::invalid_value(
+                        de::Unexpected::Str(tip),
+                        &"a valid CommandTip value",
+                    )),
+                }
+            }
+        }
+    }
+}
+
+/// This tip can help clients determine the shards to send the command in clustering mode.
+///
+/// The default behavior a client should implement for commands without the request_policy tip is as follows:
+/// 1. The command doesn't accept key name arguments: the client can execute the command on an arbitrary shard.
+/// 2. For commands that accept one or more key name arguments: the client should route the command to a single shard,
+/// as determined by the hash slot of the input keys.
+#[derive(Debug, Clone, Deserialize)]
+pub enum RequestPolicy {
+    /// the client should execute the command on all nodes - masters and replicas alike.
+    ///
+    /// An example is the [`config_set`](crate::commands::ServerCommands::config_set) command.
+    /// This tip is in-use by commands that don't accept key name arguments. The command operates atomically per shard.
+    AllNodes,
+    /// the client should execute the command on all master shards (e.g., the [`dbsize`](crate::commands::ServerCommands::dbsize) command).
+    ///
+    /// This tip is in-use by commands that don't accept key name arguments. The command operates atomically per shard.
+    AllShards,
+    /// the client should execute the command on several shards.
+    ///
+    /// The shards that execute the command are determined by the hash slots of its input key name arguments.
+    /// Examples for such commands include [`mset`](crate::commands::StringCommands::mset), [`mget`](crate::commands::StringCommands::mget)
+    /// and [`del`](crate::commands::GenericCommands::del).
+    /// However, note that [`sunionstore`](crate::commands::SetCommands::sunionstore) isn't considered
+    /// as multi_shard because all of its keys must belong to the same hash slot.
+    MultiShard,
+    /// indicates a non-trivial form of the client's request policy, such as the [`scan`](crate::commands::GenericCommands::scan) command.
+    Special,
+}
+
+impl FromStr for RequestPolicy {
+    type Err = Error;
+
+    fn from_str(str: &str) -> Result<Self> {
+        match str {
+            "all_nodes" => Ok(RequestPolicy::AllNodes),
+            "all_shards" => Ok(RequestPolicy::AllShards),
+            "multi_shard" => Ok(RequestPolicy::MultiShard),
+            "special" => Ok(RequestPolicy::Special),
+            _ => Err(Error::Client(
+                "Cannot parse RequestPolicy from result".to_owned(),
+            )),
+        }
+    }
+}
+
+/// This tip can help clients determine the aggregate they need to compute from the replies of multiple shards in a cluster.
+///
+/// The default behavior for commands without a request_policy tip only applies to replies with of nested types
+/// (i.e., an array, a set, or a map).
+/// The client's implementation for the default behavior should be as follows:
+/// 1. The command doesn't accept key name arguments: the client can aggregate all replies within a single nested data structure.
+/// For example, the array replies we get from calling [`keys`](crate::commands::GenericCommands::keys) against all shards.
+/// These should be packed in a single in no particular order.
+/// 2. For commands that accept one or more key name arguments: the client needs to retain the same order of replies as the input key names.
+/// For example, [`mget`](crate::commands::StringCommands::mget)'s aggregated reply.
+#[derive(Debug, Clone, Deserialize)]
+pub enum ResponsePolicy {
+    /// the clients should return success if at least one shard didn't reply with an error.
+    ///
+    /// The client should reply with the first non-error reply it obtains.
+    /// If all shards return an error, the client can reply with any one of these.
+    /// For example, consider a [`script_kill`](crate::commands::ScriptingCommands::script_kill) command that's sent to all shards.
+    /// Although the script should be loaded in all of the cluster's shards,
+    /// the [`script_kill`](crate::commands::ScriptingCommands::script_kill) will typically run only on one at a given time.
+    OneSucceeded,
+    /// the client should return successfully only if there are no error replies.
+    ///
+    /// Even a single error reply should disqualify the aggregate and be returned.
+    /// Otherwise, the client should return one of the non-error replies.
+    /// As an example, consider the [`config_set`](crate::commands::ServerCommands::config_set),
+    /// [`script_flush`](crate::commands::ScriptingCommands::script_flush) and
+    /// [`script_load`](crate::commands::ScriptingCommands::script_load) commands.
+    AllSucceeded,
+    /// the client should return the result of a logical `AND` operation on all replies
+    /// (only applies to integer replies, usually from commands that return either 0 or 1).
+    ///
+    /// Consider the [`script_exists`](crate::commands::ScriptingCommands::script_exists) command as an example.
+    /// It returns an array of 0's and 1's that denote the existence of its given SHA1 sums in the script cache.
+    /// The aggregated response should be 1 only when all shards had reported that a given script SHA1 sum is in their respective cache.
+    AggLogicalAnd,
+    /// the client should return the result of a logical `OR` operation on all replies
+    /// (only applies to integer replies, usually from commands that return either 0 or 1).
+    AggLogicalOr,
+    /// the client should return the minimal value from the replies (only applies to numerical replies).
+    ///
+    /// The aggregate reply from a cluster-wide [`wait`](crate::commands::ServerCommands::wait) command, for example,
+    /// should be the minimal value (number of synchronized replicas) from all shards
+    AggMin,
+    /// the client should return the maximal value from the replies (only applies to numerical replies).
+    AggMax,
+    /// the client should return the sum of replies (only applies to numerical replies).
+    ///
+    /// Example: [`dbsize`](crate::commands::ServerCommands::dbsize).
+    AggSum,
+    /// this type of tip indicates a non-trivial form of reply policy.
+    ///
+    /// [`info`](crate::commands::ServerCommands::info) is an excellent example of that.
+    Special,
+}
+
+impl FromStr for ResponsePolicy {
+    type Err = Error;
+
+    fn from_str(str: &str) -> Result<Self> {
+        match str {
+            "one_succeeded" => Ok(ResponsePolicy::OneSucceeded),
+            "all_succeeded" => Ok(ResponsePolicy::AllSucceeded),
+            "agg_logical_and" => Ok(ResponsePolicy::AggLogicalAnd),
+            "agg_logical_or" => Ok(ResponsePolicy::AggLogicalOr),
+            "agg_min" => Ok(ResponsePolicy::AggMin),
+            "agg_max" => Ok(ResponsePolicy::AggMax),
+            "agg_sum" => Ok(ResponsePolicy::AggSum),
+            "special" => Ok(ResponsePolicy::Special),
+            _ => Err(Error::Client(
+                "Cannot parse ResponsePolicy from result".to_owned(),
+            )),
+        }
+    }
+}
+
+/// Key specifications of a command for the [`command`](CommandCommands::command) command.
+#[derive(Debug, Clone, Deserialize)]
+pub struct KeySpecification {
+    pub begin_search: BeginSearch,
+    pub find_keys: FindKeys,
+    pub flags: Vec<String>,
+    #[serde(default)]
+    pub notes: String,
+}
+
+/// The BeginSearch value of a specification informs
+/// the client of the extraction's beginning
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", content = "spec")]
+#[serde(rename_all = "lowercase")]
+pub enum BeginSearch {
+    #[serde(deserialize_with = "deserialize_begin_search_idx")]
+    Index(usize),
+    Keyword {
+        keyword: String,
+        #[serde(rename = "startfrom")]
+        start_from: isize,
+    },
+    #[serde(deserialize_with = "deserialize_begin_search_unknown")]
+    Unknown,
+}
+
+fn deserialize_begin_search_idx<'de, D>(deserializer: D) -> std::result::Result<usize, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let map = HashMap::<String, usize>::deserialize(deserializer)?;
+    let index = map
+        .get("index")
+        .ok_or_else(|| de::Error::custom("Cannot parse BeginSearch index"))?;
+    Ok(*index)
+}
+
+fn deserialize_begin_search_unknown<'de, D>(deserializer: D) -> std::result::Result<(), D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let map = HashMap::<String, ()>::deserialize(deserializer)?;
+    assert!(map.is_empty());
+    Ok(())
+}
+
+/// The FindKeys value of a key specification tells the client
+/// how to continue the search for key names.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", content = "spec")]
+#[serde(rename_all = "lowercase")]
+pub enum FindKeys {
+    Range {
+        #[serde(rename = "lastkey")]
+        last_key: isize,
+        #[serde(rename = "keystep")]
+        key_step: usize,
+        limit: usize,
+    },
+    KeyNum {
+        #[serde(rename = "keynumidx")]
+        key_num_idx: usize,
+        #[serde(rename = "firstkey")]
+        first_key: usize,
+        #[serde(rename = "keystep")]
+        key_step: usize,
+    },
+    Unknown {},
+}
+
+/// Command doc result for the [`command_docs`](CommandCommands::command_docs) command
+#[derive(Debug, Default, Deserialize)]
+pub struct CommandDoc {
+    /// short command description.
+    pub summary: String,
+    /// the Redis version that added the command (or for module commands, the module version).
+    pub since: String,
+    /// he functional group to which the command belongs.
+    pub group: String,
+    /// a short explanation about the command's time complexity.
+    pub complexity: String,
+    /// an array of documentation flags. Possible values are:
+    /// - `deprecated`: the command is deprecated.
+    /// - `syscmd`: a system command that isn't meant to be called by users.
+    #[serde(default)]
+    pub doc_flags: Vec<CommandDocFlag>,
+    /// the Redis version that deprecated the command (or for module commands, the module version).
+    #[serde(default)]
+    pub deprecated_since: String,
+    /// the alternative for a deprecated command.
+    #[serde(default)]
+    pub replaced_by: String,
+    /// an array of historical notes describing changes to the command's behavior or arguments.
+    #[serde(default)]
+    pub history: Vec<HistoricalNote>,
+    /// an array of [`command arguments`](https://redis.io/docs/reference/command-arguments/)
+    pub arguments: Vec<CommandArgument>,
+}
+
+/// Command documenation flag
+#[derive(Debug, Deserialize)]
+pub enum CommandDocFlag {
+    /// the command is deprecated.
+    Deprecated,
+    /// a system command that isn't meant to be called by users.
+    SystemCommand,
+}
+
+/// Sub-result for the [`command_docs`](CommandCommands::command_docs) command
+#[derive(Debug, Deserialize)]
+pub struct HistoricalNote {
+    pub version: String,
+    pub description: String,
+}
+
+/// [`command argument`](https://redis.io/docs/reference/command-arguments/)
+#[derive(Debug, Deserialize)]
+pub struct CommandArgument {
+    ///  the argument's name, always present.
+    pub name: String,
+    /// the argument's display string, present in arguments that have a displayable representation
+    #[serde(default)]
+    pub display_text: String,
+    ///  the argument's type, always present.
+    #[serde(rename = "type")]
+    pub type_: CommandArgumentType,
+    /// this value is available for every argument of the `key` type.
+    /// t is a 0-based index of the specification in the command's [`key specifications`](https://redis.io/topics/key-specs)
+    /// that corresponds to the argument.
+    #[serde(default)]
+    pub key_spec_index: usize,
+    /// a constant literal that precedes the argument (user input) itself.
+    #[serde(default)]
+    pub token: String,
+    /// a short description of the argument.
+    #[serde(default)]
+    pub summary: String,
+    /// the debut Redis version of the argument (or for module commands, the module version).
+    #[serde(default)]
+    pub since: String,
+    /// the Redis version that deprecated the command (or for module commands, the module version).
+    #[serde(default)]
+    pub deprecated_since: String,
+    /// an array of argument flags.
+    #[serde(default)]
+    pub flags: Vec<ArgumentFlag>,
+    /// the argument's value.
+    #[serde(default)]
+    pub value: Vec<String>,
+}
+
+/// An argument must have one of the following types:
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CommandArgumentType {
+    /// a string argument.
+    String,
+    /// an integer argument.
+    Integer,
+    /// a double-precision argument.
+    Double,
+    /// a string that represents the name of a key.
+    Key,
+    /// a string that represents a glob-like pattern.
+    Pattern,
+    /// an integer that represents a Unix timestamp.
+    UnixTime,
+    /// a token, i.e. a reserved keyword, which may or may not be provided.
+    /// Not to be confused with free-text user input.
+    PureToken,
+    /// the argument is a container for nested arguments.
+    /// This type enables choice among several nested arguments
+    Oneof,
+    /// the argument is a container for nested arguments.
+    /// This type enables grouping arguments and applying a property (such as optional) to all
+    Block,
+}
+
+/// Flag for a command argument
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ArgumentFlag {
+    /// denotes that the argument is optional (for example, the GET clause of the SET command).
+    Optional,
+    /// denotes that the argument may be repeated (such as the key argument of DEL).
+    Multiple,
+    ///  denotes the possible repetition of the argument with its preceding token (see SORT's GET pattern clause).
+    MultipleToken,
+}
+
+/// Options for the [`command_list`](CommandCommands::command_list) command.
+#[derive(Default)]
+pub struct CommandListOptions {
+    command_args: CommandArgs,
+}
+
+impl CommandListOptions {
+    /// get the commands that belong to the module specified by `module-name`.
+    #[must_use]
+    pub fn filter_by_module_name<M: SingleArg>(mut self, module_name: M) -> Self {
+        Self {
+            command_args: self
+                .command_args
+                .arg("FILTERBY")
+                .arg("MODULE")
+                .arg(module_name)
+                .build(),
+        }
+    }
+
+    /// get the commands in the [`ACL category`](https://redis.io/docs/manual/security/acl/#command-categories) specified by `category`.
+    #[must_use]
+    pub fn filter_by_acl_category<C: SingleArg>(mut self, category: C) -> Self {
+        Self {
+            command_args: self
+                .command_args
+                .arg("FILTERBY")
+                .arg("ACLCAT")
+                .arg(category)
+                .build(),
+        }
+    }
+
+    /// get the commands that match the given glob-like `pattern`.
+    #[must_use]
+    pub fn filter_by_pattern<P: SingleArg>(mut self, pattern: P) -> Self {
+        Self {
+            command_args: self
+                .command_args
+                .arg("FILTERBY")
+                .arg("PATTERN")
+                .arg(pattern)
+                .build(),
+        }
+    }
+}
+
+impl ToArgs for CommandListOptions {
+    fn write_args(&self, args: &mut CommandArgs) {
+        args.arg(&self.command_args);
+    }
+}
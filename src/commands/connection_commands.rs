@@ -139,7 +139,10 @@ pub trait ConnectionCommands<'a> {
         prepare_command(self, cmd("CLIENT").arg("LIST").arg(options))
     }
 
-    ///  sets the [`client eviction`](https://redis.io/docs/reference/clients/#client-eviction) mode for the current connection.
+    /// Sets the [`client eviction`](https://redis.io/docs/reference/clients/#client-eviction) mode for the current connection.
+    ///
+    /// The mode is a connection-level state: on a [`Client`](crate::client::Client),
+    /// it applies to all the clones sharing the same connection.
     ///
     /// # See Also
     /// [<https://redis.io/commands/client-no-evict/>](https://redis.io/commands/client-no-evict/)
@@ -156,9 +159,41 @@ pub trait ConnectionCommands<'a> {
         )
     }
 
+    /// Controls whether commands sent by the current connection alter the LRU/LFU of the keys they access.
+    ///
+    /// When `ON`, the keys accessed by the connection are not touched,
+    /// except by the [`touch`](crate::commands::GenericCommands::touch) command.
+    ///
+    /// The mode is a connection-level state: on a [`Client`](crate::client::Client),
+    /// it applies to all the clones sharing the same connection.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/client-no-touch/>](https://redis.io/commands/client-no-touch/)
+    #[must_use]
+    fn client_no_touch(self, no_touch: bool) -> PreparedCommand<'a, Self, ()>
+    where
+        Self: Sized,
+    {
+        prepare_command(
+            self,
+            cmd("CLIENT")
+                .arg("NO-TOUCH")
+                .arg(if no_touch { "ON" } else { "OFF" }),
+        )
+    }
+
     /// Connections control command able to suspend all the Redis clients
     /// for the specified amount of time (in milliseconds).
     ///
+    /// The pause can be ended before the timeout with [`client_unpause`](ConnectionCommands::client_unpause).
+    ///
+    /// # Remark
+    /// The connection which sends this command is suspended too.
+    /// Because a [`Client`](crate::client::Client) and its clones share a single connection,
+    /// every command sent through this client, including from other tasks,
+    /// is delayed until the end of the pause, or only write commands in
+    /// [`Write`](ClientPauseMode::Write) mode.
+    ///
     /// # See Also
     /// [<https://redis.io/commands/client-pause/>](https://redis.io/commands/client-pause/)
     #[must_use]
@@ -171,6 +206,9 @@ pub trait ConnectionCommands<'a> {
 
     /// Sometimes it can be useful for clients to completely disable replies from the Redis server.
     ///
+    /// Commands sent while replies are disabled must be forgotten,
+    /// see [`next_reply_skip`](crate::client::Client::next_reply_skip) to skip the reply of a single command.
+    ///
     /// # See Also
     /// [<https://redis.io/commands/client-reply/>](https://redis.io/commands/client-reply/)
     #[must_use]
@@ -194,6 +232,25 @@ pub trait ConnectionCommands<'a> {
         prepare_command(self, cmd("CLIENT").arg("SETNAME").arg(connection_name))
     }
 
+    /// Assigns various info attributes to the current connection
+    /// which are displayed in the output of [`client_list`](ConnectionCommands::client_list)
+    /// and [`client_info`](ConnectionCommands::client_info).
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/client-setinfo/>](https://redis.io/commands/client-setinfo/)
+    #[must_use]
+    fn client_setinfo<V>(
+        self,
+        attribute: ClientInfoAttribute,
+        value: V,
+    ) -> PreparedCommand<'a, Self, ()>
+    where
+        Self: Sized,
+        V: SingleArg,
+    {
+        prepare_command(self, cmd("CLIENT").arg("SETINFO").arg(attribute).arg(value))
+    }
+
     /// This command enables the tracking feature of the Redis server,
     /// that is used for [`server assisted client side caching`](https://redis.io/topics/client-side-caching).
     ///
@@ -277,6 +334,13 @@ pub trait ConnectionCommands<'a> {
     /// optionally authenticating and setting the connection's name,
     /// or provide a contextual client report.
     ///
+    /// # Return
+    /// The server handshake information: server name, version, protocol, connection id,
+    /// mode, role and loaded modules.
+    ///
+    /// Calling `hello` with [`HelloOptions::default()`](HelloOptions::default) does not
+    /// switch the protocol and can be used to inspect the server capabilities at any time.
+    ///
     /// # See Also
     /// [<https://redis.io/commands/hello/>](https://redis.io/commands/hello/)
     #[must_use]
@@ -315,6 +379,9 @@ pub trait ConnectionCommands<'a> {
     /// This command performs a full reset of the connection's server-side context,
     /// mimicking the effect of disconnecting and reconnecting again.
     ///
+    /// The client forgets its subscriptions as well:
+    /// existing [`PubSubStream`](crate::client::PubSubStream)s stop receiving messages.
+    ///
     /// # See Also
     /// [<https://redis.io/commands/reset/>](https://redis.io/commands/reset/)
     #[must_use]
@@ -327,6 +394,9 @@ pub trait ConnectionCommands<'a> {
 
     /// Select the Redis logical database having the specified zero-based numeric index.
     ///
+    /// The selected database is selected again when the client reconnects,
+    /// before the pending commands are sent.
+    ///
     /// # See Also
     /// [<https://redis.io/commands/reset/>](https://redis.io/commands/reset/)
     #[must_use]
@@ -727,6 +797,23 @@ impl ToArgs for ClientReplyMode {
     }
 }
 
+/// Attribute for the [`client_setinfo`](ConnectionCommands::client_setinfo) command.
+pub enum ClientInfoAttribute {
+    /// Name of the client library
+    LibName,
+    /// Version of the client library
+    LibVer,
+}
+
+impl ToArgs for ClientInfoAttribute {
+    fn write_args(&self, args: &mut CommandArgs) {
+        args.arg(match self {
+            ClientInfoAttribute::LibName => "LIB-NAME",
+            ClientInfoAttribute::LibVer => "LIB-VER",
+        });
+    }
+}
+
 /// Status options for the [`client_tracking`](ConnectionCommands::client_tracking) command.
 pub enum ClientTrackingStatus {
     On,
@@ -837,6 +924,9 @@ impl ToArgs for ClientUnblockMode {
 }
 
 /// Options for the [`hello`](ConnectionCommands::hello) command.
+///
+/// The default options send `HELLO` without any protocol version,
+/// which returns the handshake information of the current connection.
 #[derive(Default)]
 pub struct HelloOptions {
     command_args: CommandArgs,
@@ -884,19 +974,39 @@ impl ToArgs for HelloOptions {
 }
 
 /// Result for the [`hello`](ConnectionCommands::hello) command
-#[derive(Deserialize)]
+#[derive(Debug, Deserialize)]
 pub struct HelloResult {
+    /// Server name (`redis`)
     pub server: String,
+    /// Server version
     pub version: String,
+    /// Protocol version used by the connection
     pub proto: usize,
+    /// Connection id
     pub id: i64,
+    /// Server mode (`standalone`, `sentinel` or `cluster`)
     pub mode: String,
+    /// Replication role (`master` or `replica`)
     #[serde(default)]
     pub role: String,
+    /// Modules loaded by the server
     #[serde(default)]
     pub modules: Vec<ModuleInfo>,
 }
 
+impl HelloResult {
+    /// Check if a module has been loaded by the server
+    ///
+    /// # Example
+    /// `hello_result.has_module("ReJSON")` before sending [`JsonCommands`](crate::commands::JsonCommands)
+    #[must_use]
+    pub fn has_module(&self, name: &str) -> bool {
+        self.modules
+            .iter()
+            .any(|m| m.name.eq_ignore_ascii_case(name))
+    }
+}
+
 /// Options for the [`ping`](ConnectionCommands::ping) command.
 #[derive(Default)]
 pub struct PingOptions {
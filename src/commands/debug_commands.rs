@@ -0,0 +1,152 @@
+use crate::{
+    client::{prepare_command, PreparedCommand},
+    resp::{cmd, SingleArg},
+    Error, Result,
+};
+use serde::{de, Deserialize, Deserializer};
+use std::{collections::HashMap, str::FromStr};
+
+/// A group of Redis `DEBUG` sub-commands, dedicated to tests and troubleshooting
+///
+/// These commands can block or alter the behavior of the server
+/// and must not be used in production.
+///
+/// # See Also
+/// [DEBUG](https://redis.io/commands/debug/)
+pub trait DebugCommands<'a> {
+    /// Returns low level information about the internal representation of a key.
+    ///
+    /// # Return
+    /// The parsed debug information, see [`DebugObject`](DebugObject)
+    ///
+    /// # Errors
+    /// An error is returned if the key does not exist.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/debug/>](https://redis.io/commands/debug/)
+    #[must_use]
+    fn debug_object<K>(self, key: K) -> PreparedCommand<'a, Self, DebugObject>
+    where
+        Self: Sized,
+        K: SingleArg,
+    {
+        prepare_command(self, cmd("DEBUG").arg("OBJECT").arg(key))
+    }
+
+    /// Sets the size threshold, in bytes, above which a list element
+    /// is stored in its own plain quicklist node instead of a packed one.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/debug/>](https://redis.io/commands/debug/)
+    #[must_use]
+    fn debug_quicklist_packed_threshold(self, threshold: usize) -> PreparedCommand<'a, Self, ()>
+    where
+        Self: Sized,
+    {
+        prepare_command(
+            self,
+            cmd("DEBUG")
+                .arg("QUICKLIST-PACKED-THRESHOLD")
+                .arg(threshold),
+        )
+    }
+
+    /// Enables or disables the active expiration cycle of keys with a time to live.
+    ///
+    /// When disabled, keys are only expired lazily, when accessed.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/debug/>](https://redis.io/commands/debug/)
+    #[must_use]
+    fn debug_set_active_expire(self, enabled: bool) -> PreparedCommand<'a, Self, ()>
+    where
+        Self: Sized,
+    {
+        prepare_command(
+            self,
+            cmd("DEBUG")
+                .arg("SET-ACTIVE-EXPIRE")
+                .arg(if enabled { 1 } else { 0 }),
+        )
+    }
+
+    /// Blocks the server for the given number of `seconds`, fractions of a second allowed.
+    ///
+    /// All the clients of the server are blocked during this time.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/debug/>](https://redis.io/commands/debug/)
+    #[must_use]
+    fn debug_sleep(self, seconds: f64) -> PreparedCommand<'a, Self, ()>
+    where
+        Self: Sized,
+    {
+        prepare_command(self, cmd("DEBUG").arg("SLEEP").arg(seconds))
+    }
+}
+
+/// Result for the [`debug_object`](DebugCommands::debug_object) command.
+#[derive(Debug)]
+pub struct DebugObject {
+    /// memory address of the value
+    pub address: String,
+    /// number of references to the value
+    pub refcount: usize,
+    /// internal encoding of the value (`embstr`, `listpack`, `quicklist`, ...)
+    pub encoding: String,
+    /// length of the value once serialized in a RDB file
+    pub serialized_length: usize,
+    /// LRU clock of the last access to the value
+    pub lru: u64,
+    /// number of seconds since the last access to the value
+    pub lru_seconds_idle: u64,
+    /// additional fields depending on the encoding (e.g. `ql_nodes` for quicklists)
+    pub additional_fields: HashMap<String, String>,
+}
+
+impl DebugObject {
+    /// Parses the status line replied by `DEBUG OBJECT`
+    ///
+    /// Missing fields keep their default value,
+    /// but a field which cannot be parsed is an error.
+    pub(crate) fn from_line(line: &str) -> Result<DebugObject> {
+        // e.g. "Value at:0x7f2b refcount:1 encoding:embstr serializedlength:6 lru:1 lru_seconds_idle:0"
+        let mut values: HashMap<String, String> = line
+            .split_whitespace()
+            .filter_map(|kvp| kvp.split_once(':'))
+            .map(|(key, value)| (key.to_owned(), value.to_owned()))
+            .collect();
+
+        fn number<T: FromStr + Default>(
+            values: &mut HashMap<String, String>,
+            field: &str,
+        ) -> Result<T> {
+            match values.remove(field) {
+                Some(value) => value.parse::<T>().map_err(|_| {
+                    Error::Client(format!("Cannot parse DEBUG OBJECT field {field}: {value}"))
+                }),
+                None => Ok(T::default()),
+            }
+        }
+
+        Ok(DebugObject {
+            address: values.remove("at").unwrap_or_default(),
+            refcount: number(&mut values, "refcount")?,
+            encoding: values.remove("encoding").unwrap_or_default(),
+            serialized_length: number(&mut values, "serializedlength")?,
+            lru: number(&mut values, "lru")?,
+            lru_seconds_idle: number(&mut values, "lru_seconds_idle")?,
+            additional_fields: values,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for DebugObject {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let line = <&str>::deserialize(deserializer)?;
+        DebugObject::from_line(line).map_err(de::Error::custom)
+    }
+}
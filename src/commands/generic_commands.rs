@@ -5,7 +5,7 @@ use crate::{
         SingleArgCollection, ToArgs,
     },
 };
-use serde::{de::DeserializeOwned, Deserialize};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer};
 
 /// A group of generic Redis commands
 ///
@@ -61,13 +61,16 @@ pub trait GenericCommands<'a> {
 
     /// Serialize the value stored at key in a Redis-specific format and return it to the user.
     ///
+    /// The serialized value is an opaque binary payload which can be passed
+    /// to [`restore`](GenericCommands::restore), e.g. to migrate a key between instances.
+    ///
     /// # Return
-    /// The serialized value.
+    /// The serialized value, or `None` if `key` does not exist.
     ///
     /// # See Also
     /// [<https://redis.io/commands/dump/>](https://redis.io/commands/dump/)
     #[must_use]
-    fn dump<K>(self, key: K) -> PreparedCommand<'a, Self, DumpResult>
+    fn dump<K>(self, key: K) -> PreparedCommand<'a, Self, Option<DumpResult>>
     where
         Self: Sized,
         K: SingleArg,
@@ -146,14 +149,14 @@ pub trait GenericCommands<'a> {
     /// Returns the absolute Unix timestamp (since January 1, 1970) in seconds at which the given key will expire.
     ///
     /// # Return
-    /// Expiration Unix timestamp in seconds, or a negative value in order to signal an error (see the description below).
-    /// - The command returns -1 if the key exists but has no associated expiration time.
-    /// - The command returns -2 if the key does not exist.
+    /// * [`ExpireTime::Timestamp`](ExpireTime::Timestamp) - the expiration Unix timestamp in seconds
+    /// * [`ExpireTime::NoExpiry`](ExpireTime::NoExpiry) - if the key exists but has no associated expiration time.
+    /// * [`ExpireTime::KeyNotFound`](ExpireTime::KeyNotFound) - if the key does not exist.
     ///
     /// # See Also
     /// [<https://redis.io/commands/expiretime/>](https://redis.io/commands/expiretime/)
     #[must_use]
-    fn expiretime<K>(self, key: K) -> PreparedCommand<'a, Self, i64>
+    fn expiretime<K>(self, key: K) -> PreparedCommand<'a, Self, ExpireTime>
     where
         Self: Sized,
         K: SingleArg,
@@ -218,12 +221,13 @@ pub trait GenericCommands<'a> {
     ///
     /// # Return
     /// * `true` - if key was moved.
-    /// * `false` - f key was not moved.
+    /// * `false` - if key was not moved, e.g. key does not exist,
+    ///   or it already exists in the destination database.
     ///
     /// # See Also
     /// [<https://redis.io/commands/move/>](https://redis.io/commands/move/)
     #[must_use]
-    fn move_<K>(self, key: K, db: usize) -> PreparedCommand<'a, Self, i64>
+    fn move_key<K>(self, key: K, db: usize) -> PreparedCommand<'a, Self, bool>
     where
         Self: Sized,
         K: SingleArg,
@@ -231,10 +235,25 @@ pub trait GenericCommands<'a> {
         prepare_command(self, cmd("MOVE").arg(key).arg(db))
     }
 
+    /// Move key from the currently selected database to the specified destination database.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/move/>](https://redis.io/commands/move/)
+    #[deprecated(note = "use `GenericCommands::move_key` instead")]
+    #[must_use]
+    fn move_<K>(self, key: K, db: usize) -> PreparedCommand<'a, Self, bool>
+    where
+        Self: Sized,
+        K: SingleArg,
+    {
+        self.move_key(key, db)
+    }
+
     /// Returns the internal encoding for the Redis object stored at `key`
     ///
     /// # Return
-    /// The encoding of the object, or nil if the key doesn't exist
+    /// The encoding of the object, or nil if the key doesn't exist.
+    /// Use [`ObjectEncoding`](ObjectEncoding) (or `Option<ObjectEncoding>`) as a typed response.
     ///
     /// # See Also
     /// [<https://redis.io/commands/object-encoding/>](https://redis.io/commands/object-encoding/)
@@ -370,14 +389,14 @@ pub trait GenericCommands<'a> {
     /// but returns the absolute Unix expiration timestamp in milliseconds instead of seconds.
     ///
     /// # Return
-    ///  Expiration Unix timestamp in milliseconds, or a negative value in order to signal an error (see the description below).
-    /// - The command returns -1 if the key exists but has no associated expiration time.
-    /// - The command returns -2 if the key does not exist.
+    /// * [`ExpireTime::Timestamp`](ExpireTime::Timestamp) - the expiration Unix timestamp in milliseconds
+    /// * [`ExpireTime::NoExpiry`](ExpireTime::NoExpiry) - if the key exists but has no associated expiration time.
+    /// * [`ExpireTime::KeyNotFound`](ExpireTime::KeyNotFound) - if the key does not exist.
     ///
     /// # See Also
     /// [<https://redis.io/commands/pexpiretime/>](https://redis.io/commands/pexpiretime/)
     #[must_use]
-    fn pexpiretime<K>(self, key: K) -> PreparedCommand<'a, Self, i64>
+    fn pexpiretime<K>(self, key: K) -> PreparedCommand<'a, Self, ExpireTime>
     where
         Self: Sized,
         K: SingleArg,
@@ -406,7 +425,8 @@ pub trait GenericCommands<'a> {
     /// Return a random key from the currently selected database.
     ///
     /// # Return
-    /// The number of references.
+    /// A random key, or nil when the database is empty
+    /// (use an `Option<R>` response type to handle this case).
     ///
     /// # See Also
     /// [<https://redis.io/commands/randomkey/>](https://redis.io/commands/randomkey/)
@@ -452,10 +472,15 @@ pub trait GenericCommands<'a> {
     }
 
     /// Create a key associated with a value that is obtained by deserializing
-    /// the provided serialized value (obtained via DUMP).
+    /// the provided serialized value (obtained via [`dump`](GenericCommands::dump)).
     ///
-    /// # Return
-    /// Restore command builder
+    /// `ttl` is in milliseconds, `0` means that the key is created without any expire.
+    /// With [`RestoreOptions::abs_ttl`](RestoreOptions::abs_ttl), `ttl` is an absolute
+    /// Unix timestamp in milliseconds.
+    ///
+    /// # Errors
+    /// A `BUSYKEY` error is returned if `key` already exists,
+    /// unless [`RestoreOptions::replace`](RestoreOptions::replace) is used.
     ///
     /// # See Also
     /// [<https://redis.io/commands/restore/>](https://redis.io/commands/restore/)
@@ -566,10 +591,11 @@ pub trait GenericCommands<'a> {
         prepare_command(self, cmd("SORT_RO").arg(key).arg(options))
     }
 
-    /// Alters the last access time of a key(s). A key is ignored if it does not exist.
+    /// Alters the last access time of a key(s), as used by the LRU and LFU eviction policies.
+    /// A key is ignored if it does not exist.
     ///
     /// # Return
-    /// The number of keys that were touched.
+    /// The number of keys that were touched, i.e. the number of existing keys.
     ///
     /// # See Also
     /// [<https://redis.io/commands/touch/>](https://redis.io/commands/touch/)
@@ -606,7 +632,7 @@ pub trait GenericCommands<'a> {
     /// The different types that can be returned are: string, list, set, zset, hash and stream.
     ///
     /// # Return
-    /// type of key, or empty string when key does not exist.
+    /// type of key, or `none` when key does not exist.
     ///
     /// # See Also
     /// [<https://redis.io/commands/type/>](https://redis.io/commands/type/)
@@ -619,41 +645,47 @@ pub trait GenericCommands<'a> {
         prepare_command(self, cmd("TYPE").arg(key))
     }
 
-    /// This command is very similar to DEL: it removes the specified keys.
+    /// Returns the type of the value stored at key.
+    ///
+    /// Same as [`type_`](GenericCommands::type_) with a typed response.
     ///
     /// # Return
-    /// The number of keys that were unlinked.
+    /// type of key, or [`RedisType::None`](RedisType::None) when key does not exist.
     ///
     /// # See Also
-    /// [<https://redis.io/commands/unlink/>](https://redis.io/commands/unlink/)
+    /// [<https://redis.io/commands/type/>](https://redis.io/commands/type/)
     #[must_use]
-    fn unlink<K, C>(self, keys: C) -> PreparedCommand<'a, Self, usize>
+    fn key_type<K>(self, key: K) -> PreparedCommand<'a, Self, RedisType>
     where
         Self: Sized,
         K: SingleArg,
-        C: SingleArgCollection<K>,
     {
-        prepare_command(self, cmd("UNLINK").arg(keys))
+        prepare_command(self, cmd("TYPE").arg(key))
     }
 
-    /// This command blocks the current client until all the previous write commands are
-    /// successfully transferred and acknowledged by at least the specified number of replicas.
+    /// This command is very similar to DEL: it removes the specified keys.
     ///
     /// # Return
-    /// The number of replicas reached by all the writes performed in the context of the current connection.
+    /// The number of keys that were unlinked.
     ///
     /// # See Also
-    /// [<https://redis.io/commands/wait/>](https://redis.io/commands/wait/)
+    /// [<https://redis.io/commands/unlink/>](https://redis.io/commands/unlink/)
     #[must_use]
-    fn wait(self, num_replicas: usize, timeout: u64) -> PreparedCommand<'a, Self, usize>
+    fn unlink<K, C>(self, keys: C) -> PreparedCommand<'a, Self, usize>
     where
         Self: Sized,
+        K: SingleArg,
+        C: SingleArgCollection<K>,
     {
-        prepare_command(self, cmd("WAIT").arg(num_replicas).arg(timeout))
+        prepare_command(self, cmd("UNLINK").arg(keys))
     }
 }
 
-/// Options for the [`expire`](GenericCommands::expire) command
+/// Options for the [`expire`](GenericCommands::expire), [`pexpire`](GenericCommands::pexpire),
+/// [`expireat`](GenericCommands::expireat) and [`pexpireat`](GenericCommands::pexpireat) commands
+/// and their hash field counterparts, e.g. [`hexpire`](crate::commands::HashCommands::hexpire)
+///
+/// A non-existent expiry is considered as an infinite TTL by the `Gt` and `Lt` options.
 #[derive(Default)]
 pub enum ExpireOption {
     /// No option
@@ -661,7 +693,7 @@ pub enum ExpireOption {
     None,
     /// Set expiry only when the key has no expiry
     Nx,
-    /// Set expiry only when the key has no expiry    
+    /// Set expiry only when the key has an existing expiry
     Xx,
     /// Set expiry only when the new expiry is greater than current one
     Gt,
@@ -750,6 +782,7 @@ pub struct RestoreOptions {
 }
 
 impl RestoreOptions {
+    /// Replace the key if it already exists
     #[must_use]
     pub fn replace(mut self) -> Self {
         Self {
@@ -757,6 +790,7 @@ impl RestoreOptions {
         }
     }
 
+    /// `ttl` represents an absolute Unix timestamp in milliseconds
     #[must_use]
     pub fn abs_ttl(mut self) -> Self {
         Self {
@@ -764,6 +798,7 @@ impl RestoreOptions {
         }
     }
 
+    /// Idle time of the restored key, in seconds, used by LRU eviction policies
     #[must_use]
     pub fn idle_time(mut self, idle_time: i64) -> Self {
         Self {
@@ -771,6 +806,7 @@ impl RestoreOptions {
         }
     }
 
+    /// Access frequency of the restored key, used by LFU eviction policies
     #[must_use]
     pub fn frequency(mut self, frequency: f64) -> Self {
         Self {
@@ -800,13 +836,32 @@ impl ToArgs for SortOrder {
     }
 }
 
-/// Options for the [`sort`](GenericCommands::sort) command
+/// Options for the [`sort`](GenericCommands::sort), [`sort_and_store`](GenericCommands::sort_and_store)
+/// and [`sort_readonly`](GenericCommands::sort_readonly) commands
+///
+/// # Example
+/// Sort the ids of a list by the weights stored in `weight_<id>` keys,
+/// and return each id with its `name` field stored in the `object_<id>` hash:
+/// ```
+/// # use rustis::commands::SortOptions;
+/// let options = SortOptions::default()
+///     .by("weight_*")
+///     .get("#")
+///     .get("object_*->name")
+///     .limit(0, 10);
+/// ```
+/// The reply is flattened: the values of the `GET` patterns of each element
+/// follow each other, e.g. `[id1, name1, id2, name2]` for the example above.
 #[derive(Default)]
 pub struct SortOptions {
     command_args: CommandArgs,
 }
 
 impl SortOptions {
+    /// Sort by the values of external keys, e.g. `weight_*`,
+    /// where `*` is replaced by each element to sort.
+    ///
+    /// `nosort` skips the sort, `->` gets a field of a hash, e.g. `object_*->weight`
     #[must_use]
     pub fn by<P: SingleArg>(mut self, pattern: P) -> Self {
         Self {
@@ -826,6 +881,11 @@ impl SortOptions {
         }
     }
 
+    /// Return the values of external keys instead of the sorted elements,
+    /// e.g. `data_*`, where `*` is replaced by each sorted element.
+    ///
+    /// Can be called multiple times to get several values for each element.
+    /// `#` returns the element itself, `->` gets a field of a hash, e.g. `object_*->name`
     #[must_use]
     pub fn get<P: SingleArg>(mut self, pattern: P) -> Self {
         Self {
@@ -854,7 +914,7 @@ impl ToArgs for SortOptions {
     }
 }
 
-/// Result for the [`dump`](GenericCommands::dump) command.
+/// Result for the [`dump`](GenericCommands::dump) command: the opaque serialized value.
 #[derive(Deserialize)]
 pub struct DumpResult(#[serde(deserialize_with = "deserialize_byte_buf")] pub Vec<u8>);
 
@@ -879,6 +939,8 @@ impl ScanOptions {
         }
     }
 
+    /// Only returns keys of the given type,
+    /// usually a [`RedisType`](RedisType) or the name of a module type
     #[must_use]
     pub fn type_<TY: SingleArg>(mut self, type_: TY) -> Self {
         Self {
@@ -893,6 +955,92 @@ impl ToArgs for ScanOptions {
     }
 }
 
+/// Type of the value stored at a key
+///
+/// Response of the [`key_type`](GenericCommands::key_type) command.
+/// Can also be used as a filter of the [`scan`](GenericCommands::scan) command,
+/// see [`ScanOptions::type_`](ScanOptions::type_)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RedisType {
+    /// The key does not exist
+    None,
+    String,
+    List,
+    Set,
+    ZSet,
+    Hash,
+    Stream,
+    /// Any other type, e.g. a module type like `ReJSON-RL`
+    Other(String),
+}
+
+impl PrimitiveResponse for RedisType {}
+
+impl<'de> Deserialize<'de> for RedisType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let type_ = String::deserialize(deserializer)?;
+        Ok(match type_.as_str() {
+            "none" => Self::None,
+            "string" => Self::String,
+            "list" => Self::List,
+            "set" => Self::Set,
+            "zset" => Self::ZSet,
+            "hash" => Self::Hash,
+            "stream" => Self::Stream,
+            _ => Self::Other(type_),
+        })
+    }
+}
+
+impl SingleArg for RedisType {}
+
+impl ToArgs for RedisType {
+    fn write_args(&self, args: &mut CommandArgs) {
+        args.arg(match self {
+            RedisType::String => "string",
+            RedisType::List => "list",
+            RedisType::Set => "set",
+            RedisType::ZSet => "zset",
+            RedisType::Hash => "hash",
+            RedisType::Stream => "stream",
+            RedisType::None => "none",
+            RedisType::Other(type_) => type_.as_str(),
+        });
+    }
+}
+
+/// Absolute expiration time of a key
+///
+/// Response of the [`expiretime`](GenericCommands::expiretime)
+/// and [`pexpiretime`](GenericCommands::pexpiretime) commands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpireTime {
+    /// Expiration Unix timestamp, in seconds for `EXPIRETIME`, in milliseconds for `PEXPIRETIME`
+    Timestamp(i64),
+    /// The key exists but has no associated expiration time (reply -1)
+    NoExpiry,
+    /// The key does not exist (reply -2)
+    KeyNotFound,
+}
+
+impl PrimitiveResponse for ExpireTime {}
+
+impl<'de> Deserialize<'de> for ExpireTime {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(match i64::deserialize(deserializer)? {
+            -1 => Self::NoExpiry,
+            -2 => Self::KeyNotFound,
+            timestamp => Self::Timestamp(timestamp),
+        })
+    }
+}
+
 /// Result for the [`migrate`](GenericCommands::migrate) command
 #[derive(Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
@@ -902,3 +1050,56 @@ pub enum MigrateResult {
     /// no keys were found in the source instance.
     NoKey,
 }
+
+/// Internal encoding of a Redis object
+///
+/// Response of the [`object_encoding`](GenericCommands::object_encoding) command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ObjectEncoding {
+    /// String encoded as a 64 bit signed integer
+    Int,
+    /// Small string embedded in the object
+    EmbStr,
+    /// Raw string
+    Raw,
+    /// Space efficient encoding of small lists, sets, hashes and sorted sets
+    Listpack,
+    /// Linked list of listpacks
+    Quicklist,
+    /// Hash table of sets and hashes
+    Hashtable,
+    /// Skip list of sorted sets
+    Skiplist,
+    /// Set of integers
+    Intset,
+    /// Space efficient encoding of small lists, hashes and sorted sets (Redis < 7.0)
+    Ziplist,
+    /// Stream
+    Stream,
+    /// Any other encoding
+    Other(String),
+}
+
+impl PrimitiveResponse for ObjectEncoding {}
+
+impl<'de> Deserialize<'de> for ObjectEncoding {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let encoding = String::deserialize(deserializer)?;
+        Ok(match encoding.as_str() {
+            "int" => Self::Int,
+            "embstr" => Self::EmbStr,
+            "raw" => Self::Raw,
+            "listpack" => Self::Listpack,
+            "quicklist" => Self::Quicklist,
+            "hashtable" => Self::Hashtable,
+            "skiplist" => Self::Skiplist,
+            "intset" => Self::Intset,
+            "ziplist" => Self::Ziplist,
+            "stream" => Self::Stream,
+            _ => Self::Other(encoding),
+        })
+    }
+}
@@ -126,9 +126,12 @@ pub trait GeoCommands<'a> {
     /// Return the members of a sorted set populated with geospatial information using [geoadd](GeoCommands::geoadd),
     /// which are within the borders of the area specified by a given shape.
     ///
+    /// This command replaces the deprecated `GEORADIUS` and `GEORADIUSBYMEMBER` commands.
+    ///
     /// # Return
     /// An array of members + additional information depending
-    /// on which `with_xyz` options have been selected
+    /// on which `with_xyz` options have been selected:
+    /// the optional fields of [`GeoSearchResult`] are only filled for the requested options.
     ///
     /// # See Also
     /// [<https://redis.io/commands/geosearch/>](https://redis.io/commands/geosearch/)
@@ -431,6 +434,28 @@ where
                         Ok(GeoSearchResultField::GeoHash(v))
                     }
 
+                    // RESP3 servers may reply the distance as a double
+                    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E>
+                    where
+                        E: de::Error,
+                    {
+                        Ok(GeoSearchResultField::Distance(v))
+                    }
+
+                    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+                    where
+                        E: de::Error,
+                    {
+                        let Ok(distance) = v.parse::<f64>() else {
+                            return Err(de::Error::invalid_value(
+                                Unexpected::Str(v),
+                                &"A valid f64",
+                            ));
+                        };
+
+                        Ok(GeoSearchResultField::Distance(distance))
+                    }
+
                     fn visit_seq<A>(self, seq: A) -> Result<Self::Value, A::Error>
                     where
                         A: de::SeqAccess<'de>,
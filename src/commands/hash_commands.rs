@@ -1,11 +1,12 @@
 use crate::{
     client::{prepare_command, PreparedCommand},
+    commands::ExpireOption,
     resp::{
         cmd, deserialize_vec_of_pairs, CollectionResponse, CommandArgs, KeyValueArgsCollection,
         KeyValueCollectionResponse, PrimitiveResponse, SingleArg, SingleArgCollection, ToArgs,
     },
 };
-use serde::{de::DeserializeOwned, Deserialize};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer};
 
 /// A group of Redis commands related to [`Hashes`](https://redis.io/docs/data-types/hashes/)
 ///
@@ -48,10 +49,111 @@ pub trait HashCommands<'a> {
         prepare_command(self, cmd("HEXISTS").arg(key).arg(field))
     }
 
+    /// Set a timeout in seconds on one or more fields of the hash stored at `key` (since Redis 7.4)
+    ///
+    /// A timeout of 0 deletes the fields.
+    ///
+    /// # Return
+    /// One [`HExpireResult`](HExpireResult) per field, in the order of the requested fields.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/hexpire/>](https://redis.io/commands/hexpire/)
+    #[must_use]
+    fn hexpire<K, F, C>(
+        self,
+        key: K,
+        seconds: u64,
+        option: ExpireOption,
+        fields: C,
+    ) -> PreparedCommand<'a, Self, Vec<HExpireResult>>
+    where
+        Self: Sized,
+        K: SingleArg,
+        F: SingleArg,
+        C: SingleArgCollection<F>,
+    {
+        prepare_command(
+            self,
+            cmd("HEXPIRE")
+                .arg(key)
+                .arg(seconds)
+                .arg(option)
+                .arg("FIELDS")
+                .arg(fields.num_args())
+                .arg(fields),
+        )
+    }
+
+    /// HEXPIREAT has the same effect and semantic as HEXPIRE,
+    /// but instead of specifying the number of seconds representing the TTL (time to live),
+    /// it takes an absolute Unix timestamp (seconds since January 1, 1970)
+    ///
+    /// A timestamp in the past deletes the fields.
+    ///
+    /// # Return
+    /// One [`HExpireResult`](HExpireResult) per field, in the order of the requested fields.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/hexpireat/>](https://redis.io/commands/hexpireat/)
+    #[must_use]
+    fn hexpireat<K, F, C>(
+        self,
+        key: K,
+        unix_time_seconds: u64,
+        option: ExpireOption,
+        fields: C,
+    ) -> PreparedCommand<'a, Self, Vec<HExpireResult>>
+    where
+        Self: Sized,
+        K: SingleArg,
+        F: SingleArg,
+        C: SingleArgCollection<F>,
+    {
+        prepare_command(
+            self,
+            cmd("HEXPIREAT")
+                .arg(key)
+                .arg(unix_time_seconds)
+                .arg(option)
+                .arg("FIELDS")
+                .arg(fields.num_args())
+                .arg(fields),
+        )
+    }
+
+    /// Returns the absolute Unix timestamp in seconds at which the given fields of the hash stored at `key` will expire (since Redis 7.4)
+    ///
+    /// # Return
+    /// One integer per field, in the order of the requested fields:
+    /// * the expiration Unix timestamp, in seconds
+    /// * `-1` - if the field exists but has no associated expiration
+    /// * `-2` - if the field or the key does not exist
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/hexpiretime/>](https://redis.io/commands/hexpiretime/)
+    #[must_use]
+    fn hexpiretime<K, F, C>(self, key: K, fields: C) -> PreparedCommand<'a, Self, Vec<i64>>
+    where
+        Self: Sized,
+        K: SingleArg,
+        F: SingleArg,
+        C: SingleArgCollection<F>,
+    {
+        prepare_command(
+            self,
+            cmd("HEXPIRETIME")
+                .arg(key)
+                .arg("FIELDS")
+                .arg(fields.num_args())
+                .arg(fields),
+        )
+    }
+
     /// Returns the value associated with field in the hash stored at key.
     ///
     /// # Return
-    /// The value associated with field, or nil when field is not present in the hash or key does not exist.
+    /// The value associated with field, or nil when field is not present in the hash or key does not exist
+    /// (use an `Option<V>` response type to handle this case).
     ///
     /// # See Also
     /// [<https://redis.io/commands/hget/>](https://redis.io/commands/hget/)
@@ -174,6 +276,157 @@ pub trait HashCommands<'a> {
         prepare_command(self, cmd("HMGET").arg(key).arg(fields))
     }
 
+    /// Remove the existing expiration on the given fields of the hash stored at `key` (since Redis 7.4)
+    ///
+    /// # Return
+    /// One integer per field, in the order of the requested fields:
+    /// * `1` - if the expiration was removed
+    /// * `-1` - if the field exists but has no associated expiration
+    /// * `-2` - if the field or the key does not exist
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/hpersist/>](https://redis.io/commands/hpersist/)
+    #[must_use]
+    fn hpersist<K, F, C>(self, key: K, fields: C) -> PreparedCommand<'a, Self, Vec<i64>>
+    where
+        Self: Sized,
+        K: SingleArg,
+        F: SingleArg,
+        C: SingleArgCollection<F>,
+    {
+        prepare_command(
+            self,
+            cmd("HPERSIST")
+                .arg(key)
+                .arg("FIELDS")
+                .arg(fields.num_args())
+                .arg(fields),
+        )
+    }
+
+    /// This command works exactly like HEXPIRE but the time to live of the fields is specified in milliseconds instead of seconds.
+    ///
+    /// # Return
+    /// One [`HExpireResult`](HExpireResult) per field, in the order of the requested fields.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/hpexpire/>](https://redis.io/commands/hpexpire/)
+    #[must_use]
+    fn hpexpire<K, F, C>(
+        self,
+        key: K,
+        milliseconds: u64,
+        option: ExpireOption,
+        fields: C,
+    ) -> PreparedCommand<'a, Self, Vec<HExpireResult>>
+    where
+        Self: Sized,
+        K: SingleArg,
+        F: SingleArg,
+        C: SingleArgCollection<F>,
+    {
+        prepare_command(
+            self,
+            cmd("HPEXPIRE")
+                .arg(key)
+                .arg(milliseconds)
+                .arg(option)
+                .arg("FIELDS")
+                .arg(fields.num_args())
+                .arg(fields),
+        )
+    }
+
+    /// HPEXPIREAT has the same effect and semantic as HEXPIREAT,
+    /// but the Unix time at which the fields will expire is specified in milliseconds instead of seconds.
+    ///
+    /// # Return
+    /// One [`HExpireResult`](HExpireResult) per field, in the order of the requested fields.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/hpexpireat/>](https://redis.io/commands/hpexpireat/)
+    #[must_use]
+    fn hpexpireat<K, F, C>(
+        self,
+        key: K,
+        unix_time_milliseconds: u64,
+        option: ExpireOption,
+        fields: C,
+    ) -> PreparedCommand<'a, Self, Vec<HExpireResult>>
+    where
+        Self: Sized,
+        K: SingleArg,
+        F: SingleArg,
+        C: SingleArgCollection<F>,
+    {
+        prepare_command(
+            self,
+            cmd("HPEXPIREAT")
+                .arg(key)
+                .arg(unix_time_milliseconds)
+                .arg(option)
+                .arg("FIELDS")
+                .arg(fields.num_args())
+                .arg(fields),
+        )
+    }
+
+    /// HPEXPIRETIME has the same semantic as HEXPIRETIME, but returns the absolute Unix expiration timestamp in milliseconds instead of seconds.
+    ///
+    /// # Return
+    /// One integer per field, in the order of the requested fields:
+    /// * the expiration Unix timestamp, in milliseconds
+    /// * `-1` - if the field exists but has no associated expiration
+    /// * `-2` - if the field or the key does not exist
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/hpexpiretime/>](https://redis.io/commands/hpexpiretime/)
+    #[must_use]
+    fn hpexpiretime<K, F, C>(self, key: K, fields: C) -> PreparedCommand<'a, Self, Vec<i64>>
+    where
+        Self: Sized,
+        K: SingleArg,
+        F: SingleArg,
+        C: SingleArgCollection<F>,
+    {
+        prepare_command(
+            self,
+            cmd("HPEXPIRETIME")
+                .arg(key)
+                .arg("FIELDS")
+                .arg(fields.num_args())
+                .arg(fields),
+        )
+    }
+
+    /// Like HTTL, this command returns the remaining TTL (time to live) of the given fields, but in milliseconds instead of seconds.
+    ///
+    /// # Return
+    /// One integer per field, in the order of the requested fields:
+    /// * the remaining time to live, in milliseconds
+    /// * `-1` - if the field exists but has no associated expiration
+    /// * `-2` - if the field or the key does not exist
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/hpttl/>](https://redis.io/commands/hpttl/)
+    #[must_use]
+    fn hpttl<K, F, C>(self, key: K, fields: C) -> PreparedCommand<'a, Self, Vec<i64>>
+    where
+        Self: Sized,
+        K: SingleArg,
+        F: SingleArg,
+        C: SingleArgCollection<F>,
+    {
+        prepare_command(
+            self,
+            cmd("HPTTL")
+                .arg(key)
+                .arg("FIELDS")
+                .arg(fields.num_args())
+                .arg(fields),
+        )
+    }
+
     /// return random fields from the hash value stored at key.
     ///
     /// # Return
@@ -322,6 +575,34 @@ pub trait HashCommands<'a> {
         prepare_command(self, cmd("HSTRLEN").arg(key).arg(field))
     }
 
+    /// Returns the remaining TTL (time to live) of the given fields of the hash stored at `key` (since Redis 7.4)
+    ///
+    /// # Return
+    /// One integer per field, in the order of the requested fields:
+    /// * the remaining time to live, in seconds
+    /// * `-1` - if the field exists but has no associated expiration
+    /// * `-2` - if the field or the key does not exist
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/httl/>](https://redis.io/commands/httl/)
+    #[must_use]
+    fn httl<K, F, C>(self, key: K, fields: C) -> PreparedCommand<'a, Self, Vec<i64>>
+    where
+        Self: Sized,
+        K: SingleArg,
+        F: SingleArg,
+        C: SingleArgCollection<F>,
+    {
+        prepare_command(
+            self,
+            cmd("HTTL")
+                .arg(key)
+                .arg("FIELDS")
+                .arg(fields.num_args())
+                .arg(fields),
+        )
+    }
+
     /// list of values in the hash, or an empty list when key does not exist.
     ///
     /// # Return
@@ -341,6 +622,39 @@ pub trait HashCommands<'a> {
     }
 }
 
+/// Per-field result of the [`hexpire`](HashCommands::hexpire), [`hpexpire`](HashCommands::hpexpire),
+/// [`hexpireat`](HashCommands::hexpireat) and [`hpexpireat`](HashCommands::hpexpireat) commands
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HExpireResult {
+    /// The field or the key does not exist (reply -2)
+    NoSuchField,
+    /// The expiration was not set because of the [`ExpireOption`](ExpireOption) condition (reply 0)
+    ConditionNotMet,
+    /// The expiration was set or updated (reply 1)
+    Set,
+    /// The field was deleted because of a 0 TTL or a past timestamp (reply 2)
+    Deleted,
+}
+
+impl PrimitiveResponse for HExpireResult {}
+
+impl<'de> Deserialize<'de> for HExpireResult {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match i64::deserialize(deserializer)? {
+            -2 => Ok(Self::NoSuchField),
+            0 => Ok(Self::ConditionNotMet),
+            1 => Ok(Self::Set),
+            2 => Ok(Self::Deleted),
+            code => Err(serde::de::Error::custom(format!(
+                "Unexpected hash field expiration result: {code}"
+            ))),
+        }
+    }
+}
+
 /// Options for the [`hscan`](HashCommands::hscan) command
 #[derive(Default)]
 pub struct HScanOptions {
@@ -15,7 +15,8 @@ pub trait ListCommands<'a> {
     /// Returns the element at index index in the list stored at key.
     ///
     /// # Return
-    /// The requested element, or nil when index is out of range.
+    /// The requested element, or nil when index is out of range
+    /// (use an `Option<E>` response type to handle this case).
     ///
     /// # See Also
     /// [<https://redis.io/commands/lindex/>](https://redis.io/commands/lindex/)
@@ -75,8 +76,11 @@ pub trait ListCommands<'a> {
     /// of the list stored at source, and pushes the element at the first/last element
     /// (head/tail depending on the whereto argument) of the list stored at destination.
     ///
+    /// `LMOVE` replaces the deprecated `RPOPLPUSH` command (`lmove(source, destination, Right, Left)`).
+    ///
     /// # Return
-    /// The element being popped and pushed.
+    /// The element being popped and pushed,
+    /// or nil if `source` does not exist (use an `Option<E>` response type to handle this case).
     ///
     /// # See Also
     /// [<https://redis.io/commands/lmove/>](https://redis.io/commands/lmove/)
@@ -107,7 +111,8 @@ pub trait ListCommands<'a> {
     /// Pops one or more elements from the first non-empty list key from the list of provided key names.
     ///
     /// # Return
-    /// Tuple composed by the name of the key from which elements were popped and the list of popped element
+    /// - `None` when no element could be popped (all the keys are empty or do not exist)
+    /// - Tuple composed by the name of the key from which elements were popped and the list of popped element
     ///
     /// # See Also
     /// [<https://redis.io/commands/lmpop/>](https://redis.io/commands/lmpop/)
@@ -117,7 +122,7 @@ pub trait ListCommands<'a> {
         keys: C,
         where_: LMoveWhere,
         count: usize,
-    ) -> PreparedCommand<'a, Self, (String, Vec<E>)>
+    ) -> PreparedCommand<'a, Self, Option<(String, Vec<E>)>>
     where
         Self: Sized,
         K: SingleArg,
@@ -165,25 +170,19 @@ pub trait ListCommands<'a> {
         self,
         key: K,
         element: E,
-        rank: Option<usize>,
-        max_len: Option<usize>,
+        options: LPosOptions,
     ) -> PreparedCommand<'a, Self, Option<usize>>
     where
         Self: Sized,
         K: SingleArg,
         E: SingleArg,
     {
-        prepare_command(
-            self,
-            cmd("LPOS")
-                .arg(key)
-                .arg(element)
-                .arg(rank.map(|r| ("RANK", r)))
-                .arg(max_len.map(|l| ("MAXLEN", l))),
-        )
+        prepare_command(self, cmd("LPOS").arg(key).arg(element).arg(options))
     }
 
-    /// Returns the index of matching elements inside a Redis list.
+    /// Returns the indexes of up to `num_matches` matching elements inside a Redis list.
+    ///
+    /// A `num_matches` of `0` returns all the matching elements.
     ///
     /// # Return
     /// An array of integers representing the matching elements.
@@ -192,13 +191,12 @@ pub trait ListCommands<'a> {
     /// # See Also
     /// [<https://redis.io/commands/lpos/>](https://redis.io/commands/lpos/)
     #[must_use]
-    fn lpos_with_count<K, E, A>(
+    fn lpos_count<K, E, A>(
         self,
         key: K,
         element: E,
         num_matches: usize,
-        rank: Option<usize>,
-        max_len: Option<usize>,
+        options: LPosOptions,
     ) -> PreparedCommand<'a, Self, A>
     where
         Self: Sized,
@@ -211,13 +209,43 @@ pub trait ListCommands<'a> {
             cmd("LPOS")
                 .arg(key)
                 .arg(element)
-                .arg(rank.map(|r| ("RANK", r)))
                 .arg("COUNT")
                 .arg(num_matches)
-                .arg(max_len.map(|l| ("MAXLEN", l))),
+                .arg(options),
         )
     }
 
+    /// Returns the index of matching elements inside a Redis list.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/lpos/>](https://redis.io/commands/lpos/)
+    #[deprecated(note = "use `ListCommands::lpos_count` instead")]
+    #[must_use]
+    fn lpos_with_count<K, E, A>(
+        self,
+        key: K,
+        element: E,
+        num_matches: usize,
+        rank: Option<usize>,
+        max_len: Option<usize>,
+    ) -> PreparedCommand<'a, Self, A>
+    where
+        Self: Sized,
+        K: SingleArg,
+        E: SingleArg,
+        A: CollectionResponse<usize>,
+    {
+        let mut options = LPosOptions::default();
+        if let Some(rank) = rank {
+            options = options.rank(rank as isize);
+        }
+        if let Some(max_len) = max_len {
+            options = options.max_len(max_len);
+        }
+
+        self.lpos_count(key, element, num_matches, options)
+    }
+
     /// Insert all the specified values at the head of the list stored at key
     ///
     /// # Return
@@ -402,3 +430,36 @@ impl ToArgs for LMoveWhere {
         });
     }
 }
+
+/// Options for the [`lpos`](ListCommands::lpos)
+/// and [`lpos_count`](ListCommands::lpos_count) commands
+#[derive(Default)]
+pub struct LPosOptions {
+    command_args: CommandArgs,
+}
+
+impl LPosOptions {
+    /// Skip the first `rank - 1` matches.
+    ///
+    /// A negative rank searches the list from the tail to the head.
+    #[must_use]
+    pub fn rank(mut self, rank: isize) -> Self {
+        Self {
+            command_args: self.command_args.arg("RANK").arg(rank).build(),
+        }
+    }
+
+    /// Compare the element only with the first `max_len` elements of the list
+    #[must_use]
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        Self {
+            command_args: self.command_args.arg("MAXLEN").arg(max_len).build(),
+        }
+    }
+}
+
+impl ToArgs for LPosOptions {
+    fn write_args(&self, args: &mut CommandArgs) {
+        args.arg(&self.command_args);
+    }
+}
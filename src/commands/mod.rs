@@ -14,7 +14,10 @@ These is the list of existing command traits:
 * [`BlockingCommands`](BlockingCommands): Commands that block the connection until the Redis server
   has a new element to send. This trait is implemented only by the [`Client`](crate::client::Client) struct.
 * [`ClusterCommands`](ClusterCommands): [Redis cluster](https://redis.io/docs/reference/cluster-spec/)
+* [`CommandCommands`](CommandCommands): Introspection of the server commands like key positions or documentation
 * [`ConnectionCommands`](ConnectionCommands): Connection management like authentication or RESP version management
+* [`DebugCommands`](DebugCommands): [`DEBUG`](https://redis.io/commands/debug/) sub-commands for tests & troubleshooting.
+  This trait requires the `debug-commands` feature.
 * [`GenericCommands`](GenericCommands): Generic commands like deleting, renaming or expiring keys
 * [`GeoCommands`](GeoCommands): [Geospatial](https://redis.io/docs/data-types/geospatial/) indices
 * [`HashCommands`](HashCommands): [Hashes](https://redis.io/docs/data-types/hashes/)
@@ -88,6 +91,7 @@ mod blocking_commands;
 #[cfg(feature = "redis-bloom")]
 mod bloom_commands;
 mod cluster_commands;
+mod command_commands;
 mod connection_commands;
 #[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
 #[cfg(feature = "redis-bloom")]
@@ -95,6 +99,9 @@ mod count_min_sktech_commands;
 #[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
 #[cfg(feature = "redis-bloom")]
 mod cuckoo_commands;
+#[cfg_attr(docsrs, doc(cfg(feature = "debug-commands")))]
+#[cfg(feature = "debug-commands")]
+mod debug_commands;
 mod generic_commands;
 mod geo_commands;
 #[cfg_attr(docsrs, doc(cfg(feature = "redis-graph")))]
@@ -141,6 +148,7 @@ pub use blocking_commands::*;
 #[cfg(feature = "redis-bloom")]
 pub use bloom_commands::*;
 pub use cluster_commands::*;
+pub use command_commands::*;
 pub use connection_commands::*;
 #[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
 #[cfg(feature = "redis-bloom")]
@@ -148,6 +156,9 @@ pub use count_min_sktech_commands::*;
 #[cfg_attr(docsrs, doc(cfg(feature = "redis-bloom")))]
 #[cfg(feature = "redis-bloom")]
 pub use cuckoo_commands::*;
+#[cfg_attr(docsrs, doc(cfg(feature = "debug-commands")))]
+#[cfg(feature = "debug-commands")]
+pub use debug_commands::*;
 pub use generic_commands::*;
 pub use geo_commands::*;
 #[cfg_attr(docsrs, doc(cfg(feature = "redis-graph")))]
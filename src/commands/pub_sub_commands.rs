@@ -1,5 +1,5 @@
 use crate::{
-    client::{prepare_command, PreparedCommand, PubSubStream},
+    client::{prepare_command, PreparedCommand, PubSubEventStream, PubSubStream},
     resp::{
         cmd, CollectionResponse, CommandArgs, KeyValueCollectionResponse, PrimitiveResponse,
         SingleArg, SingleArgCollection, ToArgs,
@@ -165,6 +165,8 @@ pub trait PubSubCommands<'a> {
 
     /// Posts a message to the given shard channel.
     ///
+    /// In cluster mode, the message is sent to the master node owning the hash slot of `shardchannel`.
+    ///
     /// # Return
     /// The number of clients that received the message.
     ///
@@ -179,10 +181,13 @@ pub trait PubSubCommands<'a> {
         prepare_command(self, cmd("SPUBLISH").arg(shardchannel).arg(message))
     }
 
-    /// Subscribes the client to the specified channels.
+    /// Subscribes the client to the specified shard channels.
+    ///
+    /// In cluster mode, each shard channel is subscribed on the master node
+    /// owning its hash slot, with one `SSUBSCRIBE` command per hash slot.
     ///
     /// # See Also
-    /// [<https://redis.io/commands/subscribe/>](https://redis.io/commands/subscribe/)
+    /// [<https://redis.io/commands/ssubscribe/>](https://redis.io/commands/ssubscribe/)
     fn ssubscribe<C, CC>(self, shardchannels: CC) -> Future<'a, PubSubStream>
     where
         C: SingleArg + Send + 'a,
@@ -228,6 +233,21 @@ pub trait PubSubCommands<'a> {
     where
         C: SingleArg + Send + 'a,
         CC: SingleArgCollection<C>;
+
+    /// Subscribes the client to the specified channels,
+    /// streaming subscription confirmations along with the messages.
+    ///
+    /// Useful to know exactly when a subscription is active,
+    /// e.g. before instructing a publisher to send messages.
+    ///
+    /// See [`PubSubEventStream`](crate::client::PubSubEventStream)
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/subscribe/>](https://redis.io/commands/subscribe/)
+    fn subscribe_with_events<C, CC>(self, channels: CC) -> Future<'a, PubSubEventStream>
+    where
+        C: SingleArg + Send + 'a,
+        CC: SingleArgCollection<C>;
 }
 
 /// Options for the [`pub_sub_channels`](PubSubCommands::pub_sub_channels) command
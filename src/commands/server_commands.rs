@@ -10,7 +10,7 @@ use serde::{
     de::{self, DeserializeOwned, SeqAccess, Visitor},
     Deserialize, Deserializer,
 };
-use std::{collections::HashMap, fmt, str::FromStr};
+use std::{collections::HashMap, fmt, str::FromStr, time::Duration};
 
 /// A group of Redis commands related to Server Management
 /// # See Also
@@ -109,7 +109,8 @@ pub trait ServerCommands<'a> {
     /// The command returns all the rules defined for an existing ACL user.
     ///
     /// # Return
-    /// A collection of ACL rule definitions for the user.
+    /// A collection of ACL rule definitions for the user,
+    /// or an [`AclUser`](AclUser) instance (`Option<AclUser>` if the user may not exist).
     ///
     /// # See Also
     /// [<https://redis.io/commands/acl-getuser/>](https://redis.io/commands/acl-getuser/)
@@ -242,117 +243,6 @@ pub trait ServerCommands<'a> {
         prepare_command(self, cmd("ACL").arg("WHOAMI"))
     }
 
-    /// Return an array with details about every Redis command.
-    ///
-    /// # Return
-    /// A nested list of command details.
-    /// The order of commands in the array is random.
-    ///
-    /// # See Also
-    /// [<https://redis.io/commands/command/>](https://redis.io/commands/command/)
-    fn command(self) -> PreparedCommand<'a, Self, Vec<CommandInfo>>
-    where
-        Self: Sized,
-    {
-        prepare_command(self, cmd("COMMAND"))
-    }
-
-    /// Number of total commands in this Redis server.
-    ///
-    /// # Return
-    /// number of commands returned by [`command`](ServerCommands::command)
-    ///
-    /// # See Also
-    /// [<https://redis.io/commands/command-count/>](https://redis.io/commands/command-count/)
-    fn command_count(self) -> PreparedCommand<'a, Self, usize>
-    where
-        Self: Sized,
-    {
-        prepare_command(self, cmd("COMMAND").arg("COUNT"))
-    }
-
-    /// Number of total commands in this Redis server.
-    ///
-    /// # Return
-    /// map key=command name, value=command doc
-    ///
-    /// # See Also
-    /// [<https://redis.io/commands/command-docs/>](https://redis.io/commands/command-docs/)
-    fn command_docs<N, NN, DD>(self, command_names: NN) -> PreparedCommand<'a, Self, DD>
-    where
-        Self: Sized,
-        N: SingleArg,
-        NN: SingleArgCollection<N>,
-        DD: KeyValueCollectionResponse<String, CommandDoc>,
-    {
-        prepare_command(self, cmd("COMMAND").arg("DOCS").arg(command_names))
-    }
-
-    /// A helper command to let you find the keys from a full Redis command.
-    ///
-    /// # Return
-    /// list of keys from your command.
-    ///
-    /// # See Also
-    /// [<https://redis.io/commands/command-_getkeys/>](https://redis.io/commands/command-_getkeys/)
-    fn command_getkeys<A, AA, KK>(self, args: AA) -> PreparedCommand<'a, Self, KK>
-    where
-        Self: Sized,
-        A: SingleArg,
-        AA: SingleArgCollection<A>,
-        KK: CollectionResponse<String>,
-    {
-        prepare_command(self, cmd("COMMAND").arg("GETKEYS").arg(args))
-    }
-
-    /// A helper command to let you find the keys from a full Redis command together with flags indicating what each key is used for.
-    ///
-    /// # Return
-    /// map of keys with their flags from your command.
-    ///
-    /// # See Also
-    /// [<https://redis.io/commands/command-getkeysandflags/>](https://redis.io/commands/command-getkeysandflags/)
-    fn command_getkeysandflags<A, AA, KK>(self, args: AA) -> PreparedCommand<'a, Self, KK>
-    where
-        Self: Sized,
-        A: SingleArg,
-        AA: SingleArgCollection<A>,
-        KK: KeyValueCollectionResponse<String, Vec<String>>,
-    {
-        prepare_command(self, cmd("COMMAND").arg("GETKEYSANDFLAGS").arg(args))
-    }
-
-    /// Return an array with details about multiple Redis command.
-    ///
-    /// # Return
-    /// A nested list of command details.
-    ///
-    /// # See Also
-    /// [<https://redis.io/commands/command-info/>](https://redis.io/commands/command-info/)
-    fn command_info<N, NN>(self, command_names: NN) -> PreparedCommand<'a, Self, Vec<CommandInfo>>
-    where
-        Self: Sized,
-        N: SingleArg,
-        NN: SingleArgCollection<N>,
-    {
-        prepare_command(self, cmd("COMMAND").arg("INFO").arg(command_names))
-    }
-
-    /// Return an array of the server's command names based on optional filters
-    ///
-    /// # Return
-    /// an array of the server's command names.
-    ///
-    /// # See Also
-    /// [<https://redis.io/commands/command-list/>](https://redis.io/commands/command-list/)
-    fn command_list<CC>(self, options: CommandListOptions) -> PreparedCommand<'a, Self, CC>
-    where
-        Self: Sized,
-        CC: CollectionResponse<String>,
-    {
-        prepare_command(self, cmd("COMMAND").arg("LIST").arg(options))
-    }
-
     /// Used to read the configuration parameters of a running Redis server.
     ///
     /// For every key that does not hold a string value or does not exist,
@@ -469,10 +359,14 @@ pub trait ServerCommands<'a> {
     /// This command returns information and statistics about the server
     /// in a format that is simple to parse by computers and easy to read by humans.
     ///
+    /// # Return
+    /// A [`ServerInfo`](ServerInfo) with the typed fields of the requested sections.
+    /// Fields unknown to this client are kept as raw strings.
+    ///
     /// # See Also
     /// [<https://redis.io/commands/info/>](https://redis.io/commands/info/)
     #[must_use]
-    fn info<SS>(self, sections: SS) -> PreparedCommand<'a, Self, String>
+    fn info<SS>(self, sections: SS) -> PreparedCommand<'a, Self, ServerInfo>
     where
         Self: Sized,
         SS: SingleArgCollection<InfoSection>,
@@ -563,14 +457,9 @@ pub trait ServerCommands<'a> {
     ///
     /// # Return
     /// A collection of the latest latency events logged.
-    /// Each reported event has the following fields:
-    /// - Event name.
-    /// - Unix timestamp of the latest latency spike for the event.
-    /// - Latest event latency in millisecond.
-    /// - All-time maximum latency for this event.
     ///
-    /// "All-time" means the maximum latency since the Redis instance was started,
-    /// or the time that events were [`reset`](crate::commands::ConnectionCommands::reset).
+    /// "All-time" maximum latency means the maximum latency since the Redis instance was started,
+    /// or the time that events were [`reset`](ServerCommands::latency_reset).
     ///
     /// # See Also
     /// [<https://redis.io/commands/latency-latest/>](https://redis.io/commands/latency-latest/)
@@ -578,7 +467,7 @@ pub trait ServerCommands<'a> {
     fn latency_latest<RR>(self) -> PreparedCommand<'a, Self, RR>
     where
         Self: Sized,
-        RR: CollectionResponse<(String, u32, u32, u32)>,
+        RR: CollectionResponse<LatencyEvent>,
     {
         prepare_command(self, cmd("LATENCY").arg("LATEST"))
     }
@@ -589,7 +478,7 @@ pub trait ServerCommands<'a> {
     /// the number of event time series that were reset.
     ///
     /// # See Also
-    /// [<https://redis.io/commands/latency-latest/>](https://redis.io/commands/latency-latest/)
+    /// [<https://redis.io/commands/latency-reset/>](https://redis.io/commands/latency-reset/)
     #[must_use]
     fn latency_reset<EE>(self, events: EE) -> PreparedCommand<'a, Self, usize>
     where
@@ -661,7 +550,8 @@ pub trait ServerCommands<'a> {
     /// This command returns information about the memory usage of the server.
     ///
     /// # Return
-    /// the memory allocator's internal statistics report.
+    /// the memory usage statistics of the server,
+    /// see [`MemoryStats`](MemoryStats).
     ///
     /// # See Also
     /// [<https://redis.io/commands/memory-stats/>](https://redis.io/commands/memory-stats/)
@@ -751,6 +641,10 @@ pub trait ServerCommands<'a> {
     /// Provide information on the role of a Redis instance in the context of replication,
     /// by returning if the instance is currently a `master`, `slave`, or `sentinel`.
     ///
+    /// # Return
+    /// The role of the instance with its replication details,
+    /// depending on the role (see [`RoleResult`](RoleResult)).
+    ///
     /// # See Also
     /// [<https://redis.io/commands/role/>](https://redis.io/commands/role/)
     #[must_use]
@@ -848,6 +742,76 @@ pub trait ServerCommands<'a> {
     {
         prepare_command(self, cmd("TIME"))
     }
+
+    /// This command blocks the current client until all the previous write commands are
+    /// successfully transferred and acknowledged by at least the specified number of replicas.
+    ///
+    /// # Arguments
+    /// * `num_replicas` - The number of replicas to wait for.
+    /// * `timeout` - Timeout with a millisecond precision, [`Duration::ZERO`](Duration::ZERO) means to block forever.
+    ///
+    /// # Return
+    /// The number of replicas reached by all the writes performed in the context of the current connection.
+    ///
+    /// # Note
+    /// This command is never retried on network error, even if
+    /// [`retry_on_error`](crate::client::Config::retry_on_error) is set:
+    /// after a reconnection, the acknowledgements would not relate to the writes of the previous connection.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/wait/>](https://redis.io/commands/wait/)
+    #[must_use]
+    fn wait(self, num_replicas: usize, timeout: Duration) -> PreparedCommand<'a, Self, usize>
+    where
+        Self: Sized,
+    {
+        prepare_command(
+            self,
+            cmd("WAIT")
+                .arg(num_replicas)
+                .arg(timeout.as_millis() as u64),
+        )
+        .retry_on_error(false)
+    }
+
+    /// This command blocks the current client until all the previous write commands are
+    /// acknowledged as having been fsynced to the AOF of the local Redis
+    /// and/or at least the specified number of replicas.
+    ///
+    /// # Arguments
+    /// * `num_local` - The number of local AOF to wait for (`0` or `1`).
+    /// * `num_replicas` - The number of replicas to wait for.
+    /// * `timeout` - Timeout with a millisecond precision, [`Duration::ZERO`](Duration::ZERO) means to block forever.
+    ///
+    /// # Return
+    /// A tuple of two integers:
+    /// * The number of local Redis (0 or 1) that fsynced to AOF all writes performed in the context of the current connection.
+    /// * The number of replicas that acknowledged doing the same.
+    ///
+    /// # Note
+    /// Like [`wait`](ServerCommands::wait), this command is never retried on network error.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/waitaof/>](https://redis.io/commands/waitaof/)
+    #[must_use]
+    fn waitaof(
+        self,
+        num_local: usize,
+        num_replicas: usize,
+        timeout: Duration,
+    ) -> PreparedCommand<'a, Self, (usize, usize)>
+    where
+        Self: Sized,
+    {
+        prepare_command(
+            self,
+            cmd("WAITAOF")
+                .arg(num_local)
+                .arg(num_replicas)
+                .arg(timeout.as_millis() as u64),
+        )
+        .retry_on_error(false)
+    }
 }
 
 /// Database flushing mode
@@ -979,452 +943,88 @@ impl ToArgs for AclLogOptions {
     }
 }
 
-/// Command info result for the [`command`](ServerCommands::command) command.
-#[derive(Debug, Clone, Deserialize)]
-pub struct CommandInfo {
-    /// This is the command's name in lowercase.
-    pub name: String,
-    /// Arity is the number of arguments a command expects. It follows a simple pattern:
-    /// - A positive integer means a fixed number of arguments.
-    /// - A negative integer means a minimal number of arguments.
-    pub arity: isize,
-    /// Command flags are an array.
-    /// See [COMMAND documentation](https://redis.io/commands/command/) for the list of flags
-    pub flags: Vec<String>,
-    /// The position of the command's first key name argument.
-    /// For most commands, the first key's position is 1. Position 0 is always the command name itself.
-    pub first_key: usize,
-    /// The position of the command's last key name argument.
-    pub last_key: isize,
-    /// The step, or increment, between the first key and the position of the next key.
-    pub step: usize,
-    /// [From Redis 6.0] This is an array of simple strings that are the ACL categories to which the command belongs.
-    pub acl_categories: Vec<String>,
-    /// [From Redis 7.0] Helpful information about the command. To be used by clients/proxies.
-    /// See [<https://redis.io/docs/reference/command-tips/>](https://redis.io/docs/reference/command-tips/)
-    #[serde(default)]
-    pub command_tips: Vec<CommandTip>,
-    /// [From Redis 7.0] This is an array consisting of the command's key specifications.
-    /// See [<https://redis.io/docs/reference/key-specs/>](https://redis.io/docs/reference/key-specs/)
-    #[serde(default)]
-    pub key_specifications: Vec<KeySpecification>,
-    #[serde(default)]
-    pub sub_commands: Vec<CommandInfo>,
-}
-
-/// Get additional information about a command
+/// Result for the [`acl_getuser`](ServerCommands::acl_getuser) command.
 ///
-/// See <https://redis.io/docs/reference/command-tips/>
-#[derive(Debug, Clone)]
-pub enum CommandTip {
-    NonDeterministricOutput,
-    NonDeterministricOutputOrder,
-    RequestPolicy(RequestPolicy),
-    ResponsePolicy(ResponsePolicy),
-}
-
-impl<'de> Deserialize<'de> for CommandTip {
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let tip = <&str>::deserialize(deserializer)?;
-        match tip {
-            "nondeterministic_output" => Ok(CommandTip::NonDeterministricOutput),
-            "nondeterministic_output_order" => Ok(CommandTip::NonDeterministricOutputOrder),
-            _ => {
-                let mut parts = tip.split(':');
-                match (parts.next(), parts.next(), parts.next()) {
-                    (Some("request_policy"), Some(policy), None) => {
-                        match RequestPolicy::from_str(policy) {
-                            Ok(request_policy) => Ok(CommandTip::RequestPolicy(request_policy)),
-                            Err(_) => Err(de::Error::invalid_value(
-                                de::Unexpected::Str(policy),
-                                &"a valid RequestPolicy value",
-                            )),
-                        }
-                    }
-                    (Some("response_policy"), Some(policy), None) => {
-                        match ResponsePolicy::from_str(policy) {
-                            Ok(response_policy) => Ok(CommandTip::ResponsePolicy(response_policy)),
-                            Err(_) => Err(de::Error::invalid_value(
-                                de::Unexpected::Str(policy),
-                                &"a valid ResponsePolicy value",
-                            )),
-                        }
-                    }
-                    _ => Err(de::Error::invalid_value(
-                        de::Unexpected::Str(tip),
-                        &"a valid CommandTip value",
-                    )),
-                }
-            }
-        }
-    }
-}
-
-/// This tip can help clients determine the shards to send the command in clustering mode.
-///
-/// The default behavior a client should implement for commands without the request_policy tip is as follows:
-/// 1. The command doesn't accept key name arguments: the client can execute the command on an arbitrary shard.
-/// 2. For commands that accept one or more key name arguments: the client should route the command to a single shard,
-/// as determined by the hash slot of the input keys.
-#[derive(Debug, Clone, Deserialize)]
-pub enum RequestPolicy {
-    /// the client should execute the command on all nodes - masters and replicas alike.
-    ///
-    /// An example is the [`config_set`](ServerCommands::config_set) command.
-    /// This tip is in-use by commands that don't accept key name arguments. The command operates atomically per shard.
-    AllNodes,
-    /// the client should execute the command on all master shards (e.g., the [`dbsize`](ServerCommands::dbsize) command).
-    ///
-    /// This tip is in-use by commands that don't accept key name arguments. The command operates atomically per shard.
-    AllShards,
-    /// the client should execute the command on several shards.
-    ///
-    /// The shards that execute the command are determined by the hash slots of its input key name arguments.
-    /// Examples for such commands include [`mset`](crate::commands::StringCommands::mset), [`mget`](crate::commands::StringCommands::mget)
-    /// and [`del`](crate::commands::GenericCommands::del).
-    /// However, note that [`sunionstore`](crate::commands::SetCommands::sunionstore) isn't considered
-    /// as multi_shard because all of its keys must belong to the same hash slot.
-    MultiShard,
-    /// indicates a non-trivial form of the client's request policy, such as the [`scan`](crate::commands::GenericCommands::scan) command.
-    Special,
-}
-
-impl FromStr for RequestPolicy {
-    type Err = Error;
-
-    fn from_str(str: &str) -> Result<Self> {
-        match str {
-            "all_nodes" => Ok(RequestPolicy::AllNodes),
-            "all_shards" => Ok(RequestPolicy::AllShards),
-            "multi_shard" => Ok(RequestPolicy::MultiShard),
-            "special" => Ok(RequestPolicy::Special),
-            _ => Err(Error::Client(
-                "Cannot parse RequestPolicy from result".to_owned(),
-            )),
-        }
-    }
-}
-
-/// This tip can help clients determine the aggregate they need to compute from the replies of multiple shards in a cluster.
-///
-/// The default behavior for commands without a request_policy tip only applies to replies with of nested types
-/// (i.e., an array, a set, or a map).
-/// The client's implementation for the default behavior should be as follows:
-/// 1. The command doesn't accept key name arguments: the client can aggregate all replies within a single nested data structure.
-/// For example, the array replies we get from calling [`keys`](crate::commands::GenericCommands::keys) against all shards.
-/// These should be packed in a single in no particular order.
-/// 2. For commands that accept one or more key name arguments: the client needs to retain the same order of replies as the input key names.
-/// For example, [`mget`](crate::commands::StringCommands::mget)'s aggregated reply.
-#[derive(Debug, Clone, Deserialize)]
-pub enum ResponsePolicy {
-    /// the clients should return success if at least one shard didn't reply with an error.
-    ///
-    /// The client should reply with the first non-error reply it obtains.
-    /// If all shards return an error, the client can reply with any one of these.
-    /// For example, consider a [`script_kill`](crate::commands::ScriptingCommands::script_kill) command that's sent to all shards.
-    /// Although the script should be loaded in all of the cluster's shards,
-    /// the [`script_kill`](crate::commands::ScriptingCommands::script_kill) will typically run only on one at a given time.
-    OneSucceeded,
-    /// the client should return successfully only if there are no error replies.
-    ///
-    /// Even a single error reply should disqualify the aggregate and be returned.
-    /// Otherwise, the client should return one of the non-error replies.
-    /// As an example, consider the [`config_set`](ServerCommands::config_set),
-    /// [`script_flush`](crate::commands::ScriptingCommands::script_flush) and
-    /// [`script_load`](crate::commands::ScriptingCommands::script_load) commands.
-    AllSucceeded,
-    /// the client should return the result of a logical `AND` operation on all replies
-    /// (only applies to integer replies, usually from commands that return either 0 or 1).
-    ///
-    /// Consider the [`script_exists`](crate::commands::ScriptingCommands::script_exists) command as an example.
-    /// It returns an array of 0's and 1's that denote the existence of its given SHA1 sums in the script cache.
-    /// The aggregated response should be 1 only when all shards had reported that a given script SHA1 sum is in their respective cache.
-    AggLogicalAnd,
-    /// the client should return the result of a logical `OR` operation on all replies
-    /// (only applies to integer replies, usually from commands that return either 0 or 1).
-    AggLogicalOr,
-    /// the client should return the minimal value from the replies (only applies to numerical replies).
-    ///
-    /// The aggregate reply from a cluster-wide [`wait`](crate::commands::GenericCommands::wait) command, for example,
-    /// should be the minimal value (number of synchronized replicas) from all shards
-    AggMin,
-    /// the client should return the maximal value from the replies (only applies to numerical replies).
-    AggMax,
-    /// the client should return the sum of replies (only applies to numerical replies).
-    ///
-    /// Example: [`dbsize`](ServerCommands::dbsize).
-    AggSum,
-    /// this type of tip indicates a non-trivial form of reply policy.
-    ///
-    /// [`info`](ServerCommands::info) is an excellent example of that.
-    Special,
-}
-
-impl FromStr for ResponsePolicy {
-    type Err = Error;
-
-    fn from_str(str: &str) -> Result<Self> {
-        match str {
-            "one_succeeded" => Ok(ResponsePolicy::OneSucceeded),
-            "all_succeeded" => Ok(ResponsePolicy::AllSucceeded),
-            "agg_logical_and" => Ok(ResponsePolicy::AggLogicalAnd),
-            "agg_logical_or" => Ok(ResponsePolicy::AggLogicalOr),
-            "agg_min" => Ok(ResponsePolicy::AggMin),
-            "agg_max" => Ok(ResponsePolicy::AggMax),
-            "agg_sum" => Ok(ResponsePolicy::AggSum),
-            "special" => Ok(ResponsePolicy::Special),
-            _ => Err(Error::Client(
-                "Cannot parse ResponsePolicy from result".to_owned(),
-            )),
-        }
-    }
-}
-
-/// Key specifications of a command for the [`command`](ServerCommands::command) command.
-#[derive(Debug, Clone, Deserialize)]
-pub struct KeySpecification {
-    pub begin_search: BeginSearch,
-    pub find_keys: FindKeys,
+/// Parsed from the RESP3 map reply as well as from the RESP2 flat array reply.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AclUser {
+    /// Flags of the user, e.g. `on`, `nopass`, `sanitize-payload`
     pub flags: Vec<String>,
+    /// SHA-256 hashes of the passwords of the user
+    pub passwords: Vec<String>,
+    /// Command rules of the user, e.g. `+@all -debug`
+    pub commands: String,
+    /// Key patterns of the user, e.g. `~* %R~read*`
+    #[serde(default, deserialize_with = "deserialize_acl_rules")]
+    pub keys: String,
+    /// Pub/Sub channel patterns of the user, e.g. `&*`
+    #[serde(default, deserialize_with = "deserialize_acl_rules")]
+    pub channels: String,
+    /// [From Redis 7.0] Additional sets of rules of the user
     #[serde(default)]
-    pub notes: String,
+    pub selectors: Vec<AclSelector>,
 }
 
-/// The BeginSearch value of a specification informs
-/// the client of the extraction's beginning
-#[derive(Debug, Clone, Deserialize)]
-#[serde(tag = "type", content = "spec")]
-#[serde(rename_all = "lowercase")]
-pub enum BeginSearch {
-    #[serde(deserialize_with = "deserialize_begin_search_idx")]
-    Index(usize),
-    Keyword {
-        keyword: String,
-        #[serde(rename = "startfrom")]
-        start_from: isize,
-    },
-    #[serde(deserialize_with = "deserialize_begin_search_unknown")]
-    Unknown,
-}
+impl KeyValueCollectionResponse<String, Value> for AclUser {}
+impl KeyValueCollectionResponse<String, Value> for Option<AclUser> {}
 
-fn deserialize_begin_search_idx<'de, D>(deserializer: D) -> std::result::Result<usize, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    let map = HashMap::<String, usize>::deserialize(deserializer)?;
-    let index = map
-        .get("index")
-        .ok_or_else(|| de::Error::custom("Cannot parse BeginSearch index"))?;
-    Ok(*index)
+/// Selector of an [`AclUser`](AclUser): an additional set of rules
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AclSelector {
+    /// Command rules of the selector
+    pub commands: String,
+    /// Key patterns of the selector
+    #[serde(default, deserialize_with = "deserialize_acl_rules")]
+    pub keys: String,
+    /// Pub/Sub channel patterns of the selector
+    #[serde(default, deserialize_with = "deserialize_acl_rules")]
+    pub channels: String,
 }
 
-fn deserialize_begin_search_unknown<'de, D>(deserializer: D) -> std::result::Result<(), D::Error>
+/// Before Redis 7.0, key and channel patterns are returned as an array instead of a single string
+fn deserialize_acl_rules<'de, D>(deserializer: D) -> std::result::Result<String, D::Error>
 where
     D: Deserializer<'de>,
 {
-    let map = HashMap::<String, ()>::deserialize(deserializer)?;
-    assert!(map.is_empty());
-    Ok(())
-}
-
-/// The FindKeys value of a key specification tells the client
-/// how to continue the search for key names.
-#[derive(Debug, Clone, Deserialize)]
-#[serde(tag = "type", content = "spec")]
-#[serde(rename_all = "lowercase")]
-pub enum FindKeys {
-    Range {
-        #[serde(rename = "lastkey")]
-        last_key: isize,
-        #[serde(rename = "keystep")]
-        key_step: usize,
-        limit: usize,
-    },
-    KeyNum {
-        #[serde(rename = "keynumidx")]
-        key_num_idx: usize,
-        #[serde(rename = "firstkey")]
-        first_key: usize,
-        #[serde(rename = "keystep")]
-        key_step: usize,
-    },
-    Unknown {},
-}
-
-/// Command doc result for the [`command_docs`](ServerCommands::command_docs) command
-#[derive(Debug, Default, Deserialize)]
-pub struct CommandDoc {
-    /// short command description.
-    pub summary: String,
-    /// the Redis version that added the command (or for module commands, the module version).
-    pub since: String,
-    /// he functional group to which the command belongs.
-    pub group: String,
-    /// a short explanation about the command's time complexity.
-    pub complexity: String,
-    /// an array of documentation flags. Possible values are:
-    /// - `deprecated`: the command is deprecated.
-    /// - `syscmd`: a system command that isn't meant to be called by users.
-    #[serde(default)]
-    pub doc_flags: Vec<CommandDocFlag>,
-    /// the Redis version that deprecated the command (or for module commands, the module version).
-    #[serde(default)]
-    pub deprecated_since: String,
-    /// the alternative for a deprecated command.
-    #[serde(default)]
-    pub replaced_by: String,
-    /// an array of historical notes describing changes to the command's behavior or arguments.
-    #[serde(default)]
-    pub history: Vec<HistoricalNote>,
-    /// an array of [`command arguments`](https://redis.io/docs/reference/command-arguments/)
-    pub arguments: Vec<CommandArgument>,
-}
-
-/// Command documenation flag
-#[derive(Debug, Deserialize)]
-pub enum CommandDocFlag {
-    /// the command is deprecated.
-    Deprecated,
-    /// a system command that isn't meant to be called by users.
-    SystemCommand,
-}
+    struct AclRulesVisitor;
 
-/// Sub-result for the [`command_docs`](ServerCommands::command_docs) command
-#[derive(Debug, Deserialize)]
-pub struct HistoricalNote {
-    pub version: String,
-    pub description: String,
-}
+    impl<'de> Visitor<'de> for AclRulesVisitor {
+        type Value = String;
 
-/// [`command argument`](https://redis.io/docs/reference/command-arguments/)
-#[derive(Debug, Deserialize)]
-pub struct CommandArgument {
-    ///  the argument's name, always present.
-    pub name: String,
-    /// the argument's display string, present in arguments that have a displayable representation
-    #[serde(default)]
-    pub display_text: String,
-    ///  the argument's type, always present.
-    #[serde(rename = "type")]
-    pub type_: CommandArgumentType,
-    /// this value is available for every argument of the `key` type.
-    /// t is a 0-based index of the specification in the command's [`key specifications`](https://redis.io/topics/key-specs)
-    /// that corresponds to the argument.
-    #[serde(default)]
-    pub key_spec_index: usize,
-    /// a constant literal that precedes the argument (user input) itself.
-    #[serde(default)]
-    pub token: String,
-    /// a short description of the argument.
-    #[serde(default)]
-    pub summary: String,
-    /// the debut Redis version of the argument (or for module commands, the module version).
-    #[serde(default)]
-    pub since: String,
-    /// the Redis version that deprecated the command (or for module commands, the module version).
-    #[serde(default)]
-    pub deprecated_since: String,
-    /// an array of argument flags.
-    #[serde(default)]
-    pub flags: Vec<ArgumentFlag>,
-    /// the argument's value.
-    #[serde(default)]
-    pub value: Vec<String>,
-}
-
-/// An argument must have one of the following types:
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum CommandArgumentType {
-    /// a string argument.
-    String,
-    /// an integer argument.
-    Integer,
-    /// a double-precision argument.
-    Double,
-    /// a string that represents the name of a key.
-    Key,
-    /// a string that represents a glob-like pattern.
-    Pattern,
-    /// an integer that represents a Unix timestamp.
-    UnixTime,
-    /// a token, i.e. a reserved keyword, which may or may not be provided.
-    /// Not to be confused with free-text user input.
-    PureToken,
-    /// the argument is a container for nested arguments.
-    /// This type enables choice among several nested arguments
-    Oneof,
-    /// the argument is a container for nested arguments.
-    /// This type enables grouping arguments and applying a property (such as optional) to all
-    Block,
-}
-
-/// Flag for a command argument
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum ArgumentFlag {
-    /// denotes that the argument is optional (for example, the GET clause of the SET command).
-    Optional,
-    /// denotes that the argument may be repeated (such as the key argument of DEL).
-    Multiple,
-    ///  denotes the possible repetition of the argument with its preceding token (see SORT's GET pattern clause).
-    MultipleToken,
-}
-
-/// Options for the [`command_list`](ServerCommands::command_list) command.
-#[derive(Default)]
-pub struct CommandListOptions {
-    command_args: CommandArgs,
-}
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("String or Vec<String>")
+        }
 
-impl CommandListOptions {
-    /// get the commands that belong to the module specified by `module-name`.
-    #[must_use]
-    pub fn filter_by_module_name<M: SingleArg>(mut self, module_name: M) -> Self {
-        Self {
-            command_args: self
-                .command_args
-                .arg("FILTERBY")
-                .arg("MODULE")
-                .arg(module_name)
-                .build(),
+        fn visit_str<E>(self, v: &str) -> std::result::Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(v.to_owned())
         }
-    }
 
-    /// get the commands in the [`ACL category`](https://redis.io/docs/manual/security/acl/#command-categories) specified by `category`.
-    #[must_use]
-    pub fn filter_by_acl_category<C: SingleArg>(mut self, category: C) -> Self {
-        Self {
-            command_args: self
-                .command_args
-                .arg("FILTERBY")
-                .arg("ACLCAT")
-                .arg(category)
-                .build(),
+        fn visit_bytes<E>(self, v: &[u8]) -> std::result::Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            match std::str::from_utf8(v) {
+                Ok(v) => Ok(v.to_owned()),
+                Err(_) => Err(de::Error::invalid_value(de::Unexpected::Bytes(v), &self)),
+            }
         }
-    }
 
-    /// get the commands that match the given glob-like `pattern`.
-    #[must_use]
-    pub fn filter_by_pattern<P: SingleArg>(mut self, pattern: P) -> Self {
-        Self {
-            command_args: self
-                .command_args
-                .arg("FILTERBY")
-                .arg("PATTERN")
-                .arg(pattern)
-                .build(),
+        fn visit_seq<A>(self, mut seq: A) -> std::result::Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut rules = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+            while let Some(rule) = seq.next_element::<String>()? {
+                rules.push(rule);
+            }
+            Ok(rules.join(" "))
         }
     }
-}
 
-impl ToArgs for CommandListOptions {
-    fn write_args(&self, args: &mut CommandArgs) {
-        args.arg(&self.command_args);
-    }
+    deserializer.deserialize_any(AclRulesVisitor)
 }
 
 /// Options for the [`failover`](ServerCommands::failover) command.
@@ -1521,6 +1121,426 @@ impl ToArgs for InfoSection {
     }
 }
 
+/// Result for the [`info`](ServerCommands::info) command.
+///
+/// Sections which were not requested are left to their default value.
+#[derive(Debug, Default)]
+pub struct ServerInfo {
+    /// General information about the Redis server
+    pub server: ServerInfoServer,
+    /// Client connections section
+    pub clients: ServerInfoClients,
+    /// Memory consumption related information
+    pub memory: ServerInfoMemory,
+    /// RDB and AOF related information
+    pub persistence: ServerInfoPersistence,
+    /// General statistics
+    pub stats: ServerInfoStats,
+    /// Master/replica replication information
+    pub replication: ServerInfoReplication,
+    /// CPU consumption statistics
+    pub cpu: ServerInfoCpu,
+    /// Database related statistics, by database index
+    pub keyspace: HashMap<usize, KeyspaceInfo>,
+    /// Other sections (`commandstats`, `errorstats`, `modules`, ...)
+    /// as raw key/value pairs, by lowercase section name
+    pub additional_sections: HashMap<String, HashMap<String, String>>,
+}
+
+impl FromStr for ServerInfo {
+    type Err = Error;
+
+    fn from_str(str: &str) -> Result<Self> {
+        let mut sections: HashMap<String, HashMap<String, String>> = HashMap::new();
+        let mut section = String::new();
+
+        for line in str.lines() {
+            let line = line.trim_end();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix('#') {
+                section = name.trim().to_lowercase();
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once(':') else {
+                return Err(Error::Client(format!("Cannot parse INFO line: {line}")));
+            };
+
+            sections
+                .entry(section.clone())
+                .or_default()
+                .insert(key.to_owned(), value.to_owned());
+        }
+
+        let mut section = |name: &str| InfoFields(sections.remove(name).unwrap_or_default());
+
+        let server = ServerInfoServer::from_fields(section("server"));
+        let clients = ServerInfoClients::from_fields(section("clients"));
+        let memory = ServerInfoMemory::from_fields(section("memory"));
+        let persistence = ServerInfoPersistence::from_fields(section("persistence"));
+        let stats = ServerInfoStats::from_fields(section("stats"));
+        let replication = ServerInfoReplication::from_fields(section("replication"));
+        let cpu = ServerInfoCpu::from_fields(section("cpu"));
+        let keyspace = section("keyspace")
+            .0
+            .into_iter()
+            .map(|(db, info)| {
+                let index = db
+                    .strip_prefix("db")
+                    .and_then(|index| index.parse::<usize>().ok())
+                    .ok_or_else(|| Error::Client(format!("Cannot parse keyspace db: {db}")))?;
+                Ok((index, KeyspaceInfo::from_str(&info)?))
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(ServerInfo {
+            server,
+            clients,
+            memory,
+            persistence,
+            stats,
+            replication,
+            cpu,
+            keyspace,
+            additional_sections: sections,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for ServerInfo {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let info = <&str>::deserialize(deserializer)?;
+        ServerInfo::from_str(info).map_err(de::Error::custom)
+    }
+}
+
+/// Key/value pairs of an INFO section, consumed while building the typed section
+struct InfoFields(HashMap<String, String>);
+
+impl InfoFields {
+    fn string(&mut self, key: &str) -> String {
+        self.0.remove(key).unwrap_or_default()
+    }
+
+    fn number<T: FromStr + Default>(&mut self, key: &str) -> T {
+        self.0
+            .remove(key)
+            .and_then(|value| value.parse::<T>().ok())
+            .unwrap_or_default()
+    }
+
+    fn flag(&mut self, key: &str) -> bool {
+        self.0.remove(key).is_some_and(|value| value == "1")
+    }
+}
+
+/// `server` section of the [`info`](ServerCommands::info) command.
+#[derive(Debug, Default)]
+pub struct ServerInfoServer {
+    /// Version of the Redis server
+    pub redis_version: String,
+    /// The server's mode ("standalone", "sentinel" or "cluster")
+    pub redis_mode: String,
+    /// Operating system hosting the Redis server
+    pub os: String,
+    /// Architecture (32 or 64 bits)
+    pub arch_bits: u32,
+    /// PID of the server process
+    pub process_id: u64,
+    /// Random value identifying the Redis server (to be used by Sentinel and Cluster)
+    pub run_id: String,
+    /// TCP/IP listen port
+    pub tcp_port: u16,
+    /// Number of seconds since Redis server start
+    pub uptime_in_seconds: u64,
+    /// The server's current frequency setting
+    pub hz: u32,
+    /// The path to the server's executable
+    pub executable: String,
+    /// The path to the config file
+    pub config_file: String,
+    /// Fields not covered by this struct
+    pub additional_fields: HashMap<String, String>,
+}
+
+impl ServerInfoServer {
+    fn from_fields(mut fields: InfoFields) -> Self {
+        Self {
+            redis_version: fields.string("redis_version"),
+            redis_mode: fields.string("redis_mode"),
+            os: fields.string("os"),
+            arch_bits: fields.number("arch_bits"),
+            process_id: fields.number("process_id"),
+            run_id: fields.string("run_id"),
+            tcp_port: fields.number("tcp_port"),
+            uptime_in_seconds: fields.number("uptime_in_seconds"),
+            hz: fields.number("hz"),
+            executable: fields.string("executable"),
+            config_file: fields.string("config_file"),
+            additional_fields: fields.0,
+        }
+    }
+}
+
+/// `clients` section of the [`info`](ServerCommands::info) command.
+#[derive(Debug, Default)]
+pub struct ServerInfoClients {
+    /// Number of client connections (excluding connections from replicas)
+    pub connected_clients: u64,
+    /// An approximation of the number of sockets used by the cluster's bus
+    pub cluster_connections: u64,
+    /// The value of the `maxclients` configuration directive
+    pub maxclients: u64,
+    /// Number of clients pending on a blocking call
+    pub blocked_clients: u64,
+    /// Number of clients being tracked
+    pub tracking_clients: u64,
+    /// Fields not covered by this struct
+    pub additional_fields: HashMap<String, String>,
+}
+
+impl ServerInfoClients {
+    fn from_fields(mut fields: InfoFields) -> Self {
+        Self {
+            connected_clients: fields.number("connected_clients"),
+            cluster_connections: fields.number("cluster_connections"),
+            maxclients: fields.number("maxclients"),
+            blocked_clients: fields.number("blocked_clients"),
+            tracking_clients: fields.number("tracking_clients"),
+            additional_fields: fields.0,
+        }
+    }
+}
+
+/// `memory` section of the [`info`](ServerCommands::info) command.
+#[derive(Debug, Default)]
+pub struct ServerInfoMemory {
+    /// Total number of bytes allocated by Redis using its allocator
+    pub used_memory: u64,
+    /// Number of bytes that Redis allocated as seen by the operating system
+    pub used_memory_rss: u64,
+    /// Peak memory consumed by Redis (in bytes)
+    pub used_memory_peak: u64,
+    /// The value of the `maxmemory` configuration directive
+    pub maxmemory: u64,
+    /// The value of the `maxmemory-policy` configuration directive
+    pub maxmemory_policy: String,
+    /// Ratio between `used_memory_rss` and `used_memory`
+    pub mem_fragmentation_ratio: f64,
+    /// Fields not covered by this struct
+    pub additional_fields: HashMap<String, String>,
+}
+
+impl ServerInfoMemory {
+    fn from_fields(mut fields: InfoFields) -> Self {
+        Self {
+            used_memory: fields.number("used_memory"),
+            used_memory_rss: fields.number("used_memory_rss"),
+            used_memory_peak: fields.number("used_memory_peak"),
+            maxmemory: fields.number("maxmemory"),
+            maxmemory_policy: fields.string("maxmemory_policy"),
+            mem_fragmentation_ratio: fields.number("mem_fragmentation_ratio"),
+            additional_fields: fields.0,
+        }
+    }
+}
+
+/// `persistence` section of the [`info`](ServerCommands::info) command.
+#[derive(Debug, Default)]
+pub struct ServerInfoPersistence {
+    /// Flag indicating if the load of a dump file is on-going
+    pub loading: bool,
+    /// Number of changes since the last dump
+    pub rdb_changes_since_last_save: u64,
+    /// Flag indicating a RDB save is on-going
+    pub rdb_bgsave_in_progress: bool,
+    /// Epoch-based timestamp of last successful RDB save
+    pub rdb_last_save_time: u64,
+    /// Status of the last RDB save operation
+    pub rdb_last_bgsave_status: String,
+    /// Flag indicating AOF logging is activated
+    pub aof_enabled: bool,
+    /// Flag indicating a AOF rewrite operation is on-going
+    pub aof_rewrite_in_progress: bool,
+    /// Status of the last AOF rewrite operation
+    pub aof_last_bgrewrite_status: String,
+    /// Fields not covered by this struct
+    pub additional_fields: HashMap<String, String>,
+}
+
+impl ServerInfoPersistence {
+    fn from_fields(mut fields: InfoFields) -> Self {
+        Self {
+            loading: fields.flag("loading"),
+            rdb_changes_since_last_save: fields.number("rdb_changes_since_last_save"),
+            rdb_bgsave_in_progress: fields.flag("rdb_bgsave_in_progress"),
+            rdb_last_save_time: fields.number("rdb_last_save_time"),
+            rdb_last_bgsave_status: fields.string("rdb_last_bgsave_status"),
+            aof_enabled: fields.flag("aof_enabled"),
+            aof_rewrite_in_progress: fields.flag("aof_rewrite_in_progress"),
+            aof_last_bgrewrite_status: fields.string("aof_last_bgrewrite_status"),
+            additional_fields: fields.0,
+        }
+    }
+}
+
+/// `stats` section of the [`info`](ServerCommands::info) command.
+#[derive(Debug, Default)]
+pub struct ServerInfoStats {
+    /// Total number of connections accepted by the server
+    pub total_connections_received: u64,
+    /// Total number of commands processed by the server
+    pub total_commands_processed: u64,
+    /// Number of commands processed per second
+    pub instantaneous_ops_per_sec: u64,
+    /// Number of connections rejected because of `maxclients` limit
+    pub rejected_connections: u64,
+    /// Total number of key expiration events
+    pub expired_keys: u64,
+    /// Number of evicted keys due to `maxmemory` limit
+    pub evicted_keys: u64,
+    /// Number of successful lookup of keys in the main dictionary
+    pub keyspace_hits: u64,
+    /// Number of failed lookup of keys in the main dictionary
+    pub keyspace_misses: u64,
+    /// Global number of pub/sub channels with client subscriptions
+    pub pubsub_channels: u64,
+    /// Global number of pub/sub pattern with client subscriptions
+    pub pubsub_patterns: u64,
+    /// Fields not covered by this struct
+    pub additional_fields: HashMap<String, String>,
+}
+
+impl ServerInfoStats {
+    fn from_fields(mut fields: InfoFields) -> Self {
+        Self {
+            total_connections_received: fields.number("total_connections_received"),
+            total_commands_processed: fields.number("total_commands_processed"),
+            instantaneous_ops_per_sec: fields.number("instantaneous_ops_per_sec"),
+            rejected_connections: fields.number("rejected_connections"),
+            expired_keys: fields.number("expired_keys"),
+            evicted_keys: fields.number("evicted_keys"),
+            keyspace_hits: fields.number("keyspace_hits"),
+            keyspace_misses: fields.number("keyspace_misses"),
+            pubsub_channels: fields.number("pubsub_channels"),
+            pubsub_patterns: fields.number("pubsub_patterns"),
+            additional_fields: fields.0,
+        }
+    }
+}
+
+/// `replication` section of the [`info`](ServerCommands::info) command.
+#[derive(Debug, Default)]
+pub struct ServerInfoReplication {
+    /// Value is "master" if the instance is replica of no one,
+    /// or "slave" if the instance is a replica of some master instance
+    pub role: String,
+    /// Number of connected replicas
+    pub connected_slaves: u64,
+    /// The replication ID of the Redis server
+    pub master_replid: String,
+    /// The server's current replication offset
+    pub master_repl_offset: u64,
+    /// Host or IP address of the master (replica only)
+    pub master_host: Option<String>,
+    /// Master listening TCP port (replica only)
+    pub master_port: Option<u16>,
+    /// Status of the link (up/down) (replica only)
+    pub master_link_status: Option<String>,
+    /// Fields not covered by this struct (`slave0`, `slave1`, ...)
+    pub additional_fields: HashMap<String, String>,
+}
+
+impl ServerInfoReplication {
+    fn from_fields(mut fields: InfoFields) -> Self {
+        Self {
+            role: fields.string("role"),
+            connected_slaves: fields.number("connected_slaves"),
+            master_replid: fields.string("master_replid"),
+            master_repl_offset: fields.number("master_repl_offset"),
+            master_host: fields.0.remove("master_host"),
+            master_port: fields
+                .0
+                .remove("master_port")
+                .and_then(|port| port.parse::<u16>().ok()),
+            master_link_status: fields.0.remove("master_link_status"),
+            additional_fields: fields.0,
+        }
+    }
+}
+
+/// `cpu` section of the [`info`](ServerCommands::info) command.
+#[derive(Debug, Default)]
+pub struct ServerInfoCpu {
+    /// System CPU consumed by the Redis server
+    pub used_cpu_sys: f64,
+    /// User CPU consumed by the Redis server
+    pub used_cpu_user: f64,
+    /// System CPU consumed by the background processes
+    pub used_cpu_sys_children: f64,
+    /// User CPU consumed by the background processes
+    pub used_cpu_user_children: f64,
+    /// Fields not covered by this struct
+    pub additional_fields: HashMap<String, String>,
+}
+
+impl ServerInfoCpu {
+    fn from_fields(mut fields: InfoFields) -> Self {
+        Self {
+            used_cpu_sys: fields.number("used_cpu_sys"),
+            used_cpu_user: fields.number("used_cpu_user"),
+            used_cpu_sys_children: fields.number("used_cpu_sys_children"),
+            used_cpu_user_children: fields.number("used_cpu_user_children"),
+            additional_fields: fields.0,
+        }
+    }
+}
+
+/// Database statistics of the `keyspace` section of the [`info`](ServerCommands::info) command.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct KeyspaceInfo {
+    /// Number of keys
+    pub keys: u64,
+    /// Number of keys with an expiration
+    pub expires: u64,
+    /// Average time to live of the keys with an expiration, in milliseconds
+    pub avg_ttl: u64,
+}
+
+impl FromStr for KeyspaceInfo {
+    type Err = Error;
+
+    /// Parses a keyspace entry like `keys=1,expires=0,avg_ttl=0`
+    fn from_str(str: &str) -> Result<Self> {
+        let mut info = KeyspaceInfo::default();
+
+        for field in str.split(',') {
+            let Some((key, value)) = field.split_once('=') else {
+                return Err(Error::Client(format!("Cannot parse keyspace info: {str}")));
+            };
+            let value = || {
+                value
+                    .parse::<u64>()
+                    .map_err(|_| Error::Client(format!("Cannot parse keyspace info: {str}")))
+            };
+            match key {
+                "keys" => info.keys = value()?,
+                "expires" => info.expires = value()?,
+                "avg_ttl" => info.avg_ttl = value()?,
+                _ => (),
+            }
+        }
+
+        Ok(info)
+    }
+}
+
 /// Latency history event for the [`latency_graph`](ServerCommands::latency_graph)
 /// & [`latency_history`](ServerCommands::latency_history) commands.
 pub enum LatencyHistoryEvent {
@@ -1567,6 +1587,66 @@ impl ToArgs for LatencyHistoryEvent {
     }
 }
 
+/// Latency event for the [`latency_latest`](ServerCommands::latency_latest) command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LatencyEvent {
+    /// Event name.
+    pub event: String,
+    /// Unix timestamp in seconds of the latest latency spike for the event.
+    pub timestamp: u32,
+    /// Latest event latency in milliseconds.
+    pub latest_ms: u32,
+    /// All-time maximum latency for this event in milliseconds.
+    pub max_ms: u32,
+}
+
+impl<'de> Deserialize<'de> for LatencyEvent {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct LatencyEventVisitor;
+
+        impl<'de> Visitor<'de> for LatencyEventVisitor {
+            type Value = LatencyEvent;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("LatencyEvent")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> std::result::Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let Some(event) = seq.next_element::<String>()? else {
+                    return Err(de::Error::invalid_length(0, &"more elements in sequence"));
+                };
+                let Some(timestamp) = seq.next_element::<u32>()? else {
+                    return Err(de::Error::invalid_length(1, &"more elements in sequence"));
+                };
+                let Some(latest_ms) = seq.next_element::<u32>()? else {
+                    return Err(de::Error::invalid_length(2, &"more elements in sequence"));
+                };
+                let Some(max_ms) = seq.next_element::<u32>()? else {
+                    return Err(de::Error::invalid_length(3, &"more elements in sequence"));
+                };
+
+                // fields added by newer server versions
+                while seq.next_element::<de::IgnoredAny>()?.is_some() {}
+
+                Ok(LatencyEvent {
+                    event,
+                    timestamp,
+                    latest_ms,
+                    max_ms,
+                })
+            }
+        }
+
+        deserializer.deserialize_seq(LatencyEventVisitor)
+    }
+}
+
 /// Command Histogram for the [`latency_histogram`](ServerCommands::latency_histogram) commands.
 #[derive(Default, Deserialize)]
 pub struct CommandHistogram {
@@ -1746,6 +1826,11 @@ pub struct MemoryStats {
     #[serde(rename = "fragmentation.bytes")]
     #[serde(default)]
     pub fragmentation_bytes: isize,
+
+    /// Other statistics, e.g. the overheads of each database (`db.<dbid>`)
+    /// or the statistics added by future versions of Redis
+    #[serde(flatten)]
+    pub additional_values: HashMap<String, Value>,
 }
 
 /// Sub-result for the [`memory_stats`](ServerCommands::memory_stats) command.
@@ -1782,12 +1867,12 @@ impl ToArgs for MemoryUsageOptions {
 }
 
 /// Module information result for the [`module_list`](ServerCommands::module_list) command.
-#[derive(Deserialize)]
+#[derive(Debug, Deserialize)]
 pub struct ModuleInfo {
     /// Name of the module
     pub name: String,
     /// Version of the module
-    #[serde(default)]
+    #[serde(default, alias = "ver")]
     pub version: u64,
 }
 
@@ -1884,7 +1969,7 @@ pub enum RoleResult {
         /// in partial resynchronizations,
         /// the part of the replication stream the replicas needs to fetch to continue.
         master_replication_offset: usize,
-        /// information av=bout the connected replicas
+        /// information about the connected replicas
         replica_infos: Vec<ReplicaInfo>,
     },
     Replica {
@@ -1996,14 +2081,21 @@ pub struct ReplicaInfo {
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "lowercase")]
 pub enum ReplicationState {
+    /// no replication is active
+    None,
     /// the instance needs to connect to its master
     Connect,
     /// the master-replica connection is in progress
     Connecting,
+    /// the master and replica are performing the replication handshake
+    Handshake,
     /// the master and replica are trying to perform the synchronization
     Sync,
     /// the replica is online
     Connected,
+    /// a state unknown to this client
+    #[serde(other)]
+    Unknown,
 }
 
 /// options for the [`shutdown`](ServerCommands::shutdown) command.
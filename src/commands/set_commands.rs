@@ -105,15 +105,15 @@ pub trait SetCommands<'a> {
     /// it returns just the cardinality of the result.
     ///
     /// limit: if the intersection cardinality reaches limit partway through the computation,
-    /// the algorithm will exit and yield limit as the cardinality. 0 means unlimited
+    /// the algorithm will exit and yield limit as the cardinality. `None` or `Some(0)` means unlimited
     ///
     /// # Return
-    /// A list with members of the resulting set.
+    /// The cardinality of the intersection.
     ///
     /// # See Also
     /// [<https://redis.io/commands/sintercard/>](https://redis.io/commands/sintercard/)
     #[must_use]
-    fn sintercard<K, C>(self, keys: C, limit: usize) -> PreparedCommand<'a, Self, usize>
+    fn sintercard<K, C>(self, keys: C, limit: Option<usize>) -> PreparedCommand<'a, Self, usize>
     where
         Self: Sized,
         K: SingleArg,
@@ -124,8 +124,7 @@ pub trait SetCommands<'a> {
             cmd("SINTERCARD")
                 .arg(keys.num_args())
                 .arg(keys)
-                .arg("LIMIT")
-                .arg(limit),
+                .arg(limit.map(|l| ("LIMIT", l))),
         )
     }
 
@@ -236,15 +235,19 @@ pub trait SetCommands<'a> {
         prepare_command(self, cmd("SPOP").arg(key).arg(count))
     }
 
-    /// Removes and returns one or more random members from the set value store at key.
+    /// Returns one or more random members from the set value store at key.
     ///
     /// # Return
-    /// the list of popped elements
+    /// * If the provided count argument is positive, return an array of distinct members.
+    ///   The array's length is either count or the set's cardinality (SCARD), whichever is lower.
+    /// * If called with a negative count, the behavior changes and the command is allowed
+    ///   to return the same member multiple times. In this case, the number of returned members
+    ///   is the absolute value of the specified count: collect them in a `Vec` to keep the duplicates.
     ///
     /// # See Also
     /// [<https://redis.io/commands/srandmember/>](https://redis.io/commands/srandmember/)
     #[must_use]
-    fn srandmember<K, M, A>(self, key: K, count: usize) -> PreparedCommand<'a, Self, A>
+    fn srandmember<K, M, A>(self, key: K, count: isize) -> PreparedCommand<'a, Self, A>
     where
         Self: Sized,
         K: SingleArg,
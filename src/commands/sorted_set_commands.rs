@@ -262,13 +262,16 @@ pub trait SortedSetCommands<'a> {
     /// This command is similar to [zinter](SortedSetCommands::zinter),
     /// but instead of returning the result set, it returns just the cardinality of the result.
     ///
-    //// limit: if the intersection cardinality reaches limit partway through the computation,
-    /// the algorithm will exit and yield limit as the cardinality. 0 means unlimited
+    /// limit: if the intersection cardinality reaches limit partway through the computation,
+    /// the algorithm will exit and yield limit as the cardinality. `None` or `Some(0)` means unlimited
+    ///
+    /// # Return
+    /// The cardinality of the intersection.
     ///
     /// # See Also
     /// [<https://redis.io/commands/zintercard/>](https://redis.io/commands/zintercard/)
     #[must_use]
-    fn zintercard<K, C>(self, keys: C, limit: usize) -> PreparedCommand<'a, Self, usize>
+    fn zintercard<K, C>(self, keys: C, limit: Option<usize>) -> PreparedCommand<'a, Self, usize>
     where
         Self: Sized,
         K: SingleArg,
@@ -279,8 +282,7 @@ pub trait SortedSetCommands<'a> {
             cmd("ZINTERCARD")
                 .arg(keys.num_args())
                 .arg(keys)
-                .arg("LIMIT")
-                .arg(limit),
+                .arg(limit.map(|l| ("LIMIT", l))),
         )
     }
 
@@ -378,7 +380,8 @@ pub trait SortedSetCommands<'a> {
     /// For every member that does not exist in the sorted set, a nil value is returned.
     ///
     /// # Return
-    /// The list of scores or nil associated with the specified member value
+    /// The list of scores or nil associated with the specified member value,
+    /// in the same order as they are requested.
     ///
     /// # See Also
     /// [<https://redis.io/commands/zmscore/>](https://redis.io/commands/zmscore/)
@@ -430,7 +433,8 @@ pub trait SortedSetCommands<'a> {
     /// Return a random element from the sorted set value stored at key.
     ///
     /// # Return
-    /// The randomly selected element, or nil when key does not exist.
+    /// The randomly selected element, or nil when key does not exist
+    /// (use an `Option<E>` response type to handle this case).
     ///
     /// # See Also
     /// [<https://redis.io/commands/zrandmember/>](https://redis.io/commands/zrandmember/)
@@ -481,11 +485,11 @@ pub trait SortedSetCommands<'a> {
         self,
         key: K,
         count: isize,
-    ) -> PreparedCommand<'a, Self, Vec<E>>
+    ) -> PreparedCommand<'a, Self, Vec<(E, f64)>>
     where
         Self: Sized,
         K: SingleArg,
-        E: DeserializeOwned,
+        E: PrimitiveResponse + DeserializeOwned,
     {
         prepare_command(
             self,
@@ -522,6 +526,9 @@ pub trait SortedSetCommands<'a> {
 
     /// Returns the specified range of elements in the sorted set stored at `key`.
     ///
+    /// `ZRANGEBYSCORE`, `ZRANGEBYLEX` and `ZREVRANGE` are covered
+    /// with the [`sort_by`](ZRangeOptions::sort_by) and [`reverse`](ZRangeOptions::reverse) options.
+    ///
     /// # Return
     /// A collection of elements and their scores in the specified range
     ///
@@ -70,11 +70,14 @@ pub trait StreamCommands<'a> {
 
     /// This command transfers ownership of pending stream entries that match the specified criteria.
     ///
+    /// Only the entries idle for at least `min_idle_time` milliseconds are claimed.
+    ///
     /// # Return
-    /// An instance of StreamAutoClaimResult
+    /// An instance of [`XAutoClaimResult`](XAutoClaimResult)
     ///
     /// # See Also
     /// [<https://redis.io/commands/xautoclaim/>](https://redis.io/commands/xautoclaim/)
+    #[must_use]
     fn xautoclaim<K, G, C, I, V>(
         self,
         key: K,
@@ -104,19 +107,55 @@ pub trait StreamCommands<'a> {
         )
     }
 
-    /// In the context of a stream consumer group, this command changes the ownership of a pending message,
-    /// so that the new owner is the consumer specified as the command argument.
+    /// Same as [`xautoclaim`](StreamCommands::xautoclaim) with the `JUSTID` option:
+    /// only the IDs of the messages successfully claimed are returned, without the actual messages.
+    ///
+    /// The retry counter of the claimed messages is not incremented.
     ///
     /// # Return
-    /// The ID of the added entry.
+    /// An instance of [`XAutoClaimJustIdResult`](XAutoClaimJustIdResult)
     ///
-    /// The ID is the one auto-generated if * is passed as ID argument,
-    /// otherwise the command just returns the same ID specified by the user during insertion.
+    /// # See Also
+    /// [<https://redis.io/commands/xautoclaim/>](https://redis.io/commands/xautoclaim/)
+    #[must_use]
+    fn xautoclaim_just_id<K, G, C, I>(
+        self,
+        key: K,
+        group: G,
+        consumer: C,
+        min_idle_time: u64,
+        start: I,
+        options: XAutoClaimOptions,
+    ) -> PreparedCommand<'a, Self, XAutoClaimJustIdResult>
+    where
+        Self: Sized,
+        K: SingleArg,
+        G: SingleArg,
+        C: SingleArg,
+        I: SingleArg,
+    {
+        prepare_command(
+            self,
+            cmd("XAUTOCLAIM")
+                .arg(key)
+                .arg(group)
+                .arg(consumer)
+                .arg(min_idle_time)
+                .arg(start)
+                .arg(options)
+                .arg("JUSTID"),
+        )
+    }
+
+    /// In the context of a stream consumer group, this command changes the ownership of a pending message,
+    /// so that the new owner is the consumer specified as the command argument.
     ///
-    /// The command returns a Null reply when used with create_stream=false and the key doesn't exist.
+    /// # Return
+    /// The messages successfully claimed, in the same format as [`xrange`](StreamCommands::xrange).
     ///
     /// # See Also
     /// [<https://redis.io/commands/xclaim/>](https://redis.io/commands/xclaim/)
+    #[must_use]
     fn xclaim<K, G, C, I, II, V>(
         self,
         key: K,
@@ -147,6 +186,48 @@ pub trait StreamCommands<'a> {
         )
     }
 
+    /// Same as [`xclaim`](StreamCommands::xclaim) with the `JUSTID` option:
+    /// only the IDs of the messages successfully claimed are returned, without the actual messages.
+    ///
+    /// The retry counter of the claimed messages is not incremented.
+    ///
+    /// # Return
+    /// The IDs of the messages successfully claimed.
+    ///
+    /// # See Also
+    /// [<https://redis.io/commands/xclaim/>](https://redis.io/commands/xclaim/)
+    #[must_use]
+    fn xclaim_just_id<K, G, C, I, II, R>(
+        self,
+        key: K,
+        group: G,
+        consumer: C,
+        min_idle_time: u64,
+        ids: II,
+        options: XClaimOptions,
+    ) -> PreparedCommand<'a, Self, Vec<R>>
+    where
+        Self: Sized,
+        K: SingleArg,
+        G: SingleArg,
+        C: SingleArg,
+        I: SingleArg,
+        II: SingleArgCollection<I>,
+        R: PrimitiveResponse + DeserializeOwned,
+    {
+        prepare_command(
+            self,
+            cmd("XCLAIM")
+                .arg(key)
+                .arg(group)
+                .arg(consumer)
+                .arg(min_idle_time)
+                .arg(ids)
+                .arg(options)
+                .arg("JUSTID"),
+        )
+    }
+
     /// Removes the specified entries from a stream, and returns the number of entries deleted.
     ///
     /// # Return
@@ -326,6 +407,7 @@ pub trait StreamCommands<'a> {
     ///
     /// # Return
     /// A collection of XGroupInfo.
+    /// Both the RESP2 flat array and the RESP3 map forms of the reply are supported.
     ///
     /// # See Also
     /// [<https://redis.io/commands/xinfo-groups/>](https://redis.io/commands/xinfo-groups/)
@@ -340,7 +422,8 @@ pub trait StreamCommands<'a> {
     /// This command returns information about the stream stored at `key`.
     ///
     /// # Return
-    /// A collection of XGroupInfo.
+    /// An instance of XStreamInfo.
+    /// Both the RESP2 flat array and the RESP3 map forms of the reply are supported.
     ///
     /// # See Also
     /// [<https://redis.io/commands/xinfo-stream/>](https://redis.io/commands/xinfo-stream/)
@@ -667,6 +750,12 @@ impl XAutoClaimOptions {
         }
     }
 
+    /// Return just an array of IDs of messages successfully claimed,
+    /// without returning the actual message.
+    ///
+    /// The reply does not match [`XAutoClaimResult`](XAutoClaimResult):
+    /// use [`xautoclaim_just_id`](StreamCommands::xautoclaim_just_id) instead.
+    #[deprecated(note = "use `StreamCommands::xautoclaim_just_id` instead")]
     #[must_use]
     pub fn just_id(mut self) -> Self {
         Self {
@@ -711,6 +800,19 @@ where
     pub deleted_ids: Vec<String>,
 }
 
+/// Result for the [`xautoclaim_just_id`](StreamCommands::xautoclaim_just_id) command.
+#[derive(Deserialize)]
+pub struct XAutoClaimJustIdResult {
+    /// A stream ID to be used as the `start` argument for
+    /// the next call to [`xautoclaim_just_id`](StreamCommands::xautoclaim_just_id).
+    pub start_stream_id: String,
+    /// The IDs of the messages successfully claimed.
+    pub ids: Vec<String>,
+    /// An array containing message IDs that no longer exist in the stream,
+    /// and were deleted from the PEL in which they were found.
+    pub deleted_ids: Vec<String>,
+}
+
 /// Options for the [`xclaim`](StreamCommands::xclaim) command
 #[derive(Default)]
 pub struct XClaimOptions {
@@ -759,6 +861,7 @@ impl XClaimOptions {
 
     ///  Return just an array of IDs of messages successfully claimed,
     /// without returning the actual message.
+    #[deprecated(note = "use `StreamCommands::xclaim_just_id` instead")]
     #[must_use]
     pub fn just_id(mut self) -> Self {
         Self {
@@ -909,12 +1012,15 @@ pub struct XStreamInfo {
     /// the count of all entries added to the stream during its lifetime
     pub entries_added: usize,
 
-    /// the ID and field-value tuples of the first entry in the stream
-    pub first_entry: StreamEntry<String>,
+    /// the ID and field-value tuples of the first entry in the stream,
+    /// or `None` if the stream is empty
+    pub first_entry: Option<StreamEntry<String>>,
 
-    /// the ID and field-value tuples of the last entry in the stream
-    pub last_entry: StreamEntry<String>,
+    /// the ID and field-value tuples of the last entry in the stream,
+    /// or `None` if the stream is empty
+    pub last_entry: Option<StreamEntry<String>>,
 
+    /// the ID of the first entry recorded in the stream
     pub recorded_first_entry_id: String,
 }
 
@@ -85,7 +85,8 @@ pub trait StringCommands<'a> {
     /// string values.
     ///
     /// # Return
-    /// the value of key, or `nil` when key does not exist.
+    /// the value of key, or `nil` when key does not exist
+    /// (use an `Option<V>` response type to handle this case).
     ///
     /// # Example
     /// ```
@@ -107,10 +108,15 @@ pub trait StringCommands<'a> {
     ///     assert_eq!(None, value);
     ///
     ///     // ... or it can be directly a String.
-    ///     // In this cas a `nil` value will result in an empty String
+    ///     // In this case a `nil` value will result in an empty String,
+    ///     // which cannot be told apart from an empty value
     ///     let value: String = client.get("key").await?;
     ///     assert_eq!("", &value);
     ///
+    ///     client.set("key", "").await?;
+    ///     let value: Option<String> = client.get("key").await?;
+    ///     assert_eq!(Some("".to_owned()), value);
+    ///
     ///     client.set("key", "value").await?;
     ///     let value: String = client.get("key").await?;
     ///     assert_eq!("value", value);
@@ -129,7 +135,7 @@ pub trait StringCommands<'a> {
         V: PrimitiveResponse,
         Self: Sized,
     {
-        prepare_command(self, cmd("GET").arg(key))
+        prepare_command(self, cmd("GET").arg(key)).compress_values()
     }
 
     /// Get the value of key and delete the key.
@@ -139,6 +145,7 @@ pub trait StringCommands<'a> {
     ///
     /// # Return
     /// the value of key, `nil` when key does not exist, or an error if the key's value type isn't a string.
+    /// Use an `Option<V>` response type to tell a missing key apart from an empty value.
     ///
     /// # See Also
     /// [<https://redis.io/commands/getdel/>](https://redis.io/commands/getdel/)
@@ -149,19 +156,14 @@ pub trait StringCommands<'a> {
         K: SingleArg,
         V: PrimitiveResponse,
     {
-        prepare_command(self, cmd("GETDEL").arg(key))
+        prepare_command(self, cmd("GETDEL").arg(key)).compress_values()
     }
 
     /// Get the value of key and optionally set its expiration. GETEX is similar to GET, but is a write command with additional options.
     ///
-    /// Decrements the number stored at key by decrement.
-    /// If the key does not exist, it is set to 0 before performing the operation.
-    /// An error is returned if the key contains a value of the wrong type
-    /// or contains a string that can not be represented as integer.
-    /// This operation is limited to 64 bit signed integers.
-    ///
     /// # Return
-    /// the value of key, or `nil` when key does not exist.
+    /// the value of key, or `nil` when key does not exist
+    /// (use an `Option<V>` response type to handle this case).
     ///
     /// # Example
     /// ```
@@ -198,7 +200,7 @@ pub trait StringCommands<'a> {
         K: SingleArg,
         V: PrimitiveResponse,
     {
-        prepare_command(self, cmd("GETEX").arg(key).arg(options))
+        prepare_command(self, cmd("GETEX").arg(key).arg(options)).compress_values()
     }
 
     /// Returns the substring of the string value stored at key, determined by the offsets start and end (both are inclusive).
@@ -207,11 +209,14 @@ pub trait StringCommands<'a> {
     /// So -1 means the last character, -2 the penultimate and so forth.
     ///
     /// The function handles out of range requests by limiting the resulting range to the actual length of the string.
-
+    ///
+    /// # Return
+    /// the substring, or an empty string when key does not exist.
+    ///
     /// # See Also
     /// [<https://redis.io/commands/getrange/>](https://redis.io/commands/getrange/)
     #[must_use]
-    fn getrange<K, V>(self, key: K, start: usize, end: isize) -> PreparedCommand<'a, Self, V>
+    fn getrange<K, V>(self, key: K, start: isize, end: isize) -> PreparedCommand<'a, Self, V>
     where
         Self: Sized,
         K: SingleArg,
@@ -225,7 +230,8 @@ pub trait StringCommands<'a> {
     /// Any previous time to live associated with the key is discarded on successful SET operation.
     ///
     /// # Return
-    /// the old value stored at key, or nil when key did not exist.
+    /// the old value stored at key, or nil when key did not exist
+    /// (use an `Option<R>` response type to handle this case).
     ///
     /// # See Also
     /// [<https://redis.io/commands/getset/>](https://redis.io/commands/getset/)
@@ -237,7 +243,7 @@ pub trait StringCommands<'a> {
         V: SingleArg,
         R: PrimitiveResponse,
     {
-        prepare_command(self, cmd("GETSET").arg(key).arg(value))
+        prepare_command(self, cmd("GETSET").arg(key).arg(value)).compress_values()
     }
 
     /// Increments the number stored at key by one.
@@ -362,7 +368,8 @@ pub trait StringCommands<'a> {
     /// # Return
     /// An array with the LCS length and all the ranges in both the strings,
     /// start and end offset for each string, where there are matches.
-    /// When `with_match_len` is given each match will also have the length of the match
+    /// When [`with_match_len`](LcsOptions::with_match_len) is given
+    /// each match will also have the length of the match
     ///
     /// # See Also
     /// [<https://redis.io/commands/lcs/>](https://redis.io/commands/lcs/)
@@ -371,22 +378,13 @@ pub trait StringCommands<'a> {
         self,
         key1: K,
         key2: K,
-        min_match_len: Option<usize>,
-        with_match_len: bool,
+        options: LcsOptions,
     ) -> PreparedCommand<'a, Self, LcsResult>
     where
         Self: Sized,
         K: SingleArg,
     {
-        prepare_command(
-            self,
-            cmd("LCS")
-                .arg(key1)
-                .arg(key2)
-                .arg("IDX")
-                .arg(min_match_len.map(|len| ("MINMATCHLEN", len)))
-                .arg_if(with_match_len, "WITHMATCHLEN"),
-        )
+        prepare_command(self, cmd("LCS").arg(key1).arg(key2).arg("IDX").arg(options))
     }
 
     /// Returns the values of all specified keys.
@@ -408,7 +406,7 @@ pub trait StringCommands<'a> {
         V: PrimitiveResponse + serde::de::DeserializeOwned,
         VV: CollectionResponse<V>,
     {
-        prepare_command(self, cmd("MGET").arg(keys))
+        prepare_command(self, cmd("MGET").arg(keys)).compress_values()
     }
 
     /// Sets the given keys to their respective values.
@@ -426,7 +424,7 @@ pub trait StringCommands<'a> {
         K: SingleArg,
         V: SingleArg,
     {
-        prepare_command(self, cmd("MSET").arg(items))
+        prepare_command(self, cmd("MSET").arg(items)).compress_values()
     }
 
     /// Sets the given keys to their respective values.
@@ -454,7 +452,7 @@ pub trait StringCommands<'a> {
         K: SingleArg,
         V: SingleArg,
     {
-        prepare_command(self, cmd("MSETNX").arg(items))
+        prepare_command(self, cmd("MSETNX").arg(items)).compress_values()
     }
 
     /// Works exactly like [setex](StringCommands::setex) with the sole
@@ -472,7 +470,7 @@ pub trait StringCommands<'a> {
         K: SingleArg,
         V: SingleArg,
     {
-        prepare_command(self, cmd("PSETEX").arg(key).arg(milliseconds).arg(value))
+        prepare_command(self, cmd("PSETEX").arg(key).arg(milliseconds).arg(value)).compress_values()
     }
 
     ///Set key to hold the string value.
@@ -490,10 +488,13 @@ pub trait StringCommands<'a> {
         V: SingleArg,
         Self: Sized,
     {
-        prepare_command(self, cmd("SET").arg(key).arg(value))
+        prepare_command(self, cmd("SET").arg(key).arg(value)).compress_values()
     }
 
-    ///Set key to hold the string value.
+    /// Set key to hold the string value.
+    ///
+    /// If `keep_ttl` is `true`, the time to live associated with the key is retained (`KEEPTTL`),
+    /// instead of being discarded when the key is overwritten.
     ///
     /// # Return
     /// * `true` if SET was executed correctly.
@@ -525,6 +526,7 @@ pub trait StringCommands<'a> {
                 .arg(expiration)
                 .arg_if(keep_ttl, "KEEPTTL"),
         )
+        .compress_values()
     }
 
     /// Set key to hold the string value wit GET option enforced
@@ -556,6 +558,7 @@ pub trait StringCommands<'a> {
                 .arg(expiration)
                 .arg_if(keep_ttl, "KEEPTTL"),
         )
+        .compress_values()
     }
 
     /// Set key to hold the string value and set key to timeout after a given number of seconds.
@@ -569,7 +572,7 @@ pub trait StringCommands<'a> {
         K: SingleArg,
         V: SingleArg,
     {
-        prepare_command(self, cmd("SETEX").arg(key).arg(seconds).arg(value))
+        prepare_command(self, cmd("SETEX").arg(key).arg(seconds).arg(value)).compress_values()
     }
 
     /// Set key to hold string value if key does not exist.
@@ -592,13 +595,16 @@ pub trait StringCommands<'a> {
         K: SingleArg,
         V: SingleArg,
     {
-        prepare_command(self, cmd("SETNX").arg(key).arg(value))
+        prepare_command(self, cmd("SETNX").arg(key).arg(value)).compress_values()
     }
 
     /// Overwrites part of the string stored at key,
     /// starting at the specified offset,
     /// for the entire length of value.
     ///
+    /// If the offset is larger than the current length of the string at key,
+    /// or if key does not exist, the string is padded with zero-bytes to make offset fit.
+    ///
     /// # Return
     /// the length of the string after it was modified by the command.
     ///
@@ -659,6 +665,40 @@ impl ToArgs for GetExOptions {
     }
 }
 
+/// Options for the [`lcs_idx`](StringCommands::lcs_idx) command
+#[derive(Default)]
+pub struct LcsOptions {
+    command_args: CommandArgs,
+}
+
+impl LcsOptions {
+    /// Only return the matches with a length greater than or equal to `min_match_len`
+    #[must_use]
+    pub fn min_match_len(mut self, min_match_len: usize) -> Self {
+        Self {
+            command_args: self
+                .command_args
+                .arg("MINMATCHLEN")
+                .arg(min_match_len)
+                .build(),
+        }
+    }
+
+    /// Return the length of each match
+    #[must_use]
+    pub fn with_match_len(mut self) -> Self {
+        Self {
+            command_args: self.command_args.arg("WITHMATCHLEN").build(),
+        }
+    }
+}
+
+impl ToArgs for LcsOptions {
+    fn write_args(&self, args: &mut CommandArgs) {
+        args.arg(&self.command_args);
+    }
+}
+
 /// Part of the result for the [`lcs`](StringCommands::lcs) command
 #[derive(Debug, PartialEq, Eq)]
 pub struct LcsMatch(pub (usize, usize), pub (usize, usize), pub Option<usize>);
@@ -132,6 +132,13 @@ impl From<FromUtf8Error> for Error {
     }
 }
 
+#[cfg(feature = "serde-json")]
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Client(e.to_string())
+    }
+}
+
 impl From<ParseFloatError> for Error {
     fn from(e: ParseFloatError) -> Self {
         Error::Client(e.to_string())
@@ -182,6 +189,7 @@ pub enum RedisErrorKind {
     NoPerm,
     NoProto,
     NoQuorum,
+    NoScript,
     NotBusy,
     OutOfMemory,
     Readonly,
@@ -227,6 +235,7 @@ impl FromStr for RedisErrorKind {
             "NOPERM" => Ok(Self::NoPerm),
             "NOPROTO" => Ok(Self::NoProto),
             "NOQUORUM" => Ok(Self::NoQuorum),
+            "NOSCRIPT" => Ok(Self::NoScript),
             "NOTBUSY" => Ok(Self::NotBusy),
             "OOM" => Ok(Self::OutOfMemory),
             "READONLY" => Ok(Self::Readonly),
@@ -278,6 +287,7 @@ impl Display for RedisErrorKind {
             RedisErrorKind::NoPerm => f.write_str("NOPERM"),
             RedisErrorKind::NoProto => f.write_str("NOPROTO"),
             RedisErrorKind::NoQuorum => f.write_str("NOQUORUM"),
+            RedisErrorKind::NoScript => f.write_str("NOSCRIPT"),
             RedisErrorKind::NotBusy => f.write_str("NOTBUSY"),
             RedisErrorKind::OutOfMemory => f.write_str("OOM"),
             RedisErrorKind::Readonly => f.write_str("READONLY"),
@@ -39,6 +39,16 @@ rustis is a Redis client for Rust.
 | `tokio-tls` | Tokio TLS support (optional) |
 | `async-std-tls` | async-std TLS support (optional) |
 | `pool` | Pooled client manager (optional) |
+| `lz4` | LZ4 [compression](client::CompressionConfig) of large string values (optional) |
+| `zstd` | Zstandard [compression](client::CompressionConfig) of large string values (optional) |
+| `sync` | [`SyncClient`](client::SyncClient), a blocking facade for non-async callers (optional) |
+| `derive` | [`ToRedisHash`](ToRedisHash) and [`FromRedisHash`](FromRedisHash) derive macros, to store structs as hashes, and [`FromRedisValue`](FromRedisValue) to parse string replies into enums (optional) |
+| `serde-json` | [`get_serde`](client::Client::get_serde) and [`set_serde`](client::Client::set_serde), to store values as JSON strings (optional) |
+| `tracing` | Wrap each command or batch of commands in a [`tracing`](https://docs.rs/tracing) span following the OpenTelemetry semantic conventions, with argument values redacted (optional) |
+| `bytes` | [`BulkBytes`](resp::BulkBytes) responses sharing the network buffer instead of copying large values, and [`Bytes`](https://docs.rs/bytes/latest/bytes/struct.Bytes.html) responses (optional) |
+| `codec` | [`RespCodec`](resp::RespCodec), a `tokio-util` RESP codec to reuse the protocol implementation without the client layer, e.g. in a proxy (optional) |
+| `debug-commands` | [`DebugCommands`](commands::DebugCommands), `DEBUG` sub-commands for tests, not meant for production (optional) |
+| `indexmap` | [`IndexMap`](https://docs.rs/indexmap/latest/indexmap/map/struct.IndexMap.html) and [`IndexSet`](https://docs.rs/indexmap/latest/indexmap/set/struct.IndexSet.html) responses, preserving the order of the reply (optional) |
 | `redis-json` | [RedisJSON v2.4](https://redis.io/docs/stack/json/) support (optional) |
 | `redis-search` | [RedisSearch v2.6](https://redis.io/docs/stack/search/) support (optional) |
 | `redis-graph` | [RedisGraph v2.10](https://redis.io/docs/stack/graph/) support (optional) |
@@ -164,6 +174,19 @@ pub mod resp;
 pub use bb8;
 pub use error::*;
 use network::*;
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+#[cfg(feature = "derive")]
+pub use rustis_derive::{FromRedisHash, FromRedisValue, ToRedisHash};
+
+// allows the code generated by the derive macros to be used within this crate
+#[cfg(feature = "derive")]
+extern crate self as rustis;
+
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub mod __private {
+    pub use serde;
+}
 
 /// Library general result type.
 pub type Result<T> = std::result::Result<T, Error>;
@@ -176,5 +199,8 @@ compile_error!("feature \"tokio-runtime\" and feature \"async-std-runtime\" cann
 #[cfg(all(feature = "pool", feature = "async-std-runtime"))]
 compile_error!("feature \"pool\" is only compatible with \"tokio-runtime\" (bb8 constraint)");
 
+#[cfg(all(feature = "sync", feature = "async-std-runtime"))]
+compile_error!("feature \"sync\" is only compatible with \"tokio-runtime\"");
+
 #[cfg(test)]
 mod tests;
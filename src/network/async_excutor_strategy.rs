@@ -1,10 +1,14 @@
 #[cfg(feature = "tls")]
 use crate::client::TlsConfig;
-use crate::{client::Config, Error, Result};
+use crate::{
+    client::{Config, Resolver},
+    Error, Result,
+};
 use futures_util::{Future, FutureExt};
-use log::{debug, info};
+use log::{debug, info, warn};
 use socket2::{SockRef, TcpKeepalive};
 use std::{
+    net::SocketAddr,
     pin::Pin,
     task::{Context, Poll},
     time::Duration,
@@ -36,6 +40,71 @@ pub(crate) type TcpTlsStreamWriter = tokio_util::compat::Compat<
     futures::io::WriteHalf<async_native_tls::TlsStream<async_std::net::TcpStream>>,
 >;
 
+#[cfg(feature = "tokio-runtime")]
+type TcpStream = tokio::net::TcpStream;
+#[cfg(feature = "async-std-runtime")]
+type TcpStream = async_std::net::TcpStream;
+
+/// Resolve `host`:`port` with the resolver of the async runtime
+pub(crate) async fn lookup_host(host: &str, port: u16) -> Result<Vec<SocketAddr>> {
+    #[cfg(feature = "tokio-runtime")]
+    let addrs = tokio::net::lookup_host((host, port)).await?.collect();
+    #[cfg(feature = "async-std-runtime")]
+    let addrs = async_std::net::ToSocketAddrs::to_socket_addrs(&(host, port))
+        .await?
+        .collect();
+
+    Ok(addrs)
+}
+
+/// Connect to `host`:`port`, through the addresses returned by the custom resolver if any.
+///
+/// Resolved addresses are tried in order, each one with the connect timeout,
+/// until a connection succeeds.
+async fn tcp_stream_connect(
+    host: &str,
+    port: u16,
+    resolver: Option<&Resolver>,
+    connect_timeout: Duration,
+) -> Result<TcpStream> {
+    let Some(resolver) = resolver else {
+        return Ok(timeout(connect_timeout, TcpStream::connect((host, port))).await??);
+    };
+
+    let addrs = resolver.resolve(host, port).await?;
+    debug!("Resolved {host}:{port} to {addrs:?}");
+
+    let mut last_error = None;
+    for addr in addrs {
+        match timeout(connect_timeout, TcpStream::connect(addr)).await {
+            Ok(Ok(stream)) => return Ok(stream),
+            Ok(Err(e)) => {
+                warn!("Cannot connect to {addr} ({host}:{port}): {e}");
+                last_error = Some(e.into());
+            }
+            Err(e) => {
+                warn!("Cannot connect to {addr} ({host}:{port}): {e}");
+                last_error = Some(e);
+            }
+        }
+    }
+
+    Err(last_error
+        .unwrap_or_else(|| Error::Client(format!("No address resolved for {host}:{port}"))))
+}
+
+/// Applies [`keep_alive`](Config::keep_alive) and [`no_delay`](Config::no_delay)
+/// to a freshly connected socket, before any TLS handshake
+fn configure_tcp_stream(socket: SockRef, config: &Config) -> Result<()> {
+    if let Some(keep_alive) = config.keep_alive {
+        socket.set_tcp_keepalive(&TcpKeepalive::new().with_time(keep_alive))?;
+    }
+
+    socket.set_nodelay(config.no_delay)?;
+
+    Ok(())
+}
+
 pub(crate) async fn tcp_connect(
     host: &str,
     port: u16,
@@ -51,19 +120,11 @@ pub(crate) async fn tcp_connect(
 
     #[cfg(feature = "tokio-runtime")]
     {
-        let stream = timeout(
-            config.connect_timeout,
-            tokio::net::TcpStream::connect((host, port)),
-        )
-        .await??;
-
-        if let Some(keep_alive) = config.keep_alive {
-            SockRef::from(&stream).set_tcp_keepalive(&TcpKeepalive::new().with_time(keep_alive))?;
-        }
+        let stream =
+            tcp_stream_connect(host, port, config.resolver.as_ref(), config.connect_timeout)
+                .await?;
 
-        if config.no_delay {
-            stream.set_nodelay(true)?;
-        }
+        configure_tcp_stream(SockRef::from(&stream), config)?;
 
         (reader, writer) = tokio::io::split(stream);
     }
@@ -72,19 +133,11 @@ pub(crate) async fn tcp_connect(
         use futures::AsyncReadExt;
         use tokio_util::compat::{FuturesAsyncReadCompatExt, FuturesAsyncWriteCompatExt};
 
-        let stream = timeout(
-            config.connect_timeout,
-            async_std::net::TcpStream::connect((host, port)),
-        )
-        .await??;
+        let stream =
+            tcp_stream_connect(host, port, config.resolver.as_ref(), config.connect_timeout)
+                .await?;
 
-        if let Some(keep_alive) = config.keep_alive {
-            SockRef::from(&stream).set_tcp_keepalive(&TcpKeepalive::new().with_time(keep_alive))?;
-        }
-
-        if config.no_delay {
-            stream.set_nodelay(true)?;
-        }
+        configure_tcp_stream(SockRef::from(&stream), config)?;
 
         let (r, w) = stream.split();
         reader = r.compat();
@@ -101,9 +154,12 @@ pub(crate) async fn tcp_tls_connect(
     host: &str,
     port: u16,
     tls_config: &TlsConfig,
-    connect_timeout: Duration,
+    config: &Config,
 ) -> Result<(TcpTlsStreamReader, TcpTlsStreamWriter)> {
-    debug!("Connecting to {host}:{port} with timeout {connect_timeout:?}...");
+    debug!(
+        "Connecting to {host}:{port} with timeout {:?}...",
+        config.connect_timeout
+    );
 
     let reader: TcpTlsStreamReader;
     let writer: TcpTlsStreamWriter;
@@ -112,11 +168,10 @@ pub(crate) async fn tcp_tls_connect(
     #[cfg(feature = "tokio-runtime")]
     #[cfg(feature = "tokio-tls")]
     {
-        let stream = timeout(
-            connect_timeout,
-            tokio::net::TcpStream::connect((host, port)),
-        )
-        .await??;
+        let stream =
+            tcp_stream_connect(host, port, config.resolver.as_ref(), config.connect_timeout)
+                .await?;
+        configure_tcp_stream(SockRef::from(&stream), config)?;
         let tls_connector: native_tls::TlsConnector = builder.build()?;
         let tls_connector = tokio_native_tls::TlsConnector::from(tls_connector);
         let tls_stream = tls_connector.connect(host, stream).await?;
@@ -128,11 +183,10 @@ pub(crate) async fn tcp_tls_connect(
         use futures::AsyncReadExt;
         use tokio_util::compat::{FuturesAsyncReadCompatExt, FuturesAsyncWriteCompatExt};
 
-        let stream = timeout(
-            connect_timeout,
-            async_std::net::TcpStream::connect((host, port)),
-        )
-        .await??;
+        let stream =
+            tcp_stream_connect(host, port, config.resolver.as_ref(), config.connect_timeout)
+                .await?;
+        configure_tcp_stream(SockRef::from(&stream), config)?;
         let tls_connector: async_native_tls::TlsConnector = builder.into();
         let tls_stream = tls_connector.connect(host, stream).await?;
         let (r, w) = tls_stream.split();
@@ -1,11 +1,11 @@
 use crate::{
-    client::{ClusterConfig, Config},
+    client::{ClusterConfig, ClusterNodeInfo, ClusterNodes, Config, Version},
     commands::{
         ClusterCommands, ClusterHealthStatus, ClusterNodeResult, ClusterShardResult, CommandTip,
         LegacyClusterShardResult, RequestPolicy, ResponsePolicy,
     },
-    network::{CommandInfoManager, Version},
-    resp::{Command, RespBuf, RespDeserializer, RespSerializer},
+    network::{CommandInfoCache, CommandInfoManager},
+    resp::{cmd, Command, RespBuf, RespDeserializer, RespSerializer},
     Error, RedisError, RedisErrorKind, Result, RetryReason, StandaloneConnection,
 };
 use futures_util::{future, FutureExt};
@@ -102,6 +102,8 @@ pub struct ClusterConnection {
     slot_ranges: Vec<SlotRange>,
     command_info_manager: CommandInfoManager,
     pending_requests: VecDeque<RequestInfo>,
+    /// snapshot of `nodes`, shared with the clients
+    cluster_nodes: ClusterNodes,
     tag: String,
 }
 
@@ -119,15 +121,62 @@ impl ClusterConnection {
             CommandInfoManager::initialize(&mut first_node.connection).await?;
         let tag = first_node.connection.tag().to_owned();
 
-        Ok(ClusterConnection {
+        let connection = ClusterConnection {
             cluster_config: cluster_config.clone(),
             config: config.clone(),
             nodes,
             slot_ranges,
             command_info_manager,
             pending_requests: VecDeque::new(),
+            cluster_nodes: ClusterNodes::default(),
             tag,
-        })
+        };
+        connection.update_cluster_nodes();
+
+        Ok(connection)
+    }
+
+    /// Snapshot of the cluster nodes, kept up to date when the topology changes
+    pub(crate) fn cluster_nodes(&self) -> ClusterNodes {
+        self.cluster_nodes.clone()
+    }
+
+    /// Metadata of the commands, shared with the clients
+    pub(crate) fn command_info_cache(&self) -> CommandInfoCache {
+        self.command_info_manager.command_info_cache()
+    }
+
+    /// Lowest version among the cluster nodes,
+    /// since a feature can only be relied on once all the nodes support it
+    pub(crate) fn server_version(&self) -> Option<Version> {
+        self.nodes
+            .iter()
+            .filter_map(|node| Version::try_from(node.connection.get_version()).ok())
+            .min()
+    }
+
+    /// Protocol negotiated with the nodes, which all receive the same `HELLO`
+    pub(crate) fn protocol(&self) -> Option<usize> {
+        self.nodes
+            .first()
+            .map(|node| node.connection.get_protocol())
+    }
+
+    fn update_cluster_nodes(&self) {
+        let cluster_nodes = self
+            .nodes
+            .iter()
+            .map(|node| ClusterNodeInfo {
+                id: node.id.as_ref().to_owned(),
+                host: node.address.0.clone(),
+                port: node.address.1,
+                is_master: node.is_master,
+            })
+            .collect();
+
+        if let Ok(mut guard) = self.cluster_nodes.write() {
+            *guard = cluster_nodes;
+        }
     }
 
     pub async fn write(&mut self, command: &Command) -> Result<()> {
@@ -141,7 +190,13 @@ impl ClusterConnection {
     ) -> Result<()> {
         debug!("[{}] Analyzing command {command:?}", self.tag);
 
-        let command_info = self.command_info_manager.get_command_info(command);
+        // commands missing from the cache, e.g. from a module loaded after the connection,
+        // are looked up once with `COMMAND INFO`
+        let node_idx = self.get_random_node_index();
+        let command_info = self
+            .command_info_manager
+            .fetch_command_info(command, &mut self.nodes[node_idx].connection)
+            .await?;
 
         let command_info = if let Some(command_info) = command_info {
             command_info
@@ -152,7 +207,8 @@ impl ClusterConnection {
             )));
         };
 
-        let command_name = command_info.name.to_string();
+        // names are cached in upper case
+        let command_name = command_info.name.to_uppercase();
 
         let request_policy = command_info.command_tips.iter().find_map(|tip| {
             if let CommandTip::RequestPolicy(request_policy) = tip {
@@ -162,15 +218,25 @@ impl ClusterConnection {
             }
         });
 
-        let node_idx = self.get_random_node_index();
         let keys = self
             .command_info_manager
             .extract_keys(command, &mut self.nodes[node_idx].connection)
             .await?;
-        let slots = Self::hash_slots(&keys);
+        let mut slots = Self::hash_slots(&keys);
+        if slots.is_empty() {
+            if let Some(slot) = Self::slot_argument(command)? {
+                slots.push(slot);
+            }
+        }
 
         debug!("[{}] keys: {keys:?}, slots: {slots:?}", self.tag);
 
+        if let "SUBSCRIBE" | "PSUBSCRIBE" | "SSUBSCRIBE" | "UNSUBSCRIBE" | "PUNSUBSCRIBE"
+        | "SUNSUBSCRIBE" = command.name
+        {
+            return self.write_subscription(command, keys, slots).await;
+        }
+
         if let Some(request_policy) = request_policy {
             match request_policy {
                 RequestPolicy::AllNodes => {
@@ -208,14 +274,17 @@ impl ClusterConnection {
         commands: impl Iterator<Item = &mut Command>,
         retry_reasons: &[RetryReason],
     ) -> Result<()> {
-        if retry_reasons.iter().any(|r| {
-            matches!(
-                r,
-                RetryReason::Moved {
-                    hash_slot: _,
-                    address: _
-                }
-            )
+        // a MOVED redirection means that the topology has changed:
+        // new nodes are connected lazily, when the cluster is refreshed
+        if retry_reasons.iter().any(|r| match r {
+            RetryReason::Moved {
+                hash_slot: _,
+                address: _,
+            } => true,
+            RetryReason::Ask {
+                hash_slot: _,
+                address,
+            } => !self.nodes.iter().any(|n| n.address == *address),
         }) {
             self.refresh_nodes_and_slot_ranges().await?;
         }
@@ -238,6 +307,61 @@ impl ClusterConnection {
         Ok(())
     }
 
+    /// Subscription commands are not registered as pending requests:
+    /// their replies are push messages, handled by the network handler.
+    ///
+    /// Sharded subscriptions are sent to the master node owning the hash slot of each shard channel,
+    /// with one command per hash slot.
+    /// Regular subscriptions are sent to the first node, which receives the messages
+    /// published on any node of the cluster.
+    async fn write_subscription(
+        &mut self,
+        command: &Command,
+        keys: SmallVec<[String; 10]>,
+        slots: SmallVec<[u16; 10]>,
+    ) -> Result<()> {
+        match command.name {
+            "SSUBSCRIBE" | "SUNSUBSCRIBE" if !keys.is_empty() => {
+                let mut slot_keys = zip(slots, keys).collect::<SmallVec<[(u16, String); 10]>>();
+                slot_keys.sort();
+
+                let mut start = 0;
+                while start < slot_keys.len() {
+                    let slot = slot_keys[start].0;
+                    let end = slot_keys[start..]
+                        .iter()
+                        .position(|(s, _)| *s != slot)
+                        .map_or(slot_keys.len(), |i| start + i);
+
+                    let (node_idx, _) = self
+                        .get_master_node_index_by_slot(slot, &[])
+                        .ok_or_else(|| Error::Client("Cluster misconfiguration".to_owned()))?;
+
+                    let mut shard_command = cmd(command.name);
+                    for (_, key) in &slot_keys[start..end] {
+                        shard_command = shard_command.arg(key.clone());
+                    }
+
+                    let connection = &mut self.nodes[node_idx].connection;
+                    connection.write(&shard_command).await?;
+
+                    start = end;
+                }
+            }
+            "SUNSUBSCRIBE" => {
+                // shard channels may be subscribed on any master node
+                for node in self.nodes.iter_mut().filter(|n| n.is_master) {
+                    node.connection.write(command).await?;
+                }
+            }
+            _ => {
+                self.nodes[0].connection.write(command).await?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// The client should execute the command on all master shards (e.g., the DBSIZE command).
     /// This tip is in-use by commands that don't accept key name arguments.
     /// The command operates atomically per shard.
@@ -833,6 +957,7 @@ impl ClusterConnection {
 
         self.nodes = nodes;
         self.slot_ranges = slot_ranges;
+        self.update_cluster_nodes();
 
         Ok(())
 
@@ -970,6 +1095,7 @@ impl ClusterConnection {
         }
 
         self.nodes.sort_by(|n1, n2| n1.id.cmp(&n2.id));
+        self.update_cluster_nodes();
 
         debug!(
             "[{}] Cluster replicas connected: nodes={:?}, slot_ranges={:?}",
@@ -1057,6 +1183,7 @@ impl ClusterConnection {
 
         self.slot_ranges.sort_by_key(|s| s.slot_range.0);
         self.nodes.sort_by(|n1, n2| n1.id.cmp(&n2.id));
+        self.update_cluster_nodes();
 
         debug!(
             "[{}] Cluster new setup: nodes={:?}, slot_ranges={:?}",
@@ -1129,6 +1256,39 @@ impl ClusterConnection {
         }
     }
 
+    /// Extract the hash slot argument of the commands scoped to a slot without keys:
+    /// `CLUSTER COUNTKEYSINSLOT` & `CLUSTER GETKEYSINSLOT`
+    fn slot_argument(command: &Command) -> Result<Option<u16>> {
+        if command.name != "CLUSTER" {
+            return Ok(None);
+        }
+
+        let mut args = command.args.iter();
+        let Some(subcommand) = args.next() else {
+            return Ok(None);
+        };
+        if !subcommand.eq_ignore_ascii_case(b"COUNTKEYSINSLOT")
+            && !subcommand.eq_ignore_ascii_case(b"GETKEYSINSLOT")
+        {
+            return Ok(None);
+        }
+
+        // without a valid slot, no node can be selected
+        let Some(arg) = args.next() else {
+            return Ok(None);
+        };
+        let slot = std::str::from_utf8(arg)
+            .ok()
+            .and_then(|s| s.parse::<u16>().ok());
+        match slot {
+            Some(slot) if slot < 16384 => Ok(Some(slot)),
+            _ => Err(Error::Client(format!(
+                "Invalid hash slot {}: expected a slot between 0 and 16383",
+                String::from_utf8_lossy(arg)
+            ))),
+        }
+    }
+
     fn hash_slots(keys: &[String]) -> SmallVec<[u16; 10]> {
         keys.iter().map(|k| Self::hash_slot(k)).collect()
     }
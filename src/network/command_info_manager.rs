@@ -1,65 +1,113 @@
 use crate::{
-    commands::{BeginSearch, CommandInfo, FindKeys, ServerCommands},
-    network::Version,
+    client::Version,
+    commands::{BeginSearch, CommandCommands, CommandInfo, FindKeys},
     resp::{cmd, Command, CommandArgs},
     Error, Result, StandaloneConnection,
 };
 use smallvec::SmallVec;
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+/// Metadata of the commands by upper case name, e.g. `GET` or `CONFIG|GET`,
+/// shared by the clones of a client and by its cluster connection.
+///
+/// Commands unknown to the server are not cached,
+/// so that the commands of a module loaded later are found by the next lookup.
+pub(crate) type CommandInfoCache = Arc<RwLock<HashMap<String, Arc<CommandInfo>>>>;
+
+/// Insert `command_infos` and their sub commands in `command_info_cache`
+pub(crate) fn cache_command_infos(
+    command_info_cache: &CommandInfoCache,
+    command_infos: impl IntoIterator<Item = CommandInfo>,
+) {
+    let Ok(mut command_info_cache) = command_info_cache.write() else {
+        return;
+    };
+
+    let mut command_infos = command_infos.into_iter().collect::<Vec<_>>();
+    while let Some(command_info) = command_infos.pop() {
+        command_infos.extend(command_info.sub_commands.iter().cloned());
+        command_info_cache.insert(command_info.name.to_uppercase(), Arc::new(command_info));
+    }
+}
+
+/// Look up the metadata of `command`, or of its sub command for container commands
+pub(crate) fn get_cached_command_info(
+    command_info_cache: &CommandInfoCache,
+    command: &Command,
+) -> Option<Arc<CommandInfo>> {
+    let command_info_cache = command_info_cache.read().ok()?;
+    let command_info = command_info_cache.get(command.name)?;
+
+    if command_info.arity == -2 && !command_info.sub_commands.is_empty() {
+        if let Some(first_arg) = command.args.into_iter().next() {
+            if let Ok(first_arg) = std::str::from_utf8(first_arg) {
+                let command_name = format!("{}|{}", command.name, first_arg.to_uppercase());
+                if let Some(sub_command_info) = command_info_cache.get(&command_name) {
+                    return Some(sub_command_info.clone());
+                }
+            }
+        }
+    }
+
+    Some(command_info.clone())
+}
 
 pub(crate) struct CommandInfoManager {
-    command_info_map: HashMap<String, CommandInfo>,
+    command_info_cache: CommandInfoCache,
     legacy: bool,
 }
 
 impl CommandInfoManager {
     pub async fn initialize(connection: &mut StandaloneConnection) -> Result<CommandInfoManager> {
-        let mut command_info_result = connection.command().await?;
-        let sub_commands = command_info_result
-            .iter()
-            .filter_map(|c| {
-                if c.sub_commands.is_empty() {
-                    None
-                } else {
-                    Some(c.sub_commands.clone())
-                }
-            })
-            .flatten()
-            .collect::<Vec<_>>();
-        command_info_result.extend(sub_commands);
+        let command_info_result = connection.command().await?;
+        let command_info_cache = CommandInfoCache::default();
+        cache_command_infos(&command_info_cache, command_info_result);
 
         let version: Version = connection.get_version().try_into()?;
 
         Ok(CommandInfoManager {
-            command_info_map: command_info_result
-                .into_iter()
-                .map(|mut c| {
-                    c.name = c.name.to_uppercase();
-                    (c.name.to_string(), c)
-                })
-                .collect(),
+            command_info_cache,
             legacy: version.major < 7,
         })
     }
 
-    pub fn get_command_info_by_name(&self, command_name: &str) -> Option<&CommandInfo> {
-        self.command_info_map.get(command_name)
+    /// Cache shared with the clients of the cluster connection
+    pub fn command_info_cache(&self) -> CommandInfoCache {
+        self.command_info_cache.clone()
     }
 
-    pub fn get_command_info(&self, command: &Command) -> Option<&CommandInfo> {
-        let command_info = self.command_info_map.get(command.name);
-        if let Some(command_info) = command_info {
-            if command_info.arity == -2 && !command_info.sub_commands.is_empty() {
-                if let Some(first_arg) = command.args.into_iter().next() {
-                    if let Ok(first_arg) = std::str::from_utf8(first_arg) {
-                        let command_name = format!("{}|{}", command.name, first_arg);
-                        return self.command_info_map.get(&command_name);
-                    }
-                }
-            }
+    pub fn get_command_info_by_name(&self, command_name: &str) -> Option<Arc<CommandInfo>> {
+        self.command_info_cache
+            .read()
+            .ok()?
+            .get(command_name)
+            .cloned()
+    }
+
+    pub fn get_command_info(&self, command: &Command) -> Option<Arc<CommandInfo>> {
+        get_cached_command_info(&self.command_info_cache, command)
+    }
+
+    /// Look up the metadata of `command` in the cache,
+    /// and fetch them with `COMMAND INFO` on a cache miss, e.g. for a module loaded after the connection.
+    pub async fn fetch_command_info(
+        &self,
+        command: &Command,
+        connection: &mut StandaloneConnection,
+    ) -> Result<Option<Arc<CommandInfo>>> {
+        if let Some(command_info) = get_cached_command_info(&self.command_info_cache, command) {
+            return Ok(Some(command_info));
+        }
+
+        let command_infos: Vec<Option<CommandInfo>> = connection.command_info(command.name).await?;
+        if let Some(command_info) = command_infos.into_iter().next().flatten() {
+            cache_command_infos(&self.command_info_cache, [command_info]);
         }
 
-        command_info
+        Ok(self.get_command_info(command))
     }
 
     /// see <https://redis.io/docs/reference/key-specs/>
@@ -68,7 +116,7 @@ impl CommandInfoManager {
         command: &Command,
         connection: &mut StandaloneConnection,
     ) -> Result<SmallVec<[String; 10]>> {
-        let command_info = if let Some(command_info) = self.command_info_map.get(command.name) {
+        let command_info = if let Some(command_info) = self.get_command_info_by_name(command.name) {
             command_info
         } else {
             return Err(Error::Client(format!("Unknown command {}", command.name)));
@@ -208,7 +256,7 @@ impl CommandInfoManager {
         command: &Command,
         shard_keys: impl Iterator<Item = &'a String>,
     ) -> Result<Command> {
-        let command_info = if let Some(command_info) = self.command_info_map.get(command.name) {
+        let command_info = if let Some(command_info) = self.get_command_info_by_name(command.name) {
             command_info
         } else {
             return Err(Error::Client(format!("Unknown command {}", command.name)));
@@ -1,12 +1,9 @@
 use crate::{
-    client::{Config, PreparedCommand, ServerConfig},
-    commands::InternalPubSubCommands,
+    client::{ClusterNodes, Config, ServerConfig, Version},
     resp::{Command, RespBuf},
-    ClusterConnection, Error, Future, Result, RetryReason, SentinelConnection,
-    StandaloneConnection,
+    ClusterConnection, CommandInfoCache, Result, RetryReason, SentinelConnection,
+    StandaloneConnection, Transport,
 };
-use serde::de::DeserializeOwned;
-use std::future::IntoFuture;
 
 pub enum Connection {
     Standalone(StandaloneConnection),
@@ -29,9 +26,11 @@ impl Connection {
             )),
         }
     }
+}
 
+impl Transport for Connection {
     #[inline]
-    pub async fn write(&mut self, command: &Command) -> Result<()> {
+    async fn write(&mut self, command: &Command) -> Result<()> {
         match self {
             Connection::Standalone(connection) => connection.write(command).await,
             Connection::Sentinel(connection) => connection.write(command).await,
@@ -40,9 +39,9 @@ impl Connection {
     }
 
     #[inline]
-    pub async fn write_batch(
+    async fn write_batch<'a>(
         &mut self,
-        commands: impl Iterator<Item = &mut Command>,
+        commands: impl Iterator<Item = &'a mut Command> + Send,
         retry_reasons: &[RetryReason],
     ) -> Result<()> {
         match self {
@@ -59,7 +58,7 @@ impl Connection {
     }
 
     #[inline]
-    pub async fn read(&mut self) -> Option<Result<RespBuf>> {
+    async fn read(&mut self) -> Option<Result<RespBuf>> {
         match self {
             Connection::Standalone(connection) => connection.read().await,
             Connection::Sentinel(connection) => connection.read().await,
@@ -68,7 +67,7 @@ impl Connection {
     }
 
     #[inline]
-    pub async fn reconnect(&mut self) -> Result<()> {
+    async fn reconnect(&mut self) -> Result<()> {
         match self {
             Connection::Standalone(connection) => connection.reconnect().await,
             Connection::Sentinel(connection) => connection.reconnect().await,
@@ -76,37 +75,47 @@ impl Connection {
         }
     }
 
-    #[inline]
-    pub async fn send(&mut self, command: &Command) -> Result<RespBuf> {
-        self.write(command).await?;
-        self.read()
-            .await
-            .ok_or_else(|| Error::Client("Disconnected by peer".to_owned()))?
-    }
-
-    pub(crate) fn tag(&self) -> &str {
+    fn tag(&self) -> &str {
         match self {
             Connection::Standalone(connection) => connection.tag(),
             Connection::Sentinel(connection) => connection.tag(),
             Connection::Cluster(connection) => connection.tag(),
         }
     }
-}
 
-impl<'a, R> IntoFuture for PreparedCommand<'a, &'a mut Connection, R>
-where
-    R: DeserializeOwned + Send + 'a,
-{
-    type Output = Result<R>;
-    type IntoFuture = Future<'a, R>;
+    fn is_cluster(&self) -> bool {
+        matches!(self, Connection::Cluster(_))
+    }
 
-    #[inline]
-    fn into_future(self) -> Self::IntoFuture {
-        Box::pin(async move {
-            let result = self.executor.send(&self.command).await?;
-            result.to()
-        })
+    fn cluster_nodes(&self) -> Option<ClusterNodes> {
+        match self {
+            Connection::Cluster(connection) => Some(connection.cluster_nodes()),
+            _ => None,
+        }
+    }
+
+    fn command_info_cache(&self) -> Option<CommandInfoCache> {
+        match self {
+            Connection::Cluster(connection) => Some(connection.command_info_cache()),
+            _ => None,
+        }
+    }
+
+    fn server_version(&self) -> Option<Version> {
+        match self {
+            Connection::Standalone(connection) => connection.get_version().try_into().ok(),
+            Connection::Sentinel(connection) => {
+                connection.inner_connection.get_version().try_into().ok()
+            }
+            Connection::Cluster(connection) => connection.server_version(),
+        }
     }
-}
 
-impl<'a> InternalPubSubCommands<'a> for &'a mut Connection {}
+    fn protocol(&self) -> Option<usize> {
+        match self {
+            Connection::Standalone(connection) => Some(connection.get_protocol()),
+            Connection::Sentinel(connection) => Some(connection.inner_connection.get_protocol()),
+            Connection::Cluster(connection) => connection.protocol(),
+        }
+    }
+}
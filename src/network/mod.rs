@@ -3,16 +3,18 @@ mod cluster_connection;
 mod command_info_manager;
 mod connection;
 mod network_handler;
+mod pub_sub_channel;
 mod sentinel_connection;
 mod standalone_connection;
+mod transport;
 mod util;
-mod version;
 
 pub(crate) use async_excutor_strategy::*;
 pub(crate) use cluster_connection::*;
 pub(crate) use command_info_manager::*;
 pub(crate) use connection::*;
 pub(crate) use network_handler::*;
+pub(crate) use pub_sub_channel::*;
 pub(crate) use sentinel_connection::*;
 pub(crate) use standalone_connection::*;
-pub(crate) use version::*;
+pub(crate) use transport::*;
@@ -1,15 +1,19 @@
 use super::util::RefPubSubMessage;
 use crate::{
-    client::{Commands, Config, Message},
-    commands::InternalPubSubCommands,
-    resp::{cmd, Command, RespBuf},
-    spawn, Connection, Error, JoinHandle, Result, RetryReason,
+    client::{ClusterNodes, Commands, Config, Message, ReconnectPolicy, Version},
+    resp::{cmd, Command, RespBuf, Value},
+    sleep, spawn, timeout, CommandInfoCache, Connection, Error, JoinHandle, PubSubSender, Result,
+    RetryReason, Transport,
 };
 use futures_channel::{mpsc, oneshot};
 use futures_util::{select, FutureExt, SinkExt, StreamExt};
 use log::{trace, debug, error, info, log_enabled, warn, Level};
 use smallvec::SmallVec;
-use std::collections::{HashMap, VecDeque};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, RwLock},
+    time::Duration,
+};
 use tokio::sync::broadcast;
 
 pub(crate) type MsgSender = mpsc::UnboundedSender<Message>;
@@ -18,12 +22,16 @@ pub(crate) type ResultSender = oneshot::Sender<Result<RespBuf>>;
 pub(crate) type ResultReceiver = oneshot::Receiver<Result<RespBuf>>;
 pub(crate) type ResultsSender = oneshot::Sender<Result<Vec<RespBuf>>>;
 pub(crate) type ResultsReceiver = oneshot::Receiver<Result<Vec<RespBuf>>>;
-pub(crate) type PubSubSender = mpsc::UnboundedSender<Result<RespBuf>>;
-pub(crate) type PubSubReceiver = mpsc::UnboundedReceiver<Result<RespBuf>>;
+pub(crate) type WriteSender = oneshot::Sender<Result<()>>;
+pub(crate) type WriteReceiver = oneshot::Receiver<Result<()>>;
+pub(crate) type StreamSender = mpsc::Sender<Result<RespBuf>>;
+pub(crate) type StreamReceiver = mpsc::Receiver<Result<RespBuf>>;
 pub(crate) type PushSender = mpsc::UnboundedSender<Result<RespBuf>>;
 pub(crate) type PushReceiver = mpsc::UnboundedReceiver<Result<RespBuf>>;
 pub(crate) type ReconnectSender = broadcast::Sender<()>;
 pub(crate) type ReconnectReceiver = broadcast::Receiver<()>;
+pub(crate) type ServerVersion = Arc<RwLock<Option<Version>>>;
+pub(crate) type ServerProtocol = Arc<RwLock<Option<usize>>>;
 
 #[derive(Clone, Copy, Debug)]
 enum Status {
@@ -73,38 +81,87 @@ impl MessageToReceive {
     }
 }
 
-pub(crate) struct NetworkHandler {
+pub(crate) struct NetworkHandler<T: Transport = Connection> {
     status: Status,
-    connection: Connection,
+    connection: T,
     /// for retries
     msg_sender: MsgSender,
     msg_receiver: MsgReceiver,
     messages_to_send: VecDeque<MessageToSend>,
     messages_to_receive: VecDeque<MessageToReceive>,
-    pending_subscriptions: HashMap<Vec<u8>, (SubscriptionType, PubSubSender)>,
+    /// the flag tells if subscription confirmations are forwarded to the sender
+    pending_subscriptions: HashMap<Vec<u8>, (SubscriptionType, PubSubSender, bool)>,
     pending_unsubscriptions: VecDeque<HashMap<Vec<u8>, SubscriptionType>>,
-    subscriptions: HashMap<Vec<u8>, (SubscriptionType, PubSubSender)>,
+    subscriptions: HashMap<Vec<u8>, (SubscriptionType, PubSubSender, bool)>,
     is_reply_on: bool,
+    /// `CLIENT REPLY SKIP` only suppresses the reply of the command that follows it
+    skip_next_reply: bool,
+    /// database switched to with `SELECT`, restored after a reconnection
+    selected_database: Option<usize>,
     push_sender: Option<PushSender>,
     pending_replies: Option<Vec<RespBuf>>,
     reconnect_sender: ReconnectSender,
+    /// shared with the clients, updated at each reconnection
+    server_version: ServerVersion,
+    /// shared with the clients, updated at each reconnection
+    server_protocol: ServerProtocol,
     auto_resubscribe: bool,
     auto_remonitor: bool,
     max_command_attempts: usize,
+    close_timeout: Duration,
+    write_coalesce: Option<Duration>,
+    reconnect_policy: ReconnectPolicy,
+    /// consecutive failed attempts to reconnect
+    num_reconnect_failures: u32,
     tag: String,
 }
 
 impl NetworkHandler {
-    pub async fn connect(config: Config) -> Result<(MsgSender, JoinHandle<()>, ReconnectSender)> {
+    pub async fn connect(
+        config: Config,
+    ) -> Result<(
+        MsgSender,
+        JoinHandle<()>,
+        ReconnectSender,
+        Option<ClusterNodes>,
+        ServerVersion,
+        ServerProtocol,
+        CommandInfoCache,
+    )> {
+        let connection = Connection::connect(config.clone()).await?;
+        Ok(NetworkHandler::start(connection, &config))
+    }
+}
+
+impl<T: Transport> NetworkHandler<T> {
+    /// Spawns the network loop over an already connected transport
+    pub fn start(
+        connection: T,
+        config: &Config,
+    ) -> (
+        MsgSender,
+        JoinHandle<()>,
+        ReconnectSender,
+        Option<ClusterNodes>,
+        ServerVersion,
+        ServerProtocol,
+        CommandInfoCache,
+    ) {
         // options
         let auto_resubscribe = config.auto_resubscribe;
         let auto_remonitor = config.auto_remonitor;
         let max_command_attempts = config.max_command_attempts;
+        let close_timeout = config.close_timeout;
+        let write_coalesce = config.write_coalesce;
+        let reconnect_policy = config.reconnect_policy;
 
-        let connection = Connection::connect(config).await?;
         let (msg_sender, msg_receiver): (MsgSender, MsgReceiver) = mpsc::unbounded();
         let (reconnect_sender, _): (ReconnectSender, ReconnectReceiver) = broadcast::channel(32);
         let tag = connection.tag().to_owned();
+        let cluster_nodes = connection.cluster_nodes();
+        let server_version = Arc::new(RwLock::new(connection.server_version()));
+        let server_protocol = Arc::new(RwLock::new(connection.protocol()));
+        let command_info_cache = connection.command_info_cache().unwrap_or_default();
 
         let mut network_handler = NetworkHandler {
             status: Status::Connected,
@@ -117,12 +174,20 @@ impl NetworkHandler {
             pending_unsubscriptions: VecDeque::new(),
             subscriptions: HashMap::new(),
             is_reply_on: true,
+            skip_next_reply: false,
+            selected_database: None,
             push_sender: None,
             pending_replies: None,
             reconnect_sender: reconnect_sender.clone(),
+            server_version: server_version.clone(),
+            server_protocol: server_protocol.clone(),
             auto_resubscribe,
             auto_remonitor,
             max_command_attempts,
+            close_timeout,
+            write_coalesce,
+            reconnect_policy,
+            num_reconnect_failures: 0,
             tag,
         };
 
@@ -135,7 +200,15 @@ impl NetworkHandler {
             }
         });
 
-        Ok((msg_sender, join_handle, reconnect_sender))
+        (
+            msg_sender,
+            join_handle,
+            reconnect_sender,
+            cluster_nodes,
+            server_version,
+            server_protocol,
+            command_info_cache,
+        )
     }
 
     async fn network_loop(&mut self) -> Result<()> {
@@ -150,12 +223,74 @@ impl NetworkHandler {
             }
         }
 
+        self.drain().await;
+
         debug!("[{}] end of network loop", self.tag);
         Ok(())
     }
 
+    /// Once the client is closed, wait for the replies of the commands already sent,
+    /// up to `close_timeout`, then close the connection with `QUIT`
+    async fn drain(&mut self) {
+        if !self.messages_to_send.is_empty() || !self.messages_to_receive.is_empty() {
+            debug!(
+                "[{}] draining {} messages to send and {} messages to receive",
+                self.tag,
+                self.messages_to_send.len(),
+                self.messages_to_receive.len()
+            );
+
+            let result = timeout(self.close_timeout, async {
+                while !self.messages_to_send.is_empty() || !self.messages_to_receive.is_empty() {
+                    // messages queued while disconnected have not been written yet
+                    if let Status::Disconnected = self.status {
+                        self.reconnect().await;
+                        if let Status::Disconnected = self.status {
+                            break;
+                        }
+                    } else if !self.messages_to_send.is_empty() {
+                        self.send_messages().await;
+                    }
+
+                    if self.messages_to_receive.is_empty() {
+                        continue;
+                    }
+
+                    let value = self.connection.read().await;
+                    self.handle_result(value).await;
+                }
+            })
+            .await;
+
+            if result.is_err() {
+                warn!(
+                    "[{}] close timeout expired, dropping {} pending messages",
+                    self.tag,
+                    self.messages_to_send.len() + self.messages_to_receive.len()
+                );
+            }
+        }
+
+        if let Status::Disconnected = self.status {
+            return;
+        }
+
+        if self.connection.is_cluster() {
+            return;
+        }
+
+        debug!("[{}] closing connection", self.tag);
+        let _result = timeout(self.close_timeout, async {
+            self.connection.write(&cmd("QUIT")).await?;
+            self.connection.read().await;
+            Ok::<(), Error>(())
+        })
+        .await;
+    }
+
     async fn handle_message(&mut self, mut msg: Option<Message>) -> bool {
         let is_channel_closed: bool;
+        let mut write_coalesce = self.write_coalesce;
 
         loop {
             if let Some(mut msg) = msg {
@@ -172,9 +307,12 @@ impl NetworkHandler {
                         _ => unreachable!(),
                     };
 
-                    let pending_subscriptions = pub_sub_senders
-                        .into_iter()
-                        .map(|(channel, sender)| (channel, (subscription_type, sender)));
+                    let pending_subscriptions =
+                        pub_sub_senders
+                            .into_iter()
+                            .map(|(channel, sender, with_events)| {
+                                (channel, (subscription_type, sender, with_events))
+                            });
 
                     self.pending_subscriptions.extend(pending_subscriptions);
                 }
@@ -185,6 +323,20 @@ impl NetworkHandler {
                     self.push_sender = Some(push_sender);
                 }
 
+                // RESET ends MONITOR through its own status, in other statuses it ends pub/sub
+                if let Status::Connected
+                | Status::Subscribing
+                | Status::Subscribed
+                | Status::Disconnected = self.status
+                {
+                    if (&msg.commands)
+                        .into_iter()
+                        .any(|command| command.name == "RESET")
+                    {
+                        self.reset();
+                    }
+                }
+
                 match &self.status {
                     Status::Connected => {
                         for command in &msg.commands {
@@ -254,6 +406,14 @@ impl NetworkHandler {
             match self.msg_receiver.try_next() {
                 Ok(m) => msg = m,
                 Err(_) => {
+                    // wait once for more messages to write them in the same batch
+                    if let Some(delay) = write_coalesce.take() {
+                        sleep(delay).await;
+                        if let Ok(m) = self.msg_receiver.try_recv() {
+                            msg = Some(m);
+                            continue;
+                        }
+                    }
                     // there are no messages available, but channel is not yet closed
                     is_channel_closed = false;
                     break;
@@ -269,6 +429,25 @@ impl NetworkHandler {
         !is_channel_closed
     }
 
+    /// Forget the pub/sub state of the connection after a `RESET` command.
+    ///
+    /// Each pub/sub stream holds its own sender,
+    /// so the subscription channels are closed to end the matching pub/sub streams.
+    fn reset(&mut self) {
+        debug!("[{}] resetting connection state", self.tag);
+        for (_, (_, mut sender, _)) in self
+            .subscriptions
+            .drain()
+            .chain(self.pending_subscriptions.drain())
+        {
+            sender.close_channel();
+        }
+        self.pending_unsubscriptions.clear();
+        if let Status::Subscribing | Status::Subscribed = self.status {
+            self.status = Status::Connected;
+        }
+    }
+
     async fn send_messages(&mut self) {
         if log_enabled!(Level::Debug) {
             let num_commands = self
@@ -294,18 +473,42 @@ impl NetworkHandler {
             let mut num_commands_to_receive: usize = 0;
 
             for command in commands.into_iter() {
+                let is_reply_skipped = std::mem::take(&mut self.skip_next_reply);
+                let mut is_replied = self.is_reply_on && !is_reply_skipped;
+
                 if command.name == "CLIENT" {
                     let mut args = command.args.into_iter();
 
                     match (args.next(), args.next()) {
-                        (Some(b"REPLY"), Some(b"OFF")) => self.is_reply_on = false,
-                        (Some(b"REPLY"), Some(b"SKIP")) => self.is_reply_on = false,
-                        (Some(b"REPLY"), Some(b"ON")) => self.is_reply_on = true,
+                        (Some(b"REPLY"), Some(b"OFF")) => {
+                            self.is_reply_on = false;
+                            is_replied = false;
+                        }
+                        (Some(b"REPLY"), Some(b"SKIP")) => {
+                            // ignored by the server when replies are off
+                            self.skip_next_reply = self.is_reply_on;
+                            is_replied = false;
+                        }
+                        (Some(b"REPLY"), Some(b"ON")) => {
+                            // ON is always replied, even right after SKIP
+                            self.is_reply_on = true;
+                            is_replied = true;
+                        }
                         _ => (),
                     }
+                } else if command.name == "SELECT" {
+                    if let Some(database) = command.args.into_iter().next().and_then(atoi::atoi) {
+                        self.selected_database = Some(database);
+                    }
+                } else if command.name == "RESET" {
+                    // RESET restores the default reply mode and database,
+                    // and is replied unless it follows a SKIP
+                    self.is_reply_on = true;
+                    self.selected_database = Some(0);
+                    is_replied = !is_reply_skipped;
                 }
 
-                if self.is_reply_on {
+                if is_replied {
                     num_commands_to_receive += 1;
                 }
 
@@ -349,6 +552,24 @@ impl NetworkHandler {
                             );
                             }
                         }
+                        Commands::Stream(_, mut stream_sender) => {
+                            if let Err(e) = stream_sender.send(Err(e.clone())).await {
+                                warn!(
+                                "[{}] Cannot send value to caller because receiver is not there anymore: {:?}",
+                                self.tag,
+                                e
+                            );
+                            }
+                        }
+                        Commands::BatchForget(_, Some(write_sender)) => {
+                            if let Err(e) = write_sender.send(Err(e.clone())) {
+                                warn!(
+                                "[{}] Cannot send value to caller because receiver is not there anymore: {:?}",
+                                self.tag,
+                                e
+                            );
+                            }
+                        }
                         _ => (),
                     }
                 }
@@ -356,7 +577,12 @@ impl NetworkHandler {
             }
         } else {
             let mut idx: usize = 0;
-            while let Some(msg) = self.messages_to_send.pop_front() {
+            while let Some(mut msg) = self.messages_to_send.pop_front() {
+                if let Commands::BatchForget(_, write_sender) = &mut msg.message.commands {
+                    if let Some(write_sender) = write_sender.take() {
+                        let _ = write_sender.send(Ok(()));
+                    }
+                }
                 if commands_to_receive[idx] > 0 {
                     self.messages_to_receive.push_back(MessageToReceive::new(
                         msg.message,
@@ -388,7 +614,7 @@ impl NetworkHandler {
                         }
                     },
                     _ => {
-                        self.receive_result(result);
+                        self.receive_result(result).await;
                     }
                 },
                 Status::Subscribing => {
@@ -399,16 +625,16 @@ impl NetworkHandler {
                     }
 
                     if let Some(resp_buf) = self.try_match_pubsub_message(result).await {
-                        self.receive_result(resp_buf);
+                        self.receive_result(resp_buf).await;
                     }
                 }
                 Status::Subscribed => {
                     if let Some(resp_buf) = self.try_match_pubsub_message(result).await {
-                        self.receive_result(resp_buf);
+                        self.receive_result(resp_buf).await;
                     }
                 }
                 Status::EnteringMonitor => {
-                    self.receive_result(result);
+                    self.receive_result(result).await;
                     self.status = Status::Monitor;
                 }
                 Status::Monitor => match &result {
@@ -422,7 +648,7 @@ impl NetworkHandler {
                             }
                         }
                     }
-                    _ => self.receive_result(result),
+                    _ => self.receive_result(result).await,
                 },
                 Status::LeavingMonitor => match &result {
                     Ok(resp_buf) if resp_buf.is_monitor_message() => {
@@ -436,7 +662,7 @@ impl NetworkHandler {
                         }
                     }
                     _ => {
-                        self.receive_result(result);
+                        self.receive_result(result).await;
                         self.status = Status::Connected;
                     }
                 },
@@ -446,7 +672,34 @@ impl NetworkHandler {
         }
     }
 
-    fn receive_result(&mut self, result: Result<RespBuf>) {
+    async fn receive_result(&mut self, result: Result<RespBuf>) {
+        if let Some(MessageToReceive {
+            message:
+                Message {
+                    commands: Commands::Stream(_, stream_sender),
+                    ..
+                },
+            num_commands,
+            ..
+        }) = self.messages_to_receive.front_mut()
+        {
+            *num_commands -= 1;
+            let mut stream_sender = stream_sender.clone();
+            if *num_commands == 0 {
+                // end of stream once the last sender is dropped
+                self.messages_to_receive.pop_front();
+            }
+
+            // waiting for the caller to consume the stream stops reading the connection
+            if let Err(e) = stream_sender.send(result).await {
+                debug!(
+                    "[{}] Cannot send value to caller because receiver is not there anymore: {e:?}",
+                    self.tag
+                );
+            }
+            return;
+        }
+
         match self.messages_to_receive.front_mut() {
             Some(message_to_receive) => {
                 if message_to_receive.num_commands == 1 || result.is_err() {
@@ -506,7 +759,10 @@ impl NetworkHandler {
                                         }
                                     }
                                 },
-                                Commands::None | Commands::Single(_, None) => {
+                                Commands::None
+                                | Commands::Single(_, None)
+                                | Commands::BatchForget(_, _)
+                                | Commands::Stream(_, _) => {
                                     debug!("[{}] forget value {result:?}", self.tag) // fire & forget
                                 }
                             }
@@ -520,7 +776,13 @@ impl NetworkHandler {
                     if let Some(pending_replies) = &mut self.pending_replies {
                         match result {
                             Ok(value) => {
-                                pending_replies.push(value);
+                                // replies of a forgotten batch are not buffered
+                                if !matches!(
+                                    message_to_receive.message.commands,
+                                    Commands::BatchForget(_, _)
+                                ) {
+                                    pending_replies.push(value);
+                                }
                                 message_to_receive.num_commands -= 1;
                             }
                             Err(Error::Retry(reasons)) => {
@@ -559,7 +821,7 @@ impl NetworkHandler {
                     RefPubSubMessage::Message(channel_or_pattern, _)
                     | RefPubSubMessage::SMessage(channel_or_pattern, _) => {
                         match self.subscriptions.get_mut(channel_or_pattern) {
-                            Some((_subscription_type, pub_sub_sender)) => {
+                            Some((_subscription_type, pub_sub_sender, _with_events)) => {
                                 if let Err(e) = pub_sub_sender.send(value).await {
                                     warn!(
                                         "[{}] Cannot send pub/sub message to caller: {e}",
@@ -580,11 +842,22 @@ impl NetworkHandler {
                     RefPubSubMessage::Subscribe(channel_or_pattern)
                     | RefPubSubMessage::PSubscribe(channel_or_pattern)
                     | RefPubSubMessage::SSubscribe(channel_or_pattern) => {
-                        if let Some(pub_sub_sender) =
+                        if let Some(subscription) =
                             self.pending_subscriptions.remove(channel_or_pattern)
                         {
                             self.subscriptions
-                                .insert(channel_or_pattern.to_vec(), pub_sub_sender);
+                                .insert(channel_or_pattern.to_vec(), subscription);
+                        }
+                        // also on resubscription after a reconnection
+                        if let Some((_subscription_type, pub_sub_sender, true)) =
+                            self.subscriptions.get_mut(channel_or_pattern)
+                        {
+                            if let Err(e) = pub_sub_sender.send(Ok(ref_value.clone())).await {
+                                warn!(
+                                    "[{}] Cannot send subscription confirmation to caller: {e}",
+                                    self.tag
+                                );
+                            }
                         }
                         if !self.pending_subscriptions.is_empty() {
                             return None;
@@ -594,7 +867,16 @@ impl NetworkHandler {
                     RefPubSubMessage::Unsubscribe(channel_or_pattern)
                     | RefPubSubMessage::PUnsubscribe(channel_or_pattern)
                     | RefPubSubMessage::SUnsubscribe(channel_or_pattern) => {
-                        self.subscriptions.remove(channel_or_pattern);
+                        if let Some((_subscription_type, mut pub_sub_sender, true)) =
+                            self.subscriptions.remove(channel_or_pattern)
+                        {
+                            if let Err(e) = pub_sub_sender.send(Ok(ref_value.clone())).await {
+                                warn!(
+                                    "[{}] Cannot send unsubscription confirmation to caller: {e}",
+                                    self.tag
+                                );
+                            }
+                        }
                         if let Some(remaining) = self.pending_unsubscriptions.front_mut() {
                             if remaining.len() > 1 {
                                 if remaining.remove(channel_or_pattern).is_none() {
@@ -631,7 +913,7 @@ impl NetworkHandler {
                     }
                     RefPubSubMessage::PMessage(pattern, channel, _) => {
                         match self.subscriptions.get_mut(pattern) {
-                            Some((_subscription_type, pub_sub_sender)) => {
+                            Some((_subscription_type, pub_sub_sender, _with_events)) => {
                                 if let Err(e) = pub_sub_sender.send(value).await {
                                     warn!(
                                         "[{}] Cannot send pub/sub message to caller: {e}",
@@ -651,6 +933,17 @@ impl NetworkHandler {
                         None
                     }
                 }
+            } else if ref_value.is_push_message() && !ref_value.is_monitor_message() {
+                // out of band push message (e.g. client tracking invalidation)
+                match &mut self.push_sender {
+                    Some(push_sender) => {
+                        if let Err(e) = push_sender.send(value).await {
+                            warn!("[{}] Cannot send push message to caller: {e}", self.tag);
+                        }
+                        None
+                    }
+                    None => Some(value),
+                }
             } else {
                 Some(value)
             }
@@ -678,7 +971,11 @@ impl NetworkHandler {
 
         while let Some(message_to_receive) = self.messages_to_receive.front() {
             if !message_to_receive.message.retry_on_error
-                || message_to_receive.attempts >= self.max_command_attempts
+                || message_to_receive.attempts
+                    >= message_to_receive
+                        .message
+                        .max_attempts
+                        .unwrap_or(self.max_command_attempts)
             {
                 debug!(
                     "[{}] {:?}, max attempts reached",
@@ -707,6 +1004,17 @@ impl NetworkHandler {
                             );
                             }
                         }
+                        Commands::Stream(_, mut stream_sender) => {
+                            if let Err(e) = stream_sender
+                                .send(Err(Error::Client("Disconnected from server".to_string())))
+                                .await
+                            {
+                                warn!(
+                                "[{}] Cannot send value to caller because receiver is not there anymore: {e:?}",
+                                self.tag
+                            );
+                            }
+                        }
                         _ => (),
                     }
                 }
@@ -729,7 +1037,11 @@ impl NetworkHandler {
 
         while let Some(message_to_send) = self.messages_to_send.front() {
             if !message_to_send.message.retry_on_error
-                || message_to_send.attempts >= self.max_command_attempts
+                || message_to_send.attempts
+                    >= message_to_send
+                        .message
+                        .max_attempts
+                        .unwrap_or(self.max_command_attempts)
             {
                 debug!(
                     "[{}] {:?}, max attempts reached",
@@ -758,6 +1070,27 @@ impl NetworkHandler {
                             );
                             }
                         }
+                        Commands::Stream(_, mut stream_sender) => {
+                            if let Err(e) = stream_sender
+                                .send(Err(Error::Client("Disconnected from server".to_string())))
+                                .await
+                            {
+                                warn!(
+                                "[{}] Cannot send value to caller because receiver is not there anymore: {e:?}",
+                                self.tag
+                            );
+                            }
+                        }
+                        Commands::BatchForget(_, Some(write_sender)) => {
+                            if let Err(e) = write_sender
+                                .send(Err(Error::Client("Disconnected from server".to_string())))
+                            {
+                                warn!(
+                                "[{}] Cannot send value to caller because receiver is not there anymore: {e:?}",
+                                self.tag
+                            );
+                            }
+                        }
                         _ => (),
                     }
                 }
@@ -768,12 +1101,29 @@ impl NetworkHandler {
 
         if let Err(e) = self.connection.reconnect().await {
             error!("[{}] Failed to reconnect: {e:?}", self.tag);
+            self.wait_before_reconnecting().await;
+            return;
+        }
+
+        // the server may have been upgraded or replaced by a failover
+        if let Ok(mut server_version) = self.server_version.write() {
+            *server_version = self.connection.server_version();
+        }
+        if let Ok(mut server_protocol) = self.server_protocol.write() {
+            *server_protocol = self.connection.protocol();
+        }
+
+        // queued commands must not hit another database or expect replies switched off
+        if let Err(e) = self.restore_connection_state().await {
+            error!("[{}] Failed to reconnect: {e:?}", self.tag);
+            self.wait_before_reconnecting().await;
             return;
         }
 
         if self.auto_resubscribe {
             if let Err(e) = self.auto_resubscribe().await {
                 error!("[{}] Failed to reconnect: {e:?}", self.tag);
+                self.wait_before_reconnecting().await;
                 return;
             }
         }
@@ -781,10 +1131,13 @@ impl NetworkHandler {
         if self.auto_remonitor {
             if let Err(e) = self.auto_remonitor(old_status).await {
                 error!("[{}] Failed to reconnect: {e:?}", self.tag);
+                self.wait_before_reconnecting().await;
                 return;
             }
         }
 
+        self.num_reconnect_failures = 0;
+
         if let Err(e) = self.reconnect_sender.send(()) {
             debug!(
                 "[{}] Cannot send reconnect notification to clients: {e}",
@@ -814,76 +1167,75 @@ impl NetworkHandler {
         info!("[{}] reconnected!", self.tag);
     }
 
+    async fn wait_before_reconnecting(&mut self) {
+        self.num_reconnect_failures = self.num_reconnect_failures.saturating_add(1);
+        let delay = self.reconnect_policy.delay(self.num_reconnect_failures);
+        debug!(
+            "[{}] waiting {delay:?} before reconnecting (attempt {})",
+            self.tag,
+            self.num_reconnect_failures.saturating_add(1)
+        );
+        sleep(delay).await;
+    }
+
+    /// Re-applies the `SELECT` and `CLIENT REPLY` state observed on the previous connection
+    async fn restore_connection_state(&mut self) -> Result<()> {
+        if let Some(database) = self.selected_database {
+            let result = self
+                .connection
+                .send(&cmd("SELECT").arg(database))
+                .await?
+                .to::<()>();
+
+            match result {
+                // e.g. an out of range index: the previous connection rejected it as well
+                Err(Error::Redis(e)) => {
+                    warn!("[{}] Cannot select database {database}: {e}", self.tag);
+                    self.selected_database = None;
+                }
+                result => result?,
+            }
+        }
+
+        // no reply is sent back for these commands
+        if !self.is_reply_on {
+            self.connection
+                .write(&cmd("CLIENT").arg("REPLY").arg("OFF"))
+                .await?;
+        } else if self.skip_next_reply {
+            self.connection
+                .write(&cmd("CLIENT").arg("REPLY").arg("SKIP"))
+                .await?;
+        }
+
+        Ok(())
+    }
+
     async fn auto_resubscribe(&mut self) -> Result<()> {
         if !self.subscriptions.is_empty() {
-            for (channel_or_pattern, (subscription_type, _)) in &self.subscriptions {
-                match subscription_type {
-                    SubscriptionType::Channel => {
-                        self.connection
-                            .subscribe(channel_or_pattern.clone())
-                            .await?;
-                    }
-                    SubscriptionType::Pattern => {
-                        self.connection
-                            .psubscribe(channel_or_pattern.clone())
-                            .await?;
-                    }
-                    SubscriptionType::ShardChannel => {
-                        self.connection
-                            .ssubscribe(channel_or_pattern.clone())
-                            .await?;
-                    }
-                }
+            for (channel_or_pattern, (subscription_type, _, _)) in &self.subscriptions {
+                Self::resubscribe(&mut self.connection, *subscription_type, channel_or_pattern)
+                    .await?;
             }
         }
 
         if !self.pending_subscriptions.is_empty() {
-            for (channel_or_pattern, (subscription_type, sender)) in
+            for (channel_or_pattern, (subscription_type, sender, with_events)) in
                 self.pending_subscriptions.drain()
             {
-                match subscription_type {
-                    SubscriptionType::Channel => {
-                        self.connection
-                            .subscribe(channel_or_pattern.clone())
-                            .await?;
-                    }
-                    SubscriptionType::Pattern => {
-                        self.connection
-                            .psubscribe(channel_or_pattern.clone())
-                            .await?;
-                    }
-                    SubscriptionType::ShardChannel => {
-                        self.connection
-                            .ssubscribe(channel_or_pattern.clone())
-                            .await?;
-                    }
-                }
+                Self::resubscribe(&mut self.connection, subscription_type, &channel_or_pattern)
+                    .await?;
 
                 self.subscriptions
-                    .insert(channel_or_pattern, (subscription_type, sender));
+                    .insert(channel_or_pattern, (subscription_type, sender, with_events));
             }
         }
 
         if !self.pending_unsubscriptions.is_empty() {
             for mut map in self.pending_unsubscriptions.drain(..) {
                 for (channel_or_pattern, subscription_type) in map.drain() {
-                    match subscription_type {
-                        SubscriptionType::Channel => {
-                            self.connection
-                                .subscribe(channel_or_pattern.clone())
-                                .await?;
-                        }
-                        SubscriptionType::Pattern => {
-                            self.connection
-                                .psubscribe(channel_or_pattern.clone())
-                                .await?;
-                        }
-                        SubscriptionType::ShardChannel => {
-                            self.connection
-                                .ssubscribe(channel_or_pattern.clone())
-                                .await?;
-                        }
-                    }
+                    Self::resubscribe(&mut self.connection, subscription_type, &channel_or_pattern)
+                        .await?;
 
                     self.subscriptions.remove(&channel_or_pattern);
                 }
@@ -893,6 +1245,25 @@ impl NetworkHandler {
         Ok(())
     }
 
+    async fn resubscribe(
+        connection: &mut T,
+        subscription_type: SubscriptionType,
+        channel_or_pattern: &[u8],
+    ) -> Result<()> {
+        let command_name = match subscription_type {
+            SubscriptionType::Channel => "SUBSCRIBE",
+            SubscriptionType::Pattern => "PSUBSCRIBE",
+            SubscriptionType::ShardChannel => "SSUBSCRIBE",
+        };
+
+        connection
+            .send(&cmd(command_name).arg(channel_or_pattern))
+            .await?
+            .to::<Value>()?;
+
+        Ok(())
+    }
+
     async fn auto_remonitor(&mut self, old_status: Status) -> Result<()> {
         if let Status::Monitor | Status::EnteringMonitor = old_status {
             self.connection.send(&cmd("MONITOR")).await?;
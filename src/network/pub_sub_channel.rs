@@ -0,0 +1,205 @@
+use crate::{client::BackpressurePolicy, resp::RespBuf, Error, Result};
+use futures_channel::mpsc;
+use futures_util::{SinkExt, Stream, StreamExt};
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+/// Creates the channel between the network handler and a [`PubSubStream`](crate::client::PubSubStream)
+///
+/// The channel is unbounded if no capacity is provided.
+pub(crate) fn pub_sub_channel(
+    capacity: Option<usize>,
+    backpressure_policy: BackpressurePolicy,
+) -> (PubSubSender, PubSubReceiver) {
+    match (capacity, backpressure_policy) {
+        (None, _) => {
+            let (sender, receiver) = mpsc::unbounded();
+            (
+                PubSubSender::Unbounded(sender),
+                PubSubReceiver::Unbounded(receiver),
+            )
+        }
+        (Some(capacity), BackpressurePolicy::Backpressure) => {
+            // the capacity of a futures channel is incremented by the number of senders
+            let (sender, receiver) = mpsc::channel(capacity.saturating_sub(1));
+            (
+                PubSubSender::Bounded(sender),
+                PubSubReceiver::Bounded(receiver),
+            )
+        }
+        (Some(capacity), policy) => {
+            let shared = Arc::new(Mutex::new(DroppingChannel {
+                queue: VecDeque::with_capacity(capacity),
+                capacity: capacity.max(1),
+                policy,
+                num_senders: 1,
+                is_closed: false,
+                is_receiver_alive: true,
+                waker: None,
+            }));
+            (
+                PubSubSender::Dropping(DroppingSender(shared.clone())),
+                PubSubReceiver::Dropping(DroppingReceiver(shared)),
+            )
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(crate) enum PubSubSender {
+    Unbounded(mpsc::UnboundedSender<Result<RespBuf>>),
+    Bounded(mpsc::Sender<Result<RespBuf>>),
+    Dropping(DroppingSender),
+}
+
+impl std::fmt::Debug for PubSubSender {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PubSubSender::Unbounded(sender) => sender.fmt(f),
+            PubSubSender::Bounded(sender) => sender.fmt(f),
+            PubSubSender::Dropping(_) => f.write_str("DroppingSender"),
+        }
+    }
+}
+
+impl PubSubSender {
+    /// Sends a message to the [`PubSubStream`](crate::client::PubSubStream).
+    ///
+    /// With [`BackpressurePolicy::Backpressure`](BackpressurePolicy::Backpressure),
+    /// waits until the channel has room for the message.
+    pub async fn send(&mut self, value: Result<RespBuf>) -> Result<()> {
+        match self {
+            PubSubSender::Unbounded(sender) => sender
+                .send(value)
+                .await
+                .map_err(|e| Error::Client(e.to_string())),
+            PubSubSender::Bounded(sender) => sender
+                .send(value)
+                .await
+                .map_err(|e| Error::Client(e.to_string())),
+            PubSubSender::Dropping(sender) => sender.send(value),
+        }
+    }
+
+    /// Closes the channel for all its senders,
+    /// so the [`PubSubStream`](crate::client::PubSubStream) ends
+    /// once the messages already sent have been received.
+    pub fn close_channel(&mut self) {
+        match self {
+            PubSubSender::Unbounded(sender) => sender.close_channel(),
+            PubSubSender::Bounded(sender) => sender.close_channel(),
+            PubSubSender::Dropping(sender) => sender.close_channel(),
+        }
+    }
+}
+
+pub(crate) enum PubSubReceiver {
+    Unbounded(mpsc::UnboundedReceiver<Result<RespBuf>>),
+    Bounded(mpsc::Receiver<Result<RespBuf>>),
+    Dropping(DroppingReceiver),
+}
+
+impl Stream for PubSubReceiver {
+    type Item = Result<RespBuf>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        match self.get_mut() {
+            PubSubReceiver::Unbounded(receiver) => receiver.poll_next_unpin(cx),
+            PubSubReceiver::Bounded(receiver) => receiver.poll_next_unpin(cx),
+            PubSubReceiver::Dropping(receiver) => receiver.poll_next(cx),
+        }
+    }
+}
+
+/// Bounded channel which drops a message when full, instead of waiting for the receiver
+struct DroppingChannel {
+    queue: VecDeque<Result<RespBuf>>,
+    capacity: usize,
+    policy: BackpressurePolicy,
+    num_senders: usize,
+    is_closed: bool,
+    is_receiver_alive: bool,
+    waker: Option<Waker>,
+}
+
+pub(crate) struct DroppingSender(Arc<Mutex<DroppingChannel>>);
+
+impl DroppingSender {
+    fn send(&self, value: Result<RespBuf>) -> Result<()> {
+        let mut channel = self.0.lock().unwrap();
+        if !channel.is_receiver_alive || channel.is_closed {
+            return Err(Error::Client("receiver is gone".to_owned()));
+        }
+
+        if channel.queue.len() >= channel.capacity {
+            match channel.policy {
+                BackpressurePolicy::DropOldest => {
+                    channel.queue.pop_front();
+                }
+                _ => return Ok(()),
+            }
+        }
+
+        channel.queue.push_back(value);
+        if let Some(waker) = channel.waker.take() {
+            waker.wake();
+        }
+
+        Ok(())
+    }
+
+    fn close_channel(&self) {
+        let mut channel = self.0.lock().unwrap();
+        channel.is_closed = true;
+        if let Some(waker) = channel.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl Clone for DroppingSender {
+    fn clone(&self) -> Self {
+        self.0.lock().unwrap().num_senders += 1;
+        Self(self.0.clone())
+    }
+}
+
+impl Drop for DroppingSender {
+    fn drop(&mut self) {
+        let mut channel = self.0.lock().unwrap();
+        channel.num_senders -= 1;
+        if channel.num_senders == 0 {
+            if let Some(waker) = channel.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+pub(crate) struct DroppingReceiver(Arc<Mutex<DroppingChannel>>);
+
+impl DroppingReceiver {
+    fn poll_next(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<RespBuf>>> {
+        let mut channel = self.0.lock().unwrap();
+        if let Some(value) = channel.queue.pop_front() {
+            Poll::Ready(Some(value))
+        } else if channel.num_senders == 0 || channel.is_closed {
+            Poll::Ready(None)
+        } else {
+            channel.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for DroppingReceiver {
+    fn drop(&mut self) {
+        let mut channel = self.0.lock().unwrap();
+        channel.is_receiver_alive = false;
+        channel.queue.clear();
+    }
+}
@@ -1,13 +1,23 @@
+use super::util::RefPubSubMessage;
 use crate::{
     client::{Config, SentinelConfig},
     commands::{RoleResult, SentinelCommands, ServerCommands},
-    resp::{Command, RespBuf},
-    sleep, Error, Result, RetryReason, StandaloneConnection,
+    resp::{cmd, Command, RespBuf},
+    sleep, spawn, Error, Result, RetryReason, StandaloneConnection,
 };
-use log::debug;
+use futures_channel::oneshot;
+use futures_util::{select, FutureExt};
+use log::{debug, info, warn};
+
+const SWITCH_MASTER_CHANNEL: &str = "+switch-master";
 
 pub struct SentinelConnection {
     pub inner_connection: StandaloneConnection,
+    sentinel_config: SentinelConfig,
+    config: Box<Config>,
+    /// notified by the Sentinel watcher task when the master has been switched
+    switch_receiver: Option<oneshot::Receiver<()>>,
+    master_switched: bool,
 }
 
 impl SentinelConnection {
@@ -27,14 +37,45 @@ impl SentinelConnection {
             .await
     }
 
-    #[inline]
     pub async fn read(&mut self) -> Option<Result<RespBuf>> {
+        if self.master_switched {
+            // simulate a disconnection until the new master is resolved
+            return None;
+        }
+
+        if let Some(switch_receiver) = &mut self.switch_receiver {
+            select! {
+                value = self.inner_connection.read().fuse() => return value,
+                result = switch_receiver.fuse() => {
+                    if result.is_ok() {
+                        info!(
+                            "[{}] Master `{}` has been switched",
+                            self.inner_connection.tag(),
+                            self.sentinel_config.service_name
+                        );
+                        self.master_switched = true;
+                        return None;
+                    }
+                }
+            }
+
+            // the Sentinel watcher task has ended without a switch
+            self.switch_receiver = None;
+        }
+
         self.inner_connection.read().await
     }
 
-    #[inline]
     pub async fn reconnect(&mut self) -> Result<()> {
-        self.inner_connection.reconnect().await
+        if self.sentinel_config.refresh_on_switch {
+            // the master may have changed since the last connection
+            let sentinel_config = self.sentinel_config.clone();
+            let config = self.config.clone();
+            *self = Self::connect(&sentinel_config, &config).await?;
+            Ok(())
+        } else {
+            self.inner_connection.reconnect().await
+        }
     }
 
     /// Follow `Redis service discovery via Sentinel` documentation
@@ -50,6 +91,7 @@ impl SentinelConnection {
         let mut restart = false;
         let mut unreachable_sentinel = true;
 
+        let original_config = config;
         let mut config = config.clone();
         config.username = sentinel_config.username.clone();
         config.password = sentinel_config.password.clone();
@@ -99,8 +141,24 @@ impl SentinelConnection {
                     replica_infos: _,
                 } = role
                 {
+                    let switch_receiver = if sentinel_config.refresh_on_switch {
+                        let (switch_sender, switch_receiver) = oneshot::channel();
+                        spawn(watch_switch_master(
+                            sentinel_connection,
+                            sentinel_config.service_name.clone(),
+                            switch_sender,
+                        ));
+                        Some(switch_receiver)
+                    } else {
+                        None
+                    };
+
                     return Ok(SentinelConnection {
                         inner_connection: master_connection,
+                        sentinel_config: sentinel_config.clone(),
+                        config: Box::new(original_config.clone()),
+                        switch_receiver,
+                        master_switched: false,
                     });
                 } else {
                     sleep(sentinel_config.wait_between_failures).await;
@@ -133,3 +191,54 @@ impl SentinelConnection {
         self.inner_connection.tag()
     }
 }
+
+/// Listen to the `+switch-master` events of a Sentinel instance
+/// and notify `switch_sender` when the master `service_name` is switched.
+///
+/// The task ends after the first switch, when the Sentinel connection is lost
+/// or when the [`SentinelConnection`] is dropped.
+async fn watch_switch_master(
+    mut sentinel_connection: StandaloneConnection,
+    service_name: String,
+    mut switch_sender: oneshot::Sender<()>,
+) {
+    let tag = sentinel_connection.tag().to_owned();
+
+    if let Err(e) = sentinel_connection
+        .write(&cmd("SUBSCRIBE").arg(SWITCH_MASTER_CHANNEL))
+        .await
+    {
+        warn!("[{tag}] Cannot subscribe to `{SWITCH_MASTER_CHANNEL}`: {e}");
+        return;
+    }
+
+    loop {
+        let value = select! {
+            _ = switch_sender.cancellation().fuse() => return,
+            value = sentinel_connection.read().fuse() => value,
+        };
+
+        match value {
+            Some(Ok(resp_buf)) => {
+                if let Some(RefPubSubMessage::Message(_, payload)) =
+                    RefPubSubMessage::from_resp(&resp_buf)
+                {
+                    // payload: <master name> <old ip> <old port> <new ip> <new port>
+                    let payload = String::from_utf8_lossy(payload);
+                    if payload.split(' ').next() == Some(service_name.as_str()) {
+                        debug!("[{tag}] Received `{SWITCH_MASTER_CHANNEL}`: {payload}");
+                        let _ = switch_sender.send(());
+                        return;
+                    }
+                }
+            }
+            Some(Err(e)) => debug!("[{tag}] Error while watching `{SWITCH_MASTER_CHANNEL}`: {e}"),
+            None => {
+                warn!(
+                    "[{tag}] Connection to Sentinel lost, stop watching `{SWITCH_MASTER_CHANNEL}`"
+                );
+                return;
+            }
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use crate::{
     client::{Config, PreparedCommand},
     commands::{
-        ClusterCommands, ConnectionCommands, HelloOptions, SentinelCommands, ServerCommands,
+        ClientInfoAttribute, ClusterCommands, CommandCommands, ConnectionCommands, HelloOptions,
+        SentinelCommands, ServerCommands,
     },
     resp::{BufferDecoder, Command, CommandEncoder, RespBuf},
     tcp_connect, Error, Future, Result, RetryReason, TcpStreamReader, TcpStreamWriter,
@@ -32,9 +33,8 @@ impl Streams {
     pub async fn connect(host: &str, port: u16, config: &Config) -> Result<Self> {
         #[cfg(feature = "tls")]
         if let Some(tls_config) = &config.tls_config {
-            let (reader, writer) =
-                tcp_tls_connect(host, port, tls_config, config.connect_timeout).await?;
-            let framed_read = FramedRead::new(reader, BufferDecoder);
+            let (reader, writer) = tcp_tls_connect(host, port, tls_config, config).await?;
+            let framed_read = FramedRead::new(reader, BufferDecoder::default());
             let framed_write = FramedWrite::new(writer, CommandEncoder);
             Ok(Streams::TcpTls(framed_read, framed_write))
         } else {
@@ -47,7 +47,7 @@ impl Streams {
 
     pub async fn connect_non_secure(host: &str, port: u16, config: &Config) -> Result<Self> {
         let (reader, writer) = tcp_connect(host, port, config).await?;
-        let framed_read = FramedRead::new(reader, BufferDecoder);
+        let framed_read = FramedRead::new(reader, BufferDecoder::default());
         let framed_write = FramedWrite::new(writer, CommandEncoder);
         Ok(Streams::Tcp(framed_read, framed_write))
     }
@@ -60,6 +60,7 @@ pub struct StandaloneConnection {
     streams: Streams,
     buffer: BytesMut,
     version: String,
+    protocol: usize,
     tag: String,
 }
 
@@ -74,6 +75,7 @@ impl StandaloneConnection {
             streams,
             buffer: BytesMut::new(),
             version: String::new(),
+            protocol: 0,
             tag: if config.connection_name.is_empty() {
                 format!("{}:{}", host, port)
             } else {
@@ -91,9 +93,19 @@ impl StandaloneConnection {
             debug!("[{}] Sending {command:?}", self.tag);
         }
         match &mut self.streams {
-            Streams::Tcp(_, framed_write) => framed_write.send(command).await,
+            Streams::Tcp(framed_read, framed_write) => {
+                if command.inline {
+                    framed_read.decoder_mut().accept_inline_replies();
+                }
+                framed_write.send(command).await
+            }
             #[cfg(feature = "tls")]
-            Streams::TcpTls(_, framed_write) => framed_write.send(command).await,
+            Streams::TcpTls(framed_read, framed_write) => {
+                if command.inline {
+                    framed_read.decoder_mut().accept_inline_replies();
+                }
+                framed_write.send(command).await
+            }
         }
     }
 
@@ -104,10 +116,14 @@ impl StandaloneConnection {
     ) -> Result<()> {
         self.buffer.clear();
 
-        let command_encoder = match &mut self.streams {
-            Streams::Tcp(_, framed_write) => framed_write.encoder_mut(),
+        let (buffer_decoder, command_encoder) = match &mut self.streams {
+            Streams::Tcp(framed_read, framed_write) => {
+                (framed_read.decoder_mut(), framed_write.encoder_mut())
+            }
             #[cfg(feature = "tls")]
-            Streams::TcpTls(_, framed_write) => framed_write.encoder_mut(),
+            Streams::TcpTls(framed_read, framed_write) => {
+                (framed_read.decoder_mut(), framed_write.encoder_mut())
+            }
         };
 
         #[cfg(debug_assertions)]
@@ -124,6 +140,10 @@ impl StandaloneConnection {
                 command.kill_connection_on_write -= 1;
             }
 
+            if command.inline {
+                buffer_decoder.accept_inline_replies();
+            }
+
             command_encoder.encode(command, &mut self.buffer)?;
         }
 
@@ -199,6 +219,20 @@ impl StandaloneConnection {
 
         let hello_result = self.hello(hello_options).await?;
         self.version = hello_result.version;
+        self.protocol = hello_result.proto;
+
+        // library name & version, not supported before Redis 7.2
+        if let Err(e) = self
+            .client_setinfo(ClientInfoAttribute::LibName, "rustis")
+            .await
+        {
+            debug!("[{}] Cannot set client lib-name: {e}", self.tag);
+        } else if let Err(e) = self
+            .client_setinfo(ClientInfoAttribute::LibVer, env!("CARGO_PKG_VERSION"))
+            .await
+        {
+            debug!("[{}] Cannot set client lib-ver: {e}", self.tag);
+        }
 
         // select database
         if self.config.database != 0 {
@@ -212,6 +246,10 @@ impl StandaloneConnection {
         &self.version
     }
 
+    pub fn get_protocol(&self) -> usize {
+        self.protocol
+    }
+
     pub(crate) fn tag(&self) -> &str {
         &self.tag
     }
@@ -238,6 +276,7 @@ where
 }
 
 impl<'a> ClusterCommands<'a> for &'a mut StandaloneConnection {}
+impl<'a> CommandCommands<'a> for &'a mut StandaloneConnection {}
 impl<'a> ConnectionCommands<'a> for &'a mut StandaloneConnection {}
 impl<'a> SentinelCommands<'a> for &'a mut StandaloneConnection {}
 impl<'a> ServerCommands<'a> for &'a mut StandaloneConnection {}
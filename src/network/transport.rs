@@ -0,0 +1,74 @@
+use crate::{
+    client::{ClusterNodes, Version},
+    network::CommandInfoCache,
+    resp::{Command, RespBuf},
+    Error, Result, RetryReason,
+};
+use std::future::Future;
+
+/// Connection to a Redis server, as seen by the [`NetworkHandler`](crate::network::NetworkHandler)
+///
+/// The network handler is generic over this trait so it can be driven by an in-memory
+/// implementation in tests, while the production code path stays monomorphized
+/// over [`Connection`](crate::network::Connection).
+pub(crate) trait Transport: Send + 'static {
+    /// Writes a single command to the server
+    fn write(&mut self, command: &Command) -> impl Future<Output = Result<()>> + Send;
+
+    /// Writes a batch of commands to the server
+    fn write_batch<'a>(
+        &mut self,
+        commands: impl Iterator<Item = &'a mut Command> + Send,
+        retry_reasons: &[RetryReason],
+    ) -> impl Future<Output = Result<()>> + Send;
+
+    /// Reads the next reply or push message.
+    ///
+    /// `None` means that the connection has been closed by the server.
+    /// This future must be cancel safe: it is dropped each time a message is received from a client.
+    fn read(&mut self) -> impl Future<Output = Option<Result<RespBuf>>> + Send;
+
+    /// Reconnects to the server after a network error
+    fn reconnect(&mut self) -> impl Future<Output = Result<()>> + Send;
+
+    /// Tag used to identify the connection in the logs
+    fn tag(&self) -> &str;
+
+    /// `true` if the connection spans the multiple nodes of a cluster
+    fn is_cluster(&self) -> bool {
+        false
+    }
+
+    /// Nodes of the cluster, kept up to date by the connection, `None` outside of cluster mode
+    fn cluster_nodes(&self) -> Option<ClusterNodes> {
+        None
+    }
+
+    /// Metadata of the commands, fetched by the connection for its routing,
+    /// `None` if the connection does not fetch them
+    fn command_info_cache(&self) -> Option<CommandInfoCache> {
+        None
+    }
+
+    /// Version of the server, as negotiated with `HELLO` at connection time,
+    /// `None` if it is unknown
+    fn server_version(&self) -> Option<Version> {
+        None
+    }
+
+    /// RESP protocol version, as negotiated with `HELLO` at connection time,
+    /// `None` if it is unknown
+    fn protocol(&self) -> Option<usize> {
+        None
+    }
+
+    /// Writes a single command and waits for its reply
+    fn send(&mut self, command: &Command) -> impl Future<Output = Result<RespBuf>> + Send {
+        async move {
+            self.write(command).await?;
+            self.read()
+                .await
+                .ok_or_else(|| Error::Client("Disconnected by peer".to_owned()))?
+        }
+    }
+}
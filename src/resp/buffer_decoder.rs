@@ -1,10 +1,29 @@
 use super::RespDeserializer;
-use crate::{resp::RespBuf, Error, Result};
-use bytes::BytesMut;
+use crate::{
+    resp::{
+        RespBuf, ARRAY_TAG, ATTRIBUTE_TAG, BIG_NUMBER_TAG, BLOB_ERROR_TAG, BOOL_TAG,
+        BULK_STRING_TAG, DOUBLE_TAG, ERROR_TAG, INTEGER_TAG, MAP_TAG, NIL_TAG, PUSH_TAG, SET_TAG,
+        SIMPLE_STRING_TAG, VERBATIM_STRING_TAG,
+    },
+    Error, Result,
+};
+use bytes::{BufMut, BytesMut};
 use serde::{de::IgnoredAny, Deserialize};
 use tokio_util::codec::Decoder;
 
-pub(crate) struct BufferDecoder;
+#[derive(Default)]
+pub(crate) struct BufferDecoder {
+    accept_inline_replies: bool,
+}
+
+impl BufferDecoder {
+    /// Decodes the following replies without type prefix as inline replies,
+    /// once an [inline command](crate::resp::Command::inline) has been sent
+    /// on the connection
+    pub fn accept_inline_replies(&mut self) {
+        self.accept_inline_replies = true;
+    }
+}
 
 impl Decoder for BufferDecoder {
     type Item = RespBuf;
@@ -15,6 +34,10 @@ impl Decoder for BufferDecoder {
             return Ok(None);
         }
 
+        if self.accept_inline_replies && !is_resp_tag(src[0]) {
+            return Ok(decode_inline_reply(src));
+        }
+
         let bytes = src.as_ref();
         let mut deserializer = RespDeserializer::new(bytes);
         let result = IgnoredAny::deserialize(&mut deserializer);
@@ -25,3 +48,37 @@ impl Decoder for BufferDecoder {
         }
     }
 }
+
+#[inline]
+fn is_resp_tag(byte: u8) -> bool {
+    matches!(
+        byte,
+        SIMPLE_STRING_TAG
+            | ERROR_TAG
+            | INTEGER_TAG
+            | BULK_STRING_TAG
+            | ARRAY_TAG
+            | MAP_TAG
+            | SET_TAG
+            | DOUBLE_TAG
+            | NIL_TAG
+            | BOOL_TAG
+            | VERBATIM_STRING_TAG
+            | PUSH_TAG
+            | BLOB_ERROR_TAG
+            | BIG_NUMBER_TAG
+            | ATTRIBUTE_TAG
+    )
+}
+
+/// Some RESP compatible servers reply with inline lines, without type prefix:
+/// such a line is decoded as a simple string
+fn decode_inline_reply(src: &mut BytesMut) -> Option<RespBuf> {
+    let pos = src.windows(2).position(|window| window == b"\r\n")?;
+    let line = src.split_to(pos + 2);
+
+    let mut bytes = BytesMut::with_capacity(line.len() + 1);
+    bytes.put_u8(SIMPLE_STRING_TAG);
+    bytes.put(line);
+    Some(RespBuf::new(bytes.freeze()))
+}
@@ -0,0 +1,171 @@
+use bytes::Bytes;
+use serde::{de::Visitor, Deserialize, Deserializer};
+use std::{cell::Cell, fmt, ops::Deref};
+
+/// Name of the newtype struct requested by [`BulkBytes`] deserialization,
+/// so that a [`RespDeserializer`](crate::resp::RespDeserializer) holding the buffer
+/// of a [`RespBuf`](crate::resp::RespBuf) can share it
+pub(crate) const BULK_BYTES_NEWTYPE: &str = "$rustis::BulkBytes";
+
+thread_local! {
+    /// Slice of its buffer handed over by the deserializer to the `BulkBytes` being visited
+    static HANDED_OVER_BYTES: Cell<Option<Bytes>> = const { Cell::new(None) };
+}
+
+/// Hands `bytes` over to the [`BulkBytes`] visited by `visit`
+///
+/// Serde visitors only receive borrowed slices:
+/// the shared buffer is handed over for the duration of the visit only.
+pub(crate) fn hand_over_bytes<R>(bytes: Bytes, visit: impl FnOnce() -> R) -> R {
+    HANDED_OVER_BYTES.with(|handed_over| handed_over.set(Some(bytes)));
+    let result = visit();
+    HANDED_OVER_BYTES.with(|handed_over| handed_over.set(None));
+    result
+}
+
+/// Bulk string sharing the buffer of the reply instead of copying it
+///
+/// When deserialized from a [`RespBuf`](crate::resp::RespBuf), directly
+/// or as part of a pipeline result, a `BulkBytes` is a cheap slice of the network buffer:
+/// no allocation nor copy is performed, which matters for large values.
+/// In other contexts, the bytes are copied.
+///
+/// # Example
+/// ```
+/// use rustis::resp::{BulkBytes, RespBuf};
+///
+/// let resp_buf = RespBuf::from_slice(b"$5\r\nhello\r\n");
+/// let bytes: BulkBytes = resp_buf.to().unwrap();
+/// assert_eq!(b"hello", &bytes[..]);
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct BulkBytes(Bytes);
+
+impl BulkBytes {
+    /// Constructs a new `BulkBytes` from a `Bytes` buffer
+    #[inline]
+    pub fn new(bytes: Bytes) -> Self {
+        Self(bytes)
+    }
+
+    /// Returns the internal buffer as a byte slice
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Returns the internal `Bytes` buffer
+    #[inline]
+    pub fn into_bytes(self) -> Bytes {
+        self.0
+    }
+}
+
+impl Deref for BulkBytes {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<BulkBytes> for Bytes {
+    #[inline]
+    fn from(bb: BulkBytes) -> Self {
+        bb.0
+    }
+}
+
+impl From<Bytes> for BulkBytes {
+    #[inline]
+    fn from(bytes: Bytes) -> Self {
+        BulkBytes(bytes)
+    }
+}
+
+impl fmt::Debug for BulkBytes {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("BulkBytes").field(&self.0).finish()
+    }
+}
+
+impl<'de> Deserialize<'de> for BulkBytes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BulkBytesVisitor;
+
+        impl<'de> Visitor<'de> for BulkBytesVisitor {
+            type Value = BulkBytes;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("BulkBytes")
+            }
+
+            fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                let bytes = match HANDED_OVER_BYTES.with(Cell::take) {
+                    Some(bytes) if bytes.as_ptr() == v.as_ptr() && bytes.len() == v.len() => bytes,
+                    _ => Bytes::copy_from_slice(v),
+                };
+                Ok(BulkBytes(bytes))
+            }
+
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(BulkBytes(Bytes::copy_from_slice(v)))
+            }
+
+            fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(BulkBytes(Bytes::from(v)))
+            }
+
+            fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                self.visit_borrowed_bytes(v.as_bytes())
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                self.visit_bytes(v.as_bytes())
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                self.visit_byte_buf(v.into_bytes())
+            }
+
+            fn visit_newtype_struct<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_bytes(self)
+            }
+
+            fn visit_unit<E>(self) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(BulkBytes::default())
+            }
+        }
+
+        deserializer.deserialize_newtype_struct(BULK_BYTES_NEWTYPE, BulkBytesVisitor)
+    }
+}
@@ -1,4 +1,8 @@
-use crate::resp::{CommandArgs, ToArgs};
+use crate::{
+    client::first_key_index,
+    resp::{CommandArgs, CommandArgsIterator, ToArgs},
+};
+use std::fmt;
 
 #[cfg(debug_assertions)]
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -6,6 +10,9 @@ use std::sync::atomic::{AtomicUsize, Ordering};
 #[cfg(debug_assertions)]
 static COMMAND_SEQUENCE_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
+/// Name given to the [inline commands](Command::inline), whose actual name is part of the line
+pub(crate) const INLINE_COMMAND_NAME: &str = "INLINE";
+
 /// Shortcut function for creating a command.
 #[must_use]
 #[inline(always)]
@@ -24,6 +31,8 @@ pub struct Command {
     pub name: &'static str,
     /// Collection of arguments of the command.
     pub args: CommandArgs,
+    /// the command is written as a single inline line, kept in the only argument
+    pub(crate) inline: bool,
     #[doc(hidden)]
     #[cfg(debug_assertions)]
     pub kill_connection_on_write: usize,
@@ -42,6 +51,7 @@ impl Command {
         Self {
             name,
             args: CommandArgs::default(),
+            inline: false,
             #[cfg(debug_assertions)]
             kill_connection_on_write: 0,
             #[cfg(debug_assertions)]
@@ -49,6 +59,14 @@ impl Command {
         }
     }
 
+    /// Creates an [inline command](https://redis.io/docs/reference/protocol-spec/#inline-commands),
+    /// written as is, without any escaping
+    pub(crate) fn inline(line: &str) -> Self {
+        let mut command = Self::new(INLINE_COMMAND_NAME).arg(line);
+        command.inline = true;
+        command
+    }
+
     /// Builder function to add an argument to an existing command.
     #[must_use]
     #[inline(always)]
@@ -73,6 +91,32 @@ impl Command {
         self
     }
 
+    /// Iterator over the arguments of the command, the name excluded
+    #[inline]
+    pub fn args_iter(&self) -> CommandArgsIterator<'_> {
+        self.args.into_iter()
+    }
+
+    /// Number of arguments of the command, the name excluded
+    #[must_use]
+    #[inline]
+    pub fn num_args(&self) -> usize {
+        self.args.len()
+    }
+
+    /// First key argument of the command
+    ///
+    /// The key position is guessed from the command name,
+    /// following the [key specifications](https://redis.io/docs/reference/key-specs/)
+    /// of the Redis commands.
+    ///
+    /// # Return
+    /// `None` for commands without key or unknown commands (e.g. module commands)
+    #[must_use]
+    pub fn first_key(&self) -> Option<&[u8]> {
+        first_key_index(self).map(|index| self.args[index].as_slice())
+    }
+
     #[cfg(debug_assertions)]
     #[inline]
     pub fn kill_connection_on_write(mut self, num_kills: usize) -> Self {
@@ -80,3 +124,36 @@ impl Command {
         self
     }
 }
+
+/// Renders the command as typed in `redis-cli`, each argument being quoted,
+/// e.g. `SET "key" "value"`
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.inline {
+            for arg in &self.args {
+                f.write_str(&String::from_utf8_lossy(arg))?;
+            }
+            return Ok(());
+        }
+
+        f.write_str(self.name)?;
+
+        for arg in &self.args {
+            f.write_str(" \"")?;
+            for byte in arg {
+                match byte {
+                    b'"' => f.write_str("\\\"")?,
+                    b'\\' => f.write_str("\\\\")?,
+                    b'\n' => f.write_str("\\n")?,
+                    b'\r' => f.write_str("\\r")?,
+                    b'\t' => f.write_str("\\t")?,
+                    0x20..=0x7e => f.write_fmt(format_args!("{}", *byte as char))?,
+                    _ => f.write_fmt(format_args!("\\x{byte:02x}"))?,
+                }
+            }
+            f.write_str("\"")?;
+        }
+
+        Ok(())
+    }
+}
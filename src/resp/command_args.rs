@@ -66,6 +66,11 @@ impl CommandArgs {
         self.len() == 0
     }
 
+    #[inline]
+    pub(crate) fn args_mut(&mut self) -> &mut [Vec<u8>] {
+        &mut self.args
+    }
+
     #[inline]
     pub(crate) fn write_arg(&mut self, buf: &[u8]) {
         self.args.push(buf.to_vec());
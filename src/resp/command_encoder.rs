@@ -12,6 +12,14 @@ impl Encoder<&Command> for CommandEncoder {
 
     #[inline]
     fn encode(&mut self, command: &Command, buf: &mut BytesMut) -> Result<()> {
+        if command.inline {
+            for arg in &command.args {
+                buf.put(arg);
+            }
+            encode_crlf(buf);
+            return Ok(());
+        }
+
         buf.reserve(calculate_buf_size(command));
 
         buf.put_u8(b'*');
@@ -292,6 +292,7 @@ Current implementation provides the following deserializations from a RESP Buffe
 * `bool`,
 * `String`,
 * [`BulkString`],
+* [`BulkBytes`] and [`Bytes`](https://docs.rs/bytes/latest/bytes/struct.Bytes.html) (`bytes` feature),
 * `Option<T>`
 
 #### Example
@@ -339,6 +340,50 @@ async fn main() -> Result<()> {
 }
 ```
 
+#### Enums
+
+Many commands return a string from a fixed set of values.
+Such a reply can be deserialized into a C-like enum with serde derive attributes only:
+* `#[serde(rename_all = "...")]` or `#[serde(rename = "...")]` to match the strings returned by Redis,
+* `#[serde(other)]` on a unit variant to accept unknown values instead of failing.
+
+With the `derive` feature, [`FromRedisValue`](crate::FromRedisValue) generates the same implementation
+along with [`PrimitiveResponse`](PrimitiveResponse), and can keep unknown values in an `Unknown(String)` variant:
+```ignore
+#[derive(Debug, PartialEq, rustis::FromRedisValue)]
+#[redis(rename_all = "lowercase")]
+pub enum Role {
+    Master,
+    #[redis(rename = "slave")]
+    Replica,
+    #[redis(other)]
+    Unknown(String),
+}
+```
+
+```
+use rustis::{resp::{PrimitiveResponse, RespBuf}, Result};
+use serde::Deserialize;
+
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    Master,
+    #[serde(rename = "slave")]
+    Replica,
+    #[serde(other)]
+    Unknown,
+}
+impl PrimitiveResponse for Role {}
+
+fn main() -> Result<()> {
+    assert_eq!(Role::Master, RespBuf::from_slice(b"$6\r\nmaster\r\n").to::<Role>()?);
+    assert_eq!(Role::Replica, RespBuf::from_slice(b"+slave\r\n").to::<Role>()?);
+    assert_eq!(Role::Unknown, RespBuf::from_slice(b"+sentinel\r\n").to::<Role>()?);
+    Ok(())
+}
+```
+
 ### CollectionResponse
 
 Several Redis commands return a collection of items.
@@ -433,12 +478,18 @@ async fn main() -> Result<()> {
 */
 
 mod buffer_decoder;
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+#[cfg(feature = "bytes")]
+mod bulk_bytes;
 mod bulk_string;
 mod command;
 mod command_args;
 mod command_encoder;
 mod resp_batch_deserializer;
 mod resp_buf;
+#[cfg_attr(docsrs, doc(cfg(feature = "codec")))]
+#[cfg(feature = "codec")]
+mod resp_codec;
 mod resp_deserializer;
 mod resp_serializer;
 mod response;
@@ -450,12 +501,18 @@ mod value_deserializer;
 mod value_serialize;
 
 pub(crate) use buffer_decoder::*;
+#[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
+#[cfg(feature = "bytes")]
+pub use bulk_bytes::*;
 pub use bulk_string::*;
 pub use command::*;
 pub use command_args::*;
 pub(crate) use command_encoder::*;
 pub(crate) use resp_batch_deserializer::*;
 pub use resp_buf::*;
+#[cfg_attr(docsrs, doc(cfg(feature = "codec")))]
+#[cfg(feature = "codec")]
+pub use resp_codec::*;
 pub use resp_deserializer::*;
 pub use resp_serializer::*;
 pub use response::*;
@@ -1,7 +1,4 @@
-use crate::{
-    resp::{RespBuf, RespDeserializer},
-    Error, Result,
-};
+use crate::{resp::RespBuf, Error, Result};
 use serde::{de::DeserializeSeed, forward_to_deserialize_any, Deserializer};
 use std::slice;
 
@@ -27,8 +24,8 @@ impl<'de> Deserializer<'de> for &'de RespBatchDeserializer<'de> {
 
     forward_to_deserialize_any! {
         bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
-        bytes byte_buf option unit_struct newtype_struct tuple
-        tuple_struct map struct enum identifier ignored_any
+        bytes byte_buf option unit_struct newtype_struct
+        map struct enum identifier ignored_any
     }
 
     fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value>
@@ -38,6 +35,26 @@ impl<'de> Deserializer<'de> for &'de RespBatchDeserializer<'de> {
         visitor.visit_seq(SeqAccess::new(self.bufs))
     }
 
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        check_tuple_len(len, self.bufs.len())?;
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        self.deserialize_tuple(len, visitor)
+    }
+
     fn deserialize_unit<V>(self, visitor: V) -> Result<V::Value>
     where
         V: serde::de::Visitor<'de>,
@@ -50,6 +67,18 @@ impl<'de> Deserializer<'de> for &'de RespBatchDeserializer<'de> {
     }
 }
 
+/// Check that the arity of the requested tuple matches
+/// the number of results of a batch (forgotten commands excluded)
+pub(crate) fn check_tuple_len(expected: usize, queued: usize) -> Result<()> {
+    if expected == queued {
+        Ok(())
+    } else {
+        Err(Error::Client(format!(
+            "expected {expected} results, queued {queued}"
+        )))
+    }
+}
+
 struct SeqAccess<'de> {
     iter: slice::Iter<'de, RespBuf>,
     len: usize,
@@ -73,7 +102,7 @@ impl<'de> serde::de::SeqAccess<'de> for SeqAccess<'de> {
     {
         match self.iter.next() {
             Some(buf) => {
-                seed.deserialize(&mut RespDeserializer::new(buf)).map(Some)
+                seed.deserialize(&mut buf.deserializer()).map(Some)
             }
             None => Ok(None),
         }
@@ -64,8 +64,14 @@ impl RespBuf {
     /// Convert the RESP Buffer to a Rust type `T` by using serde deserialization
     #[inline]
     pub fn to<'de, T: Deserialize<'de>>(&'de self) -> Result<T> {
-        let mut deserializer = RespDeserializer::new(&self.0);
-        T::deserialize(&mut deserializer)
+        T::deserialize(&mut self.deserializer())
+    }
+
+    /// Creates a deserializer of the RESP Buffer,
+    /// which can share the buffer with the deserialized values instead of copying it
+    #[inline]
+    pub(crate) fn deserializer(&self) -> RespDeserializer<'_> {
+        RespDeserializer::from_bytes(&self.0)
     }
 
     /// Returns the internal buffer as a byte slice
@@ -0,0 +1,75 @@
+use crate::{
+    resp::{BufferDecoder, Command, CommandEncoder, Value},
+    Error, Result,
+};
+use bytes::BytesMut;
+use tokio_util::codec::{Decoder, Encoder};
+
+/// [`RESP`](https://redis.io/docs/reference/protocol-spec/) codec
+/// which encodes [`commands`](Command) and decodes replies as [`values`](Value)
+///
+/// It allows to reuse the RESP protocol implementation of **rustis** without its client layer,
+/// for example to build a proxy on top of any `AsyncRead + AsyncWrite` with
+/// [`Framed`](tokio_util::codec::Framed).
+///
+/// A Redis error reply is decoded as a [`Value::Error`] frame and does not end the stream.
+///
+/// # Example
+/// ```
+/// use bytes::BytesMut;
+/// use rustis::{
+///     resp::{cmd, RespCodec, Value},
+///     Result,
+/// };
+/// use tokio_util::codec::{Decoder, Encoder};
+///
+/// fn main() -> Result<()> {
+///     let mut codec = RespCodec;
+///
+///     let mut buf = BytesMut::new();
+///     codec.encode(cmd("GET").arg("key"), &mut buf)?;
+///     assert_eq!(&b"*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n"[..], &buf[..]);
+///
+///     let mut buf = BytesMut::from(&b"$5\r\nvalue\r\n"[..]);
+///     let value = codec.decode(&mut buf)?;
+///     assert_eq!(Some(Value::BulkString(b"value".to_vec())), value);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RespCodec;
+
+impl Encoder<&Command> for RespCodec {
+    type Error = Error;
+
+    #[inline]
+    fn encode(&mut self, command: &Command, buf: &mut BytesMut) -> Result<()> {
+        CommandEncoder.encode(command, buf)
+    }
+}
+
+impl Encoder<Command> for RespCodec {
+    type Error = Error;
+
+    #[inline]
+    fn encode(&mut self, command: Command, buf: &mut BytesMut) -> Result<()> {
+        CommandEncoder.encode(&command, buf)
+    }
+}
+
+impl Decoder for RespCodec {
+    type Item = Value;
+    type Error = Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>> {
+        match BufferDecoder::default().decode(src)? {
+            Some(resp_buf) => match resp_buf.to::<Value>() {
+                Ok(value) => Ok(Some(value)),
+                Err(Error::Redis(e)) => Ok(Some(Value::Error(e))),
+                Err(e) => Err(e),
+            },
+            None => Ok(None),
+        }
+    }
+}
@@ -1,10 +1,22 @@
-use crate::{resp::PUSH_FAKE_FIELD, Error, RedisError, Result};
+#[cfg(feature = "bytes")]
+use crate::resp::{hand_over_bytes, BULK_BYTES_NEWTYPE};
+use crate::{
+    resp::{BIG_NUMBER_FAKE_FIELD, PUSH_FAKE_FIELD, VERBATIM_STRING_FAKE_FIELD},
+    Error, RedisError, Result,
+};
+use bytes::Bytes;
 use memchr::memchr;
 use serde::{
-    de::{DeserializeSeed, EnumAccess, IntoDeserializer, VariantAccess, Visitor},
+    de::{
+        value::MapDeserializer, DeserializeSeed, EnumAccess, IntoDeserializer, VariantAccess,
+        Visitor,
+    },
     forward_to_deserialize_any, Deserializer,
 };
-use std::str::{self, FromStr};
+use std::{
+    iter,
+    str::{self, FromStr},
+};
 
 pub(crate) const SIMPLE_STRING_TAG: u8 = b'+';
 pub(crate) const ERROR_TAG: u8 = b'-';
@@ -19,6 +31,8 @@ pub(crate) const BOOL_TAG: u8 = b'#';
 pub(crate) const VERBATIM_STRING_TAG: u8 = b'=';
 pub(crate) const PUSH_TAG: u8 = b'>';
 pub(crate) const BLOB_ERROR_TAG: u8 = b'!';
+pub(crate) const BIG_NUMBER_TAG: u8 = b'(';
+pub(crate) const ATTRIBUTE_TAG: u8 = b'|';
 
 #[inline(always)]
 fn eof<T>() -> Result<T> {
@@ -28,6 +42,9 @@ fn eof<T>() -> Result<T> {
 /// Serde deserializer for [`RESP3`](https://github.com/redis/redis-specifications/blob/master/protocol/RESP3.md)
 pub struct RespDeserializer<'de> {
     buf: &'de [u8],
+    /// Owner of `buf`, to share it instead of copying it
+    #[cfg(feature = "bytes")]
+    bytes: Option<&'de Bytes>,
     pos: usize,
     eat_error: bool,
 }
@@ -38,6 +55,21 @@ impl<'de> RespDeserializer<'de> {
     pub fn new(buf: &'de [u8]) -> Self {
         RespDeserializer {
             buf,
+            #[cfg(feature = "bytes")]
+            bytes: None,
+            pos: 0,
+            eat_error: true,
+        }
+    }
+
+    /// Creates a new `RespDeserializer` which can share slices of `bytes`
+    /// with the deserialized values, like [`BulkBytes`](crate::resp::BulkBytes)
+    #[inline]
+    pub(crate) fn from_bytes(bytes: &'de Bytes) -> Self {
+        RespDeserializer {
+            buf: bytes,
+            #[cfg(feature = "bytes")]
+            bytes: Some(bytes),
             pos: 0,
             eat_error: true,
         }
@@ -52,6 +84,7 @@ impl<'de> RespDeserializer<'de> {
     // Look at the first byte in the input without consuming it.
     #[inline]
     fn peek(&mut self) -> Result<u8> {
+        self.skip_attributes()?;
         if let Some(&byte) = self.buf.get(self.pos) {
             if self.eat_error {
                 match byte {
@@ -76,6 +109,22 @@ impl<'de> RespDeserializer<'de> {
         }
     }
 
+    /// Attributes are auxiliary information attached to a reply, they are not part of it.
+    /// They are skipped so the following reply can be deserialized as if they were not here.
+    #[inline]
+    fn skip_attributes(&mut self) -> Result<()> {
+        while let Some(&ATTRIBUTE_TAG) = self.buf.get(self.pos) {
+            self.advance();
+            let len = self.parse_integer::<usize>()? * 2;
+            let eat_error = self.eat_error;
+            for _ in 0..len {
+                self.ignore_value()?;
+            }
+            self.eat_error = eat_error;
+        }
+        Ok(())
+    }
+
     #[inline(always)]
     fn next(&mut self) -> Result<u8> {
         self.peek().map(|v| {
@@ -234,7 +283,7 @@ impl<'de> RespDeserializer<'de> {
         T: atoi::FromRadix10SignedChecked + Default,
     {
         match self.next()? {
-            INTEGER_TAG => self.parse_integer::<T>(),
+            INTEGER_TAG | BIG_NUMBER_TAG => self.parse_integer::<T>(),
             DOUBLE_TAG => self.parse_integer::<T>(),
             NIL_TAG => {
                 self.parse_nil()?;
@@ -282,7 +331,7 @@ impl<'de> RespDeserializer<'de> {
         T: fast_float::FastFloat + Default,
     {
         match self.next()? {
-            INTEGER_TAG | DOUBLE_TAG => self.parse_float::<T>(),
+            INTEGER_TAG | DOUBLE_TAG | BIG_NUMBER_TAG => self.parse_float::<T>(),
             NIL_TAG => {
                 self.parse_nil()?;
                 Ok(Default::default())
@@ -354,9 +403,8 @@ impl<'de> RespDeserializer<'de> {
     fn ignore_value(&mut self) -> Result<()> {
         self.eat_error = false;
         match self.next()? {
-            SIMPLE_STRING_TAG | ERROR_TAG | INTEGER_TAG | DOUBLE_TAG | NIL_TAG | BOOL_TAG => {
-                self.ignore_line()
-            }
+            SIMPLE_STRING_TAG | ERROR_TAG | INTEGER_TAG | DOUBLE_TAG | NIL_TAG | BOOL_TAG
+            | BIG_NUMBER_TAG => self.ignore_line(),
             BULK_STRING_TAG | BLOB_ERROR_TAG | VERBATIM_STRING_TAG => self.ignore_bulk_string(),
             ARRAY_TAG | SET_TAG | PUSH_TAG => {
                 let len = self.parse_integer::<usize>()?;
@@ -365,7 +413,7 @@ impl<'de> RespDeserializer<'de> {
                 }
                 Ok(())
             }
-            MAP_TAG => {
+            MAP_TAG | ATTRIBUTE_TAG => {
                 let len = self.parse_integer::<usize>()? * 2;
                 for _ in 0..len {
                     self.ignore_value()?;
@@ -407,7 +455,22 @@ impl<'de, 'a> Deserializer<'de> for &'a mut RespDeserializer<'de> {
             SIMPLE_STRING_TAG => self.deserialize_str(visitor),
             NIL_TAG => self.deserialize_option(visitor),
             BOOL_TAG => self.deserialize_bool(visitor),
-            VERBATIM_STRING_TAG => self.deserialize_bytes(visitor),
+            VERBATIM_STRING_TAG => {
+                self.advance();
+                let verbatim = str::from_utf8(self.parse_bulk_string()?)?;
+                visitor.visit_map(MapDeserializer::new(iter::once((
+                    VERBATIM_STRING_FAKE_FIELD,
+                    verbatim,
+                ))))
+            }
+            BIG_NUMBER_TAG => {
+                self.advance();
+                let big_number = self.parse_string()?;
+                visitor.visit_map(MapDeserializer::new(iter::once((
+                    BIG_NUMBER_FAKE_FIELD,
+                    big_number,
+                ))))
+            }
             PUSH_TAG => visitor.visit_map(PushMapAccess::new(self)),
             ERROR_TAG => Err(Error::Redis(self.parse_error()?)),
             BLOB_ERROR_TAG => Err(Error::Redis(self.parse_blob_error()?)),
@@ -574,7 +637,7 @@ impl<'de, 'a> Deserializer<'de> for &'a mut RespDeserializer<'de> {
                 let bs = self.parse_verbatim_string()?;
                 str::from_utf8(bs)?
             }
-            SIMPLE_STRING_TAG => self.parse_string()?,
+            SIMPLE_STRING_TAG | BIG_NUMBER_TAG => self.parse_string()?,
             NIL_TAG => {
                 self.parse_nil()?;
                 ""
@@ -610,7 +673,7 @@ impl<'de, 'a> Deserializer<'de> for &'a mut RespDeserializer<'de> {
                 self.parse_nil()?;
                 String::from("")
             }
-            SIMPLE_STRING_TAG => self.parse_string()?.to_owned(),
+            SIMPLE_STRING_TAG | BIG_NUMBER_TAG => self.parse_string()?.to_owned(),
             ERROR_TAG => return Err(Error::Redis(self.parse_error()?)),
             BLOB_ERROR_TAG => return Err(Error::Redis(self.parse_blob_error()?)),
             _ => {
@@ -675,13 +738,22 @@ impl<'de, 'a> Deserializer<'de> for &'a mut RespDeserializer<'de> {
                 visitor.visit_none()
             }
             ARRAY_TAG => {
-                let len = self.peek_integer::<usize>()?;
-                if len == 0 {
+                let len = self.peek_integer::<isize>()?;
+                if len == -1 {
+                    // RESP2 null array
+                    self.next_line()?;
+                    visitor.visit_none()
+                } else if len == 0 {
                     visitor.visit_none()
                 } else {
                     visitor.visit_some(self)
                 }
             }
+            BULK_STRING_TAG if self.peek_integer::<isize>()? == -1 => {
+                // RESP2 null bulk string
+                self.next_line()?;
+                visitor.visit_none()
+            }
             ERROR_TAG => Err(Error::Redis(self.parse_error()?)),
             BLOB_ERROR_TAG => Err(Error::Redis(self.parse_blob_error()?)),
             _ => visitor.visit_some(self),
@@ -740,10 +812,26 @@ impl<'de, 'a> Deserializer<'de> for &'a mut RespDeserializer<'de> {
     // insignificant wrappers around the data they contain. That means not
     // parsing anything other than the contained value.
     #[inline]
-    fn deserialize_newtype_struct<V>(self, _name: &'static str, visitor: V) -> Result<V::Value>
+    fn deserialize_newtype_struct<V>(self, name: &'static str, visitor: V) -> Result<V::Value>
     where
         V: Visitor<'de>,
     {
+        #[cfg(feature = "bytes")]
+        if name == BULK_BYTES_NEWTYPE {
+            if let (Some(bytes), BULK_STRING_TAG) = (self.bytes, self.peek()?) {
+                self.advance();
+                let result = self.parse_bulk_string()?;
+                return hand_over_bytes(bytes.slice_ref(result), || {
+                    visitor.visit_borrowed_bytes(result)
+                });
+            }
+
+            return self.deserialize_bytes(visitor);
+        }
+
+        #[cfg(not(feature = "bytes"))]
+        let _ = name;
+
         visitor.visit_newtype_struct(self)
     }
 
@@ -960,7 +1048,11 @@ impl<'de, 'a> serde::de::SeqAccess<'de> for SeqAccess<'a, 'de> {
     {
         if self.len > 0 {
             self.len -= 1;
-            seed.deserialize(&mut *self.de).map(Some)
+            seed.deserialize(SeqElementDeserializer {
+                de: self.de,
+                seq_len: &mut self.len,
+            })
+            .map(Some)
         } else {
             Ok(None)
         }
@@ -1010,6 +1102,137 @@ impl<'de, 'a> serde::de::MapAccess<'de> for SeqAccess<'a, 'de> {
     }
 }
 
+/// Deserializer of an element of a RESP array
+///
+/// When a tuple is expected but the element is not an array,
+/// the tuple is read from the next consecutive elements of the parent array.
+/// This is how RESP2 flattens pairs, e.g. `ZRANGE ... WITHSCORES` replies
+/// `[member1, score1, member2, score2]` where RESP3 replies `[[member1, score1], [member2, score2]]`
+struct SeqElementDeserializer<'a, 'de: 'a> {
+    de: &'a mut RespDeserializer<'de>,
+    /// remaining elements in the parent array
+    seq_len: &'a mut usize,
+}
+
+macro_rules! forward_to_inner_deserializer {
+    ($($method:ident),*) => {
+        $(
+            #[inline]
+            fn $method<V>(self, visitor: V) -> Result<V::Value>
+            where
+                V: Visitor<'de>,
+            {
+                self.de.$method(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de, 'a> Deserializer<'de> for SeqElementDeserializer<'a, 'de> {
+    type Error = Error;
+
+    forward_to_inner_deserializer!(
+        deserialize_any,
+        deserialize_bool,
+        deserialize_i8,
+        deserialize_i16,
+        deserialize_i32,
+        deserialize_i64,
+        deserialize_u8,
+        deserialize_u16,
+        deserialize_u32,
+        deserialize_u64,
+        deserialize_f32,
+        deserialize_f64,
+        deserialize_char,
+        deserialize_str,
+        deserialize_string,
+        deserialize_bytes,
+        deserialize_byte_buf,
+        deserialize_option,
+        deserialize_unit,
+        deserialize_seq,
+        deserialize_map,
+        deserialize_identifier,
+        deserialize_ignored_any
+    );
+
+    #[inline]
+    fn deserialize_unit_struct<V>(self, name: &'static str, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.de.deserialize_unit_struct(name, visitor)
+    }
+
+    #[inline]
+    fn deserialize_newtype_struct<V>(self, name: &'static str, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.de.deserialize_newtype_struct(name, visitor)
+    }
+
+    fn deserialize_tuple<V>(self, len: usize, visitor: V) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        match self.de.peek()? {
+            ARRAY_TAG | SET_TAG | PUSH_TAG | MAP_TAG | NIL_TAG => {
+                self.de.deserialize_tuple(len, visitor)
+            }
+            _ if len > 0 && *self.seq_len >= len - 1 => {
+                // RESP2 flattened tuple
+                *self.seq_len -= len - 1;
+                visitor.visit_seq(SeqAccess { de: self.de, len })
+            }
+            tag => Err(Error::Client(format!(
+                "Cannot parse to tuple a RESP value starting with {}",
+                tag as char
+            ))),
+        }
+    }
+
+    #[inline]
+    fn deserialize_tuple_struct<V>(
+        self,
+        name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.de.deserialize_tuple_struct(name, len, visitor)
+    }
+
+    #[inline]
+    fn deserialize_struct<V>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.de.deserialize_struct(name, fields, visitor)
+    }
+
+    #[inline]
+    fn deserialize_enum<V>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value>
+    where
+        V: Visitor<'de>,
+    {
+        self.de.deserialize_enum(name, variants, visitor)
+    }
+}
+
 struct MapAccess<'a, 'de: 'a> {
     de: &'a mut RespDeserializer<'de>,
     len: usize,
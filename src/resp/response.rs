@@ -1,3 +1,5 @@
+#[cfg(feature = "bytes")]
+use crate::resp::BulkBytes;
 use crate::resp::{BulkString, Value};
 use serde::de::DeserializeOwned;
 use smallvec::SmallVec;
@@ -30,6 +32,10 @@ impl PrimitiveResponse for f64 {}
 impl PrimitiveResponse for bool {}
 impl PrimitiveResponse for String {}
 impl PrimitiveResponse for BulkString {}
+#[cfg(feature = "bytes")]
+impl PrimitiveResponse for BulkBytes {}
+#[cfg(feature = "bytes")]
+impl PrimitiveResponse for bytes::Bytes {}
 impl<T: PrimitiveResponse + DeserializeOwned> PrimitiveResponse for Option<T> {}
 
 /// Marker for a collection response
@@ -66,6 +72,12 @@ impl<T, S: BuildHasher + Default> CollectionResponse<T> for HashSet<T, S> where
 {
 }
 impl<T> CollectionResponse<T> for BTreeSet<T> where T: Response + Ord + DeserializeOwned {}
+#[cfg_attr(docsrs, doc(cfg(feature = "indexmap")))]
+#[cfg(feature = "indexmap")]
+impl<T, S: BuildHasher + Default> CollectionResponse<T> for indexmap::IndexSet<T, S> where
+    T: Response + Eq + Hash + DeserializeOwned
+{
+}
 
 /// Marker for key/value collection response
 pub trait KeyValueCollectionResponse<K, V>: Response
@@ -110,3 +122,13 @@ where
     V: Response + DeserializeOwned,
 {
 }
+
+#[cfg_attr(docsrs, doc(cfg(feature = "indexmap")))]
+#[cfg(feature = "indexmap")]
+impl<K, V, S: BuildHasher + Default> KeyValueCollectionResponse<K, V>
+    for indexmap::IndexMap<K, V, S>
+where
+    K: PrimitiveResponse + Eq + Hash + DeserializeOwned,
+    V: Response + DeserializeOwned,
+{
+}
@@ -1,4 +1,4 @@
-use crate::{RedisError, Result};
+use crate::{Error, RedisError, Result};
 use serde::de::DeserializeOwned;
 use std::{
     collections::HashMap,
@@ -25,10 +25,21 @@ pub enum Value {
     Array(Vec<Value>),
     /// [RESP3](https://github.com/antirez/RESP3/blob/master/spec.md) Map type
     Map(HashMap<Value, Value>),
-    /// [RESP3](https://github.com/antirez/RESP3/blob/master/spec.md) Push
-    Set(Vec<Value>),
     /// [RESP3](https://github.com/antirez/RESP3/blob/master/spec.md) Set reply
+    Set(Vec<Value>),
+    /// [RESP3](https://github.com/antirez/RESP3/blob/master/spec.md) Push
     Push(Vec<Value>),
+    /// [RESP3](https://github.com/antirez/RESP3/blob/master/spec.md) Big number
+    ///
+    /// The number is kept in its textual representation since it may not fit in any Rust integer type
+    BigNumber(String),
+    /// [RESP3](https://github.com/antirez/RESP3/blob/master/spec.md) Verbatim string
+    VerbatimString {
+        /// Format of the text, `txt` for plain text or `mkd` for markdown
+        format: String,
+        /// The text itself, without its format prefix
+        text: String,
+    },
     /// [RESP Error](https://redis.io/docs/reference/protocol-spec/#resp-errors)
     Error(RedisError),
     /// [RESP Null](https://redis.io/docs/reference/protocol-spec/#resp-bulk-strings)
@@ -48,8 +59,128 @@ impl Value {
     {
         T::deserialize(&self)
     }
+
+    /// Returns the integer if the value is an [`Integer`](Value::Integer)
+    #[inline]
+    pub fn as_integer(&self) -> Option<i64> {
+        match self {
+            Value::Integer(i) => Some(*i),
+            _ => None,
+        }
+    }
+
+    /// Returns the double if the value is a [`Double`](Value::Double)
+    #[inline]
+    pub fn as_double(&self) -> Option<f64> {
+        match self {
+            Value::Double(d) => Some(*d),
+            _ => None,
+        }
+    }
+
+    /// Returns the boolean if the value is a [`Boolean`](Value::Boolean)
+    #[inline]
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Boolean(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Returns the string if the value is a [`SimpleString`](Value::SimpleString)
+    #[inline]
+    pub fn as_simple_string(&self) -> Option<&str> {
+        match self {
+            Value::SimpleString(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns the bytes if the value is a [`BulkString`](Value::BulkString)
+    #[inline]
+    pub fn as_bulk_string(&self) -> Option<&[u8]> {
+        match self {
+            Value::BulkString(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns the textual representation of the number if the value is a [`BigNumber`](Value::BigNumber)
+    #[inline]
+    pub fn as_big_number(&self) -> Option<&str> {
+        match self {
+            Value::BigNumber(n) => Some(n),
+            _ => None,
+        }
+    }
+
+    /// Returns the format and the text if the value is a [`VerbatimString`](Value::VerbatimString)
+    #[inline]
+    pub fn as_verbatim_string(&self) -> Option<(&str, &str)> {
+        match self {
+            Value::VerbatimString { format, text } => Some((format, text)),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements if the value is an [`Array`](Value::Array)
+    #[inline]
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(a) => Some(a),
+            _ => None,
+        }
+    }
+
+    /// Returns the entries if the value is a [`Map`](Value::Map)
+    #[inline]
+    pub fn as_map(&self) -> Option<&HashMap<Value, Value>> {
+        match self {
+            Value::Map(m) => Some(m),
+            _ => None,
+        }
+    }
+
+    /// Returns the error if the value is an [`Error`](Value::Error)
+    #[inline]
+    pub fn as_error(&self) -> Option<&RedisError> {
+        match self {
+            Value::Error(e) => Some(e),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the value is [`Nil`](Value::Nil)
+    #[inline]
+    pub fn is_nil(&self) -> bool {
+        matches!(self, Value::Nil)
+    }
+
+    /// Returns `true` if the value is an [`Error`](Value::Error)
+    #[inline]
+    pub fn is_error(&self) -> bool {
+        matches!(self, Value::Error(_))
+    }
 }
 
+macro_rules! impl_try_from_value {
+    ($($t:ty),*) => {
+        $(
+            impl TryFrom<Value> for $t {
+                type Error = Error;
+
+                /// Same conversion rules as [`Value::into`](Value::into)
+                #[inline]
+                fn try_from(value: Value) -> Result<Self> {
+                    value.into::<$t>()
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_value!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64, bool, String);
+
 impl Hash for Value {
     fn hash<H: Hasher>(&self, state: &mut H) {
         match self {
@@ -57,6 +188,12 @@ impl Hash for Value {
             Value::Integer(i) => i.hash(state),
             Value::Double(d) => d.to_string().hash(state),
             Value::BulkString(bs) => bs.hash(state),
+            Value::Boolean(b) => b.hash(state),
+            Value::BigNumber(n) => n.hash(state),
+            Value::VerbatimString { format, text } => {
+                format.hash(state);
+                text.hash(state);
+            }
             Value::Error(e) => e.hash(state),
             Value::Nil => "_\r\n".hash(state),
             _ => unimplemented!("Hash not implemented for {self}"),
@@ -71,6 +208,18 @@ impl PartialEq for Value {
             (Self::Integer(l0), Self::Integer(r0)) => l0 == r0,
             (Self::Double(l0), Self::Double(r0)) => l0 == r0,
             (Self::BulkString(l0), Self::BulkString(r0)) => l0 == r0,
+            (Self::Boolean(l0), Self::Boolean(r0)) => l0 == r0,
+            (Self::BigNumber(l0), Self::BigNumber(r0)) => l0 == r0,
+            (
+                Self::VerbatimString {
+                    format: l0,
+                    text: l1,
+                },
+                Self::VerbatimString {
+                    format: r0,
+                    text: r1,
+                },
+            ) => l0 == r0 && l1 == r1,
             (Self::Array(l0), Self::Array(r0)) => l0 == r0,
             (Self::Map(l0), Self::Map(r0)) => l0 == r0,
             (Self::Set(l0), Self::Set(r0)) => l0 == r0,
@@ -141,6 +290,8 @@ impl Display for Value {
                 }
                 f.write_char(']')
             }
+            Value::BigNumber(n) => n.fmt(f),
+            Value::VerbatimString { text, .. } => text.fmt(f),
             Value::Error(e) => e.fmt(f),
             Value::Nil => f.write_str("Nil"),
         }
@@ -162,6 +313,12 @@ impl fmt::Debug for Value {
             Self::Map(arg0) => f.debug_tuple("Map").field(arg0).finish(),
             Self::Set(arg0) => f.debug_tuple("Set").field(arg0).finish(),
             Self::Push(arg0) => f.debug_tuple("Push").field(arg0).finish(),
+            Self::BigNumber(arg0) => f.debug_tuple("BigNumber").field(arg0).finish(),
+            Self::VerbatimString { format, text } => f
+                .debug_struct("VerbatimString")
+                .field("format", format)
+                .field("text", text)
+                .finish(),
             Self::Error(arg0) => f.debug_tuple("Error").field(arg0).finish(),
             Self::Nil => write!(f, "Nil"),
         }
@@ -1,11 +1,13 @@
 use crate::resp::Value;
 use serde::{
-    de::{MapAccess, SeqAccess, Visitor},
+    de::{self, MapAccess, SeqAccess, Visitor},
     Deserialize, Deserializer,
 };
 use std::fmt;
 
 pub(crate) const PUSH_FAKE_FIELD: &str = ">>>PUSH>>>";
+pub(crate) const BIG_NUMBER_FAKE_FIELD: &str = "(((BIG_NUMBER(((";
+pub(crate) const VERBATIM_STRING_FAKE_FIELD: &str = "===VERBATIM_STRING===";
 
 /// Implementation meant to be used with [`RespDeserializer`](crate::resp::RespDeserializer)
 impl<'de> Deserialize<'de> for Value {
@@ -29,7 +31,7 @@ impl<'de> Visitor<'de> for ValueVisitor {
 
     #[inline]
     fn visit_bool<E>(self, v: bool) -> Result<Value, E> {
-        Ok(Value::Integer(i64::from(v)))
+        Ok(Value::Boolean(v))
     }
 
     #[inline]
@@ -116,6 +118,21 @@ impl<'de> Visitor<'de> for ValueVisitor {
                             return Ok(Value::Push(values));
                         }
                     }
+                    Some(PushOrKey::BigNumber) => {
+                        return Ok(Value::BigNumber(map.next_value()?));
+                    }
+                    Some(PushOrKey::VerbatimString) => {
+                        let verbatim: String = map.next_value()?;
+                        return match verbatim.split_once(':') {
+                            Some((format, text)) => Ok(Value::VerbatimString {
+                                format: format.to_owned(),
+                                text: text.to_owned(),
+                            }),
+                            None => Err(de::Error::custom(format!(
+                                "Cannot parse verbatim string `{verbatim}`"
+                            ))),
+                        };
+                    }
                     Some(PushOrKey::Key(value)) => values.push(value),
                 };
 
@@ -128,6 +145,8 @@ impl<'de> Visitor<'de> for ValueVisitor {
 
 enum PushOrKey {
     Push,
+    BigNumber,
+    VerbatimString,
     Key(Value),
 }
 
@@ -171,21 +190,27 @@ impl<'de> Visitor<'de> for PushOrKeyVisitor {
 
     #[inline]
     fn visit_borrowed_str<E: serde::de::Error>(self, v: &'de str) -> Result<PushOrKey, E> {
-        if v == PUSH_FAKE_FIELD {
-            Ok(PushOrKey::Push)
-        } else {
-            let value_visitor = ValueVisitor;
-            value_visitor.visit_borrowed_str(v).map(PushOrKey::Key)
+        match v {
+            PUSH_FAKE_FIELD => Ok(PushOrKey::Push),
+            BIG_NUMBER_FAKE_FIELD => Ok(PushOrKey::BigNumber),
+            VERBATIM_STRING_FAKE_FIELD => Ok(PushOrKey::VerbatimString),
+            _ => {
+                let value_visitor = ValueVisitor;
+                value_visitor.visit_borrowed_str(v).map(PushOrKey::Key)
+            }
         }
     }
 
     #[inline]
     fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<PushOrKey, E> {
-        if v == PUSH_FAKE_FIELD {
-            Ok(PushOrKey::Push)
-        } else {
-            let value_visitor = ValueVisitor;
-            value_visitor.visit_str(v).map(PushOrKey::Key)
+        match v {
+            PUSH_FAKE_FIELD => Ok(PushOrKey::Push),
+            BIG_NUMBER_FAKE_FIELD => Ok(PushOrKey::BigNumber),
+            VERBATIM_STRING_FAKE_FIELD => Ok(PushOrKey::VerbatimString),
+            _ => {
+                let value_visitor = ValueVisitor;
+                value_visitor.visit_str(v).map(PushOrKey::Key)
+            }
         }
     }
 
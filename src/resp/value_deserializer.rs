@@ -21,6 +21,8 @@ impl<'de> Deserializer<'de> for &'de Value {
             Value::Double(d) => visitor.visit_f64(*d),
             Value::BulkString(bs) => visitor.visit_borrowed_bytes(bs),
             Value::Boolean(b) => visitor.visit_bool(*b),
+            Value::BigNumber(s) => visitor.visit_borrowed_str(s),
+            Value::VerbatimString { text, .. } => visitor.visit_borrowed_str(text),
             Value::Array(values) => visitor.visit_seq(SeqAccess::new(values)),
             Value::Map(values) => visitor.visit_map(MapAccess::new(values)),
             Value::Set(values) => visitor.visit_seq(SeqAccess::new(values)),
@@ -62,9 +64,10 @@ impl<'de> Deserializer<'de> for &'de Value {
         let result = match self {
             Value::Integer(i) => *i as i8,
             Value::Double(d) => *d as i8,
+            Value::Boolean(b) => i8::from(*b),
             Value::Nil => 0,
             Value::BulkString(s) => str::from_utf8(s)?.parse::<i8>()?,
-            Value::SimpleString(s) => s.parse::<i8>()?,
+            Value::SimpleString(s) | Value::BigNumber(s) => s.parse::<i8>()?,
             Value::Error(e) => return Err(Error::Redis(e.clone())),
             _ => {
                 return Err(Error::Client(format!(
@@ -84,9 +87,10 @@ impl<'de> Deserializer<'de> for &'de Value {
         let result = match self {
             Value::Integer(i) => *i as i16,
             Value::Double(d) => *d as i16,
+            Value::Boolean(b) => i16::from(*b),
             Value::Nil => 0,
             Value::BulkString(s) => str::from_utf8(s)?.parse::<i16>()?,
-            Value::SimpleString(s) => s.parse::<i16>()?,
+            Value::SimpleString(s) | Value::BigNumber(s) => s.parse::<i16>()?,
             Value::Error(e) => return Err(Error::Redis(e.clone())),
             _ => {
                 return Err(Error::Client(format!(
@@ -106,9 +110,10 @@ impl<'de> Deserializer<'de> for &'de Value {
         let result = match self {
             Value::Integer(i) => *i as i32,
             Value::Double(d) => *d as i32,
+            Value::Boolean(b) => i32::from(*b),
             Value::Nil => 0,
             Value::BulkString(s) => str::from_utf8(s)?.parse::<i32>()?,
-            Value::SimpleString(s) => s.parse::<i32>()?,
+            Value::SimpleString(s) | Value::BigNumber(s) => s.parse::<i32>()?,
             Value::Error(e) => return Err(Error::Redis(e.clone())),
             _ => {
                 return Err(Error::Client(format!(
@@ -129,9 +134,10 @@ impl<'de> Deserializer<'de> for &'de Value {
         let result = match self {
             Value::Integer(i) => *i,
             Value::Double(d) => *d as i64,
+            Value::Boolean(b) => i64::from(*b),
             Value::Nil => 0,
             Value::BulkString(s) => str::from_utf8(s)?.parse::<i64>()?,
-            Value::SimpleString(s) => s.parse::<i64>()?,
+            Value::SimpleString(s) | Value::BigNumber(s) => s.parse::<i64>()?,
             Value::Array(a) if a.len() == 1 => i64::deserialize(&a[0])?,
             Value::Error(e) => return Err(Error::Redis(e.clone())),
             _ => {
@@ -152,9 +158,10 @@ impl<'de> Deserializer<'de> for &'de Value {
         let result = match self {
             Value::Integer(i) => *i as u8,
             Value::Double(d) => *d as u8,
+            Value::Boolean(b) => u8::from(*b),
             Value::Nil => 0,
             Value::BulkString(s) => str::from_utf8(s)?.parse::<u8>()?,
-            Value::SimpleString(s) => s.parse::<u8>()?,
+            Value::SimpleString(s) | Value::BigNumber(s) => s.parse::<u8>()?,
             Value::Error(e) => return Err(Error::Redis(e.clone())),
             _ => {
                 return Err(Error::Client(format!(
@@ -174,9 +181,10 @@ impl<'de> Deserializer<'de> for &'de Value {
         let result = match self {
             Value::Integer(i) => *i as u16,
             Value::Double(d) => *d as u16,
+            Value::Boolean(b) => u16::from(*b),
             Value::Nil => 0,
             Value::BulkString(s) => str::from_utf8(s)?.parse::<u16>()?,
-            Value::SimpleString(s) => s.parse::<u16>()?,
+            Value::SimpleString(s) | Value::BigNumber(s) => s.parse::<u16>()?,
             Value::Error(e) => return Err(Error::Redis(e.clone())),
             _ => {
                 return Err(Error::Client(format!(
@@ -196,9 +204,10 @@ impl<'de> Deserializer<'de> for &'de Value {
         let result = match self {
             Value::Integer(i) => *i as u32,
             Value::Double(d) => *d as u32,
+            Value::Boolean(b) => u32::from(*b),
             Value::Nil => 0,
             Value::BulkString(s) => str::from_utf8(s)?.parse::<u32>()?,
-            Value::SimpleString(s) => s.parse::<u32>()?,
+            Value::SimpleString(s) | Value::BigNumber(s) => s.parse::<u32>()?,
             Value::Error(e) => return Err(Error::Redis(e.clone())),
             _ => {
                 return Err(Error::Client(format!(
@@ -218,9 +227,10 @@ impl<'de> Deserializer<'de> for &'de Value {
         let result = match self {
             Value::Integer(i) => *i as u64,
             Value::Double(d) => *d as u64,
+            Value::Boolean(b) => u64::from(*b),
             Value::Nil => 0,
             Value::BulkString(s) => str::from_utf8(s)?.parse::<u64>()?,
-            Value::SimpleString(s) => s.parse::<u64>()?,
+            Value::SimpleString(s) | Value::BigNumber(s) => s.parse::<u64>()?,
             Value::Array(a) if a.len() == 1 => u64::deserialize(&a[0])?,
             Value::Error(e) => return Err(Error::Redis(e.clone())),
             _ => {
@@ -241,9 +251,10 @@ impl<'de> Deserializer<'de> for &'de Value {
         let result = match self {
             Value::Integer(i) => *i as f32,
             Value::Double(d) => *d as f32,
+            Value::Boolean(b) => f32::from(*b),
             Value::BulkString(bs) => str::from_utf8(bs)?.parse::<f32>()?,
             Value::Nil => 0.,
-            Value::SimpleString(s) => s.parse::<f32>()?,
+            Value::SimpleString(s) | Value::BigNumber(s) => s.parse::<f32>()?,
             Value::Error(e) => return Err(Error::Redis(e.clone())),
             _ => {
                 return Err(Error::Client(format!(
@@ -263,9 +274,10 @@ impl<'de> Deserializer<'de> for &'de Value {
         let result = match self {
             Value::Integer(i) => *i as f64,
             Value::Double(d) => *d,
+            Value::Boolean(b) => f64::from(*b),
             Value::BulkString(bs) => str::from_utf8(bs)?.parse::<f64>()?,
             Value::Nil => 0.,
-            Value::SimpleString(s) => s.parse::<f64>()?,
+            Value::SimpleString(s) | Value::BigNumber(s) => s.parse::<f64>()?,
             Value::Error(e) => return Err(Error::Redis(e.clone())),
             _ => {
                 return Err(Error::Client(format!(
@@ -313,7 +325,8 @@ impl<'de> Deserializer<'de> for &'de Value {
         let result = match self {
             Value::BulkString(s) => str::from_utf8(s)?,
             Value::Nil => "",
-            Value::SimpleString(s) => s.as_str(),
+            Value::SimpleString(s) | Value::BigNumber(s) => s.as_str(),
+            Value::VerbatimString { text, .. } => text.as_str(),
             Value::Error(e) => return Err(Error::Redis(e.clone())),
             _ => {
                 return Err(Error::Client(format!(
@@ -335,7 +348,8 @@ impl<'de> Deserializer<'de> for &'de Value {
             Value::Double(d) => d.to_string(),
             Value::BulkString(s) => str::from_utf8(s)?.to_owned(),
             Value::Nil => String::from(""),
-            Value::SimpleString(s) => s.clone(),
+            Value::SimpleString(s) | Value::BigNumber(s) => s.clone(),
+            Value::VerbatimString { text, .. } => text.clone(),
             Value::Error(e) => return Err(Error::Redis(e.clone())),
             _ => {
                 return Err(Error::Client(format!(
@@ -355,7 +369,8 @@ impl<'de> Deserializer<'de> for &'de Value {
         let result = match self {
             Value::BulkString(s) => s.as_slice(),
             Value::Nil => &[],
-            Value::SimpleString(s) => s.as_bytes(),
+            Value::SimpleString(s) | Value::BigNumber(s) => s.as_bytes(),
+            Value::VerbatimString { text, .. } => text.as_bytes(),
             Value::Error(e) => return Err(Error::Redis(e.clone())),
             _ => {
                 return Err(Error::Client(format!(
@@ -375,7 +390,8 @@ impl<'de> Deserializer<'de> for &'de Value {
         let result = match self {
             Value::BulkString(s) => s.clone(),
             Value::Nil => vec![],
-            Value::SimpleString(s) => s.as_bytes().to_vec(),
+            Value::SimpleString(s) | Value::BigNumber(s) => s.as_bytes().to_vec(),
+            Value::VerbatimString { text, .. } => text.as_bytes().to_vec(),
             Value::Error(e) => return Err(Error::Redis(e.clone())),
             _ => {
                 return Err(Error::Client(format!(
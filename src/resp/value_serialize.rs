@@ -1,4 +1,7 @@
-use crate::resp::{Value, ERROR_FAKE_FIELD, PUSH_FAKE_FIELD, SET_FAKE_FIELD};
+use crate::resp::{
+    Value, BIG_NUMBER_FAKE_FIELD, ERROR_FAKE_FIELD, PUSH_FAKE_FIELD, SET_FAKE_FIELD,
+    VERBATIM_STRING_FAKE_FIELD,
+};
 use serde::{
     ser::{SerializeMap, SerializeSeq, SerializeTupleStruct},
     Serialize,
@@ -43,6 +46,11 @@ impl Serialize for Value {
                 }
                 ts.end()
             }
+            Value::BigNumber(n) => serializer.serialize_newtype_struct(BIG_NUMBER_FAKE_FIELD, n),
+            Value::VerbatimString { format, text } => serializer.serialize_newtype_struct(
+                VERBATIM_STRING_FAKE_FIELD,
+                format!("{format}:{text}").as_str(),
+            ),
             Value::Error(e) => {
                 serializer.serialize_newtype_struct(ERROR_FAKE_FIELD, e.to_string().as_str())
             }
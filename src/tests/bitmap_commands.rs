@@ -1,7 +1,7 @@
 use crate::{
     commands::{
-        BitFieldGetSubCommand, BitFieldOverflow, BitFieldSubCommand, BitOperation, BitRange,
-        BitUnit, BitmapCommands, StringCommands,
+        BitFieldGetSubCommand, BitFieldOffset, BitFieldOverflow, BitFieldSubCommand, BitFieldType,
+        BitOperation, BitRange, BitUnit, BitmapCommands, StringCommands,
     },
     tests::get_test_client,
     Result,
@@ -57,7 +57,7 @@ async fn bitfield() -> Result<()> {
             ],
         )
         .await?;
-    assert!(matches!(results[..], [1, 6]));
+    assert_eq!(vec![Some(1), Some(6)], results);
 
     client.set("mykey", "foobar").await?;
 
@@ -70,7 +70,7 @@ async fn bitfield() -> Result<()> {
             ],
         )
         .await?;
-    assert!(matches!(results[..], [102, 111]));
+    assert_eq!(vec![Some(102), Some(111)], results);
 
     client.set("mykey", "foobar").await?;
 
@@ -84,7 +84,7 @@ async fn bitfield() -> Result<()> {
             ],
         )
         .await?;
-    assert!(matches!(results[..], [1, 1]));
+    assert_eq!(vec![Some(1), Some(1)], results);
 
     let results = client
         .bitfield(
@@ -96,6 +96,21 @@ async fn bitfield() -> Result<()> {
         .await?;
     assert_eq!(0, results.len());
 
+    client.set("mykey", "foobar").await?;
+
+    let results = client
+        .bitfield(
+            "mykey",
+            [
+                BitFieldSubCommand::set(BitFieldType::Signed(8), BitFieldOffset::Multiplier(0), -2),
+                BitFieldSubCommand::get(BitFieldType::Signed(8), BitFieldOffset::Bit(0)),
+                BitFieldSubCommand::overflow(BitFieldOverflow::Fail),
+                BitFieldSubCommand::incr_by(BitFieldType::Unsigned(2), BitFieldOffset::Bit(0), 1),
+            ],
+        )
+        .await?;
+    assert_eq!(vec![Some(b'f' as i64), Some(-2), None], results);
+
     client.close().await?;
 
     Ok(())
@@ -113,7 +128,7 @@ async fn bitfield_readonly() -> Result<()> {
         .bitfield_readonly("mykey", [BitFieldGetSubCommand::new("i8", 0)])
         .await?;
     assert_eq!(1, results.len());
-    assert_eq!(b'f' as u64, results[0]);
+    assert_eq!(b'f' as i64, results[0]);
 
     Ok(())
 }
@@ -177,6 +192,11 @@ async fn bitpos() -> Result<()> {
         .await?;
     assert_eq!(8, pos);
 
+    let pos = client
+        .bitpos("mykey", 1, BitRange::range(0, 7).unit(BitUnit::Bit))
+        .await?;
+    assert_eq!(-1, pos);
+
     Ok(())
 }
 
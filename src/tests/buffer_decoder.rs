@@ -4,7 +4,7 @@ use tokio_util::codec::Decoder;
 use crate::{resp::BufferDecoder, Result};
 
 fn decode(str: &str) -> Result<Option<Vec<u8>>> {
-    let mut buffer_decoder = BufferDecoder;
+    let mut buffer_decoder = BufferDecoder::default();
     let mut buf: BytesMut = str.into();
     buffer_decoder.decode(&mut buf).map(|b| b.map(|b| b.to_vec()))
 }
@@ -202,4 +202,59 @@ fn map() -> Result<()> {
     assert_eq!(None, result);
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[test]
+fn big_number() -> Result<()> {
+    let result = decode("(3492890328409238509324850943850943825024385\r\n")?;
+    assert_eq!(
+        Some(
+            "(3492890328409238509324850943850943825024385\r\n"
+                .as_bytes()
+                .to_vec()
+        ),
+        result
+    );
+
+    let result = decode("(3492890328409238509324850943850943825024385\r")?;
+    assert_eq!(None, result);
+
+    Ok(())
+}
+
+#[test]
+fn attribute() -> Result<()> {
+    let result = decode("|1\r\n+ttl\r\n:3600\r\n:12\r\n")?;
+    assert_eq!(
+        Some("|1\r\n+ttl\r\n:3600\r\n:12\r\n".as_bytes().to_vec()),
+        result
+    );
+
+    let result = decode("|1\r\n+ttl\r\n:3600\r\n")?;
+    assert_eq!(None, result);
+
+    let result = decode("|1\r\n+ttl\r\n:3600\r\n:12")?;
+    assert_eq!(None, result);
+
+    Ok(())
+}
+
+#[test]
+fn inline_reply() -> Result<()> {
+    // inline replies are only expected after an inline command has been sent
+    let result = decode("OK\r\n");
+    assert!(result.is_err());
+
+    let mut buffer_decoder = BufferDecoder::default();
+    buffer_decoder.accept_inline_replies();
+
+    let mut buf: BytesMut = "OK\r\n".into();
+    let result = buffer_decoder.decode(&mut buf)?.map(|b| b.to_vec());
+    assert_eq!(Some("+OK\r\n".as_bytes().to_vec()), result);
+
+    let mut buf: BytesMut = "OK\r".into();
+    let result = buffer_decoder.decode(&mut buf)?;
+    assert!(result.is_none());
+
+    Ok(())
+}
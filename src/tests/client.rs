@@ -1,16 +1,27 @@
 use std::time::Duration;
 
 use crate::{
-    client::{Client, IntoConfig},
+    client::{
+        Client, ClientPreparedCommand, Config, IntoConfig, PreparedCommand, RetryPolicy,
+        ServerFeature, Version,
+    },
     commands::{
-        BlockingCommands, ClientKillOptions, ConnectionCommands, FlushingMode, LMoveWhere,
-        ListCommands, ServerCommands, StringCommands,
+        BlockingCommands, ClientKillOptions, ClientTrackingOptions, ClientTrackingStatus,
+        ConnectionCommands, FlushingMode, HelloOptions, LMoveWhere, ListCommands, RoleResult,
+        ServerCommands, StringCommands,
+    },
+    resp::{cmd, CommandEncoder, Value},
+    tests::{
+        get_default_addr, get_default_host, get_default_port, get_test_client, log_try_init,
+        mock_transport,
     },
-    resp::cmd,
-    tests::{get_default_addr, get_test_client, log_try_init},
     Error, Result,
 };
+use bytes::BytesMut;
+use futures_channel::mpsc;
+use futures_util::{join, StreamExt};
 use serial_test::serial;
+use tokio_util::codec::Encoder;
 
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
@@ -25,6 +36,45 @@ async fn send() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn send_pipeline_iter() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let commands = (0..10000)
+        .map(|i| cmd("SET").arg(format!("key{i}")).arg(i))
+        .collect();
+    let results = client.send_pipeline_iter(commands, 100)?;
+    let results = results.collect::<Vec<_>>().await;
+    assert_eq!(10000, results.len());
+    for result in results {
+        result?.to::<()>()?;
+    }
+
+    // slow consumer
+    let commands = (0..1000)
+        .map(|i| cmd("GET").arg(format!("key{i}")))
+        .collect();
+    let mut results = client.send_pipeline_iter(commands, 1)?;
+    let mut i = 0;
+    while let Some(result) = results.next().await {
+        let value: usize = result?.to()?;
+        assert_eq!(i, value);
+        i += 1;
+    }
+    assert_eq!(1000, i);
+
+    // other commands are processed once the stream has been consumed
+    let value: String = client.get("key999").await?;
+    assert_eq!("999", value);
+
+    client.close().await?;
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -39,6 +89,26 @@ async fn forget() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn close_drains_pending_commands() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    for i in 0..1000 {
+        client.send_and_forget(cmd("SET").arg(format!("key{i}")).arg(i), None)?;
+    }
+
+    client.close().await?;
+
+    let client = get_test_client().await?;
+    let value: i32 = client.get("key999").await?;
+    assert_eq!(999, value);
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -151,3 +221,281 @@ async fn mget_mset() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn retry_policy() {
+    let prepared_command: PreparedCommand<(), i64> =
+        PreparedCommand::new((), cmd("INCR").arg("key")).retry(RetryPolicy::Never);
+    assert_eq!(Some(false), prepared_command.retry_on_error);
+    assert_eq!(None, prepared_command.max_attempts);
+
+    let prepared_command: PreparedCommand<(), String> =
+        PreparedCommand::new((), cmd("GET").arg("key")).retry(RetryPolicy::OnError { max: 5 });
+    assert_eq!(Some(true), prepared_command.retry_on_error);
+    assert_eq!(Some(5), prepared_command.max_attempts);
+
+    let prepared_command: PreparedCommand<(), String> =
+        PreparedCommand::new((), cmd("GET").arg("key")).retry(RetryPolicy::OnReconnect);
+    assert_eq!(Some(true), prepared_command.retry_on_error);
+    assert_eq!(None, prepared_command.max_attempts);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn on_push() -> Result<()> {
+    let mut client1 = get_test_client().await?;
+    let client2 = get_test_client().await?;
+    client1.flushall(FlushingMode::Sync).await?;
+
+    client1.hello(HelloOptions::new(3)).await?;
+
+    let (sender, mut receiver) = mpsc::unbounded();
+    client1.on_push(move |push_message| {
+        let _ = sender.unbounded_send(push_message);
+    })?;
+
+    client1.set("key", "value").await?;
+    client1
+        .client_tracking(ClientTrackingStatus::On, ClientTrackingOptions::default())
+        .await?;
+    let _value: String = client1.get("key").await?;
+
+    client2.set("key", "new_value").await?;
+
+    let push_message = receiver.next().await.unwrap();
+    assert_eq!("invalidate", push_message.kind);
+    assert_eq!(1, push_message.data.len());
+    assert!(
+        matches!(&push_message.data[0], Value::Array(keys) if keys.len() == 1 && keys[0] == Value::BulkString(b"key".to_vec()))
+    );
+
+    // regular replies are not affected
+    let value: String = client1.get("key").await?;
+    assert_eq!("new_value", value);
+
+    client1
+        .client_tracking(ClientTrackingStatus::Off, ClientTrackingOptions::default())
+        .await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn health_check() -> Result<()> {
+    let client = get_test_client().await?;
+
+    let health_status = client.health_check().await?;
+    assert!(matches!(health_status.role, RoleResult::Master { .. }));
+    assert!(!health_status.read_only);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_health_check() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    let (health_status, _) = join!(client.health_check(), async {
+        assert_eq!("PING", server.receive_command().await.name);
+        assert_eq!("ROLE", server.receive_command().await.name);
+        server.reply("+PONG\r\n");
+        server.reply("*5\r\n$5\r\nslave\r\n$9\r\n127.0.0.1\r\n:6379\r\n$9\r\nconnected\r\n:42\r\n");
+    });
+    let health_status = health_status?;
+    assert!(matches!(
+        health_status.role,
+        RoleResult::Replica {
+            master_port: 6379,
+            amount_data_received: 42,
+            ..
+        }
+    ));
+    assert!(health_status.read_only);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn server_version() -> Result<()> {
+    let client = get_test_client().await?;
+
+    let hello_result = client.hello(HelloOptions::new(3)).await?;
+    let version = client.server_version().unwrap();
+    assert_eq!(hello_result.version, version.to_string());
+    assert_eq!(Some(hello_result.proto), client.protocol());
+    assert!(version >= Version::new(6, 0, 0));
+    assert_eq!(
+        version >= Version::new(7, 0, 0),
+        client.supports(ServerFeature::Functions)
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_unknown_server_version() -> Result<()> {
+    let (transport, _server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    assert_eq!(None, client.server_version());
+    assert_eq!(None, client.protocol());
+    assert!(!client.supports(ServerFeature::ShardedPubSub));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn ping_rtt() -> Result<()> {
+    let config = Config::builder()
+        .host_port(get_default_host(), get_default_port())
+        .rtt_ewma_alpha(0.5)
+        .build()?;
+    let client = Client::connect(config).await?;
+    assert_eq!(None, client.avg_rtt());
+
+    let rtt = client.ping_rtt().await?;
+    assert!(rtt > Duration::ZERO);
+    assert!(rtt < Duration::from_secs(1));
+    assert_eq!(Some(rtt), client.avg_rtt());
+
+    client.ping_rtt().await?;
+    let avg_rtt = client.avg_rtt().unwrap();
+    assert!(avg_rtt > Duration::ZERO);
+    assert!(avg_rtt < Duration::from_secs(1));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_send_inline() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    let (result, _) = join!(client.send_inline("SET key value"), async {
+        let command = server.receive_command().await;
+        assert!(command.inline);
+        assert_eq!("SET key value", command.to_string());
+
+        let mut buf = BytesMut::new();
+        CommandEncoder.encode(&command, &mut buf).unwrap();
+        assert_eq!(&b"SET key value\r\n"[..], &buf[..]);
+
+        server.reply("+OK\r\n");
+    });
+    result?.to::<()>()?;
+
+    let result = client.send_inline("SET key value\r\nFLUSHALL").await;
+    assert!(matches!(result, Err(Error::Client(_))));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_next_reply_skip() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let mut client = Client::connect_with_transport(transport, &Config::default());
+
+    client.next_reply_skip()?;
+    client.set("key", "value").forget()?;
+
+    let (value, _) = join!(async { client.get::<_, String>("key").await }, async {
+        let command = server.receive_command().await;
+        assert_eq!("CLIENT", command.name);
+        assert_eq!(
+            vec![&b"REPLY"[..], &b"SKIP"[..]],
+            command.args.into_iter().collect::<Vec<_>>()
+        );
+        assert_eq!("SET", server.receive_command().await.name);
+        assert_eq!("GET", server.receive_command().await.name);
+        // only the reply of GET is sent back
+        server.reply("$5\r\nvalue\r\n");
+    });
+    assert_eq!("value", value?);
+
+    // the replies of the following commands are received again
+    let (value, _) = join!(async { client.get::<_, String>("key").await }, async {
+        assert_eq!("GET", server.receive_command().await.name);
+        server.reply("$5\r\nvalue\r\n");
+    });
+    assert_eq!("value", value?);
+
+    // a clone of the client could send the skipped command
+    let _clone = client.clone();
+    let result = client.next_reply_skip();
+    assert!(matches!(result, Err(Error::Client(_))));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_ping_rtt() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    let (rtt, _) = join!(client.ping_rtt(), async {
+        assert_eq!("PING", server.receive_command().await.name);
+        server.reply("+PONG\r\n");
+    });
+    assert!(rtt? > Duration::ZERO);
+    // the moving average is opt-in
+    assert_eq!(None, client.avg_rtt());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn blocking_command_on_shared_connection() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let config = Config::builder().strict_blocking_commands(true).build()?;
+    let client = Client::connect_with_transport(transport, &config);
+
+    // the connection is not shared yet
+    let (result, _) = join!(client.send(cmd("BLPOP").arg("key").arg(1), None), async {
+        assert_eq!("BLPOP", server.receive_command().await.name);
+        server.reply("_\r\n");
+    });
+    result?;
+
+    let clone = client.clone();
+
+    let result = client.send(cmd("blpop").arg("key").arg(1), None).await;
+    assert!(matches!(result, Err(Error::Client(_))));
+    let result = clone
+        .send(
+            cmd("XREAD")
+                .arg("BLOCK")
+                .arg(0)
+                .arg("STREAMS")
+                .arg("key")
+                .arg("$"),
+            None,
+        )
+        .await;
+    assert!(matches!(result, Err(Error::Client(_))));
+
+    // non blocking commands are still sent
+    let (result, _) = join!(
+        client.send(cmd("XREAD").arg("STREAMS").arg("key").arg("0"), None),
+        async {
+            assert_eq!("XREAD", server.receive_command().await.name);
+            server.reply("_\r\n");
+        }
+    );
+    result?;
+
+    Ok(())
+}
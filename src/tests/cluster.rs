@@ -1,20 +1,24 @@
 use crate::{
-    client::Client,
+    client::{Client, Version},
     commands::{
-        CallBuilder, ClusterCommands, ClusterNodeResult,
+        CallBuilder, ClusterCommands, ClusterLinkState, ClusterNodeDescription, ClusterNodeResult,
+        ClusterNodesResult,
         ClusterSetSlotSubCommand::{Importing, Migrating, Node},
         ClusterShardResult, ConnectionCommands, FlushingMode, GenericCommands, HelloOptions,
         MigrateOptions, ScriptingCommands, ServerCommands, StringCommands,
     },
-    network::{Version, ClusterConnection},
+    network::ClusterConnection,
     sleep, spawn,
-    tests::{get_cluster_test_client, get_cluster_test_client_with_command_timeout},
+    tests::{
+        get_cluster_test_client, get_cluster_test_client_with_command_timeout, get_test_client,
+    },
     Error, RedisError, RedisErrorKind, Result,
 };
 use serial_test::serial;
 use std::{
     collections::HashSet,
     future::IntoFuture,
+    str::FromStr,
 };
 use futures_util::try_join;
 
@@ -465,4 +469,174 @@ async fn commands_to_different_nodes() -> Result<()> {
     assert_eq!("1", val1);
     assert_eq!("2", val2);
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn mismatched_key_slots() -> Result<()> {
+    let client = get_cluster_test_client().await?;
+    client.flushall(FlushingMode::Sync).await?;
+
+    client.set("key0", "0").await?; // cluster keyslot key0 = 13252
+    let result = client.rename("key0", "key1").await; // cluster keyslot key1 = 9189
+    assert!(matches!(result, Err(Error::Client(_))));
+
+    // same hash tag, same slot
+    client.set("{user1}:key0", "0").await?;
+    client.rename("{user1}:key0", "{user1}:key1").await?;
+    let value: String = client.get("{user1}:key1").await?;
+    assert_eq!("0", value);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn keys_in_slot() -> Result<()> {
+    let client = get_cluster_test_client().await?;
+    client.flushall(FlushingMode::Sync).await?;
+
+    client.set("{user1}:name", "John").await?;
+    client.set("{user1}:age", "42").await?;
+
+    let slot = client.cluster_keyslot("{user1}:name").await?;
+
+    let count = client.cluster_countkeysinslot(slot).await?;
+    assert_eq!(2, count);
+
+    let keys: HashSet<String> = client.cluster_getkeysinslot(slot, 10).await?;
+    assert_eq!(2, keys.len());
+    assert!(keys.contains("{user1}:name"));
+    assert!(keys.contains("{user1}:age"));
+
+    let result = client.cluster_countkeysinslot(16384).await;
+    assert!(matches!(result, Err(Error::Client(_))));
+    let result = client
+        .cluster_getkeysinslot::<String, Vec<_>>(16384, 10)
+        .await;
+    assert!(matches!(result, Err(Error::Client(_))));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn cluster_topology() -> Result<()> {
+    let client = get_cluster_test_client().await?;
+
+    let nodes = client.cluster_topology();
+    assert!(!nodes.is_empty());
+    assert!(nodes.iter().any(|n| n.is_master));
+
+    let shards: Vec<ClusterShardResult> = client.cluster_shards().await?;
+    let num_masters = shards
+        .iter()
+        .flat_map(|s| s.nodes.iter())
+        .filter(|n| n.role == "master")
+        .count();
+    assert_eq!(num_masters, nodes.iter().filter(|n| n.is_master).count());
+
+    // cluster nodes are not exposed in standalone mode
+    let client = get_test_client().await?;
+    assert!(client.cluster_topology().is_empty());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn cluster_nodes() -> Result<()> {
+    let client = get_cluster_test_client().await?;
+
+    let result = client.cluster_nodes().await?;
+    assert!(result.nodes.iter().any(|n| n.is_myself()));
+
+    let masters: Vec<&ClusterNodeDescription> =
+        result.nodes.iter().filter(|n| n.is_master()).collect();
+    assert!(!masters.is_empty());
+    let num_slots: usize = masters
+        .iter()
+        .flat_map(|n| n.slots.iter())
+        .map(|(start, end)| (end - start) as usize + 1)
+        .sum();
+    assert_eq!(16384, num_slots);
+
+    for master in masters {
+        let replicas: Vec<ClusterNodeDescription> = client.cluster_replicas(&master.id).await?;
+        assert!(replicas
+            .iter()
+            .all(|r| r.master_id.as_deref() == Some(master.id.as_str())));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn cluster_nodes_from_str() -> Result<()> {
+    let result = ClusterNodesResult::from_str(
+        "07c37dfeb235213a872192d90877d0cd55635b91 127.0.0.1:30004@31004,hostname4 slave e7d1eecce10fd6bb5eb35b9f99a514335d9ba9ca 0 1426238317239 4 connected\n\
+        67ed2db8d677e59ec4a4cefb06858cf2a1a89fa1 127.0.0.1:30002@31002,hostname2 master - 0 1426238316232 2 connected 5461-10922\n\
+        e7d1eecce10fd6bb5eb35b9f99a514335d9ba9ca 127.0.0.1:30001@31001,hostname1 myself,master - 0 0 1 connected 0-5460 [5461->-67ed2db8d677e59ec4a4cefb06858cf2a1a89fa1]\n\
+        292f8b365bb7edb5e285caf0b7e6ddc7265d2f4f 127.0.0.1:30003@31003, master - 0 1426238318243 3 connected 10923-16383 16000 [93-<-e7d1eecce10fd6bb5eb35b9f99a514335d9ba9ca]\n\
+        6ec23923021cf3ffec47632106199cb7f496ce01 :0@0 master,fail?,noaddr - 1426238316232 0 5 disconnected\n",
+    )?;
+
+    assert_eq!(5, result.nodes.len());
+
+    let replica = &result.nodes[0];
+    assert_eq!("07c37dfeb235213a872192d90877d0cd55635b91", replica.id);
+    assert_eq!("127.0.0.1", replica.ip);
+    assert_eq!(30004, replica.port);
+    assert_eq!(31004, replica.cluster_bus_port);
+    assert_eq!(Some("hostname4"), replica.hostname.as_deref());
+    assert_eq!(vec!["slave".to_owned()], replica.flags);
+    assert!(!replica.is_master());
+    assert_eq!(
+        Some("e7d1eecce10fd6bb5eb35b9f99a514335d9ba9ca"),
+        replica.master_id.as_deref()
+    );
+    assert_eq!(0, replica.ping_sent);
+    assert_eq!(1426238317239, replica.pong_recv);
+    assert_eq!(4, replica.config_epoch);
+    assert_eq!(ClusterLinkState::Connected, replica.link_state);
+    assert!(replica.slots.is_empty());
+
+    let myself = &result.nodes[2];
+    assert!(myself.is_myself());
+    assert!(myself.is_master());
+    assert_eq!(None, myself.master_id);
+    assert_eq!(vec![(0, 5460)], myself.slots);
+    assert_eq!(
+        vec![(5461, "67ed2db8d677e59ec4a4cefb06858cf2a1a89fa1".to_owned())],
+        myself.migrating_slots
+    );
+    assert!(myself.importing_slots.is_empty());
+
+    let importing = &result.nodes[3];
+    assert_eq!(None, importing.hostname);
+    assert_eq!(vec![(10923, 16383), (16000, 16000)], importing.slots);
+    assert_eq!(
+        vec![(93, "e7d1eecce10fd6bb5eb35b9f99a514335d9ba9ca".to_owned())],
+        importing.importing_slots
+    );
+
+    let failed = &result.nodes[4];
+    assert_eq!("", failed.ip);
+    assert_eq!(0, failed.port);
+    assert_eq!(
+        vec!["master".to_owned(), "fail?".to_owned(), "noaddr".to_owned()],
+        failed.flags
+    );
+    assert_eq!(ClusterLinkState::Disconnected, failed.link_state);
+
+    assert!(ClusterNodesResult::from_str(
+        "07c37dfeb235213a872192d90877d0cd55635b91 127.0.0.1:30004"
+    )
+    .is_err());
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use crate::{
     commands::{GenericCommands, HashCommands, SetCommands},
+    resp::cmd,
     tests::get_test_client,
     Result,
 };
@@ -77,3 +78,52 @@ async fn set_collection() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn command_introspection() {
+    let command = cmd("SET").arg("key").arg("value");
+    assert_eq!(2, command.num_args());
+    assert_eq!(
+        vec![b"key".as_slice(), b"value".as_slice()],
+        command.args_iter().collect::<Vec<_>>()
+    );
+    assert_eq!(Some(b"key".as_slice()), command.first_key());
+
+    let command = cmd("OBJECT").arg("ENCODING").arg("key");
+    assert_eq!(Some(b"key".as_slice()), command.first_key());
+
+    let command = cmd("EVAL").arg("return 1").arg(2).arg("key1").arg("key2");
+    assert_eq!(Some(b"key1".as_slice()), command.first_key());
+
+    let command = cmd("EVAL").arg("return 1").arg(0);
+    assert_eq!(None, command.first_key());
+
+    let command = cmd("XREAD")
+        .arg("COUNT")
+        .arg(2)
+        .arg("STREAMS")
+        .arg("stream1")
+        .arg("0-0");
+    assert_eq!(Some(b"stream1".as_slice()), command.first_key());
+
+    let command = cmd("BLPOP").arg("key1").arg("key2").arg(0);
+    assert_eq!(Some(b"key1".as_slice()), command.first_key());
+
+    let command = cmd("PING");
+    assert_eq!(0, command.num_args());
+    assert_eq!(None, command.first_key());
+
+    let command = cmd("FT.SEARCH").arg("index").arg("*");
+    assert_eq!(None, command.first_key());
+}
+
+#[test]
+fn command_display() {
+    let command = cmd("SET").arg("key").arg("my \"value\"\n");
+    assert_eq!(r#"SET "key" "my \"value\"\n""#, command.to_string());
+
+    let command = cmd("SET").arg("key").arg(vec![0u8, 0xffu8]);
+    assert_eq!(r#"SET "key" "\x00\xff""#, command.to_string());
+
+    assert_eq!("PING", cmd("PING").to_string());
+}
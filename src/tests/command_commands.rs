@@ -0,0 +1,210 @@
+use crate::{
+    client::{Client, Config},
+    commands::{CommandCommands, CommandDoc, CommandListOptions},
+    tests::{get_test_client, mock_transport},
+    Result,
+};
+use futures_util::{join, FutureExt};
+use serial_test::serial;
+use std::collections::HashMap;
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn command() -> Result<()> {
+    let client = get_test_client().await?;
+
+    let _command_infos = client.command().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn command_info() -> Result<()> {
+    let client = get_test_client().await?;
+
+    let _command_infos = client.command_info("SORT").await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_cached_command_info() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    let (command_info, _) = join!(client.cached_command_info("get"), async {
+        let command = server.receive_command().await;
+        assert_eq!("COMMAND", command.name);
+        assert_eq!(
+            vec![b"INFO".to_vec(), b"GET".to_vec()],
+            (&command.args)
+                .into_iter()
+                .map(|a| a.to_vec())
+                .collect::<Vec<_>>()
+        );
+        server.reply(concat!(
+            "*1\r\n*10\r\n",
+            "$3\r\nget\r\n:2\r\n~2\r\n+readonly\r\n+fast\r\n:1\r\n:1\r\n:1\r\n",
+            "~2\r\n+@read\r\n+@string\r\n*0\r\n*0\r\n*0\r\n"
+        ));
+    });
+    let command_info = command_info?.unwrap();
+    assert_eq!("get", command_info.name);
+    assert_eq!(2, command_info.arity);
+    assert_eq!(1, command_info.first_key);
+    assert_eq!(1, command_info.last_key);
+    assert_eq!(1, command_info.step);
+    assert_eq!(
+        vec!["@read".to_owned(), "@string".to_owned()],
+        command_info.acl_categories
+    );
+
+    // served from the cache, shared by the clones of the client
+    let command_info = client.clone().cached_command_info("GET").await?.unwrap();
+    assert_eq!("get", command_info.name);
+
+    // unknown command
+    let (command_info, _) = join!(client.cached_command_info("unknown"), async {
+        server.receive_command().await;
+        server.reply("*1\r\n_\r\n");
+    });
+    assert!(command_info?.is_none());
+
+    // not cached as unknown: fetched again, e.g. after a module is loaded
+    let (command_info, _) = join!(client.cached_command_info("unknown"), async {
+        let command = server.receive_command().await;
+        assert_eq!("COMMAND", command.name);
+        server.reply(concat!(
+            "*1\r\n*10\r\n",
+            "$7\r\nunknown\r\n:2\r\n~1\r\n+readonly\r\n:1\r\n:1\r\n:1\r\n",
+            "~0\r\n*0\r\n*0\r\n*0\r\n"
+        ));
+    });
+    assert_eq!("unknown", command_info?.unwrap().name);
+
+    // now served from the cache: no round-trip
+    assert!(matches!(
+        client.cached_command_info("unknown").now_or_never(),
+        Some(Ok(Some(_)))
+    ));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn command_count() -> Result<()> {
+    let client = get_test_client().await?;
+
+    let command_infos = client.command().await?;
+    let num_commands = client.command_count().await?;
+    assert_eq!(command_infos.len(), num_commands);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn command_docs() -> Result<()> {
+    let client = get_test_client().await?;
+
+    let _command_docs: HashMap<String, CommandDoc> =
+        client.command_docs(["XADD", "GET", "SET"]).await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn command_getkeys() -> Result<()> {
+    let client = get_test_client().await?;
+
+    let keys: Vec<String> = client
+        .command_getkeys(["MSET", "a", "b", "c", "d", "e", "f"])
+        .await?;
+    assert!(keys.contains(&"a".to_owned()));
+    assert!(keys.contains(&"c".to_owned()));
+    assert!(keys.contains(&"e".to_owned()));
+
+    let keys: Vec<String> = client
+        .command_getkeys(["EVAL", "not consulted", "3", "key1", "key2", "key3", "arg1"])
+        .await?;
+    assert!(keys.contains(&"key1".to_owned()));
+    assert!(keys.contains(&"key2".to_owned()));
+    assert!(keys.contains(&"key3".to_owned()));
+
+    let keys: Vec<String> = client
+        .command_getkeys(["SORT", "mylist", "ALPHA", "STORE", "outlist"])
+        .await?;
+    assert!(keys.contains(&"mylist".to_owned()));
+    assert!(keys.contains(&"outlist".to_owned()));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn command_getkeysandflags() -> Result<()> {
+    let client = get_test_client().await?;
+
+    let keys_and_flags: HashMap<String, Vec<String>> = client
+        .command_getkeysandflags(["MSET", "a", "b", "c", "d", "e", "f"])
+        .await?;
+    assert!(keys_and_flags.contains_key(&"a".to_owned()));
+    assert!(keys_and_flags.contains_key(&"c".to_owned()));
+    assert!(keys_and_flags.contains_key(&"e".to_owned()));
+
+    let keys_and_flags: HashMap<String, Vec<String>> = client
+        .command_getkeysandflags(["EVAL", "not consulted", "3", "key1", "key2", "key3", "arg1"])
+        .await?;
+    assert!(keys_and_flags.contains_key(&"key1".to_owned()));
+    assert!(keys_and_flags.contains_key(&"key2".to_owned()));
+    assert!(keys_and_flags.contains_key(&"key3".to_owned()));
+
+    let keys_and_flags: HashMap<String, Vec<String>> = client
+        .command_getkeysandflags(["LMOVE", "mylist1", "mylist2", "left", "left"])
+        .await?;
+    let flags = keys_and_flags.get(&"mylist1".to_owned()).unwrap();
+    assert_eq!("RW", flags[0]);
+    assert_eq!("access", flags[1]);
+    assert_eq!("delete", flags[2]);
+    let flags = keys_and_flags.get(&"mylist2".to_owned()).unwrap();
+    assert_eq!("RW", flags[0]);
+    assert_eq!("insert", flags[1]);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn command_list() -> Result<()> {
+    let client = get_test_client().await?;
+
+    let all_commands: Vec<String> = client.command_list(CommandListOptions::default()).await?;
+    assert!(!all_commands.is_empty());
+
+    let string_commands: Vec<String> = client
+        .command_list(CommandListOptions::default().filter_by_acl_category("string"))
+        .await?;
+    assert!(!string_commands.is_empty());
+    assert!(string_commands.contains(&"get".to_owned()));
+    assert!(string_commands.contains(&"set".to_owned()));
+
+    let config_commands: Vec<String> = client
+        .command_list(CommandListOptions::default().filter_by_pattern("config*"))
+        .await?;
+    assert!(!config_commands.is_empty());
+    assert!(config_commands.contains(&"config|get".to_owned()));
+    assert!(config_commands.contains(&"config|set".to_owned()));
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use crate::{
+    client::{Client, Config},
+    commands::StringCommands,
+    tests::{mock_transport, MockServer},
+    Result,
+};
+use futures_util::join;
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    future::IntoFuture,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Event, Metadata, Subscriber,
+};
+
+type Fields = Arc<Mutex<HashMap<String, String>>>;
+
+/// Records the fields of all the spans, the last recorded value wins
+#[derive(Default)]
+struct FieldsSubscriber {
+    fields: Fields,
+    next_id: AtomicU64,
+}
+
+struct FieldsVisitor<'a>(&'a Fields);
+
+impl<'a> Visit for FieldsVisitor<'a> {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0
+            .lock()
+            .unwrap()
+            .insert(field.name().to_owned(), format!("{value:?}"));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0
+            .lock()
+            .unwrap()
+            .insert(field.name().to_owned(), value.to_owned());
+    }
+}
+
+impl Subscriber for FieldsSubscriber {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        span.record(&mut FieldsVisitor(&self.fields));
+        Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+
+    fn record(&self, _span: &Id, values: &Record<'_>) {
+        values.record(&mut FieldsVisitor(&self.fields));
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+async fn reply(server: &mut MockServer, resp: &str) {
+    server.receive_command().await;
+    server.reply(resp);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn command_span() -> Result<()> {
+    let subscriber = FieldsSubscriber::default();
+    let fields = subscriber.fields.clone();
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    let (result, _) = join!(
+        client.set("key", "secret").into_future(),
+        reply(&mut server, "+OK\r\n")
+    );
+    result?;
+
+    {
+        let fields = fields.lock().unwrap();
+        assert_eq!("redis", fields["db.system"]);
+        assert_eq!("SET ? ?", fields["db.statement"]);
+        assert_eq!("127.0.0.1", fields["net.peer.name"]);
+        assert_eq!("OK", fields["otel.status_code"]);
+        assert!(fields.contains_key("db.redis.latency_ms"));
+        assert!(!fields.values().any(|v| v.contains("secret")));
+    }
+
+    let (result, _) = join!(
+        client.get::<_, String>("key").into_future(),
+        reply(&mut server, "-ERR error\r\n")
+    );
+    assert!(result.is_err());
+
+    let fields = fields.lock().unwrap();
+    assert_eq!("GET ?", fields["db.statement"]);
+    assert_eq!("ERROR", fields["otel.status_code"]);
+    assert!(fields["error.message"].contains("error"));
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use crate::{
+    client::{Client, Compression, CompressionConfig, Config},
+    commands::StringCommands,
+    resp::cmd,
+    tests::{mock_transport, MockServer},
+    Result,
+};
+use futures_util::join;
+use std::future::IntoFuture;
+
+/// Algorithm of the tests which do not depend on a specific one
+#[cfg(feature = "lz4")]
+const ANY_ALGORITHM: Compression = Compression::Lz4;
+#[cfg(not(feature = "lz4"))]
+const ANY_ALGORITHM: Compression = Compression::Zstd;
+
+fn compression_config(algorithm: Compression) -> Config {
+    Config {
+        compression: Some(CompressionConfig {
+            algorithm,
+            min_size: 10,
+        }),
+        ..Default::default()
+    }
+}
+
+/// Receives a command and replies with its last argument as a bulk string
+async fn echo_last_arg(server: &mut MockServer, name: &str) -> Vec<u8> {
+    let command = server.receive_command().await;
+    assert_eq!(name, command.name);
+    let value = command.args.last().unwrap().clone();
+
+    let mut reply = format!("${}\r\n", value.len()).into_bytes();
+    reply.extend_from_slice(&value);
+    reply.extend_from_slice(b"\r\n");
+    server.reply_bytes(&reply);
+    value
+}
+
+async fn round_trip(algorithm: Compression) -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &compression_config(algorithm));
+    let large_value = "a".repeat(1000);
+
+    // large values are compressed
+    let (result, stored) = join!(client.set("key", &large_value).into_future(), async {
+        let command = server.receive_command().await;
+        server.reply("+OK\r\n");
+        command.args[1].clone()
+    });
+    result?;
+    assert!(stored.len() < large_value.len());
+    assert_ne!(large_value.as_bytes(), &stored[..]);
+
+    // and decompressed when read
+    let mut reply = format!("${}\r\n", stored.len()).into_bytes();
+    reply.extend_from_slice(&stored);
+    reply.extend_from_slice(b"\r\n");
+    let (value, _) = join!(client.get::<_, String>("key").into_future(), async {
+        server.receive_command().await;
+        server.reply_bytes(&reply);
+    });
+    assert_eq!(large_value, value?);
+
+    // compressed & legacy values can be mixed
+    let mut reply = format!("*3\r\n${}\r\n", stored.len()).into_bytes();
+    reply.extend_from_slice(&stored);
+    reply.extend_from_slice(b"\r\n$-1\r\n$6\r\nlegacy\r\n");
+    let (values, _) = join!(
+        client
+            .mget::<_, _, Option<String>, Vec<Option<String>>>(["key", "key2", "key3"])
+            .into_future(),
+        async {
+            server.receive_command().await;
+            server.reply_bytes(&reply);
+        }
+    );
+    assert_eq!(
+        vec![Some(large_value), None, Some("legacy".to_owned())],
+        values?
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "lz4")]
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn lz4_round_trip() -> Result<()> {
+    round_trip(Compression::Lz4).await
+}
+
+#[cfg(feature = "zstd")]
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn zstd_round_trip() -> Result<()> {
+    round_trip(Compression::Zstd).await
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn uncompressed_values() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &compression_config(ANY_ALGORITHM));
+
+    // small values are not compressed
+    let (result, value) = join!(
+        client.getset::<_, _, String>("key", "small").into_future(),
+        async { echo_last_arg(&mut server, "GETSET").await }
+    );
+    assert_eq!("small", result?);
+    assert_eq!(b"small".to_vec(), value);
+
+    // raw commands are untouched
+    let large_value = "a".repeat(1000);
+    let (result, value) = join!(
+        client
+            .send(cmd("SET").arg("key").arg(&large_value), None)
+            .into_future(),
+        async { echo_last_arg(&mut server, "SET").await }
+    );
+    result?;
+    assert_eq!(large_value.as_bytes(), &value[..]);
+
+    // legacy values without header are returned as-is
+    let (values, _) = join!(
+        client
+            .mget::<_, _, Option<String>, Vec<Option<String>>>(["key1", "key2", "key3"])
+            .into_future(),
+        async {
+            server.receive_command().await;
+            server.reply("*3\r\n$6\r\nlegacy\r\n_\r\n_\r\n");
+        }
+    );
+    assert_eq!(vec![Some("legacy".to_owned()), None, None], values?);
+
+    Ok(())
+}
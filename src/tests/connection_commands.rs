@@ -1,10 +1,10 @@
 use crate::{
     client::{BatchPreparedCommand, Client, ClientPreparedCommand},
     commands::{
-        ClientCachingMode, ClientKillOptions, ClientListOptions, ClientPauseMode, ClientReplyMode,
-        ClientTrackingOptions, ClientTrackingStatus, ClientUnblockMode, ConnectionCommands,
-        FlushingMode, GenericCommands, HelloOptions, PingOptions, ServerCommands,
-        StringCommands,
+        ClientCachingMode, ClientInfoAttribute, ClientKillOptions, ClientListOptions,
+        ClientPauseMode, ClientReplyMode, ClientTrackingOptions, ClientTrackingStatus,
+        ClientUnblockMode, ConnectionCommands, FlushingMode, GenericCommands, HelloOptions,
+        PingOptions, ServerCommands, StringCommands,
     },
     network::spawn,
     sleep,
@@ -92,6 +92,35 @@ async fn client_info() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn client_setinfo() -> Result<()> {
+    let client = get_test_client().await?;
+
+    // registered on connection
+    let client_info = client.client_info().await?;
+    assert_eq!(
+        Some(&"rustis".to_owned()),
+        client_info.additional_arguments.get("lib-name")
+    );
+    assert_eq!(
+        Some(&env!("CARGO_PKG_VERSION").to_owned()),
+        client_info.additional_arguments.get("lib-ver")
+    );
+
+    client
+        .client_setinfo(ClientInfoAttribute::LibName, "mylib")
+        .await?;
+    let client_info = client.client_info().await?;
+    assert_eq!(
+        Some(&"mylib".to_owned()),
+        client_info.additional_arguments.get("lib-name")
+    );
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -134,14 +163,36 @@ async fn client_no_evict() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn client_no_touch() -> Result<()> {
+    let client = get_test_client().await?;
+
+    client.client_no_touch(true).await?;
+    client.client_no_touch(false).await?;
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
 async fn client_pause() -> Result<()> {
     let client = get_test_client().await?;
 
+    client.flushdb(FlushingMode::Sync).await?;
+    client.set("key", "value").await?;
+
     client.client_pause(1000, ClientPauseMode::Write).await?;
-    client.client_unpause().await?;
+
+    // read commands are not paused in write mode
+    let value: String = client.get("key").await?;
+    assert_eq!("value", value);
+
+    assert!(client.client_unpause().await?);
+
+    client.set("key", "value2").await?;
 
     Ok(())
 }
@@ -321,7 +372,7 @@ async fn client_unblock() -> Result<()> {
     let client_id = client1.client_id().await?;
 
     spawn(async move {
-        let result = client1.wait(2, 10000).await;
+        let result = client1.wait(2, std::time::Duration::from_secs(10)).await;
         matches!(
             result,
             Err(Error::Redis(RedisError {
@@ -369,6 +420,21 @@ async fn hello_v3() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn hello_without_protover() -> Result<()> {
+    let client = get_test_client().await?;
+
+    let result = client.hello(HelloOptions::default()).await?;
+    assert_eq!("redis", result.server);
+    assert_eq!(3, result.proto);
+    assert!(result.id > 0);
+    assert!(!result.has_module("ReJSON"));
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -415,7 +481,7 @@ async fn select() -> Result<()> {
     client.flushall(FlushingMode::Sync).await?;
 
     client.set("key", "value").await?;
-    client.move_("key", 1).await?;
+    client.move_key("key", 1).await?;
     client.select(1).await?;
     let value: String = client.get("key").await?;
     assert_eq!("value", value);
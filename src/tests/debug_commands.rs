@@ -0,0 +1,96 @@
+use crate::{
+    commands::{
+        DebugCommands, DebugObject, FlushingMode, GenericCommands, ListCommands, ServerCommands,
+        StringCommands,
+    },
+    tests::get_test_client,
+    Result,
+};
+use serial_test::serial;
+use std::time::{Duration, Instant};
+
+#[test]
+fn debug_object_from_line() -> Result<()> {
+    let debug_object = DebugObject::from_line(
+        "Value at:0x7f3e1c80f0c0 refcount:1 encoding:quicklist serializedlength:18 lru:2457 lru_seconds_idle:3 ql_nodes:1",
+    )?;
+    assert_eq!("0x7f3e1c80f0c0", debug_object.address);
+    assert_eq!(1, debug_object.refcount);
+    assert_eq!("quicklist", debug_object.encoding);
+    assert_eq!(18, debug_object.serialized_length);
+    assert_eq!(2457, debug_object.lru);
+    assert_eq!(3, debug_object.lru_seconds_idle);
+    assert_eq!(
+        Some(&"1".to_owned()),
+        debug_object.additional_fields.get("ql_nodes")
+    );
+
+    let result = DebugObject::from_line("Value at:0x7f3e1c80f0c0 refcount:one encoding:embstr");
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn debug_object() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    client.set("key", "value").await?;
+    let debug_object = client.debug_object("key").await?;
+    assert_eq!("embstr", debug_object.encoding);
+    assert!(debug_object.refcount >= 1);
+
+    let result = client.debug_object("unknown").await;
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn debug_quicklist_packed_threshold() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    client.debug_quicklist_packed_threshold(100).await?;
+    client.rpush("key", "a".repeat(200)).await?;
+    let debug_object = client.debug_object("key").await?;
+    assert!(!debug_object.encoding.is_empty());
+
+    // default threshold
+    client.debug_quicklist_packed_threshold(1 << 30).await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn debug_set_active_expire() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    client.debug_set_active_expire(false).await?;
+    client.set("key", "value").await?;
+    client.pexpire("key", 1, Default::default()).await?;
+    client.debug_set_active_expire(true).await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn debug_sleep() -> Result<()> {
+    let client = get_test_client().await?;
+
+    let start = Instant::now();
+    client.debug_sleep(0.1).await?;
+    assert!(start.elapsed() >= Duration::from_millis(100));
+
+    Ok(())
+}
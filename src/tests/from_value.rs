@@ -80,3 +80,60 @@ fn value_to_value() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn hello_result() -> Result<()> {
+    use crate::commands::HelloResult;
+
+    let module = Value::Map(HashMap::from([
+        (
+            Value::BulkString(b"name".to_vec()),
+            Value::BulkString(b"ReJSON".to_vec()),
+        ),
+        (Value::BulkString(b"ver".to_vec()), Value::Integer(20407)),
+        (
+            Value::BulkString(b"path".to_vec()),
+            Value::BulkString(b"/opt/redis-stack/lib/rejson.so".to_vec()),
+        ),
+        (Value::BulkString(b"args".to_vec()), Value::Array(vec![])),
+    ]));
+
+    let value = Value::Map(HashMap::from([
+        (
+            Value::BulkString(b"server".to_vec()),
+            Value::BulkString(b"redis".to_vec()),
+        ),
+        (
+            Value::BulkString(b"version".to_vec()),
+            Value::BulkString(b"7.2.0".to_vec()),
+        ),
+        (Value::BulkString(b"proto".to_vec()), Value::Integer(3)),
+        (Value::BulkString(b"id".to_vec()), Value::Integer(12)),
+        (
+            Value::BulkString(b"mode".to_vec()),
+            Value::BulkString(b"standalone".to_vec()),
+        ),
+        (
+            Value::BulkString(b"role".to_vec()),
+            Value::BulkString(b"master".to_vec()),
+        ),
+        (
+            Value::BulkString(b"modules".to_vec()),
+            Value::Array(vec![module]),
+        ),
+    ]));
+
+    let result: HelloResult = value.into()?;
+    assert_eq!("redis", result.server);
+    assert_eq!("7.2.0", result.version);
+    assert_eq!(3, result.proto);
+    assert_eq!(12, result.id);
+    assert_eq!("standalone", result.mode);
+    assert_eq!("master", result.role);
+    assert_eq!(1, result.modules.len());
+    assert_eq!("ReJSON", result.modules[0].name);
+    assert_eq!(20407, result.modules[0].version);
+    assert!(result.has_module("rejson"));
+
+    Ok(())
+}
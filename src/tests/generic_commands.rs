@@ -1,14 +1,20 @@
 use crate::{
+    client::{Client, Config, KeyMetadata},
     commands::{
-        ConnectionCommands, ExpireOption, FlushingMode, GenericCommands, ListCommands,
-        RestoreOptions, ScanOptions, ServerCommands, SetCommands, SortOptions, StringCommands,
+        ConnectionCommands, ExpireOption, ExpireTime, FlushingMode, GenericCommands, HashCommands,
+        ListCommands, ObjectEncoding, RedisType, RestoreOptions, ScanOptions, ServerCommands,
+        SetCommands, SortOptions, SortOrder, SortedSetCommands, StreamCommands, StringCommands,
     },
     resp::Value,
-    tests::get_test_client,
+    tests::{get_test_client, mock_transport},
     Result,
 };
+use futures_util::join;
 use serial_test::serial;
-use std::{collections::HashSet, time::SystemTime};
+use std::{
+    collections::HashSet,
+    time::{Duration, SystemTime},
+};
 
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
@@ -45,6 +51,18 @@ async fn copy() -> Result<()> {
     let value: String = client1.get("key").await?;
     assert_eq!("new_value", value);
 
+    // an existing destination in another database is only overwritten with REPLACE
+    client0.set("key", "newest_value").await?;
+    let result = client0.copy("key", "key", Some(1), false).await?;
+    assert!(!result);
+    let value: String = client1.get("key").await?;
+    assert_eq!("new_value", value);
+
+    let result = client0.copy("key", "key", Some(1), true).await?;
+    assert!(result);
+    let value: String = client1.get("key").await?;
+    assert_eq!("newest_value", value);
+
     Ok(())
 }
 
@@ -70,6 +88,107 @@ async fn del() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn del_existing() -> Result<()> {
+    let client = get_test_client().await?;
+
+    client.set("key1", "value1").await?;
+    client.set("key3", "value3").await?;
+
+    let deleted = client.del_existing(["key1", "key2", "key3"]).await?;
+    assert_eq!(vec!["key1".to_owned(), "key3".to_owned()], deleted);
+
+    let deleted = client.del_existing("key1").await?;
+    assert!(deleted.is_empty());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn key_metadata() -> Result<()> {
+    let client = get_test_client().await?;
+    client.del("key").await?;
+
+    assert_eq!(None, client.key_metadata("key").await?);
+
+    client.set("key", 12).await?;
+    let metadata = client.key_metadata("key").await?.unwrap();
+    assert_eq!(RedisType::String, metadata.key_type);
+    assert_eq!(None, metadata.ttl);
+    assert_eq!(ObjectEncoding::Int, metadata.encoding);
+    assert!(metadata.memory_bytes > 0);
+
+    client.expire("key", 10, ExpireOption::None).await?;
+    let metadata = client.key_metadata("key").await?.unwrap();
+    assert!(metadata
+        .ttl
+        .is_some_and(|ttl| ttl <= Duration::from_secs(10)));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_key_metadata() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    let (metadata, _) = join!(client.key_metadata("key"), async {
+        for (name, reply) in [
+            ("TYPE", "+hash\r\n"),
+            ("PTTL", ":1500\r\n"),
+            ("OBJECT", "$8\r\nlistpack\r\n"),
+            ("MEMORY", ":72\r\n"),
+        ] {
+            assert_eq!(name, server.receive_command().await.name);
+            server.reply(reply);
+        }
+    });
+    assert_eq!(
+        Some(KeyMetadata {
+            key_type: RedisType::Hash,
+            ttl: Some(Duration::from_millis(1500)),
+            encoding: ObjectEncoding::Listpack,
+            memory_bytes: 72,
+        }),
+        metadata?
+    );
+
+    // missing key
+    let (metadata, _) = join!(client.key_metadata("key"), async {
+        for reply in ["+none\r\n", ":-2\r\n", "_\r\n", "_\r\n"] {
+            server.receive_command().await;
+            server.reply(reply);
+        }
+    });
+    assert_eq!(None, metadata?);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_del_existing() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    let (deleted, _) = join!(client.del_existing(["key1", "key2", "key3"]), async {
+        for (key, reply) in [("key1", ":0\r\n"), ("key2", ":1\r\n"), ("key3", ":1\r\n")] {
+            let command = server.receive_command().await;
+            assert_eq!("DEL", command.name);
+            assert_eq!(key.as_bytes(), &command.args[0][..]);
+            server.reply(reply);
+        }
+    });
+    assert_eq!(vec!["key2".to_owned(), "key3".to_owned()], deleted?);
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -79,7 +198,11 @@ async fn dump() -> Result<()> {
     client.set("key", "value").await?;
 
     let dump = client.dump("key").await?;
-    assert!(!dump.0.is_empty());
+    assert!(dump.is_some_and(|dump| !dump.0.is_empty()));
+
+    client.del("key").await?;
+    let dump = client.dump("key").await?;
+    assert!(dump.is_none());
 
     Ok(())
 }
@@ -216,7 +339,15 @@ async fn expiretime() -> Result<()> {
             .await?
     );
     let time = client.expiretime("key").await?;
-    assert_eq!(time, 33177117420);
+    assert_eq!(ExpireTime::Timestamp(33177117420), time);
+
+    client.persist("key").await?;
+    let time = client.expiretime("key").await?;
+    assert_eq!(ExpireTime::NoExpiry, time);
+
+    client.del("key").await?;
+    let time = client.expiretime("key").await?;
+    assert_eq!(ExpireTime::KeyNotFound, time);
 
     Ok(())
 }
@@ -257,7 +388,7 @@ async fn keys() -> Result<()> {
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
-async fn move_() -> Result<()> {
+async fn move_key() -> Result<()> {
     let client0 = get_test_client().await?;
     let client1 = get_test_client().await?;
     client1.select(1).await?;
@@ -267,10 +398,45 @@ async fn move_() -> Result<()> {
     client1.del("key").await?;
 
     client0.set("key", "value").await?;
-    client0.move_("key", 1).await?;
+    assert!(client0.move_key("key", 1).await?);
     assert_eq!(0, client0.exists("key").await?);
     assert_eq!(1, client1.exists("key").await?);
 
+    // the key does not exist anymore in the source database
+    assert!(!client0.move_key("key", 1).await?);
+
+    // the key already exists in the destination database
+    client0.set("key", "value0").await?;
+    assert!(!client0.move_key("key", 1).await?);
+    let value: String = client0.get("key").await?;
+    assert_eq!("value0", value);
+    let value: String = client1.get("key").await?;
+    assert_eq!("value", value);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_move_key() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    let (moved, _) = join!(async { client.move_key("key", 1).await }, async {
+        let command = server.receive_command().await;
+        assert_eq!("MOVE", command.name);
+        assert_eq!(&b"1"[..], &command.args[1][..]);
+        server.reply(":1\r\n");
+    });
+    assert!(moved?);
+
+    // the key already exists in the destination database
+    let (moved, _) = join!(async { client.move_key("key", 1).await }, async {
+        server.receive_command().await;
+        server.reply(":0\r\n");
+    });
+    assert!(!moved?);
+
     Ok(())
 }
 
@@ -280,7 +446,7 @@ async fn move_() -> Result<()> {
 async fn object_encoding() -> Result<()> {
     let client = get_test_client().await?;
 
-    client.del(["key1", "key2", "unknown"]).await?;
+    client.del(["key1", "key2", "list", "unknown"]).await?;
     client.set("key1", "value").await?;
     client.set("key2", "12").await?;
 
@@ -293,6 +459,19 @@ async fn object_encoding() -> Result<()> {
     let encoding: String = client.object_encoding("unknown").await?;
     assert_eq!("", encoding);
 
+    let encoding: ObjectEncoding = client.object_encoding("key1").await?;
+    assert_eq!(ObjectEncoding::EmbStr, encoding);
+
+    client.rpush("list", ["a", "b", "c"]).await?;
+    let encoding: ObjectEncoding = client.object_encoding("list").await?;
+    assert!(matches!(
+        encoding,
+        ObjectEncoding::Listpack | ObjectEncoding::Quicklist | ObjectEncoding::Ziplist
+    ));
+
+    let encoding: Option<ObjectEncoding> = client.object_encoding("unknown").await?;
+    assert_eq!(None, encoding);
+
     Ok(())
 }
 
@@ -476,7 +655,15 @@ async fn pexpiretime() -> Result<()> {
             .await?
     );
     let time = client.pexpiretime("key").await?;
-    assert_eq!(time, 33177117420000);
+    assert_eq!(ExpireTime::Timestamp(33177117420000), time);
+
+    client.persist("key").await?;
+    let time = client.pexpiretime("key").await?;
+    assert_eq!(ExpireTime::NoExpiry, time);
+
+    client.del("key").await?;
+    let time = client.pexpiretime("key").await?;
+    assert_eq!(ExpireTime::KeyNotFound, time);
 
     Ok(())
 }
@@ -488,6 +675,9 @@ async fn randomkey() -> Result<()> {
     let client = get_test_client().await?;
 
     client.flushdb(FlushingMode::Sync).await?;
+    let key: Option<String> = client.randomkey().await?;
+    assert_eq!(None, key);
+
     client.set("key1", "value1").await?;
     client.set("key2", "value2").await?;
     client.set("key3", "value3").await?;
@@ -546,13 +736,51 @@ async fn restore() -> Result<()> {
 
     client.set("key", "value").await?;
 
-    let dump = client.dump("key").await?;
+    let dump = client.dump("key").await?.unwrap();
     client.del("key").await?;
     client
-        .restore("key", 0, dump.0, RestoreOptions::default())
+        .restore("key", 0, dump.0.clone(), RestoreOptions::default())
+        .await?;
+    let value: String = client.get("key").await?;
+    assert_eq!("value", value);
+    assert_eq!(-1, client.pttl("key").await?);
+
+    // key already exists
+    let result = client
+        .restore("key", 0, dump.0.clone(), RestoreOptions::default())
+        .await;
+    assert!(result.is_err());
+
+    // relative ttl & idle time
+    client
+        .restore(
+            "key",
+            10_000,
+            dump.0.clone(),
+            RestoreOptions::default().replace().idle_time(1000),
+        )
         .await?;
     let value: String = client.get("key").await?;
     assert_eq!("value", value);
+    let pttl = client.pttl("key").await?;
+    assert!(0 < pttl && pttl <= 10_000);
+
+    // absolute ttl
+    let expire_at = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64
+        + 10_000;
+    client
+        .restore(
+            "key",
+            expire_at,
+            dump.0,
+            RestoreOptions::default().replace().abs_ttl(),
+        )
+        .await?;
+    let pttl = client.pttl("key").await?;
+    assert!(0 < pttl && pttl <= 10_000);
 
     Ok(())
 }
@@ -578,6 +806,46 @@ async fn scan() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn scan_type() -> Result<()> {
+    let client = get_test_client().await?;
+
+    client.flushdb(FlushingMode::Sync).await?;
+
+    client.set("key1", "value").await?;
+    client.rpush("key2", "value").await?;
+    let _id: String = client
+        .xadd("key3", "*", ("field", "value"), Default::default())
+        .await?;
+    let _id: String = client
+        .xadd("other", "*", ("field", "value"), Default::default())
+        .await?;
+
+    let keys: (u64, HashSet<String>) = client
+        .scan(0, ScanOptions::default().type_(RedisType::String))
+        .await?;
+    assert_eq!(0, keys.0);
+    assert_eq!(1, keys.1.len());
+    assert!(keys.1.contains("key1"));
+
+    let keys: (u64, HashSet<String>) = client
+        .scan(
+            0,
+            ScanOptions::default()
+                .match_pattern("key*")
+                .count(100)
+                .type_(RedisType::Stream),
+        )
+        .await?;
+    assert_eq!(0, keys.0);
+    assert_eq!(1, keys.1.len());
+    assert!(keys.1.contains("key3"));
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -610,6 +878,48 @@ async fn sort() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn sort_by_get() -> Result<()> {
+    let client = get_test_client().await?;
+
+    client.flushdb(FlushingMode::Sync).await?;
+
+    client.rpush("ids", [1, 2, 3]).await?;
+    client
+        .mset([("weight_1", "30"), ("weight_2", "10"), ("weight_3", "20")])
+        .await?;
+    client.hset("object_1", ("name", "one")).await?;
+    client.hset("object_2", ("name", "two")).await?;
+    client.hset("object_3", ("name", "three")).await?;
+
+    let values: Vec<String> = client
+        .sort_readonly(
+            "ids",
+            SortOptions::default()
+                .by("weight_*")
+                .get("#")
+                .get("object_*->name")
+                .limit(0, 2),
+        )
+        .await?;
+    assert_eq!(vec!["2", "two", "3", "three"], values);
+
+    let values: Vec<String> = client
+        .sort(
+            "ids",
+            SortOptions::default()
+                .by("weight_*")
+                .order(SortOrder::Desc)
+                .get("object_*->name"),
+        )
+        .await?;
+    assert_eq!(vec!["one", "three", "two"], values);
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -622,6 +932,11 @@ async fn touch() -> Result<()> {
     let num_keys = client.touch(["key1", "key2"]).await?;
     assert_eq!(2, num_keys);
 
+    // missing keys are ignored
+    client.del("unknown").await?;
+    let num_keys = client.touch(["key1", "unknown"]).await?;
+    assert_eq!(1, num_keys);
+
     Ok(())
 }
 
@@ -650,6 +965,35 @@ async fn type_() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn key_type() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    client.set("key1", "value").await?;
+    client.lpush("key2", "value").await?;
+    client.sadd("key3", "value").await?;
+    client
+        .zadd("key4", (1.0, "value"), Default::default())
+        .await?;
+    client.hset("key5", ("field", "value")).await?;
+    let _id: String = client
+        .xadd("key6", "*", ("field", "value"), Default::default())
+        .await?;
+
+    assert_eq!(RedisType::String, client.key_type("key1").await?);
+    assert_eq!(RedisType::List, client.key_type("key2").await?);
+    assert_eq!(RedisType::Set, client.key_type("key3").await?);
+    assert_eq!(RedisType::ZSet, client.key_type("key4").await?);
+    assert_eq!(RedisType::Hash, client.key_type("key5").await?);
+    assert_eq!(RedisType::Stream, client.key_type("key6").await?);
+    assert_eq!(RedisType::None, client.key_type("unknown").await?);
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -3,6 +3,7 @@ use crate::{
         GenericCommands, GeoAddCondition, GeoCommands, GeoSearchBy, GeoSearchFrom,
         GeoSearchOptions, GeoSearchOrder, GeoSearchResult, GeoSearchStoreOptions, GeoUnit,
     },
+    resp::RespBuf,
     tests::get_test_client,
     Result,
 };
@@ -391,3 +392,40 @@ async fn geosearchstore() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn geosearch_result() -> Result<()> {
+    // no WITH* option
+    let results: Vec<GeoSearchResult<String>> =
+        RespBuf::from_slice(b"*2\r\n$7\r\nPalermo\r\n$7\r\nCatania\r\n").to()?;
+    assert_eq!(2, results.len());
+    assert_eq!("Palermo", results[0].member);
+    assert_eq!(None, results[0].distance);
+    assert_eq!(None, results[0].geo_hash);
+    assert_eq!(None, results[0].coordinates);
+
+    // WITHCOORD WITHDIST WITHHASH
+    let results: Vec<GeoSearchResult<String>> = RespBuf::from_slice(
+        b"*1\r\n*4\r\n$7\r\nCatania\r\n$7\r\n56.4413\r\n:3479447370796909\r\n*2\r\n$20\r\n15.08726745843887329\r\n$20\r\n37.50266842333162032\r\n",
+    )
+    .to()?;
+    assert_eq!(1, results.len());
+    assert_eq!("Catania", results[0].member);
+    assert_eq!(Some(56.4413), results[0].distance);
+    assert_eq!(Some(3479447370796909), results[0].geo_hash);
+    assert_eq!(
+        Some((15.087267458438873, 37.50266842333162)),
+        results[0].coordinates
+    );
+
+    // RESP3 WITHDIST WITHCOORD with doubles
+    let results: Vec<GeoSearchResult<String>> = RespBuf::from_slice(
+        b"*1\r\n*3\r\n$7\r\nCatania\r\n,56.4413\r\n*2\r\n,15.087267\r\n,37.502668\r\n",
+    )
+    .to()?;
+    assert_eq!(Some(56.4413), results[0].distance);
+    assert_eq!(None, results[0].geo_hash);
+    assert_eq!(Some((15.087267, 37.502668)), results[0].coordinates);
+
+    Ok(())
+}
@@ -1,10 +1,16 @@
 use std::collections::HashMap;
 
 use crate::{
-    commands::{GenericCommands, HScanOptions, HScanResult, HashCommands},
-    tests::get_test_client,
+    client::{Client, Config, ServerFeature},
+    commands::{
+        ExpireOption, GenericCommands, HExpireResult, HScanOptions, HScanResult, HashCommands,
+    },
+    resp::cmd,
+    tests::{get_test_client, mock_transport},
     Result,
 };
+use futures_util::join;
+use serde::Deserialize;
 use serial_test::serial;
 
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
@@ -51,6 +57,112 @@ async fn hexists() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn hexpire() -> Result<()> {
+    let client = get_test_client().await?;
+    if !client.supports(ServerFeature::HashFieldExpiration) {
+        return Ok(());
+    }
+
+    // cleanup
+    client.del("key").await?;
+
+    client
+        .hset("key", [("field1", "value1"), ("field2", "value2")])
+        .await?;
+
+    let results = client
+        .hexpire("key", 100, ExpireOption::None, ["field1", "unknown"])
+        .await?;
+    assert_eq!(
+        vec![HExpireResult::Set, HExpireResult::NoSuchField],
+        results
+    );
+
+    let results = client
+        .hexpire("key", 200, ExpireOption::Nx, "field1")
+        .await?;
+    assert_eq!(vec![HExpireResult::ConditionNotMet], results);
+
+    let ttls = client.httl("key", ["field1", "field2", "unknown"]).await?;
+    assert!(ttls[0] > 0 && ttls[0] <= 100);
+    assert_eq!(-1, ttls[1]);
+    assert_eq!(-2, ttls[2]);
+
+    let ttls = client.hpttl("key", "field1").await?;
+    assert!(ttls[0] > 0 && ttls[0] <= 100_000);
+
+    let results = client.hpersist("key", ["field1", "field2"]).await?;
+    assert_eq!(vec![1, -1], results);
+
+    let results = client
+        .hpexpire("key", 0, ExpireOption::None, "field2")
+        .await?;
+    assert_eq!(vec![HExpireResult::Deleted], results);
+    assert!(!client.hexists("key", "field2").await?);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_hexpire() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    let (results, _) = join!(
+        async {
+            client
+                .hexpire("key", 10, ExpireOption::Gt, ["f1", "f2", "f3", "f4"])
+                .await
+        },
+        async {
+            let command = server.receive_command().await;
+            assert_eq!("HEXPIRE", command.name);
+            assert_eq!(
+                vec![
+                    b"key".to_vec(),
+                    b"10".to_vec(),
+                    b"GT".to_vec(),
+                    b"FIELDS".to_vec(),
+                    b"4".to_vec(),
+                    b"f1".to_vec(),
+                    b"f2".to_vec(),
+                    b"f3".to_vec(),
+                    b"f4".to_vec()
+                ],
+                (&command.args)
+                    .into_iter()
+                    .map(|a| a.to_vec())
+                    .collect::<Vec<_>>()
+            );
+            server.reply("*4\r\n:-2\r\n:0\r\n:1\r\n:2\r\n");
+        }
+    );
+    assert_eq!(
+        vec![
+            HExpireResult::NoSuchField,
+            HExpireResult::ConditionNotMet,
+            HExpireResult::Set,
+            HExpireResult::Deleted
+        ],
+        results?
+    );
+
+    let (results, _) = join!(
+        async { client.hexpire("key", 10, ExpireOption::None, "f1").await },
+        async {
+            server.receive_command().await;
+            server.reply("*1\r\n:3\r\n");
+        }
+    );
+    assert!(results.is_err());
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -222,6 +334,14 @@ async fn hrandfield() -> Result<()> {
             .any(|v| v.0 == value.0 && v.1 == value.1));
     }
 
+    let values: Vec<(String, String)> = client.hrandfields_with_values("coin", -5).await?;
+    assert_eq!(5, values.len());
+    for value in values {
+        assert!(fields_and_values
+            .iter()
+            .any(|v| v.0 == value.0 && v.1 == value.1));
+    }
+
     Ok(())
 }
 
@@ -312,3 +432,163 @@ async fn hvals() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(feature = "indexmap")]
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn hgetall_indexmap() -> Result<()> {
+    let client = get_test_client().await?;
+
+    // cleanup
+    client.del("key").await?;
+
+    let fields = (0..100)
+        .map(|i| (format!("field{}", 99 - i), format!("value{i}")))
+        .collect::<Vec<_>>();
+    client.hset("key", fields.clone()).await?;
+
+    let result: indexmap::IndexMap<String, String> = client.hgetall("key").await?;
+    assert_eq!(fields, result.into_iter().collect::<Vec<_>>());
+
+    Ok(())
+}
+
+#[cfg(feature = "derive")]
+#[derive(Debug, Default, PartialEq, crate::ToRedisHash, crate::FromRedisHash)]
+struct User {
+    name: String,
+    #[rustis(rename = "years")]
+    age: u32,
+    email: Option<String>,
+    #[rustis(skip)]
+    session: String,
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn redis_hash_derive() -> Result<()> {
+    use crate::resp::{CommandArgs, RespBuf, ToArgs};
+
+    let user = User {
+        name: "john".to_owned(),
+        age: 42,
+        email: None,
+        session: "abc".to_owned(),
+    };
+    assert_eq!(4, user.num_args());
+    let args = CommandArgs::default().arg(&user).build();
+    let args: Vec<&[u8]> = args.into_iter().collect();
+    assert_eq!(vec![&b"name"[..], b"john", b"years", b"42"], args);
+
+    let resp_buf = RespBuf::from_slice(
+        b"*8\r\n$4\r\nname\r\n$4\r\njohn\r\n$5\r\nyears\r\n$2\r\n42\r\n$5\r\nemail\r\n$13\r\njohn@mail.com\r\n$7\r\nunknown\r\n$1\r\n0\r\n",
+    );
+    let user: User = resp_buf.to()?;
+    assert_eq!(
+        User {
+            name: "john".to_owned(),
+            age: 42,
+            email: Some("john@mail.com".to_owned()),
+            session: String::new(),
+        },
+        user
+    );
+
+    let resp_buf = RespBuf::from_slice(b"*2\r\n$4\r\nname\r\n$4\r\njohn\r\n");
+    assert!(resp_buf.to::<User>().is_err());
+
+    Ok(())
+}
+
+#[cfg(feature = "derive")]
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn hgetall_derive() -> Result<()> {
+    let client = get_test_client().await?;
+
+    // cleanup
+    client.del("key").await?;
+
+    let user = User {
+        name: "john".to_owned(),
+        age: 42,
+        email: Some("john@mail.com".to_owned()),
+        session: String::new(),
+    };
+    let len = client.hset("key", &user).await?;
+    assert_eq!(3, len);
+
+    let result: User = client.hgetall("key").await?;
+    assert_eq!(user, result);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn hgetall_struct() -> Result<()> {
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Person {
+        name: String,
+        age: u32,
+    }
+
+    let client = get_test_client().await?;
+    client.del("key").await?;
+
+    client
+        .hset("key", [("name", "John"), ("age", "42")])
+        .await?;
+
+    let person: Person = client.send(cmd("HGETALL").arg("key"), None).await?.to()?;
+    assert_eq!(
+        Person {
+            name: "John".to_owned(),
+            age: 42
+        },
+        person
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn hrandfields_with_values_resp3() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    // RESP3 replies an array of [field, value] pairs
+    let (values, _) = join!(
+        async { client.hrandfields_with_values("coin", -2).await },
+        async {
+            let command = server.receive_command().await;
+            assert_eq!("HRANDFIELD", command.name);
+            assert_eq!(
+                vec![b"coin".to_vec(), b"-2".to_vec(), b"WITHVALUES".to_vec()],
+                (&command.args)
+                    .into_iter()
+                    .map(|a| a.to_vec())
+                    .collect::<Vec<_>>()
+            );
+            server.reply(concat!(
+                "*2\r\n",
+                "*2\r\n$5\r\nheads\r\n$7\r\nobverse\r\n",
+                "*2\r\n$5\r\nheads\r\n$7\r\nobverse\r\n"
+            ));
+        }
+    );
+    let values: Vec<(String, String)> = values?;
+    assert_eq!(
+        vec![
+            ("heads".to_owned(), "obverse".to_owned()),
+            ("heads".to_owned(), "obverse".to_owned())
+        ],
+        values
+    );
+
+    Ok(())
+}
@@ -0,0 +1,145 @@
+use crate::{
+    client::{BatchPreparedCommand, Client, ClientPreparedCommand, Config},
+    commands::{GenericCommands, StringCommands},
+    resp::{Command, CommandArgs},
+    tests::mock_transport,
+    Error, Result,
+};
+use futures_util::join;
+use std::{
+    future::IntoFuture,
+    sync::{Arc, Mutex},
+};
+
+fn prefix_key(mut command: Command) -> Command {
+    let mut args = CommandArgs::default();
+    for (i, arg) in (&command.args).into_iter().enumerate() {
+        if i == 0 {
+            let mut key = b"tenant:".to_vec();
+            key.extend_from_slice(arg);
+            args.arg(key);
+        } else {
+            args.arg(arg);
+        }
+    }
+    command.args = args;
+    command
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn observe_and_rewrite() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let observed = Arc::new(Mutex::new(Vec::<String>::new()));
+    let observed_clone = observed.clone();
+
+    let client = Client::connect_with_transport(transport, &Config::default())
+        .with_async_interceptor(|command| async move { Ok(prefix_key(command)) })
+        .with_interceptor(move |command| {
+            let key = (&command.args).into_iter().next().unwrap_or_default();
+            observed_clone.lock().unwrap().push(format!(
+                "{} {}",
+                command.name,
+                String::from_utf8_lossy(key)
+            ));
+        });
+
+    // forgotten commands keep their order
+    client.set("key", "value").forget()?;
+    let (value, _) = join!(client.get::<_, String>("key").into_future(), async {
+        let command = server.receive_command().await;
+        assert_eq!("SET", command.name);
+        assert_eq!(b"tenant:key".to_vec(), command.args[0]);
+        server.reply("+OK\r\n");
+
+        let command = server.receive_command().await;
+        assert_eq!("GET", command.name);
+        assert_eq!(b"tenant:key".to_vec(), command.args[0]);
+        server.reply("$5\r\nvalue\r\n");
+    });
+    assert_eq!("value", value?);
+
+    // the most recently added interceptor sees the command first
+    assert_eq!(
+        vec!["SET key".to_owned(), "GET key".to_owned()],
+        *observed.lock().unwrap()
+    );
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn rewrite_error() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default())
+        .with_async_interceptor(|command| async move {
+            if command.name == "DEL" {
+                Err(Error::Client("DEL is not allowed".to_owned()))
+            } else {
+                Ok(command)
+            }
+        });
+
+    let result = client.del("key").await;
+    assert!(matches!(result, Err(Error::Client(e)) if e == "DEL is not allowed"));
+
+    // the command has not been sent
+    let (value, _) = join!(client.get::<_, String>("key").into_future(), async {
+        let command = server.receive_command().await;
+        assert_eq!("GET", command.name);
+        server.reply("$5\r\nvalue\r\n");
+    });
+    assert_eq!("value", value?);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn reply_interceptor() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let replies = Arc::new(Mutex::new(Vec::<(String, String)>::new()));
+    let replies_clone = replies.clone();
+
+    let client = Client::connect_with_transport(transport, &Config::default())
+        .with_reply_interceptor(move |command, result| {
+            let reply = match result {
+                Ok(resp_buf) => resp_buf.to::<String>().unwrap(),
+                Err(e) => e.to_string(),
+            };
+            replies_clone
+                .lock()
+                .unwrap()
+                .push((command.name.to_owned(), reply));
+        });
+
+    let (value, _) = join!(client.get::<_, String>("key").into_future(), async {
+        server.receive_command().await;
+        server.reply("$5\r\nvalue\r\n");
+    });
+    assert_eq!("value", value?);
+
+    // batch replies are observed command by command
+    let mut pipeline = client.create_pipeline();
+    pipeline.get::<_, String>("key1").queue();
+    pipeline.get::<_, String>("key2").queue();
+    let (values, _) = join!(pipeline.execute::<(String, String)>(), async {
+        server.receive_command().await;
+        server.receive_command().await;
+        server.reply("$6\r\nvalue1\r\n");
+        server.reply("$6\r\nvalue2\r\n");
+    });
+    assert_eq!(("value1".to_owned(), "value2".to_owned()), values?);
+
+    assert_eq!(
+        vec![
+            ("GET".to_owned(), "value".to_owned()),
+            ("GET".to_owned(), "value1".to_owned()),
+            ("GET".to_owned(), "value2".to_owned()),
+        ],
+        *replies.lock().unwrap()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,384 @@
+use crate::{
+    client::{
+        prefix_channel, prefix_command, unprefix_message, Client, Config, PubSubEvent,
+        PubSubMessage,
+    },
+    commands::{
+        CommandInfo, ExpireOption, GenericCommands, HScanOptions, HashCommands, MigrateOptions,
+        PubSubCommands, RestoreOptions, SortOptions, StringCommands,
+    },
+    network::{cache_command_infos, CommandInfoCache},
+    resp::{cmd, Command, RespBuf},
+    tests::mock_transport,
+    Error, Result,
+};
+use futures_util::{join, StreamExt};
+use std::future::IntoFuture;
+
+fn prefixed(command: Command) -> Result<Vec<String>> {
+    prefixed_with_cache(command, &CommandInfoCache::default())
+}
+
+fn prefixed_with_cache(
+    command: Command,
+    command_info_cache: &CommandInfoCache,
+) -> Result<Vec<String>> {
+    let mut command = command;
+    prefix_command(b"t:", &mut command, command_info_cache)?;
+    Ok((&command.args)
+        .into_iter()
+        .map(|arg| String::from_utf8_lossy(arg).into_owned())
+        .collect())
+}
+
+#[test]
+fn key_specs() -> Result<()> {
+    assert_eq!(
+        vec!["t:key", "value"],
+        prefixed(cmd("SET").arg("key").arg("value"))?
+    );
+    assert_eq!(
+        vec!["t:key1", "value1", "t:key2", "value2"],
+        prefixed(
+            cmd("MSET")
+                .arg("key1")
+                .arg("value1")
+                .arg("key2")
+                .arg("value2")
+        )?
+    );
+    assert_eq!(
+        vec!["t:key1", "t:key2"],
+        prefixed(cmd("MGET").arg("key1").arg("key2"))?
+    );
+    assert_eq!(
+        vec!["t:key", "NX", "1", "member"],
+        prefixed(cmd("ZADD").arg("key").arg("NX").arg(1).arg("member"))?
+    );
+    assert_eq!(
+        vec!["t:src", "t:dst"],
+        prefixed(cmd("RENAME").arg("src").arg("dst"))?
+    );
+    assert_eq!(
+        vec!["t:key1", "t:key2", "0"],
+        prefixed(cmd("BLPOP").arg("key1").arg("key2").arg(0))?
+    );
+    assert_eq!(
+        vec!["t:dst", "2", "t:key1", "t:key2", "WEIGHTS", "1", "2"],
+        prefixed(
+            cmd("ZUNIONSTORE")
+                .arg("dst")
+                .arg(2)
+                .arg("key1")
+                .arg("key2")
+                .arg("WEIGHTS")
+                .arg(1)
+                .arg(2)
+        )?
+    );
+    assert_eq!(
+        vec!["script", "1", "t:key", "arg"],
+        prefixed(cmd("EVAL").arg("script").arg(1).arg("key").arg("arg"))?
+    );
+    assert_eq!(
+        vec!["COUNT", "1", "STREAMS", "t:key1", "t:key2", "0", "0"],
+        prefixed(
+            cmd("XREAD")
+                .arg("COUNT")
+                .arg(1)
+                .arg("STREAMS")
+                .arg("key1")
+                .arg("key2")
+                .arg(0)
+                .arg(0)
+        )?
+    );
+    assert_eq!(
+        vec!["ENCODING", "t:key"],
+        prefixed(cmd("OBJECT").arg("ENCODING").arg("key"))?
+    );
+    assert_eq!(vec!["HELP"], prefixed(cmd("OBJECT").arg("HELP"))?);
+
+    assert_eq!(
+        vec!["t:key", "BY", "weight_*", "STORE", "t:dst"],
+        prefixed(
+            cmd("SORT")
+                .arg("key")
+                .arg("BY")
+                .arg("weight_*")
+                .arg("STORE")
+                .arg("dst")
+        )?
+    );
+    assert_eq!(vec!["t:STORE"], prefixed(cmd("SORT").arg("STORE"))?);
+
+    // not key-prefixable
+    assert_eq!(vec!["*"], prefixed(cmd("KEYS").arg("*"))?);
+    assert_eq!(vec!["0"], prefixed(cmd("SCAN").arg(0))?);
+    assert_eq!(Vec::<String>::new(), prefixed(cmd("PING"))?);
+
+    Ok(())
+}
+
+#[test]
+fn command_info_key_specs() -> Result<()> {
+    // module command missing from the built-in table: MYMOD.MSET key value [key value ...]
+    let command_info: CommandInfo = RespBuf::from_slice(
+        concat!(
+            "*10\r\n",
+            "$10\r\nmymod.mset\r\n:-3\r\n~1\r\n+write\r\n:1\r\n:-1\r\n:2\r\n",
+            "~0\r\n*0\r\n*0\r\n*0\r\n"
+        )
+        .as_bytes(),
+    )
+    .to()?;
+    let command = || {
+        cmd("MYMOD.MSET")
+            .arg("key1")
+            .arg("value1")
+            .arg("key2")
+            .arg("value2")
+    };
+
+    // unknown key positions
+    assert!(matches!(prefixed(command()), Err(Error::Client(_))));
+
+    let command_info_cache = CommandInfoCache::default();
+    cache_command_infos(&command_info_cache, [command_info]);
+    assert_eq!(
+        vec!["t:key1", "value1", "t:key2", "value2"],
+        prefixed_with_cache(command(), &command_info_cache)?
+    );
+
+    Ok(())
+}
+
+/// Keys are named `key*`, `src` or `dst`
+fn assert_keys_prefixed(command: Command) -> Result<()> {
+    let expected = (&command.args)
+        .into_iter()
+        .map(|arg| {
+            let arg = String::from_utf8_lossy(arg);
+            if arg.starts_with("key") || arg == "src" || arg == "dst" {
+                format!("t:{arg}")
+            } else {
+                arg.into_owned()
+            }
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(expected, prefixed(command)?);
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_generic_and_hash_key_commands() -> Result<()> {
+    let (transport, _server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    let commands = [
+        client.copy("src", "dst", None, false).command,
+        client.del(["key1", "key2"]).command,
+        client.dump("key").command,
+        client.exists(["key1", "key2"]).command,
+        client.expire("key", 10, ExpireOption::None).command,
+        client.expireat("key", 10, ExpireOption::None).command,
+        client.expiretime("key").command,
+        client.move_key("key", 1).command,
+        client.object_encoding::<_, String>("key").command,
+        client.object_freq("key").command,
+        client.object_idle_time("key").command,
+        client.object_refcount("key").command,
+        client.persist("key").command,
+        client.pexpire("key", 10, ExpireOption::None).command,
+        client.pexpireat("key", 10, ExpireOption::None).command,
+        client.pexpiretime("key").command,
+        client.pttl("key").command,
+        client.rename("src", "dst").command,
+        client.renamenx("src", "dst").command,
+        client
+            .restore("key", 0, b"value".to_vec(), RestoreOptions::default())
+            .command,
+        client
+            .sort::<_, String, Vec<String>>("key", SortOptions::default())
+            .command,
+        client
+            .sort_and_store("key", "dst", SortOptions::default())
+            .command,
+        client
+            .sort_readonly::<_, String, Vec<String>>("key", SortOptions::default())
+            .command,
+        client.touch(["key1", "key2"]).command,
+        client.ttl("key").command,
+        client.key_type("key").command,
+        client.unlink(["key1", "key2"]).command,
+        client.hdel("key", ["field1", "field2"]).command,
+        client.hexists("key", "field").command,
+        client
+            .hexpire("key", 10, ExpireOption::None, ["field1", "field2"])
+            .command,
+        client
+            .hexpireat("key", 10, ExpireOption::None, ["field1", "field2"])
+            .command,
+        client.hexpiretime("key", ["field1", "field2"]).command,
+        client.hget::<_, _, String>("key", "field").command,
+        client
+            .hgetall::<_, String, String, Vec<(String, String)>>("key")
+            .command,
+        client.hincrby("key", "field", 1).command,
+        client.hincrbyfloat("key", "field", 1.5).command,
+        client.hkeys::<_, String, Vec<String>>("key").command,
+        client.hlen("key").command,
+        client
+            .hmget::<_, _, String, _, Vec<String>>("key", ["field1", "field2"])
+            .command,
+        client.hpersist("key", ["field1", "field2"]).command,
+        client
+            .hpexpire("key", 10, ExpireOption::None, ["field1", "field2"])
+            .command,
+        client
+            .hpexpireat("key", 10, ExpireOption::None, ["field1", "field2"])
+            .command,
+        client.hpexpiretime("key", ["field1", "field2"]).command,
+        client.hpttl("key", ["field1", "field2"]).command,
+        client.hrandfield::<_, String>("key").command,
+        client
+            .hrandfields::<_, String, Vec<String>>("key", 2)
+            .command,
+        client
+            .hrandfields_with_values::<_, String, String, Vec<(String, String)>>("key", 2)
+            .command,
+        client
+            .hscan::<_, String, String>("key", 0, HScanOptions::default())
+            .command,
+        client.hset("key", [("field", "value")]).command,
+        client.hsetnx("key", "field", "value").command,
+        client.hstrlen("key", "field").command,
+        client.httl("key", ["field1", "field2"]).command,
+        client.hvals::<_, String, Vec<String>>("key").command,
+    ];
+
+    for command in commands {
+        assert_keys_prefixed(command)?;
+    }
+
+    // movable keys cannot be located without the server
+    let command = client
+        .migrate("host", 6379, "key", 0, 1000, MigrateOptions::default())
+        .command;
+    assert!(matches!(prefixed(command), Err(Error::Client(_))));
+
+    Ok(())
+}
+
+#[test]
+fn keyspace_notifications() {
+    let mut channel = b"__keyspace@0__:key".to_vec();
+    prefix_channel(b"t:", &mut channel);
+    assert_eq!(b"__keyspace@0__:t:key".to_vec(), channel);
+
+    let mut channel = b"__keyevent@0__:del".to_vec();
+    prefix_channel(b"t:", &mut channel);
+    assert_eq!(b"__keyevent@0__:del".to_vec(), channel);
+
+    let mut message = PubSubMessage {
+        pattern: b"__keyspace@*__:t:*".to_vec(),
+        channel: b"__keyspace@0__:t:key".to_vec(),
+        payload: b"set".to_vec(),
+    };
+    assert!(unprefix_message(b"t:", &mut message));
+    assert_eq!(b"__keyspace@*__:*".to_vec(), message.pattern);
+    assert_eq!(b"__keyspace@0__:key".to_vec(), message.channel);
+    assert_eq!(b"set".to_vec(), message.payload);
+
+    let mut message = PubSubMessage {
+        pattern: Vec::new(),
+        channel: b"__keyevent@0__:set".to_vec(),
+        payload: b"t:key".to_vec(),
+    };
+    assert!(unprefix_message(b"t:", &mut message));
+    assert_eq!(b"key".to_vec(), message.payload);
+
+    // keys of other tenants are skipped
+    let mut message = PubSubMessage {
+        pattern: Vec::new(),
+        channel: b"__keyevent@0__:set".to_vec(),
+        payload: b"other:key".to_vec(),
+    };
+    assert!(!unprefix_message(b"t:", &mut message));
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn prefixed_client() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let config = Config {
+        key_prefix: Some("tenant:".to_owned()),
+        ..Default::default()
+    };
+    let client = Client::connect_with_transport(transport, &config);
+
+    let (result, _) = join!(
+        client
+            .mset([("key1", "value1"), ("key2", "value2")])
+            .into_future(),
+        async {
+            let command = server.receive_command().await;
+            assert_eq!("MSET", command.name);
+            assert_eq!(b"tenant:key1".to_vec(), command.args[0]);
+            assert_eq!(b"value1".to_vec(), command.args[1]);
+            assert_eq!(b"tenant:key2".to_vec(), command.args[2]);
+            assert_eq!(b"value2".to_vec(), command.args[3]);
+            server.reply("+OK\r\n");
+        }
+    );
+    result?;
+
+    let (result, _) = join!(client.del(["key1", "key2"]).into_future(), async {
+        let command = server.receive_command().await;
+        assert_eq!("DEL", command.name);
+        assert_eq!(b"tenant:key1".to_vec(), command.args[0]);
+        assert_eq!(b"tenant:key2".to_vec(), command.args[1]);
+        server.reply(":2\r\n");
+    });
+    assert_eq!(2, result?);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_prefixed_subscription_events() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let config = Config {
+        key_prefix: Some("tenant:".to_owned()),
+        ..Default::default()
+    };
+    let client = Client::connect_with_transport(transport, &config);
+
+    let (pub_sub_stream, _) = join!(
+        async { client.subscribe_with_events("__keyspace@0__:key").await },
+        async {
+            let command = server.receive_command().await;
+            assert_eq!("SUBSCRIBE", command.name);
+            assert_eq!(b"__keyspace@0__:tenant:key".to_vec(), command.args[0]);
+            server.reply(">3\r\n$9\r\nsubscribe\r\n$25\r\n__keyspace@0__:tenant:key\r\n:1\r\n");
+        }
+    );
+    let mut pub_sub_stream = pub_sub_stream?;
+
+    let event = pub_sub_stream.next().await.unwrap()?;
+    assert!(matches!(
+        event,
+        PubSubEvent::Subscribed { ref channel, count: 1 } if channel == b"__keyspace@0__:key"
+    ));
+
+    server.reply(">3\r\n$11\r\nunsubscribe\r\n$25\r\n__keyspace@0__:tenant:key\r\n:0\r\n");
+    let event = pub_sub_stream.next().await.unwrap()?;
+    assert!(matches!(
+        event,
+        PubSubEvent::Unsubscribed { ref channel, count: 0 } if channel == b"__keyspace@0__:key"
+    ));
+
+    Ok(())
+}
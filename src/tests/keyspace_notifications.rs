@@ -0,0 +1,98 @@
+use crate::{
+    client::{KeyspaceEvent, KeyspaceEventFlags, PubSubMessage},
+    commands::{FlushingMode, ServerCommands, StringCommands},
+    tests::{get_test_client, log_try_init},
+    Result,
+};
+use futures_util::StreamExt;
+use serial_test::serial;
+
+#[test]
+fn keyspace_event_flags() {
+    assert_eq!("", KeyspaceEventFlags::default().to_string());
+    assert_eq!(
+        "K$",
+        (KeyspaceEventFlags::KEYSPACE | KeyspaceEventFlags::STRING).to_string()
+    );
+    assert_eq!(
+        "KEg$lshzxet",
+        (KeyspaceEventFlags::KEYSPACE | KeyspaceEventFlags::KEYEVENT | KeyspaceEventFlags::ALL)
+            .to_string()
+    );
+    assert!(KeyspaceEventFlags::ALL.contains(KeyspaceEventFlags::EXPIRED));
+    assert!(!KeyspaceEventFlags::ALL.contains(KeyspaceEventFlags::KEY_MISS));
+}
+
+#[test]
+fn keyspace_event_from_message() -> Result<()> {
+    let event = KeyspaceEvent::from_message(PubSubMessage {
+        pattern: b"__keyspace@0__:*".to_vec(),
+        channel: b"__keyspace@0__:mykey".to_vec(),
+        payload: b"set".to_vec(),
+    })?;
+    assert_eq!(0, event.db);
+    assert_eq!(b"mykey".to_vec(), event.key);
+    assert_eq!("set", event.event);
+
+    let event = KeyspaceEvent::from_message(PubSubMessage {
+        pattern: b"__keyevent@12__:*".to_vec(),
+        channel: b"__keyevent@12__:expired".to_vec(),
+        payload: b"my:key".to_vec(),
+    })?;
+    assert_eq!(12, event.db);
+    assert_eq!(b"my:key".to_vec(), event.key);
+    assert_eq!("expired", event.event);
+
+    let result = KeyspaceEvent::from_message(PubSubMessage {
+        pattern: vec![],
+        channel: b"mychannel".to_vec(),
+        payload: b"set".to_vec(),
+    });
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn keyspace_notifications() -> Result<()> {
+    log_try_init();
+    let pub_sub_client = get_test_client().await?;
+    let regular_client = get_test_client().await?;
+    regular_client.flushdb(FlushingMode::Sync).await?;
+
+    let mut stream = pub_sub_client
+        .keyspace_notifications(
+            0,
+            KeyspaceEventFlags::KEYSPACE
+                | KeyspaceEventFlags::KEYEVENT
+                | KeyspaceEventFlags::STRING,
+        )
+        .await?;
+
+    regular_client.set("key", "value").await?;
+
+    let event = stream.next().await.unwrap()?;
+    assert_eq!(0, event.db);
+    assert_eq!(b"key".to_vec(), event.key);
+    assert_eq!("set", event.event);
+
+    let event = stream.next().await.unwrap()?;
+    assert_eq!(0, event.db);
+    assert_eq!(b"key".to_vec(), event.key);
+    assert_eq!("set", event.event);
+
+    stream.close().await?;
+
+    regular_client
+        .config_set(("notify-keyspace-events", ""))
+        .await?;
+
+    let result = pub_sub_client
+        .keyspace_notifications(0, KeyspaceEventFlags::STRING)
+        .await;
+    assert!(result.is_err());
+
+    Ok(())
+}
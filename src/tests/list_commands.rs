@@ -1,7 +1,7 @@
 use crate::{
     commands::{
         BlockingCommands, FlushingMode, GenericCommands, LInsertWhere, LMoveWhere::Left,
-        LMoveWhere::Right, ListCommands, ServerCommands,
+        LMoveWhere::Right, LPosOptions, ListCommands, ServerCommands,
     },
     resp::Value,
     sleep, spawn,
@@ -285,6 +285,9 @@ async fn lmove() -> Result<()> {
     assert_eq!("element3".to_string(), elements[0]);
     assert_eq!("element1".to_string(), elements[1]);
 
+    let element: Option<String> = client.lmove("unknown", "myotherlist", Right, Left).await?;
+    assert_eq!(None, element);
+
     Ok(())
 }
 
@@ -304,10 +307,23 @@ async fn lmpop() -> Result<()> {
         )
         .await?;
 
-    let result: (String, Vec<String>) = client.lmpop("mylist", Left, 1).await?;
-    assert_eq!("mylist", result.0);
-    assert_eq!(1, result.1.len());
-    assert_eq!("element5".to_string(), result.1[0]);
+    let result: Option<(String, Vec<String>)> = client.lmpop("mylist", Left, 1).await?;
+    let (key, elements) = result.unwrap();
+    assert_eq!("mylist", key);
+    assert_eq!(1, elements.len());
+    assert_eq!("element5".to_string(), elements[0]);
+
+    let result: Option<(String, Vec<String>)> =
+        client.lmpop(["unknown", "mylist"], Right, 2).await?;
+    let (key, elements) = result.unwrap();
+    assert_eq!("mylist", key);
+    assert_eq!(
+        vec!["element1".to_string(), "element2".to_string()],
+        elements
+    );
+
+    let result: Option<(String, Vec<String>)> = client.lmpop("unknown", Left, 1).await?;
+    assert_eq!(None, result);
 
     Ok(())
 }
@@ -353,23 +369,69 @@ async fn lpos() -> Result<()> {
         .rpush("mylist", ["element1", "element2", "element3"])
         .await?;
 
-    let pos = client.lpos("mylist", "element2", Some(1), Some(1)).await?;
+    let pos = client
+        .lpos(
+            "mylist",
+            "element2",
+            LPosOptions::default().rank(1).max_len(1),
+        )
+        .await?;
     assert_eq!(None, pos);
 
-    let pos = client.lpos("mylist", "element2", Some(1), Some(3)).await?;
+    let pos = client
+        .lpos(
+            "mylist",
+            "element2",
+            LPosOptions::default().rank(1).max_len(3),
+        )
+        .await?;
     assert_eq!(Some(1), pos);
 
     let pos: Vec<usize> = client
-        .lpos_with_count("mylist", "element2", 1, Some(1), Some(1))
+        .lpos_count(
+            "mylist",
+            "element2",
+            1,
+            LPosOptions::default().rank(1).max_len(1),
+        )
         .await?;
     assert_eq!(0, pos.len());
 
     let pos: Vec<usize> = client
-        .lpos_with_count("mylist", "element2", 1, Some(1), Some(3))
+        .lpos_count(
+            "mylist",
+            "element2",
+            1,
+            LPosOptions::default().rank(1).max_len(3),
+        )
         .await?;
     assert_eq!(1, pos.len());
     assert_eq!(1, pos[0]);
 
+    client.rpush("mylist", ["element2", "element4"]).await?;
+
+    // negative rank searches from the tail
+    let pos = client
+        .lpos("mylist", "element2", LPosOptions::default().rank(-1))
+        .await?;
+    assert_eq!(Some(3), pos);
+
+    let pos = client
+        .lpos("mylist", "element2", LPosOptions::default().rank(-2))
+        .await?;
+    assert_eq!(Some(1), pos);
+
+    // a count of 0 returns all the matches
+    let pos: Vec<usize> = client
+        .lpos_count("mylist", "element2", 0, LPosOptions::default())
+        .await?;
+    assert_eq!(vec![1, 3], pos);
+
+    let pos: Vec<usize> = client
+        .lpos_count("mylist", "element2", 0, LPosOptions::default().rank(-1))
+        .await?;
+    assert_eq!(vec![3, 1], pos);
+
     Ok(())
 }
 
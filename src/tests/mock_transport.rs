@@ -0,0 +1,132 @@
+use crate::{
+    network::Transport,
+    resp::{Command, RespBuf},
+    Error, Result, RetryReason,
+};
+use futures_channel::mpsc;
+use futures_util::StreamExt;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+enum MockEvent {
+    Reply(RespBuf),
+    Disconnect,
+}
+
+/// In-memory [`Transport`](crate::network::Transport) scripted by a [`MockServer`](MockServer)
+pub(crate) struct MockTransport {
+    commands: mpsc::UnboundedSender<Command>,
+    events: mpsc::UnboundedReceiver<MockEvent>,
+    num_reconnections: Arc<AtomicUsize>,
+    num_refused_reconnections: Arc<AtomicUsize>,
+}
+
+/// Server side of a [`MockTransport`](MockTransport)
+pub(crate) struct MockServer {
+    commands: mpsc::UnboundedReceiver<Command>,
+    events: mpsc::UnboundedSender<MockEvent>,
+    num_reconnections: Arc<AtomicUsize>,
+    num_refused_reconnections: Arc<AtomicUsize>,
+}
+
+pub(crate) fn mock_transport() -> (MockTransport, MockServer) {
+    let (commands_sender, commands_receiver) = mpsc::unbounded();
+    let (events_sender, events_receiver) = mpsc::unbounded();
+    let num_reconnections = Arc::new(AtomicUsize::new(0));
+    let num_refused_reconnections = Arc::new(AtomicUsize::new(0));
+
+    (
+        MockTransport {
+            commands: commands_sender,
+            events: events_receiver,
+            num_reconnections: num_reconnections.clone(),
+            num_refused_reconnections: num_refused_reconnections.clone(),
+        },
+        MockServer {
+            commands: commands_receiver,
+            events: events_sender,
+            num_reconnections,
+            num_refused_reconnections,
+        },
+    )
+}
+
+impl MockServer {
+    /// Waits for the next command written by the client
+    pub async fn receive_command(&mut self) -> Command {
+        self.commands.next().await.expect("mock transport dropped")
+    }
+
+    /// Sends a raw RESP reply to the client
+    pub fn reply(&self, resp: &str) {
+        self.reply_bytes(resp.as_bytes());
+    }
+
+    /// Sends a raw RESP reply to the client, from bytes which may not be valid UTF-8
+    pub fn reply_bytes(&self, resp: &[u8]) {
+        let _ = self
+            .events
+            .unbounded_send(MockEvent::Reply(RespBuf::from_slice(resp)));
+    }
+
+    /// Simulates the connection being closed by the server
+    pub fn disconnect(&self) {
+        let _ = self.events.unbounded_send(MockEvent::Disconnect);
+    }
+
+    pub fn num_reconnections(&self) -> usize {
+        self.num_reconnections.load(Ordering::SeqCst)
+    }
+
+    /// Makes the next `num_reconnections` reconnections of the client fail
+    pub fn refuse_reconnections(&self, num_reconnections: usize) {
+        self.num_refused_reconnections
+            .store(num_reconnections, Ordering::SeqCst);
+    }
+}
+
+impl Transport for MockTransport {
+    async fn write(&mut self, command: &Command) -> Result<()> {
+        let _ = self.commands.unbounded_send(command.clone());
+        Ok(())
+    }
+
+    async fn write_batch<'a>(
+        &mut self,
+        commands: impl Iterator<Item = &'a mut Command> + Send,
+        _retry_reasons: &[RetryReason],
+    ) -> Result<()> {
+        for command in commands {
+            let _ = self.commands.unbounded_send(command.clone());
+        }
+        Ok(())
+    }
+
+    async fn read(&mut self) -> Option<Result<RespBuf>> {
+        match self.events.next().await {
+            Some(MockEvent::Reply(resp_buf)) => Some(Ok(resp_buf)),
+            Some(MockEvent::Disconnect) => None,
+            // the server is gone: nothing will ever be read again
+            None => futures_util::future::pending().await,
+        }
+    }
+
+    async fn reconnect(&mut self) -> Result<()> {
+        let is_refused = self
+            .num_refused_reconnections
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok();
+        if is_refused {
+            return Err(Error::Client("connection refused".to_owned()));
+        }
+
+        self.num_reconnections.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+
+    fn tag(&self) -> &str {
+        "mock"
+    }
+}
@@ -6,13 +6,20 @@ mod client;
 mod cluster;
 mod cluster_commands;
 mod command_args;
+mod command_commands;
 mod command_info_manager;
+#[cfg(feature = "tracing")]
+mod command_span;
+#[cfg(any(feature = "lz4", feature = "zstd"))]
+mod compression;
 mod config;
 mod connection_commands;
 #[cfg(feature = "redis-bloom")]
 mod count_min_sktech_commands;
 #[cfg(feature = "redis-bloom")]
 mod cuckoo_commands;
+#[cfg(feature = "debug-commands")]
+mod debug_commands;
 mod error;
 mod from_value;
 mod generic_commands;
@@ -21,26 +28,38 @@ mod geo_commands;
 mod graph_commands;
 mod hash_commands;
 mod hyper_log_log_commands;
+mod interceptor;
 #[cfg(feature = "redis-json")]
 mod json_commands;
+mod key_prefix;
+mod keyspace_notifications;
 mod list_commands;
+mod mock_transport;
+mod monitor_stream;
 mod multiplexed_client;
+mod network_handler;
 mod pipeline;
 #[cfg(feature = "pool")]
 mod pooled_client_manager;
 mod pub_sub_commands;
 mod resp3;
+#[cfg(feature = "codec")]
+mod resp_codec;
 mod resp_deserializer;
 mod resp_serializer;
 mod scripting_commands;
 #[cfg(feature = "redis-search")]
 mod search_commands;
 mod sentinel;
+#[cfg(feature = "serde-json")]
+mod serde_json_values;
 mod server_commands;
 mod set_commands;
 mod sorted_set_commands;
 mod stream_commands;
 mod string_commands;
+#[cfg(feature = "sync")]
+mod sync_client;
 #[cfg(feature = "redis-bloom")]
 mod t_disgest_commands;
 #[cfg(feature = "redis-time-series")]
@@ -55,4 +74,5 @@ mod value_deserialize;
 mod value_deserializer;
 mod value_serialize;
 
+pub(crate) use mock_transport::*;
 pub(crate) use util::*;
@@ -0,0 +1,92 @@
+use crate::{
+    client::{MonitorFilter, MonitoredCommandInfo},
+    Result,
+};
+use std::net::SocketAddr;
+
+#[test]
+fn parse() -> Result<()> {
+    let info = MonitoredCommandInfo::parse(
+        r#"1339518083.107412 [0 127.0.0.1:60866] "SET" "key" "value""#,
+    )?;
+    assert_eq!(1339518083.107412, info.unix_timestamp_millis);
+    assert_eq!(0, info.database);
+    assert_eq!("127.0.0.1:60866", info.client_addr);
+    assert_eq!("SET", info.command);
+    assert_eq!(
+        vec!["key".to_owned(), "value".to_owned()],
+        info.command_args
+    );
+
+    let info = MonitoredCommandInfo::parse(r#"1339518087.877697 [0 lua] "ping""#)?;
+    assert_eq!("lua", info.client_addr);
+    assert_eq!("ping", info.command);
+    assert!(info.command_args.is_empty());
+
+    let info = MonitoredCommandInfo::parse(
+        r#"1339518083.107412 [2 /tmp/redis.sock] "set" "key" "hello world""#,
+    )?;
+    assert_eq!(2, info.database);
+    assert_eq!("/tmp/redis.sock", info.client_addr);
+    assert_eq!(
+        vec!["key".to_owned(), "hello world".to_owned()],
+        info.command_args
+    );
+
+    Ok(())
+}
+
+#[test]
+#[allow(deprecated)]
+fn parse_server_addr() -> Result<()> {
+    let info = MonitoredCommandInfo::parse(
+        r#"1339518083.107412 [0 127.0.0.1:60866] "SET" "key" "value""#,
+    )?;
+    assert_eq!(SocketAddr::from(([127, 0, 0, 1], 60866)), info.server_addr);
+
+    let info = MonitoredCommandInfo::parse(r#"1339518087.877697 [0 lua] "ping""#)?;
+    assert_eq!(SocketAddr::from(([0, 0, 0, 0], 0)), info.server_addr);
+
+    Ok(())
+}
+
+#[test]
+fn parse_escaped_args() -> Result<()> {
+    let info = MonitoredCommandInfo::parse(
+        r#"1339518083.107412 [0 127.0.0.1:60866] "set" "my key" "say \"hi\" \\ \n\x41""#,
+    )?;
+    assert_eq!("set", info.command);
+    assert_eq!(
+        vec!["my key".to_owned(), "say \"hi\" \\ \nA".to_owned()],
+        info.command_args
+    );
+
+    assert!(MonitoredCommandInfo::parse("OK").is_err());
+    assert!(MonitoredCommandInfo::parse(r#"1339518083.107412 [0 lua] "unterminated"#).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn filter() -> Result<()> {
+    let set: MonitoredCommandInfo =
+        r#"1339518083.107412 [0 127.0.0.1:60866] "set" "key" "value""#.parse()?;
+    let get: MonitoredCommandInfo =
+        r#"1339518083.107412 [0 127.0.0.1:60866] "get" "key""#.parse()?;
+    let del: MonitoredCommandInfo =
+        r#"1339518083.107412 [1 127.0.0.1:60866] "DEL" "key""#.parse()?;
+
+    assert!(MonitorFilter::default().matches(&set));
+
+    let filter = MonitorFilter::default().commands(["SET", "DEL"]);
+    assert!(filter.matches(&set));
+    assert!(!filter.matches(&get));
+    assert!(filter.matches(&del));
+
+    let filter = filter.database(0);
+    assert!(filter.matches(&set));
+    assert!(!filter.matches(&get));
+    assert!(!filter.matches(&del));
+
+    Ok(())
+}
@@ -0,0 +1,299 @@
+use crate::{
+    client::{BackpressurePolicy, Client, Config},
+    commands::{ConnectionCommands, PubSubCommands, StringCommands},
+    resp::cmd,
+    sleep,
+    tests::{mock_transport, MockServer},
+    Error, Result,
+};
+use futures_util::{join, StreamExt};
+use std::{
+    future::IntoFuture,
+    time::{Duration, Instant},
+};
+
+async fn expect_command(server: &mut MockServer, name: &str, args: &[&str]) {
+    let command = server.receive_command().await;
+    assert_eq!(name, command.name);
+    let command_args: Vec<&[u8]> = command.args.into_iter().collect();
+    let expected_args: Vec<&[u8]> = args.iter().map(|a| a.as_bytes()).collect();
+    assert_eq!(expected_args, command_args);
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_send() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    let (value, _) = join!(client.get::<_, String>("key").into_future(), async {
+        expect_command(&mut server, "GET", &["key"]).await;
+        server.reply("$5\r\nvalue\r\n");
+    });
+    assert_eq!("value", value?);
+
+    let (result, _) = join!(client.get::<_, String>("key").into_future(), async {
+        expect_command(&mut server, "GET", &["key"]).await;
+        server.reply("-ERR error\r\n");
+    });
+    assert!(matches!(result, Err(Error::Redis(_))));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_retry_after_disconnection() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let config = Config {
+        retry_on_error: true,
+        ..Default::default()
+    };
+    let client = Client::connect_with_transport(transport, &config);
+
+    let (value, _) = join!(client.get::<_, String>("key").into_future(), async {
+        expect_command(&mut server, "GET", &["key"]).await;
+        server.disconnect();
+        // the command is sent again once reconnected
+        expect_command(&mut server, "GET", &["key"]).await;
+        server.reply("$5\r\nvalue\r\n");
+    });
+    assert_eq!("value", value?);
+    assert_eq!(1, server.num_reconnections());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_no_retry_after_disconnection() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    let (result, _) = join!(client.get::<_, String>("key").into_future(), async {
+        expect_command(&mut server, "GET", &["key"]).await;
+        server.disconnect();
+    });
+    assert!(matches!(result, Err(Error::Client(_))));
+    assert_eq!(1, server.num_reconnections());
+
+    // the connection is usable again
+    let (value, _) = join!(client.get::<_, String>("key").into_future(), async {
+        expect_command(&mut server, "GET", &["key"]).await;
+        server.reply("$5\r\nvalue\r\n");
+    });
+    assert_eq!("value", value?);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_select_after_reconnection() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let config = Config {
+        retry_on_error: true,
+        ..Default::default()
+    };
+    let client = Client::connect_with_transport(transport, &config);
+
+    let (result, _) = join!(client.select(3).into_future(), async {
+        expect_command(&mut server, "SELECT", &["3"]).await;
+        server.reply("+OK\r\n");
+    });
+    result?;
+
+    let (value, _) = join!(client.get::<_, String>("key").into_future(), async {
+        expect_command(&mut server, "GET", &["key"]).await;
+        server.disconnect();
+        // the database is selected again before the command is retried
+        expect_command(&mut server, "SELECT", &["3"]).await;
+        server.reply("+OK\r\n");
+        expect_command(&mut server, "GET", &["key"]).await;
+        server.reply("$5\r\nvalue\r\n");
+    });
+    assert_eq!("value", value?);
+    assert_eq!(1, server.num_reconnections());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_pending_unsubscriptions() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    let (pub_sub_stream, _) = join!(client.subscribe(["a", "b"]), async {
+        expect_command(&mut server, "SUBSCRIBE", &["a", "b"]).await;
+        server.reply(">3\r\n$9\r\nsubscribe\r\n$1\r\na\r\n:1\r\n");
+        server.reply(">3\r\n$9\r\nsubscribe\r\n$1\r\nb\r\n:2\r\n");
+    });
+    let mut pub_sub_stream = pub_sub_stream?;
+
+    server.reply(">3\r\n$7\r\nmessage\r\n$1\r\nb\r\n$5\r\nhello\r\n");
+    let message = pub_sub_stream.next().await.unwrap()?;
+    assert_eq!(b"b".to_vec(), message.channel);
+    assert_eq!(b"hello".to_vec(), message.payload);
+
+    let (result, _) = join!(pub_sub_stream.close(), async {
+        let command = server.receive_command().await;
+        assert_eq!("UNSUBSCRIBE", command.name);
+        // unsubscription notifications may come in any order
+        server.reply(">3\r\n$11\r\nunsubscribe\r\n$1\r\nb\r\n:1\r\n");
+        server.reply(">3\r\n$11\r\nunsubscribe\r\n$1\r\na\r\n:0\r\n");
+    });
+    result?;
+
+    // regular replies are matched again once all the unsubscriptions are acknowledged
+    let (value, _) = join!(client.get::<_, String>("key").into_future(), async {
+        expect_command(&mut server, "GET", &["key"]).await;
+        server.reply("$5\r\nvalue\r\n");
+    });
+    assert_eq!("value", value?);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_reset_subscriptions() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    let (pub_sub_stream, _) = join!(client.subscribe("a"), async {
+        expect_command(&mut server, "SUBSCRIBE", &["a"]).await;
+        server.reply(">3\r\n$9\r\nsubscribe\r\n$1\r\na\r\n:1\r\n");
+    });
+    let mut pub_sub_stream = pub_sub_stream?;
+
+    let (result, _) = join!(client.reset().into_future(), async {
+        expect_command(&mut server, "RESET", &[]).await;
+        server.reply("+RESET\r\n");
+    });
+    result?;
+
+    // regular commands work again on the same connection
+    let (value, _) = join!(client.get::<_, String>("key").into_future(), async {
+        expect_command(&mut server, "GET", &["key"]).await;
+        // late message on the former subscription
+        server.reply(">3\r\n$7\r\nmessage\r\n$1\r\na\r\n$5\r\nhello\r\n");
+        server.reply("$5\r\nvalue\r\n");
+    });
+    assert_eq!("value", value?);
+
+    // the subscription has been forgotten and the stream has ended
+    assert!(pub_sub_stream.next().await.is_none());
+    assert_eq!(0, server.num_reconnections());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_close_sends_queued_commands() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    // the connection stays down after a failed reconnection
+    server.refuse_reconnections(1);
+    server.disconnect();
+    sleep(Duration::from_millis(10)).await;
+
+    // queued but not written while disconnected
+    client.send_and_forget(cmd("SET").arg("key").arg("value"), Some(true))?;
+
+    let (result, _) = join!(client.close(), async {
+        expect_command(&mut server, "SET", &["key", "value"]).await;
+        server.reply("+OK\r\n");
+        expect_command(&mut server, "QUIT", &[]).await;
+        server.reply("+OK\r\n");
+    });
+    result?;
+    assert_eq!(1, server.num_reconnections());
+
+    Ok(())
+}
+
+async fn received_payloads(policy: BackpressurePolicy) -> Result<Vec<Vec<u8>>> {
+    let (transport, mut server) = mock_transport();
+    let config = Config {
+        pub_sub_channel_capacity: Some(2),
+        pub_sub_backpressure_policy: policy,
+        ..Default::default()
+    };
+    let client = Client::connect_with_transport(transport, &config);
+
+    let (pub_sub_stream, _) = join!(client.subscribe("a"), async {
+        expect_command(&mut server, "SUBSCRIBE", &["a"]).await;
+        server.reply(">3\r\n$9\r\nsubscribe\r\n$1\r\na\r\n:1\r\n");
+    });
+    let mut pub_sub_stream = pub_sub_stream?;
+
+    // the reply to GET ensures all the messages have been dispatched before reading them
+    let (value, _) = join!(client.get::<_, String>("key").into_future(), async {
+        expect_command(&mut server, "GET", &["key"]).await;
+        server.reply(">3\r\n$7\r\nmessage\r\n$1\r\na\r\n$1\r\n1\r\n");
+        server.reply(">3\r\n$7\r\nmessage\r\n$1\r\na\r\n$1\r\n2\r\n");
+        server.reply(">3\r\n$7\r\nmessage\r\n$1\r\na\r\n$1\r\n3\r\n");
+        server.reply("$5\r\nvalue\r\n");
+    });
+    assert_eq!("value", value?);
+
+    let mut payloads = Vec::new();
+    for _ in 0..2 {
+        payloads.push(pub_sub_stream.next().await.unwrap()?.payload);
+    }
+
+    Ok(payloads)
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_pub_sub_drop_oldest() -> Result<()> {
+    let payloads = received_payloads(BackpressurePolicy::DropOldest).await?;
+    assert_eq!(vec![b"2".to_vec(), b"3".to_vec()], payloads);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_pub_sub_drop_newest() -> Result<()> {
+    let payloads = received_payloads(BackpressurePolicy::DropNewest).await?;
+    assert_eq!(vec![b"1".to_vec(), b"2".to_vec()], payloads);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_write_coalesce() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let config = Config::builder()
+        .write_coalesce(Duration::from_millis(50))
+        .build()?;
+    let client = Client::connect_with_transport(transport, &config);
+    let clone = client.clone();
+
+    let start = Instant::now();
+    let (value1, value2, _) = join!(
+        client.get::<_, String>("key1").into_future(),
+        async {
+            sleep(Duration::from_millis(10)).await;
+            clone.get::<_, String>("key2").await
+        },
+        async {
+            // the write of the 1st command waits for the 2nd one
+            expect_command(&mut server, "GET", &["key1"]).await;
+            assert!(start.elapsed() >= Duration::from_millis(50));
+            expect_command(&mut server, "GET", &["key2"]).await;
+            server.reply("$6\r\nvalue1\r\n");
+            server.reply("$6\r\nvalue2\r\n");
+        }
+    );
+    assert_eq!("value1", value1?);
+    assert_eq!("value2", value2?);
+
+    Ok(())
+}
@@ -1,10 +1,11 @@
 use crate::{
-    client::BatchPreparedCommand,
-    commands::{FlushingMode, ServerCommands, StringCommands},
+    client::{BatchPreparedCommand, Client, Config, TypedBatchPreparedCommand},
+    commands::{FlushingMode, ListCommands, ServerCommands, StringCommands},
     resp::{cmd, Value},
-    tests::get_test_client,
-    Result,
+    tests::{get_test_client, mock_transport},
+    Error, Result,
 };
+use futures_util::join;
 use serial_test::serial;
 
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
@@ -46,3 +47,129 @@ async fn error() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn wrong_arity() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let mut pipeline = client.create_pipeline();
+    pipeline.set("key1", "value1").forget();
+    pipeline.get::<_, ()>("key1").queue();
+    pipeline.get::<_, ()>("key2").queue();
+    pipeline.get::<_, ()>("key3").queue();
+
+    let result: Result<(Value, Value)> = pipeline.execute().await;
+    assert!(matches!(result, Err(Error::Client(e)) if e == "expected 2 results, queued 3"));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn typed_pipeline() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let (value1, len, value2) = client
+        .create_typed_pipeline()
+        .set("key1", "value1")
+        .forget()
+        .get::<_, String>("key1")
+        .queue()
+        .rpush("list", ["a", "b", "c"])
+        .queue()
+        .get::<_, Option<String>>("key2")
+        .queue()
+        .execute()
+        .await?;
+    assert_eq!("value1", value1);
+    assert_eq!(3, len);
+    assert_eq!(None, value2);
+
+    let () = client
+        .create_typed_pipeline()
+        .set("key1", "value1")
+        .forget()
+        .execute()
+        .await?;
+
+    let result = client
+        .create_typed_pipeline()
+        .set("key1", "value1")
+        .forget()
+        .lpop::<_, String, Vec<String>>("key1", 1)
+        .queue()
+        .execute()
+        .await;
+    assert!(matches!(result, Err(Error::Redis(_))));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_execute_and_forget() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    let mut pipeline = client.create_pipeline();
+    pipeline.set("key1", "value1").forget();
+    pipeline.set("key2", "value2").forget();
+    // only the write of the batch is awaited
+    pipeline.execute_and_forget().await?;
+
+    for key in [b"key1", b"key2"] {
+        let command = server.receive_command().await;
+        assert_eq!("SET", command.name);
+        assert_eq!(Some(&key[..]), (&command.args).into_iter().next());
+    }
+    server.reply("+OK\r\n");
+    server.reply("+OK\r\n");
+
+    // forgotten replies do not shift the replies of the following commands
+    let (value, _) = join!(async { client.get::<_, String>("key1").await }, async {
+        assert_eq!("GET", server.receive_command().await.name);
+        server.reply("$6\r\nvalue1\r\n");
+    });
+    assert_eq!("value1", value?);
+
+    // empty pipeline
+    client.create_pipeline().execute_and_forget().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_execute_empty() -> Result<()> {
+    let (transport, _server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    client.create_pipeline().execute::<()>().await?;
+    let value: Value = client.create_pipeline().execute().await?;
+    assert_eq!(Value::Nil, value);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_execute_single_forget() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    let mut pipeline = client.create_pipeline();
+    pipeline.set("key", "value").forget();
+
+    let (result, _) = join!(pipeline.execute::<()>(), async {
+        assert_eq!("SET", server.receive_command().await.name);
+        server.reply("+OK\r\n");
+    });
+    result?;
+
+    Ok(())
+}
@@ -1,7 +1,12 @@
 use crate::{
-    client::PooledClientManager, commands::StringCommands, tests::get_default_addr, Result,
+    client::{PooledClientEvent, PooledClientManager},
+    commands::{ClientKillOptions, ConnectionCommands, StringCommands},
+    resp::cmd,
+    tests::{get_default_addr, get_test_client},
+    Result,
 };
 use serial_test::serial;
+use std::sync::{Arc, Mutex};
 
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
@@ -17,3 +22,115 @@ async fn pooled_client_manager() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn pooled_client_manager_metrics() -> Result<()> {
+    let events = Arc::new(Mutex::new(Vec::<String>::new()));
+    let events_clone = events.clone();
+
+    let manager = PooledClientManager::new(get_default_addr())?.metrics(move |event| {
+        let event = match event {
+            PooledClientEvent::Connected => "connected".to_owned(),
+            PooledClientEvent::Validated {
+                reconnections,
+                protocol,
+                ..
+            } => {
+                assert_eq!(Some(3), protocol);
+                format!("validated:{reconnections}")
+            }
+            PooledClientEvent::ValidationFailed(_) => "validation_failed".to_owned(),
+            PooledClientEvent::Dropped { .. } => "dropped".to_owned(),
+            PooledClientEvent::ConnectionFailed(_) => "connection_failed".to_owned(),
+        };
+        events_clone.lock().unwrap().push(event);
+    });
+    let pool = crate::bb8::Pool::builder()
+        .max_size(1)
+        .build(manager)
+        .await?;
+
+    let client_id = {
+        let client = pool.get().await.unwrap();
+        client.client_id().await?
+    };
+
+    // simulate a server dropping the idle connection
+    let client = get_test_client().await?;
+    client
+        .client_kill(ClientKillOptions::default().id(client_id))
+        .await?;
+
+    {
+        let client = pool.get().await.unwrap();
+        client.set("key", "value").await?;
+    }
+
+    std::mem::drop(pool);
+
+    // depending on whether the client has detected the disconnection before the checkout,
+    // it has either reconnected, or it fails its validation and is replaced by a new client
+    let events = events.lock().unwrap();
+    assert_eq!("connected", events[0]);
+    assert_eq!("validated:0", events[1]);
+    assert!(events
+        .iter()
+        .any(|e| e == "validated:1" || e == "validation_failed"));
+    assert_eq!(Some(&"dropped".to_owned()), events.last());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn pooled_client_manager_init() -> Result<()> {
+    let manager = PooledClientManager::new(get_default_addr())?
+        .database(1)
+        .init_commands(vec![cmd("CLIENT").arg("SETNAME").arg("pooled")]);
+    let pool = crate::bb8::Pool::builder().build(manager).await?;
+    let client = pool.get().await.unwrap();
+
+    let client_info = client.client_info().await?;
+    assert_eq!(1, client_info.db);
+    assert_eq!("pooled", client_info.name);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn pooled_client_manager_init_failure() -> Result<()> {
+    let manager =
+        PooledClientManager::new(get_default_addr())?.init_commands(vec![cmd("UNKNOWN_COMMAND")]);
+    let pool = crate::bb8::Pool::builder()
+        .connection_timeout(std::time::Duration::from_millis(500))
+        .build_unchecked(manager);
+
+    assert!(pool.get().await.is_err());
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn pooled_client_manager_warmup() -> Result<()> {
+    let manager = PooledClientManager::new(get_default_addr())?;
+    let pool = crate::bb8::Pool::builder()
+        .max_size(5)
+        .min_idle(Some(0))
+        .build(manager)
+        .await?;
+
+    PooledClientManager::warmup(&pool, 5).await?;
+
+    let state = pool.state();
+    assert_eq!(5, state.connections);
+    assert_eq!(5, state.idle_connections);
+
+    Ok(())
+}
@@ -1,15 +1,19 @@
 use std::collections::{HashMap, HashSet};
 
 use crate::{
-    client::{Client, IntoConfig},
+    client::{Client, Config, IntoConfig, PubSubEvent, PubSubMessage},
     commands::{
         ClientKillOptions, ClusterCommands, ClusterShardResult, ConnectionCommands, FlushingMode,
-        PubSubChannelsOptions, PubSubCommands, ServerCommands, StringCommands,
+        InternalPubSubCommands, PubSubChannelsOptions, PubSubCommands, ServerCommands,
+        StringCommands,
+    },
+    resp::BulkString,
+    tests::{
+        get_cluster_test_client, get_default_addr, get_test_client, log_try_init, mock_transport,
     },
-    tests::{get_cluster_test_client, get_default_addr, get_test_client, log_try_init},
     Result,
 };
-use futures_util::{FutureExt, StreamExt, TryStreamExt};
+use futures_util::{join, FutureExt, StreamExt, TryStreamExt};
 use serial_test::serial;
 
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
@@ -214,6 +218,39 @@ async fn pub_sub_channels() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn drop_pub_sub_stream() -> Result<()> {
+    let pub_sub_client = get_test_client().await?;
+    let regular_client = get_test_client().await?;
+
+    let stream = pub_sub_client.subscribe("mychannel").await?;
+    let stream2 = pub_sub_client.psubscribe("mychannel*").await?;
+
+    let channels: HashSet<String> = regular_client.pub_sub_channels(Default::default()).await?;
+    assert_eq!(1, channels.len());
+
+    std::mem::drop(stream);
+    std::mem::drop(stream2);
+
+    // the unsubscriptions are sent before this command on the same connection
+    pub_sub_client.ping::<String>(Default::default()).await?;
+
+    let channels: HashSet<String> = regular_client.pub_sub_channels(Default::default()).await?;
+    assert_eq!(0, channels.len());
+    let num_patterns = regular_client.pub_sub_numpat().await?;
+    assert_eq!(0, num_patterns);
+
+    // the channel can be subscribed again
+    let mut stream = pub_sub_client.subscribe("mychannel").await?;
+    regular_client.publish("mychannel", "mymessage").await?;
+    let message = stream.next().await.unwrap()?;
+    assert_eq!(b"mymessage".to_vec(), message.payload);
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -344,6 +381,48 @@ async fn subscribe_to_multiple_shardchannels() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn subscribe_to_shardchannels_in_multiple_slots() -> Result<()> {
+    let pub_sub_client = get_cluster_test_client().await?;
+    let regular_client = get_cluster_test_client().await?;
+
+    // cleanup
+    regular_client.flushdb(FlushingMode::Sync).await?;
+
+    // {1} & {2} hash tags are owned by different slots
+    let mut pub_sub_stream = pub_sub_client
+        .ssubscribe(["mychannel{1}", "mychannel{2}"])
+        .await?;
+    regular_client
+        .spublish("mychannel{1}", "mymessage1")
+        .await?;
+    regular_client
+        .spublish("mychannel{2}", "mymessage2")
+        .await?;
+
+    let mut messages = HashSet::new();
+    for _ in 0..2 {
+        let message = pub_sub_stream.next().await.unwrap()?;
+        let channel: String = String::from_utf8(message.channel).unwrap();
+        let payload: String = String::from_utf8(message.payload).unwrap();
+        messages.insert((channel, payload));
+    }
+
+    assert!(messages.contains(&("mychannel{1}".to_owned(), "mymessage1".to_owned())));
+    assert!(messages.contains(&("mychannel{2}".to_owned(), "mymessage2".to_owned())));
+
+    pub_sub_stream.close().await?;
+
+    // the pub/sub client is still usable for regular commands
+    pub_sub_client.set("key", "value").await?;
+    let value: String = pub_sub_client.get("key").await?;
+    assert_eq!("value", value);
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -610,3 +689,115 @@ async fn no_auto_resubscribe() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn pub_sub_message_payload() -> Result<()> {
+    let message = PubSubMessage {
+        pattern: vec![],
+        channel: b"mychannel".to_vec(),
+        payload: vec![0x08, 0x96, 0x01, 0xff],
+    };
+
+    assert_eq!(b"", message.pattern_bytes());
+    assert_eq!(b"mychannel", message.channel_bytes());
+    assert_eq!(&[0x08, 0x96, 0x01, 0xff], message.payload_bytes());
+
+    let channel: String = message.get_channel()?;
+    assert_eq!("mychannel", channel);
+    let payload: BulkString = message.get_payload()?;
+    assert_eq!(&[0x08, 0x96, 0x01, 0xff], payload.as_bytes());
+    assert!(message.get_payload::<String>().is_err());
+
+    let message = PubSubMessage {
+        pattern: b"my*".to_vec(),
+        channel: b"mychannel".to_vec(),
+        payload: b"12".to_vec(),
+    };
+    assert_eq!(b"my*", message.pattern_bytes());
+    assert_eq!(12, message.get_payload::<i64>()?);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_subscribe_with_events() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    let (pub_sub_stream, _) = join!(async { client.subscribe_with_events("ch").await }, async {
+        let command = server.receive_command().await;
+        assert_eq!("SUBSCRIBE", command.name);
+        server.reply(">3\r\n$9\r\nsubscribe\r\n$2\r\nch\r\n:1\r\n");
+    });
+    let mut pub_sub_stream = pub_sub_stream?;
+
+    let event = pub_sub_stream.next().await.unwrap()?;
+    assert!(matches!(
+        event,
+        PubSubEvent::Subscribed { ref channel, count: 1 } if channel == b"ch"
+    ));
+
+    server.reply(">3\r\n$7\r\nmessage\r\n$2\r\nch\r\n$5\r\nhello\r\n");
+    let PubSubEvent::Message(message) = pub_sub_stream.next().await.unwrap()? else {
+        panic!("message expected");
+    };
+    assert_eq!(b"ch".to_vec(), message.channel);
+    assert_eq!(b"hello".to_vec(), message.payload);
+
+    // unsubscription triggered by another handle of the same connection
+    let (result, _) = join!(async { client.unsubscribe("ch").await }, async {
+        assert_eq!("UNSUBSCRIBE", server.receive_command().await.name);
+        server.reply(">3\r\n$11\r\nunsubscribe\r\n$2\r\nch\r\n:0\r\n");
+    });
+    result?;
+
+    let event = pub_sub_stream.next().await.unwrap()?;
+    assert!(matches!(
+        event,
+        PubSubEvent::Unsubscribed { ref channel, count: 0 } if channel == b"ch"
+    ));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_subscribe_dedup() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    let (pub_sub_stream, _) = join!(async { client.subscribe(["a", "a"]).await }, async {
+        let command = server.receive_command().await;
+        assert_eq!("SUBSCRIBE", command.name);
+        assert_eq!(1, command.args.len());
+        server.reply(">3\r\n$9\r\nsubscribe\r\n$1\r\na\r\n:1\r\n");
+    });
+    let mut pub_sub_stream = pub_sub_stream?;
+    assert_eq!(vec!["a".to_owned()], pub_sub_stream.subscribed_channels());
+
+    // already subscribed: nothing is sent
+    pub_sub_stream.subscribe("a").await?;
+
+    let (result, _) = join!(pub_sub_stream.subscribe(["a", "b"]), async {
+        let command = server.receive_command().await;
+        assert_eq!("SUBSCRIBE", command.name);
+        assert_eq!(
+            vec![b"b".to_vec()],
+            (&command.args)
+                .into_iter()
+                .map(|a| a.to_vec())
+                .collect::<Vec<_>>()
+        );
+        server.reply(">3\r\n$9\r\nsubscribe\r\n$1\r\nb\r\n:2\r\n");
+    });
+    result?;
+    assert_eq!(
+        vec!["a".to_owned(), "b".to_owned()],
+        pub_sub_stream.subscribed_channels()
+    );
+    assert!(pub_sub_stream.subscribed_patterns().is_empty());
+    assert!(pub_sub_stream.subscribed_shardchannels().is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use crate::{
+    resp::{cmd, RespCodec, Value},
+    RedisErrorKind, Result,
+};
+use bytes::BytesMut;
+use futures_util::{SinkExt, StreamExt};
+use tokio_util::codec::{Decoder, Encoder, Framed};
+
+#[test]
+fn encode() -> Result<()> {
+    let mut codec = RespCodec;
+    let mut buf = BytesMut::new();
+
+    codec.encode(cmd("SET").arg("key").arg("value"), &mut buf)?;
+    codec.encode(&cmd("PING"), &mut buf)?;
+
+    assert_eq!(
+        &b"*3\r\n$3\r\nSET\r\n$3\r\nkey\r\n$5\r\nvalue\r\n*1\r\n$4\r\nPING\r\n"[..],
+        &buf[..]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn decode() -> Result<()> {
+    let mut codec = RespCodec;
+
+    let mut buf = BytesMut::from(&b"*2\r\n:12\r\n$3\r"[..]);
+    assert_eq!(None, codec.decode(&mut buf)?);
+
+    buf.extend_from_slice(b"\nfoo\r\n+OK\r\n");
+    assert_eq!(
+        Some(Value::Array(vec![
+            Value::Integer(12),
+            Value::BulkString(b"foo".to_vec())
+        ])),
+        codec.decode(&mut buf)?
+    );
+    assert_eq!(
+        Some(Value::SimpleString("OK".to_owned())),
+        codec.decode(&mut buf)?
+    );
+    assert_eq!(None, codec.decode(&mut buf)?);
+
+    Ok(())
+}
+
+#[test]
+fn decode_error() -> Result<()> {
+    let mut codec = RespCodec;
+
+    let mut buf = BytesMut::from(&b"-ERR unknown command\r\n:1\r\n"[..]);
+    let value = codec.decode(&mut buf)?;
+    assert!(
+        matches!(value, Some(Value::Error(e)) if e.kind == RedisErrorKind::Err && e.description == "unknown command")
+    );
+    assert_eq!(Some(Value::Integer(1)), codec.decode(&mut buf)?);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn framed() -> Result<()> {
+    let (client, server) = tokio::io::duplex(1024);
+    let mut client = Framed::new(client, RespCodec);
+    let mut server = Framed::new(server, BytesCodec);
+
+    client.send(cmd("GET").arg("key")).await?;
+    let request = server.next().await.unwrap()?;
+    assert_eq!(&b"*2\r\n$3\r\nGET\r\n$3\r\nkey\r\n"[..], &request[..]);
+
+    server.send(BytesMut::from(&b"$5\r\nvalue\r\n"[..])).await?;
+    let reply = client.next().await.unwrap()?;
+    assert_eq!(Value::BulkString(b"value".to_vec()), reply);
+
+    Ok(())
+}
+
+struct BytesCodec;
+
+impl Decoder for BytesCodec {
+    type Item = BytesMut;
+    type Error = crate::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<BytesMut>> {
+        if src.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(src.split()))
+        }
+    }
+}
+
+impl Encoder<BytesMut> for BytesCodec {
+    type Error = crate::Error;
+
+    fn encode(&mut self, item: BytesMut, dst: &mut BytesMut) -> Result<()> {
+        dst.extend_from_slice(&item);
+        Ok(())
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "bytes")]
+use crate::resp::{BulkBytes, RespBatchDeserializer, RespBuf};
 use crate::{
     resp::RespDeserializer, tests::log_try_init, Error, RedisError, RedisErrorKind, Result,
 };
@@ -94,6 +96,12 @@ fn integer() -> Result<()> {
     let result: u64 = deserialize("*1\r\n:12\r\n")?; // [12]
     assert_eq!(12, result);
 
+    let result: i64 = deserialize("(12\r\n")?; // big number 12
+    assert_eq!(12, result);
+
+    let result: i64 = deserialize("|1\r\n+ttl\r\n:3600\r\n:12\r\n")?; // 12 with attribute
+    assert_eq!(12, result);
+
     Ok(())
 }
 
@@ -125,6 +133,9 @@ fn float() -> Result<()> {
     let result: f64 = deserialize("+12.12\r\n")?; // "12.12"
     assert_eq!(12.12, result);
 
+    let result: f64 = deserialize("(12\r\n")?; // big number 12
+    assert_eq!(12.0, result);
+
     Ok(())
 }
 
@@ -200,6 +211,42 @@ fn string() -> Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "bytes")]
+#[test]
+fn bulk_bytes() -> Result<()> {
+    log_try_init();
+
+    let resp_buf = RespBuf::from_slice(b"*2\r\n$5\r\nhello\r\n_\r\n");
+    let result: Vec<BulkBytes> = resp_buf.to()?;
+    assert_eq!(2, result.len());
+    assert_eq!(b"hello", result[0].as_bytes());
+    assert_eq!(b"", result[1].as_bytes());
+
+    // the bytes are not copied from the RESP buffer
+    let hello = &resp_buf.as_bytes()[8..13];
+    assert_eq!(hello.as_ptr(), result[0].as_ptr());
+
+    // the bytes are copied when deserializing outside of a RESP buffer
+    let result: BulkBytes = deserialize("$5\r\nhello\r\n")?;
+    assert_eq!(b"hello", result.as_bytes());
+
+    // nor from the RESP buffers of a pipeline
+    let resp_bufs = vec![
+        RespBuf::from_slice(b"$5\r\nhello\r\n"),
+        RespBuf::from_slice(b"$5\r\nworld\r\n"),
+    ];
+    let (hello, world) =
+        <(BulkBytes, BulkBytes)>::deserialize(&RespBatchDeserializer::new(&resp_bufs))?;
+    assert_eq!(resp_bufs[0].as_bytes()[4..].as_ptr(), hello.as_ptr());
+    assert_eq!(resp_bufs[1].as_bytes()[4..].as_ptr(), world.as_ptr());
+
+    // `Bytes` can also be used as a response
+    let result: bytes::Bytes = resp_bufs[0].to()?;
+    assert_eq!(&b"hello"[..], &result[..]);
+
+    Ok(())
+}
+
 #[test]
 fn option() -> Result<()> {
     log_try_init();
@@ -391,6 +438,38 @@ fn tuple_struct() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn seq_of_pairs() -> Result<()> {
+    log_try_init();
+
+    // RESP3: [["one", 1.5], ["two", 2]]
+    let result: Vec<(String, f64)> =
+        deserialize("*2\r\n*2\r\n$3\r\none\r\n,1.5\r\n*2\r\n$3\r\ntwo\r\n,2\r\n")?;
+    assert_eq!(
+        vec![("one".to_owned(), 1.5), ("two".to_owned(), 2.0)],
+        result
+    );
+
+    // RESP2: ["one", "1.5", "two", "2"]
+    let result: Vec<(String, f64)> =
+        deserialize("*4\r\n$3\r\none\r\n$3\r\n1.5\r\n$3\r\ntwo\r\n$1\r\n2\r\n")?;
+    assert_eq!(
+        vec![("one".to_owned(), 1.5), ("two".to_owned(), 2.0)],
+        result
+    );
+
+    // RESP2: odd number of elements
+    let result: Result<Vec<(String, f64)>> =
+        deserialize("*3\r\n$3\r\none\r\n$3\r\n1.5\r\n$3\r\ntwo\r\n");
+    assert!(result.is_err());
+
+    // empty
+    let result: Vec<(String, f64)> = deserialize("*0\r\n")?;
+    assert!(result.is_empty());
+
+    Ok(())
+}
+
 #[test]
 fn map() -> Result<()> {
     log_try_init();
@@ -419,6 +498,30 @@ fn map() -> Result<()> {
     Ok(())
 }
 
+#[cfg(feature = "indexmap")]
+#[test]
+fn index_map() -> Result<()> {
+    log_try_init();
+
+    let resp = (0..100).fold("*200\r\n".to_owned(), |resp, i| {
+        resp + &format!(":{}\r\n:{i}\r\n", 99 - i)
+    });
+    let result: indexmap::IndexMap<i32, i32> = deserialize(&resp)?;
+    assert_eq!(100, result.len());
+    for (i, (key, value)) in result.into_iter().enumerate() {
+        assert_eq!(99 - i as i32, key);
+        assert_eq!(i as i32, value);
+    }
+
+    let result: indexmap::IndexMap<i32, i32> = deserialize("%2\r\n:14\r\n:15\r\n:12\r\n:13\r\n")?; // { 14: 15, 12: 13 }
+    assert_eq!(
+        vec![(14, 15), (12, 13)],
+        result.into_iter().collect::<Vec<_>>()
+    );
+
+    Ok(())
+}
+
 #[test]
 fn _struct() -> Result<()> {
     log_try_init();
@@ -560,3 +663,158 @@ fn array_chunks() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn batch_tuple_arity() -> Result<()> {
+    use crate::resp::{RespBatchDeserializer, RespBuf};
+
+    let bufs = vec![
+        RespBuf::from_slice(b"+OK\r\n"),
+        RespBuf::from_slice(b":12\r\n"),
+        RespBuf::from_slice(b"$5\r\nhello\r\n"),
+    ];
+    let deserializer = RespBatchDeserializer::new(&bufs);
+
+    let (ok, integer, string): (String, i64, String) = Deserialize::deserialize(&deserializer)?;
+    assert_eq!("OK", ok);
+    assert_eq!(12, integer);
+    assert_eq!("hello", string);
+
+    let result: Result<(String, i64)> = Deserialize::deserialize(&deserializer);
+    assert!(matches!(result, Err(Error::Client(e)) if e == "expected 2 results, queued 3"));
+
+    Ok(())
+}
+
+#[test]
+fn unit_enum() -> Result<()> {
+    log_try_init();
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(rename_all = "lowercase")]
+    enum Role {
+        Master,
+        #[serde(rename = "slave")]
+        Replica,
+        Sentinel,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    enum RoleOrUnknown {
+        #[serde(rename = "master")]
+        Master,
+        #[serde(other)]
+        Unknown,
+    }
+
+    let result: Role = deserialize("$6\r\nmaster\r\n")?; // b"master"
+    assert_eq!(Role::Master, result);
+
+    let result: Role = deserialize("+slave\r\n")?; // "slave"
+    assert_eq!(Role::Replica, result);
+
+    let result: Result<Role> = deserialize("$7\r\nunknown\r\n"); // b"unknown"
+    assert!(result.is_err());
+
+    let result: RoleOrUnknown = deserialize("$7\r\nunknown\r\n")?; // b"unknown"
+    assert_eq!(RoleOrUnknown::Unknown, result);
+
+    let result: RoleOrUnknown = deserialize("$6\r\nmaster\r\n")?; // b"master"
+    assert_eq!(RoleOrUnknown::Master, result);
+
+    Ok(())
+}
+
+#[test]
+fn role_result() -> Result<()> {
+    use crate::commands::{ReplicationState, RoleResult};
+
+    log_try_init();
+
+    let result: RoleResult = deserialize(
+        "*3\r\n$6\r\nmaster\r\n:3129659\r\n*2\r\n*3\r\n$9\r\n127.0.0.1\r\n$4\r\n9001\r\n$7\r\n3129242\r\n*3\r\n$9\r\n127.0.0.1\r\n$4\r\n9002\r\n$7\r\n3129543\r\n",
+    )?;
+    let RoleResult::Master {
+        master_replication_offset,
+        replica_infos,
+    } = result
+    else {
+        panic!("master role expected");
+    };
+    assert_eq!(3129659, master_replication_offset);
+    assert_eq!(2, replica_infos.len());
+    assert_eq!("127.0.0.1", replica_infos[0].ip);
+    assert_eq!(9001, replica_infos[0].port);
+    assert_eq!(3129242, replica_infos[0].last_ack_offset);
+
+    let result: RoleResult =
+        deserialize("*5\r\n$5\r\nslave\r\n$9\r\n127.0.0.1\r\n:9000\r\n$9\r\nhandshake\r\n:-1\r\n")?;
+    assert!(matches!(
+        result,
+        RoleResult::Replica {
+            master_ip,
+            master_port: 9000,
+            state: ReplicationState::Handshake,
+            amount_data_received: -1
+        } if master_ip == "127.0.0.1"
+    ));
+
+    let result: RoleResult = deserialize("*2\r\n$8\r\nsentinel\r\n*1\r\n$9\r\nmyservice\r\n")?;
+    assert!(matches!(
+        result,
+        RoleResult::Sentinel { master_names } if master_names == vec!["myservice".to_owned()]
+    ));
+
+    Ok(())
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn from_redis_value_derive() -> Result<()> {
+    use crate::resp::RespBuf;
+
+    log_try_init();
+
+    #[derive(Debug, PartialEq, crate::FromRedisValue)]
+    #[redis(rename_all = "lowercase")]
+    enum Role {
+        Master,
+        #[redis(rename = "slave")]
+        Replica,
+        Sentinel,
+    }
+
+    #[derive(Debug, PartialEq, crate::FromRedisValue)]
+    enum RoleOrUnknown {
+        #[redis(rename = "master")]
+        Master,
+        #[redis(other)]
+        Unknown(String),
+    }
+
+    let result: Role = deserialize("$6\r\nmaster\r\n")?;
+    assert_eq!(Role::Master, result);
+
+    let result: Role = deserialize("+slave\r\n")?;
+    assert_eq!(Role::Replica, result);
+
+    let result: Role = deserialize("$8\r\nsentinel\r\n")?;
+    assert_eq!(Role::Sentinel, result);
+
+    let result: Result<Role> = deserialize("$7\r\nunknown\r\n");
+    assert!(result.is_err());
+
+    let result: RoleOrUnknown = deserialize("$6\r\nmaster\r\n")?;
+    assert_eq!(RoleOrUnknown::Master, result);
+
+    let result: RoleOrUnknown = deserialize("$7\r\nunknown\r\n")?;
+    assert_eq!(RoleOrUnknown::Unknown("unknown".to_owned()), result);
+
+    // PrimitiveResponse is implemented too
+    let result: Option<Role> = RespBuf::from_slice(b"_\r\n").to()?;
+    assert_eq!(None, result);
+    let result: Vec<Role> = RespBuf::from_slice(b"*2\r\n+master\r\n+slave\r\n").to()?;
+    assert_eq!(vec![Role::Master, Role::Replica], result);
+
+    Ok(())
+}
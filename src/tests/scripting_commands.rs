@@ -1,13 +1,14 @@
 use crate::{
-    client::ClientPreparedCommand,
+    client::{Client, ClientPreparedCommand, Config, Script, ScriptCache},
     commands::{
         CallBuilder, FlushingMode, FunctionListOptions, LibraryInfo, ScriptingCommands,
         ServerCommands, StringCommands,
     },
     sleep, spawn,
-    tests::get_test_client,
-    Result,
+    tests::{get_test_client, mock_transport},
+    Error, Result,
 };
+use futures_util::join;
 use serial_test::serial;
 
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
@@ -73,6 +74,33 @@ async fn fcall() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn fcall_readonly() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let code = "#!lua name=mylib \n redis.register_function{function_name='myget', callback=function(keys, args) return redis.call('GET', keys[1]) end, flags={ 'no-writes' }} \n redis.register_function('myset', function(keys, args) return redis.call('SET', keys[1], args[1]) end)";
+    let library: String = client.function_load(true, code).await?;
+    assert_eq!("mylib", library);
+
+    client.set("key", "value").await?;
+
+    let result: String = client
+        .fcall_readonly(CallBuilder::function("myget").keys("key"))
+        .await?;
+    assert_eq!("value", result);
+
+    // functions without the `no-writes` flag cannot be called with FCALL_RO
+    let result: Result<String> = client
+        .fcall_readonly(CallBuilder::function("myset").keys("key").args("value2"))
+        .await;
+    assert!(matches!(result, Err(Error::Redis(_))));
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -306,3 +334,129 @@ async fn script_kill() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn script_cache() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushall(FlushingMode::Sync).await?;
+    client.script_flush(FlushingMode::Sync).await?;
+
+    let scripts = ScriptCache::new()
+        .script("echo", "return ARGV[1]")
+        .script("get", "return redis.call('GET', KEYS[1])");
+    scripts.load(&client).await?;
+    assert!(scripts.sha1("echo").is_some());
+
+    let result: String = scripts
+        .call(&client, "echo", Vec::<String>::new(), "hello")
+        .await?;
+    assert_eq!("hello", result);
+
+    client.set("key", "value").await?;
+    let result: String = scripts
+        .call(&client, "get", "key", Vec::<String>::new())
+        .await?;
+    assert_eq!("value", result);
+
+    // reloaded on NOSCRIPT
+    client.script_flush(FlushingMode::Sync).await?;
+    let result: String = scripts
+        .call(&client, "echo", Vec::<String>::new(), "hello")
+        .await?;
+    assert_eq!("hello", result);
+
+    let result: Result<String> = scripts
+        .call(
+            &client,
+            "unknown",
+            Vec::<String>::new(),
+            Vec::<String>::new(),
+        )
+        .await;
+    assert!(matches!(result, Err(Error::Client(_))));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_script_cache_noscript() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    let scripts = ScriptCache::new().script("echo", "return ARGV[1]");
+    let sha1 = scripts.sha1("echo").unwrap();
+
+    // loaded on first call
+    let (result, _) = join!(
+        scripts.call::<String, _, _, _, _>(&client, "echo", Vec::<String>::new(), "hello"),
+        async {
+            let command = server.receive_command().await;
+            assert_eq!("SCRIPT", command.name);
+            server.reply(&format!("$40\r\n{sha1}\r\n"));
+            let command = server.receive_command().await;
+            assert_eq!("EVALSHA", command.name);
+            server.reply("$5\r\nhello\r\n");
+        }
+    );
+    assert_eq!("hello", result?);
+
+    // flushed script: falls back to EVAL
+    let (result, _) = join!(
+        scripts.call::<String, _, _, _, _>(&client, "echo", Vec::<String>::new(), "hello"),
+        async {
+            let command = server.receive_command().await;
+            assert_eq!("EVALSHA", command.name);
+            server.reply("-NOSCRIPT No matching script.\r\n");
+            let command = server.receive_command().await;
+            assert_eq!("EVAL", command.name);
+            assert_eq!(b"return ARGV[1]".to_vec(), command.args[0]);
+            server.reply("$5\r\nhello\r\n");
+        }
+    );
+    assert_eq!("hello", result?);
+
+    Ok(())
+}
+
+#[test]
+fn script_sha1() {
+    let script = Script::new("return ARGV[1]");
+    assert_eq!("098e0f0d1448c0a81dafe820f66d460eb09263da", script.sha1());
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn script() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushall(FlushingMode::Sync).await?;
+    client.script_flush(FlushingMode::Sync).await?;
+
+    let script = Script::new("return redis.call('GET', KEYS[1])");
+    client.set("key", "value").await?;
+
+    // unknown script: falls back to EVAL
+    let result: String = script.eval(&client, "key", Vec::<String>::new()).await?;
+    assert_eq!("value", result);
+    let exists = client.script_exists(script.sha1()).await?;
+    assert_eq!(vec![true], exists);
+
+    // known script: EVALSHA
+    let result: String = script.eval(&client, "key", Vec::<String>::new()).await?;
+    assert_eq!("value", result);
+
+    // flushed script: falls back to EVAL again
+    client.script_flush(FlushingMode::Sync).await?;
+    let result: String = script.eval(&client, "key", Vec::<String>::new()).await?;
+    assert_eq!("value", result);
+
+    client.script_flush(FlushingMode::Sync).await?;
+    script.load(&client).await?;
+    let exists = client.script_exists(script.sha1()).await?;
+    assert_eq!(vec![true], exists);
+
+    Ok(())
+}
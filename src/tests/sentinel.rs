@@ -51,6 +51,20 @@ async fn connection_with_failures() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn connection_with_refresh_on_switch() -> Result<()> {
+    log_try_init();
+    let client = Client::connect(
+        "redis+sentinel://127.0.0.1:26379/myservice?sentinel_refresh_on_switch=true",
+    )
+    .await?;
+    client.hello(Default::default()).await?;
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
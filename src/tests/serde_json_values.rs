@@ -0,0 +1,47 @@
+use crate::{
+    commands::{FlushingMode, ServerCommands, StringCommands},
+    tests::get_test_client,
+    Result,
+};
+use serde::{Deserialize, Serialize};
+use serial_test::serial;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Person {
+    name: String,
+    age: u32,
+    emails: Vec<String>,
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn get_set_serde() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let person = Person {
+        name: "John".to_owned(),
+        age: 42,
+        emails: vec!["john@example.com".to_owned()],
+    };
+
+    client.set_serde("key", &person).await?;
+    let json: String = client.get("key").await?;
+    assert_eq!(
+        r#"{"name":"John","age":42,"emails":["john@example.com"]}"#,
+        json
+    );
+
+    let result: Option<Person> = client.get_serde("key").await?;
+    assert_eq!(Some(person), result);
+
+    let result: Option<Person> = client.get_serde("unknown").await?;
+    assert_eq!(None, result);
+
+    client.set("key", "not json").await?;
+    let result = client.get_serde::<_, Person>("key").await;
+    assert!(result.is_err());
+
+    Ok(())
+}
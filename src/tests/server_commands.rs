@@ -1,20 +1,25 @@
 use crate::{
-    client::Client,
+    client::{Client, Config, MonitorFilter},
     commands::{
-        AclCatOptions, AclDryRunOptions, AclGenPassOptions, AclLogOptions, BlockingCommands,
-        ClientInfo, ClientKillOptions, CommandDoc, CommandHistogram, CommandListOptions,
-        ConnectionCommands, FailOverOptions, FlushingMode, InfoSection, LatencyHistoryEvent,
-        MemoryUsageOptions, ModuleInfo, ModuleLoadOptions, ReplicaOfOptions, RoleResult,
-        ServerCommands, SlowLogOptions, StringCommands,
+        AclCatOptions, AclDryRunOptions, AclGenPassOptions, AclLogOptions, AclUser,
+        BlockingCommands, ClientInfo, ClientKillOptions, CommandHistogram, ConnectionCommands,
+        FailOverOptions, FlushingMode, InfoSection, KeyspaceInfo, LatencyEvent,
+        LatencyHistoryEvent, MemoryUsageOptions, ModuleInfo, ModuleLoadOptions, ReplicaOfOptions,
+        RoleResult, ServerCommands, ServerInfo, SlowLogOptions, StringCommands,
     },
-    resp::{cmd, Value},
+    resp::{cmd, RespBuf, Value},
     spawn,
-    tests::{get_sentinel_test_client, get_test_client},
+    tests::{get_sentinel_test_client, get_test_client, mock_transport},
     Error, RedisError, RedisErrorKind, Result,
 };
-use futures_util::StreamExt;
+use futures_util::{join, StreamExt};
 use serial_test::serial;
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    future::IntoFuture,
+    str::FromStr,
+    time::Duration,
+};
 
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
@@ -113,8 +118,69 @@ async fn acl_getuser() -> Result<()> {
     // default `commands` rule
     assert!(matches!(rules.get("commands"), Some(Value::BulkString(rule)) if rule == b"-@all"));
 
+    let user: AclUser = client.acl_getuser("foo").await?;
+    assert_eq!("-@all", user.commands);
+    assert!(user.flags.contains(&"off".to_owned()));
+
     client.acl_deluser("foo").await?;
 
+    let user: Option<AclUser> = client.acl_getuser("foo").await?;
+    assert!(user.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn acl_user() -> Result<()> {
+    // RESP3 map with a selector
+    let resp_buf = RespBuf::from_slice(
+        concat!(
+            "%6\r\n",
+            "$5\r\nflags\r\n*2\r\n$2\r\non\r\n$16\r\nsanitize-payload\r\n",
+            "$9\r\npasswords\r\n*1\r\n$3\r\nabc\r\n",
+            "$8\r\ncommands\r\n$5\r\n+@all\r\n",
+            "$4\r\nkeys\r\n$2\r\n~*\r\n",
+            "$8\r\nchannels\r\n$0\r\n\r\n",
+            "$9\r\nselectors\r\n*1\r\n%3\r\n",
+            "$8\r\ncommands\r\n$4\r\n+get\r\n",
+            "$4\r\nkeys\r\n$5\r\n%R~k*\r\n",
+            "$8\r\nchannels\r\n$0\r\n\r\n",
+        )
+        .as_bytes(),
+    );
+    let user: AclUser = resp_buf.to()?;
+    assert_eq!(
+        vec!["on".to_owned(), "sanitize-payload".to_owned()],
+        user.flags
+    );
+    assert_eq!(vec!["abc".to_owned()], user.passwords);
+    assert_eq!("+@all", user.commands);
+    assert_eq!("~*", user.keys);
+    assert_eq!("", user.channels);
+    assert_eq!(1, user.selectors.len());
+    assert_eq!("+get", user.selectors[0].commands);
+    assert_eq!("%R~k*", user.selectors[0].keys);
+
+    // RESP2 flat array, with Redis 6 patterns arrays
+    let resp_buf = RespBuf::from_slice(
+        concat!(
+            "*10\r\n",
+            "$5\r\nflags\r\n*1\r\n$3\r\noff\r\n",
+            "$9\r\npasswords\r\n*0\r\n",
+            "$8\r\ncommands\r\n$5\r\n-@all\r\n",
+            "$4\r\nkeys\r\n*2\r\n$2\r\na*\r\n$2\r\nb*\r\n",
+            "$8\r\nchannels\r\n*0\r\n",
+        )
+        .as_bytes(),
+    );
+    let user: AclUser = resp_buf.to()?;
+    assert_eq!(vec!["off".to_owned()], user.flags);
+    assert!(user.passwords.is_empty());
+    assert_eq!("-@all", user.commands);
+    assert_eq!("a* b*", user.keys);
+    assert_eq!("", user.channels);
+    assert!(user.selectors.is_empty());
+
     Ok(())
 }
 
@@ -271,142 +337,6 @@ async fn acl_whoami() -> Result<()> {
     Ok(())
 }
 
-#[cfg_attr(feature = "tokio-runtime", tokio::test)]
-#[cfg_attr(feature = "async-std-runtime", async_std::test)]
-#[serial]
-async fn command() -> Result<()> {
-    let client = get_test_client().await?;
-
-    let _command_infos = client.command().await?;
-
-    Ok(())
-}
-
-#[cfg_attr(feature = "tokio-runtime", tokio::test)]
-#[cfg_attr(feature = "async-std-runtime", async_std::test)]
-#[serial]
-async fn command_info() -> Result<()> {
-    let client = get_test_client().await?;
-
-    let _command_infos = client.command_info("SORT").await?;
-
-    Ok(())
-}
-
-#[cfg_attr(feature = "tokio-runtime", tokio::test)]
-#[cfg_attr(feature = "async-std-runtime", async_std::test)]
-#[serial]
-async fn command_count() -> Result<()> {
-    let client = get_test_client().await?;
-
-    let command_infos = client.command().await?;
-    let num_commands = client.command_count().await?;
-    assert_eq!(command_infos.len(), num_commands);
-
-    Ok(())
-}
-
-#[cfg_attr(feature = "tokio-runtime", tokio::test)]
-#[cfg_attr(feature = "async-std-runtime", async_std::test)]
-#[serial]
-async fn command_docs() -> Result<()> {
-    let client = get_test_client().await?;
-
-    let _command_docs: HashMap<String, CommandDoc> =
-        client.command_docs(["XADD", "GET", "SET"]).await?;
-
-    Ok(())
-}
-
-#[cfg_attr(feature = "tokio-runtime", tokio::test)]
-#[cfg_attr(feature = "async-std-runtime", async_std::test)]
-#[serial]
-async fn command_getkeys() -> Result<()> {
-    let client = get_test_client().await?;
-
-    let keys: Vec<String> = client
-        .command_getkeys(["MSET", "a", "b", "c", "d", "e", "f"])
-        .await?;
-    assert!(keys.contains(&"a".to_owned()));
-    assert!(keys.contains(&"c".to_owned()));
-    assert!(keys.contains(&"e".to_owned()));
-
-    let keys: Vec<String> = client
-        .command_getkeys(["EVAL", "not consulted", "3", "key1", "key2", "key3", "arg1"])
-        .await?;
-    assert!(keys.contains(&"key1".to_owned()));
-    assert!(keys.contains(&"key2".to_owned()));
-    assert!(keys.contains(&"key3".to_owned()));
-
-    let keys: Vec<String> = client
-        .command_getkeys(["SORT", "mylist", "ALPHA", "STORE", "outlist"])
-        .await?;
-    assert!(keys.contains(&"mylist".to_owned()));
-    assert!(keys.contains(&"outlist".to_owned()));
-
-    Ok(())
-}
-
-#[cfg_attr(feature = "tokio-runtime", tokio::test)]
-#[cfg_attr(feature = "async-std-runtime", async_std::test)]
-#[serial]
-async fn command_getkeysandflags() -> Result<()> {
-    let client = get_test_client().await?;
-
-    let keys_and_flags: HashMap<String, Vec<String>> = client
-        .command_getkeysandflags(["MSET", "a", "b", "c", "d", "e", "f"])
-        .await?;
-    assert!(keys_and_flags.contains_key(&"a".to_owned()));
-    assert!(keys_and_flags.contains_key(&"c".to_owned()));
-    assert!(keys_and_flags.contains_key(&"e".to_owned()));
-
-    let keys_and_flags: HashMap<String, Vec<String>> = client
-        .command_getkeysandflags(["EVAL", "not consulted", "3", "key1", "key2", "key3", "arg1"])
-        .await?;
-    assert!(keys_and_flags.contains_key(&"key1".to_owned()));
-    assert!(keys_and_flags.contains_key(&"key2".to_owned()));
-    assert!(keys_and_flags.contains_key(&"key3".to_owned()));
-
-    let keys_and_flags: HashMap<String, Vec<String>> = client
-        .command_getkeysandflags(["LMOVE", "mylist1", "mylist2", "left", "left"])
-        .await?;
-    let flags = keys_and_flags.get(&"mylist1".to_owned()).unwrap();
-    assert_eq!("RW", flags[0]);
-    assert_eq!("access", flags[1]);
-    assert_eq!("delete", flags[2]);
-    let flags = keys_and_flags.get(&"mylist2".to_owned()).unwrap();
-    assert_eq!("RW", flags[0]);
-    assert_eq!("insert", flags[1]);
-
-    Ok(())
-}
-
-#[cfg_attr(feature = "tokio-runtime", tokio::test)]
-#[cfg_attr(feature = "async-std-runtime", async_std::test)]
-#[serial]
-async fn command_list() -> Result<()> {
-    let client = get_test_client().await?;
-
-    let all_commands: Vec<String> = client.command_list(CommandListOptions::default()).await?;
-    assert!(!all_commands.is_empty());
-
-    let string_commands: Vec<String> = client
-        .command_list(CommandListOptions::default().filter_by_acl_category("string"))
-        .await?;
-    assert!(!string_commands.is_empty());
-    assert!(string_commands.contains(&"get".to_owned()));
-    assert!(string_commands.contains(&"set".to_owned()));
-
-    let config_commands: Vec<String> = client
-        .command_list(CommandListOptions::default().filter_by_pattern("config*"))
-        .await?;
-    assert!(!config_commands.is_empty());
-    assert!(config_commands.contains(&"config|get".to_owned()));
-    assert!(config_commands.contains(&"config|set".to_owned()));
-
-    Ok(())
-}
-
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -592,14 +522,88 @@ async fn info() -> Result<()> {
     let client = get_test_client().await?;
     client.flushdb(FlushingMode::Sync).await?;
 
+    client.set("key", "value").await?;
+
     let info = client.info([]).await?;
-    assert!(!info.is_empty());
+    assert!(!info.server.redis_version.is_empty());
+    assert!(info.memory.used_memory > 0);
+    assert_eq!(
+        Some(&KeyspaceInfo {
+            keys: 1,
+            expires: 0,
+            avg_ttl: 0
+        }),
+        info.keyspace.get(&0)
+    );
 
     let info = client
         .info([InfoSection::Cpu, InfoSection::Clients])
         .await?;
-    assert!(info.contains("# CPU"));
-    assert!(info.contains("# Clients"));
+    assert!(info.clients.connected_clients >= 1);
+    assert!(info.cpu.used_cpu_user >= 0.0);
+    assert!(info.server.redis_version.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn server_info_from_str() -> Result<()> {
+    let info = ServerInfo::from_str(
+        "# Server\r\n\
+        redis_version:7.2.0\r\n\
+        tcp_port:6379\r\n\
+        new_field:abc\r\n\
+        \r\n\
+        # Clients\r\n\
+        connected_clients:3\r\n\
+        \r\n\
+        # Memory\r\n\
+        used_memory:1048576\r\n\
+        mem_fragmentation_ratio:1.5\r\n\
+        \r\n\
+        # Persistence\r\n\
+        loading:0\r\n\
+        aof_enabled:1\r\n\
+        \r\n\
+        # Replication\r\n\
+        role:master\r\n\
+        slave0:ip=127.0.0.1,port=6380,state=online,offset=1,lag=0\r\n\
+        \r\n\
+        # Errorstats\r\n\
+        errorstat_ERR:count=2\r\n\
+        \r\n\
+        # Keyspace\r\n\
+        db0:keys=1,expires=0,avg_ttl=0\r\n\
+        db3:keys=12,expires=2,avg_ttl=3500\r\n",
+    )?;
+
+    assert_eq!("7.2.0", info.server.redis_version);
+    assert_eq!(6379, info.server.tcp_port);
+    assert_eq!(
+        Some(&"abc".to_owned()),
+        info.server.additional_fields.get("new_field")
+    );
+    assert_eq!(3, info.clients.connected_clients);
+    assert_eq!(1_048_576, info.memory.used_memory);
+    assert_eq!(1.5, info.memory.mem_fragmentation_ratio);
+    assert!(!info.persistence.loading);
+    assert!(info.persistence.aof_enabled);
+    assert_eq!("master", info.replication.role);
+    assert_eq!(None, info.replication.master_host);
+    assert!(info.replication.additional_fields.contains_key("slave0"));
+    assert_eq!(
+        Some(&"count=2".to_owned()),
+        info.additional_sections["errorstats"].get("errorstat_ERR")
+    );
+    assert_eq!(2, info.keyspace.len());
+    assert_eq!(
+        KeyspaceInfo {
+            keys: 12,
+            expires: 2,
+            avg_ttl: 3500
+        },
+        info.keyspace[&3]
+    );
 
     Ok(())
 }
@@ -738,8 +742,11 @@ async fn latency_latest() -> Result<()> {
         .send(cmd("DEBUG").arg("SLEEP").arg(0.2), None)
         .await?;
 
-    let report: Vec<(String, u32, u32, u32)> = client.latency_latest().await?;
-    assert!(!report.is_empty());
+    let report: Vec<LatencyEvent> = client.latency_latest().await?;
+    let event = report.iter().find(|e| e.event == "command").unwrap();
+    assert!(event.timestamp > 0);
+    assert!(event.latest_ms >= 50);
+    assert!(event.max_ms >= event.latest_ms);
 
     Ok(())
 }
@@ -827,6 +834,48 @@ async fn memory_stats() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_memory_stats() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    let (memory_stats, _) = join!(client.memory_stats().into_future(), async {
+        let command = server.receive_command().await;
+        assert_eq!("MEMORY", command.name);
+        server.reply(concat!(
+            "%7\r\n",
+            "$14\r\npeak.allocated\r\n:2000\r\n",
+            "$15\r\ntotal.allocated\r\n:1000\r\n",
+            "$10\r\nkeys.count\r\n:3\r\n",
+            "$13\r\ndataset.bytes\r\n:400\r\n",
+            "$18\r\ndataset.percentage\r\n,42.5\r\n",
+            "$4\r\ndb.0\r\n%2\r\n",
+            "$23\r\noverhead.hashtable.main\r\n:72\r\n",
+            "$26\r\noverhead.hashtable.expires\r\n:0\r\n",
+            "$9\r\nnew.field\r\n:7\r\n",
+        ));
+    });
+    let memory_stats = memory_stats?;
+    assert_eq!(2000, memory_stats.peak_allocated);
+    assert_eq!(1000, memory_stats.total_allocated);
+    assert_eq!(3, memory_stats.keys_count);
+    assert_eq!(400, memory_stats.dataset_bytes);
+    assert_eq!(42.5, memory_stats.dataset_percentage);
+    assert_eq!(2, memory_stats.additional_values.len());
+    // nested maps are flattened in arrays of keys and values
+    assert!(matches!(
+        memory_stats.additional_values.get("db.0"),
+        Some(Value::Array(values)) if values.len() == 4
+    ));
+    assert_eq!(
+        Some(&Value::Integer(7)),
+        memory_stats.additional_values.get("new.field")
+    );
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -962,6 +1011,51 @@ async fn monitor() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn monitor_filter() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let client2 = get_test_client().await?;
+    client2.select(2).await?;
+
+    let mut monitor_stream = client
+        .monitor()
+        .await?
+        .filter(MonitorFilter::default().commands(["SET"]).database(2));
+
+    spawn(async move {
+        async fn calls(client: &Client) -> Result<()> {
+            client.set("key", "value 1").await?;
+            let _value: String = client.get("key").await?;
+            client.set("key", "value 2").await?;
+
+            Ok(())
+        }
+
+        let _result = calls(&client2).await;
+    });
+
+    for value in ["value 1", "value 2"] {
+        let result = monitor_stream
+            .next()
+            .await
+            .ok_or_else(|| Error::Client("fail".to_owned()))?;
+        assert_eq!(2, result.database);
+        assert_eq!("SET", result.command);
+        assert_eq!(
+            vec!["key".to_owned(), value.to_owned()],
+            result.command_args
+        );
+    }
+
+    monitor_stream.close().await?;
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -1153,3 +1247,50 @@ async fn time() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn wait() -> Result<()> {
+    let client = get_test_client().await?;
+
+    client.set("key", "value").await?;
+
+    let num_replicas = client.wait(0, Duration::from_millis(100)).await?;
+    assert_eq!(0, num_replicas);
+
+    let (_num_local, num_replicas) = client.waitaof(0, 0, Duration::from_millis(100)).await?;
+    assert_eq!(0, num_replicas);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_wait_timeout() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    let (result, _) = join!(
+        client.wait(1, Duration::from_secs(2)).into_future(),
+        async {
+            let command = server.receive_command().await;
+            assert_eq!("WAIT", command.name);
+            assert_eq!(b"1".to_vec(), command.args[0]);
+            assert_eq!(b"2000".to_vec(), command.args[1]);
+            server.reply(":1\r\n");
+        }
+    );
+    assert_eq!(1, result?);
+
+    // zero means to block forever
+    let (result, _) = join!(client.waitaof(1, 0, Duration::ZERO).into_future(), async {
+        let command = server.receive_command().await;
+        assert_eq!("WAITAOF", command.name);
+        assert_eq!(b"0".to_vec(), command.args[2]);
+        server.reply("*2\r\n:1\r\n:0\r\n");
+    });
+    assert_eq!((1, 0), result?);
+
+    Ok(())
+}
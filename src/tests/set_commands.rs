@@ -112,7 +112,14 @@ async fn sintercard() -> Result<()> {
     client.sadd("key2", "c").await?;
     client.sadd("key3", ["a", "c", "e"]).await?;
 
-    let len = client.sintercard(["key1", "key2", "key3"], 0).await?;
+    let len = client.sintercard(["key1", "key2", "key3"], None).await?;
+    assert_eq!(1, len);
+
+    let len = client.sintercard(["key1", "key3"], Some(0)).await?;
+    assert_eq!(2, len);
+
+    // the computation stops as soon as the limit is reached
+    let len = client.sintercard(["key1", "key3"], Some(1)).await?;
     assert_eq!(1, len);
 
     Ok(())
@@ -197,6 +204,19 @@ async fn smismember() -> Result<()> {
     assert!(result[0]);
     assert!(!result[1]);
 
+    // results follow the order of the requested members
+    let result = client
+        .smismember(
+            "key",
+            ["value4", "value3", "value5", "value1", "value2", "value3"],
+        )
+        .await?;
+    assert_eq!(vec![false, true, false, true, true, true], result);
+
+    // unknown key
+    let result = client.smismember("unknown", ["value1", "value2"]).await?;
+    assert_eq!(vec![false, false], result);
+
     Ok(())
 }
 
@@ -249,6 +269,18 @@ async fn srandmember() -> Result<()> {
     let result: HashSet<String> = client.srandmember("key", 2).await?;
     assert_eq!(2, result.len());
 
+    // positive count: distinct members, up to the cardinality of the set
+    let result: Vec<String> = client.srandmember("key", 5).await?;
+    assert_eq!(3, result.len());
+    assert_eq!(3, result.iter().collect::<HashSet<_>>().len());
+
+    // negative count: the same member may be returned multiple times
+    let result: Vec<String> = client.srandmember("key", -5).await?;
+    assert_eq!(5, result.len());
+    assert!(result
+        .iter()
+        .all(|m| ["value1", "value2", "value3"].contains(&m.as_str())));
+
     Ok(())
 }
 
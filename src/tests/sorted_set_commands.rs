@@ -1,13 +1,15 @@
 use crate::{
+    client::{Client, Config},
     commands::{
         BZpopMinMaxResult, BlockingCommands, FlushingMode, GenericCommands, ServerCommands,
         SortedSetCommands, ZAddOptions, ZRangeOptions, ZRangeSortBy, ZScanOptions, ZScanResult,
         ZWhere,
     },
     sleep, spawn,
-    tests::get_test_client,
+    tests::{get_test_client, mock_transport},
     Result,
 };
+use futures_util::join;
 use serial_test::serial;
 use std::time::Duration;
 
@@ -450,6 +452,40 @@ async fn zinter() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn zintercard() -> Result<()> {
+    let client = get_test_client().await?;
+
+    // cleanup
+    client.del(["key1", "key2"]).await?;
+
+    client
+        .zadd(
+            "key1",
+            [(1.0, "one"), (2.0, "two"), (3.0, "three")],
+            ZAddOptions::default(),
+        )
+        .await?;
+    client
+        .zadd(
+            "key2",
+            [(1.0, "one"), (2.0, "two"), (3.0, "three"), (4.0, "four")],
+            ZAddOptions::default(),
+        )
+        .await?;
+
+    let len = client.zintercard(["key1", "key2"], None).await?;
+    assert_eq!(3, len);
+
+    // the computation stops as soon as the limit is reached
+    let len = client.zintercard(["key1", "key2"], Some(1)).await?;
+    assert_eq!(1, len);
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -633,6 +669,16 @@ async fn zmscore() -> Result<()> {
     assert_eq!(Some(2.0), scores[1]);
     assert_eq!(None, scores[2]);
 
+    // results follow the order of the requested members
+    let scores = client
+        .zmscore("key", ["nofield", "two", "one", "nofield", "two"])
+        .await?;
+    assert_eq!(vec![None, Some(2.0), Some(1.0), None, Some(2.0)], scores);
+
+    // unknown key
+    let scores = client.zmscore("unknown", ["one", "two"]).await?;
+    assert_eq!(vec![None, None], scores);
+
     Ok(())
 }
 
@@ -707,7 +753,17 @@ async fn zrandmember() -> Result<()> {
     let result: String = client.zrandmember("key").await?;
     assert!(values.iter().any(|v| v.1 == result));
 
+    // positive count: distinct members, up to the cardinality of the sorted set
+    let result: Vec<String> = client.zrandmembers("key", 10).await?;
+    assert_eq!(6, result.len());
+
+    // negative count: the same member may be returned multiple times
+    let result: Vec<String> = client.zrandmembers("key", -10).await?;
+    assert_eq!(10, result.len());
+    assert!(result.iter().all(|r| values.iter().any(|v| v.1 == r)));
+
     let result: Vec<(String, f64)> = client.zrandmembers_with_scores("key", -5).await?;
+    assert_eq!(5, result.len());
     assert!(result
         .iter()
         .all(|r| values.iter().any(|v| v.0 == r.1 && v.1 == r.0)));
@@ -772,6 +828,21 @@ async fn zrange() -> Result<()> {
     assert_eq!(1, values.len());
     assert_eq!("three".to_owned(), values[0]);
 
+    let values: Vec<(String, f64)> = client
+        .zrange_with_scores(
+            "key",
+            "+inf",
+            "(1",
+            ZRangeOptions::default()
+                .sort_by(ZRangeSortBy::ByScore)
+                .reverse(),
+        )
+        .await?;
+    assert_eq!(
+        vec![("three".to_owned(), 3.0), ("two".to_owned(), 2.0)],
+        values
+    );
+
     Ok(())
 }
 
@@ -1093,3 +1164,23 @@ async fn zunionstore() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn zrandmembers_with_scores_resp3() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    // RESP3 replies an array of [member, score] pairs
+    let (result, _) = join!(
+        async { client.zrandmembers_with_scores("key", -2).await },
+        async {
+            server.receive_command().await;
+            server.reply("*2\r\n*2\r\n$3\r\none\r\n,1\r\n*2\r\n$3\r\none\r\n,1\r\n");
+        }
+    );
+    let result: Vec<(String, f64)> = result?;
+    assert_eq!(vec![("one".to_owned(), 1.), ("one".to_owned(), 1.)], result);
+
+    Ok(())
+}
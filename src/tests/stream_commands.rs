@@ -1,12 +1,16 @@
 use crate::{
+    client::{Client, Config},
     commands::{
-        FlushingMode, ServerCommands, StreamCommands, StreamEntry, XAddOptions, XAutoClaimOptions,
-        XAutoClaimResult, XGroupCreateOptions, XInfoStreamOptions, XPendingOptions,
-        XReadGroupOptions, XReadOptions, XTrimOperator, XTrimOptions,
+        BlockingCommands, FlushingMode, ServerCommands, StreamCommands, StreamEntry, XAddOptions,
+        XAutoClaimOptions, XAutoClaimResult, XClaimOptions, XGroupCreateOptions, XGroupInfo,
+        XInfoStreamOptions, XPendingOptions, XReadGroupOptions, XReadOptions, XStreamInfo,
+        XTrimOperator, XTrimOptions,
     },
-    tests::get_test_client,
-    Result,
+    resp::RespBuf,
+    tests::{get_test_client, mock_transport},
+    Error, Result,
 };
+use futures_util::StreamExt;
 use serial_test::serial;
 
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
@@ -49,8 +53,8 @@ async fn xadd() -> Result<()> {
     assert_eq!("0-0", result.max_deleted_entry_id);
     assert_eq!(2, result.entries_added);
     assert_eq!(id1, result.recorded_first_entry_id);
-    assert_eq!(id1, result.first_entry.stream_id);
-    assert_eq!(id2, result.last_entry.stream_id);
+    assert_eq!(Some(id1), result.first_entry.map(|e| e.stream_id));
+    assert_eq!(Some(id2), result.last_entry.map(|e| e.stream_id));
 
     Ok(())
 }
@@ -601,6 +605,66 @@ async fn xclaim() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn xclaim_just_id() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    client
+        .xgroup_create(
+            "mystream",
+            "mygroup",
+            "$",
+            XGroupCreateOptions::default().mk_stream(),
+        )
+        .await?;
+
+    let id1: String = client
+        .xadd(
+            "mystream",
+            "*",
+            ("message", "apple"),
+            XAddOptions::default(),
+        )
+        .await?;
+
+    let id2: String = client
+        .xadd(
+            "mystream",
+            "*",
+            ("message", "orange"),
+            XAddOptions::default(),
+        )
+        .await?;
+
+    let _results: Vec<(String, Vec<StreamEntry<String>>)> = client
+        .xreadgroup(
+            "mygroup",
+            "Alice",
+            XReadGroupOptions::default().count(1),
+            "mystream",
+            ">",
+        )
+        .await?;
+
+    // id2 has not been delivered yet: FORCE creates its PEL entry
+    let results: Vec<String> = client
+        .xclaim_just_id(
+            "mystream",
+            "mygroup",
+            "Bob",
+            0,
+            [id1.clone(), id2.clone()],
+            XClaimOptions::default().force(),
+        )
+        .await?;
+    assert_eq!(vec![id1, id2], results);
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -744,6 +808,71 @@ async fn xautoclaim() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn xautoclaim_just_id() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    client
+        .xgroup_create(
+            "mystream",
+            "mygroup",
+            "$",
+            XGroupCreateOptions::default().mk_stream(),
+        )
+        .await?;
+
+    let id1: String = client
+        .xadd(
+            "mystream",
+            "*",
+            ("message", "apple"),
+            XAddOptions::default(),
+        )
+        .await?;
+
+    let id2: String = client
+        .xadd(
+            "mystream",
+            "*",
+            ("message", "orange"),
+            XAddOptions::default(),
+        )
+        .await?;
+
+    let _results: Vec<(String, Vec<StreamEntry<String>>)> = client
+        .xreadgroup(
+            "mygroup",
+            "Alice",
+            XReadGroupOptions::default().count(2),
+            "mystream",
+            ">",
+        )
+        .await?;
+
+    // deleted entries stay pending until claimed
+    let num = client.xdel("mystream", id2.clone()).await?;
+    assert_eq!(1, num);
+
+    let result = client
+        .xautoclaim_just_id(
+            "mystream",
+            "mygroup",
+            "Bob",
+            0,
+            "0-0",
+            XAutoClaimOptions::default(),
+        )
+        .await?;
+    assert_eq!("0-0", result.start_stream_id);
+    assert_eq!(vec![id1], result.ids);
+    assert_eq!(vec![id2], result.deleted_ids);
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -828,3 +957,183 @@ async fn xtrim() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn xread_stream() -> Result<()> {
+    let client = get_test_client().await?;
+    let reader = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let id1: String = client
+        .xadd(
+            "mystream",
+            "*",
+            ("message", "apple"),
+            XAddOptions::default(),
+        )
+        .await?;
+    let id2: String = client
+        .xadd(
+            "mystream",
+            "*",
+            ("message", "orange"),
+            XAddOptions::default(),
+        )
+        .await?;
+
+    let mut stream = reader.xread_stream::<String>("mystream", "0", Some(1))?;
+
+    let entry = stream.next().await.unwrap()?;
+    assert_eq!(id1, entry.stream_id);
+    assert_eq!(Some(&"apple".to_owned()), entry.items.get("message"));
+
+    let entry = stream.next().await.unwrap()?;
+    assert_eq!(id2, entry.stream_id);
+    assert_eq!(id2, stream.last_id());
+
+    let id3: String = client
+        .xadd(
+            "mystream",
+            "*",
+            ("message", "banana"),
+            XAddOptions::default(),
+        )
+        .await?;
+
+    let entry = stream.next().await.unwrap()?;
+    assert_eq!(id3, entry.stream_id);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn xreadgroup_stream() -> Result<()> {
+    let client = get_test_client().await?;
+    let reader = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    client
+        .xgroup_create(
+            "mystream",
+            "mygroup",
+            "$",
+            XGroupCreateOptions::default().mk_stream(),
+        )
+        .await?;
+
+    let id1: String = client
+        .xadd(
+            "mystream",
+            "*",
+            ("message", "apple"),
+            XAddOptions::default(),
+        )
+        .await?;
+    let id2: String = client
+        .xadd(
+            "mystream",
+            "*",
+            ("message", "orange"),
+            XAddOptions::default(),
+        )
+        .await?;
+
+    let mut stream = reader.xreadgroup_stream::<String>("mygroup", "Alice", "mystream", None)?;
+
+    let entry = stream.next().await.unwrap()?;
+    assert_eq!(id1, entry.stream_id);
+    let entry = stream.next().await.unwrap()?;
+    assert_eq!(id2, entry.stream_id);
+
+    let num = stream.ack([id1, id2]).await?;
+    assert_eq!(2, num);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_xread_stream_shared_connection() -> Result<()> {
+    let (transport, _server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    // a clone of the client would share the blocked connection
+    let _clone = client.clone();
+    assert!(matches!(
+        client.xread_stream::<String>("mystream", "0", None),
+        Err(Error::Client(_))
+    ));
+    assert!(matches!(
+        client.xreadgroup_stream::<String>("mygroup", "Alice", "mystream", None),
+        Err(Error::Client(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn xinfo_stream_reply() -> Result<()> {
+    // RESP3 map
+    let result: XStreamInfo = RespBuf::from_slice(b"%10\r\n$6\r\nlength\r\n:2\r\n$15\r\nradix-tree-keys\r\n:1\r\n$16\r\nradix-tree-nodes\r\n:2\r\n$17\r\nlast-generated-id\r\n$3\r\n2-0\r\n$20\r\nmax-deleted-entry-id\r\n$3\r\n0-0\r\n$13\r\nentries-added\r\n:2\r\n$23\r\nrecorded-first-entry-id\r\n$3\r\n1-0\r\n$6\r\ngroups\r\n:1\r\n$11\r\nfirst-entry\r\n*2\r\n$3\r\n1-0\r\n*2\r\n$4\r\nname\r\n$4\r\nJohn\r\n$10\r\nlast-entry\r\n*2\r\n$3\r\n2-0\r\n*2\r\n$4\r\nname\r\n$4\r\nJane\r\n").to()?;
+    assert_eq!(2, result.length);
+    assert_eq!(1, result.radix_tree_keys);
+    assert_eq!(1, result.groups);
+    assert_eq!("2-0", result.last_generated_id);
+    let first_entry = result.first_entry.unwrap();
+    assert_eq!("1-0", first_entry.stream_id);
+    assert_eq!(Some(&"John".to_owned()), first_entry.items.get("name"));
+    assert_eq!(
+        Some("2-0".to_owned()),
+        result.last_entry.map(|e| e.stream_id)
+    );
+
+    // RESP2 flat array
+    let result: XStreamInfo = RespBuf::from_slice(b"*20\r\n$6\r\nlength\r\n:2\r\n$15\r\nradix-tree-keys\r\n:1\r\n$16\r\nradix-tree-nodes\r\n:2\r\n$17\r\nlast-generated-id\r\n$3\r\n2-0\r\n$20\r\nmax-deleted-entry-id\r\n$3\r\n0-0\r\n$13\r\nentries-added\r\n:2\r\n$23\r\nrecorded-first-entry-id\r\n$3\r\n1-0\r\n$6\r\ngroups\r\n:1\r\n$11\r\nfirst-entry\r\n*2\r\n$3\r\n1-0\r\n*2\r\n$4\r\nname\r\n$4\r\nJohn\r\n$10\r\nlast-entry\r\n*2\r\n$3\r\n2-0\r\n*2\r\n$4\r\nname\r\n$4\r\nJane\r\n").to()?;
+    assert_eq!(2, result.length);
+    assert_eq!(1, result.groups);
+    assert_eq!("2-0", result.last_generated_id);
+    assert_eq!(
+        Some("1-0".to_owned()),
+        result.first_entry.map(|e| e.stream_id)
+    );
+    assert_eq!(
+        Some("2-0".to_owned()),
+        result.last_entry.map(|e| e.stream_id)
+    );
+
+    // empty stream
+    let result: XStreamInfo = RespBuf::from_slice(b"%10\r\n$6\r\nlength\r\n:0\r\n$15\r\nradix-tree-keys\r\n:1\r\n$16\r\nradix-tree-nodes\r\n:1\r\n$17\r\nlast-generated-id\r\n$3\r\n2-0\r\n$20\r\nmax-deleted-entry-id\r\n$3\r\n0-0\r\n$13\r\nentries-added\r\n:2\r\n$23\r\nrecorded-first-entry-id\r\n$3\r\n1-0\r\n$6\r\ngroups\r\n:1\r\n$11\r\nfirst-entry\r\n_\r\n$10\r\nlast-entry\r\n_\r\n").to()?;
+    assert_eq!(0, result.length);
+    assert!(result.first_entry.is_none());
+    assert!(result.last_entry.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn xinfo_groups_reply() -> Result<()> {
+    // RESP3 map
+    let results: Vec<XGroupInfo> = RespBuf::from_slice(b"*1\r\n%6\r\n$4\r\nname\r\n$7\r\nmygroup\r\n$9\r\nconsumers\r\n:1\r\n$7\r\npending\r\n:0\r\n$17\r\nlast-delivered-id\r\n$3\r\n0-0\r\n$12\r\nentries-read\r\n_\r\n$3\r\nlag\r\n:2\r\n").to()?;
+    assert_eq!(1, results.len());
+    assert_eq!("mygroup", results[0].name);
+    assert_eq!(1, results[0].consumers);
+    assert_eq!(0, results[0].pending);
+    assert_eq!("0-0", results[0].last_delivered_id);
+    assert_eq!(None, results[0].entries_read);
+    assert_eq!(Some(2), results[0].lag);
+
+    // RESP2 flat array
+    let results: Vec<XGroupInfo> = RespBuf::from_slice(b"*1\r\n*12\r\n$4\r\nname\r\n$7\r\nmygroup\r\n$9\r\nconsumers\r\n:1\r\n$7\r\npending\r\n:0\r\n$17\r\nlast-delivered-id\r\n$3\r\n0-0\r\n$12\r\nentries-read\r\n$-1\r\n$3\r\nlag\r\n:2\r\n").to()?;
+    assert_eq!(1, results.len());
+    assert_eq!("mygroup", results[0].name);
+    assert_eq!(1, results[0].consumers);
+    assert_eq!("0-0", results[0].last_delivered_id);
+    assert_eq!(None, results[0].entries_read);
+    assert_eq!(Some(2), results[0].lag);
+
+    Ok(())
+}
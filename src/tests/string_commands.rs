@@ -1,13 +1,19 @@
 use crate::{
+    client::{Client, Config},
     commands::{
-        GenericCommands, GetExOptions, LcsMatch, SetCondition, SetExpiration, StringCommands,
+        FlushingMode, GenericCommands, GetExOptions, HashCommands, LcsMatch, LcsOptions,
+        ListCommands, ServerCommands, SetCondition, SetExpiration, StringCommands,
     },
-    resp::Value,
-    tests::get_test_client,
+    resp::{BulkString, Value},
+    tests::{get_test_client, mock_transport},
     Error, RedisError, RedisErrorKind, Result,
 };
+use futures_util::join;
 use serial_test::serial;
-use std::time::{Duration, SystemTime};
+use std::{
+    future::IntoFuture,
+    time::{Duration, SystemTime},
+};
 
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
@@ -112,6 +118,114 @@ async fn get_and_set() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn get_missing_or_empty() -> Result<()> {
+    let client = get_test_client().await?;
+
+    // cleanup
+    client.del("key").await?;
+
+    // a missing key and an empty value cannot be told apart without Option
+    let value: String = client.get("key").await?;
+    assert_eq!("", value);
+    let value: Option<String> = client.get("key").await?;
+    assert_eq!(None, value);
+    let value: Option<BulkString> = client.get("key").await?;
+    assert!(value.is_none());
+    let value: Option<i64> = client.get("key").await?;
+    assert_eq!(None, value);
+
+    client.set("key", "").await?;
+    let value: String = client.get("key").await?;
+    assert_eq!("", value);
+    let value: Option<String> = client.get("key").await?;
+    assert_eq!(Some("".to_owned()), value);
+    let value: Option<BulkString> = client.get("key").await?;
+    assert_eq!(Some(Vec::new()), value.map(Vec::<u8>::from));
+
+    let value: Option<String> = client.getdel("key").await?;
+    assert_eq!(Some("".to_owned()), value);
+    let value: Option<String> = client.getdel("key").await?;
+    assert_eq!(None, value);
+
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_get_missing_or_empty() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    let (missing, empty, _) = join!(
+        client.get::<_, Option<String>>("missing").into_future(),
+        client.get::<_, Option<String>>("empty").into_future(),
+        async {
+            server.receive_command().await;
+            server.reply("_\r\n");
+            server.receive_command().await;
+            server.reply("$0\r\n\r\n");
+        }
+    );
+    assert_eq!(None, missing?);
+    assert_eq!(Some("".to_owned()), empty?);
+
+    let (missing, empty, _) = join!(
+        client
+            .hget::<_, _, Option<String>>("hash", "missing")
+            .into_future(),
+        client.lindex::<_, Option<String>>("list", 0).into_future(),
+        async {
+            server.receive_command().await;
+            server.reply("_\r\n");
+            server.receive_command().await;
+            server.reply("$0\r\n\r\n");
+        }
+    );
+    assert_eq!(None, missing?);
+    assert_eq!(Some("".to_owned()), empty?);
+
+    let (missing, zero, _) = join!(
+        client.get::<_, Option<i64>>("missing").into_future(),
+        client.get::<_, Option<i64>>("zero").into_future(),
+        async {
+            server.receive_command().await;
+            server.reply("_\r\n");
+            server.receive_command().await;
+            server.reply("$1\r\n0\r\n");
+        }
+    );
+    assert_eq!(None, missing?);
+    assert_eq!(Some(0), zero?);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn getdel() -> Result<()> {
+    let client = get_test_client().await?;
+
+    // cleanup
+    client.del("key").await?;
+
+    client.set("key", "value").await?;
+    let value: Option<String> = client.getdel("key").await?;
+    assert_eq!(Some("value".to_owned()), value);
+
+    let value: Option<String> = client.getdel("key").await?;
+    assert_eq!(None, value);
+
+    client.close().await?;
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -235,6 +349,12 @@ async fn getrange() -> Result<()> {
     let value: String = client.getrange("key", 1, -3).await?;
     assert_eq!("al", value);
 
+    let value: String = client.getrange("key", -3, -1).await?;
+    assert_eq!("lue", value);
+
+    let value: String = client.getrange("unknown", 0, -1).await?;
+    assert_eq!("", value);
+
     client.close().await?;
 
     Ok(())
@@ -373,18 +493,36 @@ async fn lcs() -> Result<()> {
     let result = client.lcs_len("key1", "key2").await?;
     assert_eq!(6, result);
 
-    let result = client.lcs_idx("key1", "key2", None, false).await?;
+    let result = client
+        .lcs_idx("key1", "key2", LcsOptions::default())
+        .await?;
     assert_eq!(6, result.len);
     assert_eq!(2, result.matches.len());
     assert_eq!(LcsMatch((4, 7), (5, 8), None), result.matches[0]);
     assert_eq!(LcsMatch((2, 3), (0, 1), None), result.matches[1]);
 
-    let result = client.lcs_idx("key1", "key2", Some(4), false).await?;
+    let result = client
+        .lcs_idx(
+            "key1",
+            "key2",
+            LcsOptions::default().min_match_len(2).with_match_len(),
+        )
+        .await?;
+    assert_eq!(6, result.len);
+    assert_eq!(2, result.matches.len());
+    assert_eq!(LcsMatch((4, 7), (5, 8), Some(4)), result.matches[0]);
+    assert_eq!(LcsMatch((2, 3), (0, 1), Some(2)), result.matches[1]);
+
+    let result = client
+        .lcs_idx("key1", "key2", LcsOptions::default().min_match_len(4))
+        .await?;
     assert_eq!(6, result.len);
     assert_eq!(1, result.matches.len());
     assert_eq!(LcsMatch((4, 7), (5, 8), None), result.matches[0]);
 
-    let result = client.lcs_idx("key1", "key2", None, true).await?;
+    let result = client
+        .lcs_idx("key1", "key2", LcsOptions::default().with_match_len())
+        .await?;
     assert_eq!(6, result.len);
     assert_eq!(2, result.matches.len());
     assert_eq!(LcsMatch((4, 7), (5, 8), Some(4)), result.matches[0]);
@@ -420,6 +558,82 @@ async fn mget_mset() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn mget_mset_chunked() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let items: Vec<(String, usize)> = (0..50_000).map(|i| (format!("key{i}"), i)).collect();
+    client.mset_chunked(items, Some(1_000)).await?;
+    assert_eq!(50_000, client.dbsize().await?);
+
+    let mut keys: Vec<String> = (0..50_000).map(|i| format!("key{i}")).collect();
+    keys.push("unknown".to_owned());
+    let values: Vec<Option<usize>> = client.mget_chunked(keys, Some(1_000)).await?;
+    assert_eq!(50_001, values.len());
+    assert!(values[..50_000]
+        .iter()
+        .enumerate()
+        .all(|(i, value)| *value == Some(i)));
+    assert_eq!(None, values[50_000]);
+
+    client.flushdb(FlushingMode::Sync).await?;
+    client.close().await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_mget_mset_chunked() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let config = Config::builder().chunk_size(2).build()?;
+    let client = Client::connect_with_transport(transport, &config);
+
+    let (result, _) = join!(
+        client.mset_chunked(
+            [("key1", "value1"), ("key2", "value2"), ("key3", "value3")],
+            None
+        ),
+        async {
+            for num_args in [4, 2] {
+                let command = server.receive_command().await;
+                assert_eq!("MSET", command.name);
+                assert_eq!(num_args, command.args.len());
+                server.reply("+OK\r\n");
+            }
+        }
+    );
+    result?;
+
+    let (values, _) = join!(
+        client.mget_chunked::<_, _, String>(["key1", "key2", "key3", "key4", "key5"], Some(3)),
+        async {
+            let command = server.receive_command().await;
+            assert_eq!("MGET", command.name);
+            assert_eq!(3, command.args.len());
+            server.reply("*3\r\n$6\r\nvalue1\r\n$6\r\nvalue2\r\n$6\r\nvalue3\r\n");
+            let command = server.receive_command().await;
+            assert_eq!(2, command.args.len());
+            server.reply("*2\r\n_\r\n_\r\n");
+        }
+    );
+    assert_eq!(
+        vec![
+            Some("value1".to_owned()),
+            Some("value2".to_owned()),
+            Some("value3".to_owned()),
+            None,
+            None
+        ],
+        values?
+    );
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -607,6 +821,33 @@ async fn set_with_options() -> Result<()> {
     let value: String = client.get("key").await?;
     assert_eq!("value1", value);
 
+    // KEEPTTL
+    client.setex("key", 10, "value").await?;
+    let result = client
+        .set_with_options(
+            "key",
+            "value1",
+            SetCondition::None,
+            SetExpiration::None,
+            true,
+        )
+        .await?;
+    assert!(result);
+    let ttl = client.ttl("key").await?;
+    assert!(ttl > 0 && ttl <= 10);
+    let result = client
+        .set_with_options(
+            "key",
+            "value2",
+            SetCondition::None,
+            SetExpiration::None,
+            false,
+        )
+        .await?;
+    assert!(result);
+    let ttl = client.ttl("key").await?;
+    assert_eq!(-1, ttl);
+
     client.close().await?;
 
     Ok(())
@@ -671,6 +912,19 @@ async fn setrange() -> Result<()> {
     let value: String = client.get("key").await?;
     assert_eq!("Hello Redis", value);
 
+    // zero-padding
+    client.del("key").await?;
+    let new_len = client.setrange("key", 3, b"\x01\x02").await?;
+    assert_eq!(5, new_len);
+
+    let value: BulkString = client.get("key").await?;
+    assert_eq!(b"\0\0\0\x01\x02", value.as_bytes());
+
+    // patch a fixed-width record in place
+    client.setrange("key", 3, b"\xff").await?;
+    let value: BulkString = client.getrange("key", -2, -1).await?;
+    assert_eq!(b"\xff\x02", value.as_bytes());
+
     client.close().await?;
 
     Ok(())
@@ -0,0 +1,31 @@
+use crate::{
+    client::{BatchPreparedCommand, SyncClient, SyncPreparedCommand},
+    commands::{FlushingMode, ServerCommands, StringCommands},
+    resp::cmd,
+    tests::{get_default_addr, log_try_init},
+    Result,
+};
+use serial_test::serial;
+
+#[test]
+#[serial]
+fn sync_client() -> Result<()> {
+    log_try_init();
+    let client = SyncClient::connect(get_default_addr())?;
+    client.flushdb(FlushingMode::Sync).execute()?;
+
+    client.set("key", "value").execute()?;
+    let value: String = client.get("key").execute()?;
+    assert_eq!("value", value);
+
+    let value: String = client.send(cmd("GET").arg("key"), None)?.to()?;
+    assert_eq!("value", value);
+
+    let mut pipeline = client.client().create_pipeline();
+    pipeline.set("key2", "value2").forget();
+    pipeline.get::<_, String>("key2").queue();
+    let value: String = client.block_on(pipeline.execute())?;
+    assert_eq!("value2", value);
+
+    client.close()
+}
@@ -1,10 +1,11 @@
 use crate::{
-    client::BatchPreparedCommand,
+    client::{BatchPreparedCommand, Client, Config},
     commands::{FlushingMode, ListCommands, ServerCommands, StringCommands, TransactionCommands},
     resp::cmd,
-    tests::get_test_client,
+    tests::{get_test_client, mock_transport},
     Error, RedisError, RedisErrorKind, Result,
 };
+use futures_util::join;
 use serial_test::serial;
 
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
@@ -72,6 +73,25 @@ async fn transaction_error() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn transaction_wrong_arity() -> Result<()> {
+    let client = get_test_client().await?;
+
+    let mut transaction = client.create_transaction();
+
+    transaction.set("key1", "value1").forget();
+    transaction.get::<_, ()>("key1").queue();
+    transaction.get::<_, ()>("key1").queue();
+    transaction.get::<_, ()>("key1").queue();
+
+    let result: Result<(String, String)> = transaction.execute().await;
+    assert!(matches!(result, Err(Error::Client(e)) if e.contains("expected 2 results, queued 3")));
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -109,6 +129,134 @@ async fn watch() -> Result<()> {
     Ok(())
 }
 
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn transaction_watch() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+    client.set("key", 1).await?;
+
+    let mut transaction = client.create_transaction();
+    transaction.watch("key").await?;
+    let value: i32 = client.get("key").await?;
+    transaction.set("key", value + 1).queue();
+    transaction.execute::<()>().await?;
+    assert_eq!(2, client.get::<_, i32>("key").await?);
+
+    let mut transaction = client.create_transaction();
+    transaction.watch("key").await?;
+    let value: i32 = client.get("key").await?;
+
+    // set key on another client during the transaction
+    let client2 = get_test_client().await?;
+    client2.set("key", 10).await?;
+
+    transaction.set("key", value + 1).queue();
+    let result = transaction.execute::<()>().await;
+    assert!(matches!(result, Err(Error::Aborted)));
+    assert_eq!(10, client.get::<_, i32>("key").await?);
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_transaction_watch_aborted() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    let mut transaction = client.create_transaction();
+    let (result, _) = join!(transaction.watch("key"), async {
+        let command = server.receive_command().await;
+        assert_eq!("WATCH", command.name);
+        server.reply("+OK\r\n");
+    });
+    result?;
+
+    transaction.set("key", "value").queue();
+    let (result, _) = join!(transaction.execute::<()>(), async {
+        for (name, reply) in [
+            ("MULTI", "+OK\r\n"),
+            ("SET", "+QUEUED\r\n"),
+            // nil reply: a watched key has been modified
+            ("EXEC", "_\r\n"),
+        ] {
+            assert_eq!(name, server.receive_command().await.name);
+            server.reply(reply);
+        }
+    });
+    assert!(matches!(result, Err(Error::Aborted)));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_transaction_discard() -> Result<()> {
+    let (transport, mut server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    // without watched keys, nothing is sent
+    let mut transaction = client.create_transaction();
+    transaction.set("key", "value").queue();
+    transaction.discard().await?;
+
+    // watched keys are flushed by DISCARD
+    let mut transaction = client.create_transaction();
+    let (result, _) = join!(transaction.watch("key"), async {
+        assert_eq!("WATCH", server.receive_command().await.name);
+        server.reply("+OK\r\n");
+    });
+    result?;
+    transaction.set("key", "value").queue();
+    let (result, _) = join!(transaction.discard(), async {
+        assert_eq!("MULTI", server.receive_command().await.name);
+        assert_eq!("DISCARD", server.receive_command().await.name);
+        server.reply("+OK\r\n");
+        server.reply("+OK\r\n");
+    });
+    result?;
+
+    // DISCARD errors are returned
+    let mut transaction = client.create_transaction();
+    let (result, _) = join!(transaction.watch("key"), async {
+        assert_eq!("WATCH", server.receive_command().await.name);
+        server.reply("+OK\r\n");
+    });
+    result?;
+    let (result, _) = join!(transaction.discard(), async {
+        assert_eq!("MULTI", server.receive_command().await.name);
+        assert_eq!("DISCARD", server.receive_command().await.name);
+        server.reply("+OK\r\n");
+        server.reply("-ERR DISCARD without MULTI\r\n");
+    });
+    assert!(matches!(result, Err(Error::Redis(_))));
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+async fn mock_transaction_watch_misuse() -> Result<()> {
+    let (transport, _server) = mock_transport();
+    let client = Client::connect_with_transport(transport, &Config::default());
+
+    // WATCH cannot be sent inside MULTI
+    let mut transaction = client.create_transaction();
+    transaction.queue(cmd("WATCH").arg("key"));
+    let result = transaction.execute::<()>().await;
+    assert!(matches!(result, Err(Error::Client(_))));
+
+    // the whole shared connection would be watched
+    let clone = client.clone();
+    let mut transaction = clone.create_transaction();
+    let result = transaction.watch("key").await;
+    assert!(matches!(result, Err(Error::Client(_))));
+
+    Ok(())
+}
+
 #[cfg_attr(feature = "tokio-runtime", tokio::test)]
 #[cfg_attr(feature = "async-std-runtime", async_std::test)]
 #[serial]
@@ -160,3 +308,30 @@ async fn transaction_discard() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg_attr(feature = "tokio-runtime", tokio::test)]
+#[cfg_attr(feature = "async-std-runtime", async_std::test)]
+#[serial]
+async fn transaction_discard_then_execute() -> Result<()> {
+    let client = get_test_client().await?;
+    client.flushdb(FlushingMode::Sync).await?;
+
+    let mut transaction = client.create_transaction();
+    transaction.set("key1", "value1").forget();
+    transaction.set("key2", "value2").forget();
+    transaction.discard().await?;
+
+    let value: Option<String> = client.get("key1").await?;
+    assert_eq!(None, value);
+
+    let mut transaction = client.create_transaction();
+    transaction.set("key2", "value2").forget();
+    transaction.get::<_, ()>("key2").queue();
+    let value: String = transaction.execute().await?;
+    assert_eq!("value2", value);
+
+    let value: Option<String> = client.get("key1").await?;
+    assert_eq!(None, value);
+
+    Ok(())
+}
@@ -2,7 +2,7 @@ use crate::{
     commands::{GenericCommands, SetCommands},
     resp::Value,
     tests::{get_test_client, log_try_init},
-    RedisError, RedisErrorKind, Result,
+    Error, RedisError, RedisErrorKind, Result,
 };
 use serial_test::serial;
 use std::collections::{BTreeSet, HashMap, HashSet};
@@ -102,3 +102,59 @@ fn display() {
         ])
     );
 }
+
+#[test]
+fn accessors() {
+    let value = Value::Array(vec![
+        Value::Integer(12),
+        Value::BulkString(b"bulk".to_vec()),
+        Value::Nil,
+    ]);
+
+    let Some(values) = value.as_array() else {
+        panic!("array expected");
+    };
+    assert_eq!(Some(12), values[0].as_integer());
+    assert_eq!(None, values[0].as_bulk_string());
+    assert_eq!(Some(&b"bulk"[..]), values[1].as_bulk_string());
+    assert!(values[2].is_nil());
+    assert!(!values[2].is_error());
+
+    assert_eq!(
+        Some("OK"),
+        Value::SimpleString("OK".to_owned()).as_simple_string()
+    );
+    assert_eq!(Some(12.12), Value::Double(12.12).as_double());
+    assert_eq!(Some(true), Value::Boolean(true).as_bool());
+
+    let value = Value::Error(RedisError {
+        kind: RedisErrorKind::Err,
+        description: "error".to_owned(),
+    });
+    assert!(value.is_error());
+    assert_eq!("error", value.as_error().unwrap().description);
+    assert_eq!(None, value.as_array());
+}
+
+#[test]
+fn try_from() -> Result<()> {
+    assert_eq!(12, i64::try_from(Value::Integer(12))?);
+    assert_eq!(12.12, f64::try_from(Value::Double(12.12))?);
+    assert!(bool::try_from(Value::Boolean(true))?);
+    assert_eq!(
+        "OK",
+        String::try_from(Value::SimpleString("OK".to_owned()))?
+    );
+    assert_eq!(
+        "bulk",
+        String::try_from(Value::BulkString(b"bulk".to_vec()))?
+    );
+
+    let result = i64::try_from(Value::Error(RedisError {
+        kind: RedisErrorKind::Err,
+        description: "error".to_owned(),
+    }));
+    assert!(matches!(result, Err(Error::Redis(_))));
+
+    Ok(())
+}
@@ -41,6 +41,77 @@ fn double() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn boolean() -> Result<()> {
+    log_try_init();
+
+    let result = deserialize_value("#t\r\n")?; // true
+    assert_eq!(Value::Boolean(true), result);
+
+    let result = deserialize_value("#f\r\n")?; // false
+    assert_eq!(Value::Boolean(false), result);
+
+    Ok(())
+}
+
+#[test]
+fn big_number() -> Result<()> {
+    log_try_init();
+
+    let result = deserialize_value("(3492890328409238509324850943850943825024385\r\n")?;
+    assert_eq!(
+        Value::BigNumber("3492890328409238509324850943850943825024385".to_owned()),
+        result
+    );
+
+    let result = deserialize_value("(-12\r\n")?;
+    assert_eq!(Value::BigNumber("-12".to_owned()), result);
+    assert_eq!(-12, result.into::<i64>()?);
+
+    Ok(())
+}
+
+#[test]
+fn verbatim_string() -> Result<()> {
+    log_try_init();
+
+    let result = deserialize_value("=15\r\ntxt:Some string\r\n")?;
+    assert_eq!(
+        Value::VerbatimString {
+            format: "txt".to_owned(),
+            text: "Some string".to_owned()
+        },
+        result
+    );
+    assert_eq!("Some string", result.into::<String>()?);
+
+    let result = deserialize_value("=15\r\ntxt:Some string\r");
+    assert!(matches!(result, Err(Error::EOF)));
+
+    Ok(())
+}
+
+#[test]
+fn attribute() -> Result<()> {
+    log_try_init();
+
+    let result = deserialize_value(
+        "|1\r\n+key-popularity\r\n%2\r\n$1\r\na\r\n,0.1923\r\n$1\r\nb\r\n,0.0012\r\n*2\r\n:2039123\r\n:9543892\r\n",
+    )?;
+    assert_eq!(
+        Value::Array(vec![Value::Integer(2039123), Value::Integer(9543892)]),
+        result
+    );
+
+    let result = deserialize_value("*2\r\n|1\r\n+ttl\r\n:3600\r\n:1\r\n:2\r\n")?;
+    assert_eq!(
+        Value::Array(vec![Value::Integer(1), Value::Integer(2)]),
+        result
+    );
+
+    Ok(())
+}
+
 #[test]
 fn bulk_string() -> Result<()> {
     log_try_init();
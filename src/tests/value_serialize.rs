@@ -78,3 +78,33 @@ fn array() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn big_number() -> Result<()> {
+    log_try_init();
+
+    let resp_buf = serialize(Value::BigNumber(
+        "3492890328409238509324850943850943825024385".to_owned(),
+    ))?;
+    log::debug!("resp_buf: {resp_buf}");
+    assert_eq!(
+        b"(3492890328409238509324850943850943825024385\r\n",
+        resp_buf.as_bytes()
+    );
+
+    Ok(())
+}
+
+#[test]
+fn verbatim_string() -> Result<()> {
+    log_try_init();
+
+    let resp_buf = serialize(Value::VerbatimString {
+        format: "txt".to_owned(),
+        text: "Some string".to_owned(),
+    })?;
+    log::debug!("resp_buf: {resp_buf}");
+    assert_eq!(b"=15\r\ntxt:Some string\r\n", resp_buf.as_bytes());
+
+    Ok(())
+}